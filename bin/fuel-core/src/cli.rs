@@ -27,6 +27,10 @@ pub fn default_db_path() -> PathBuf {
     dirs::home_dir().unwrap().join(".fuel").join("db")
 }
 
+#[cfg(feature = "rocksdb")]
+pub mod compression;
+#[cfg(feature = "rocksdb")]
+pub mod db;
 pub mod fee_contract;
 #[cfg(feature = "rocksdb")]
 pub mod rollback;
@@ -57,6 +61,10 @@ pub enum Fuel {
     Snapshot(snapshot::Command),
     #[cfg(feature = "rocksdb")]
     Rollback(rollback::Command),
+    #[cfg(feature = "rocksdb")]
+    Db(db::Command),
+    #[cfg(feature = "rocksdb")]
+    Compression(compression::Command),
     GenerateFeeContract(fee_contract::Command),
 }
 
@@ -139,6 +147,10 @@ pub async fn run_cli() -> anyhow::Result<()> {
             Fuel::Snapshot(command) => snapshot::exec(command).await,
             Fuel::GenerateFeeContract(command) => fee_contract::exec(command).await,
             Fuel::Rollback(command) => rollback::exec(command).await,
+            #[cfg(feature = "rocksdb")]
+            Fuel::Db(command) => db::exec(command).await,
+            #[cfg(feature = "rocksdb")]
+            Fuel::Compression(command) => compression::exec(command).await,
         },
         Err(e) => {
             // Prints the error and exits.
@@ -276,6 +288,28 @@ mod tests {
             };
             assert_eq!(output_dir, PathBuf::from("./some/path"));
         }
+
+        #[test]
+        fn diff_base_is_as_given() {
+            // given
+            let line = "./core snapshot --output-directory ./target diff --base ./base";
+
+            // when
+            let command = parse_cli(line, "")
+                .expect("should parse the snapshot command")
+                .command;
+
+            // then
+            let Fuel::Snapshot(snapshot::Command {
+                subcommand: snapshot::SubCommands::Diff { base, limit },
+                ..
+            }) = command
+            else {
+                panic!("Expected a snapshot diff command")
+            };
+            assert_eq!(base, PathBuf::from("./base"));
+            assert_eq!(limit, 10);
+        }
     }
 
     mod snapshot_everything_tests {
@@ -638,6 +672,27 @@ mod tests {
             assert!(!db_prune);
         }
 
+        #[test]
+        fn can_ask_for_offchain_reindex() {
+            // given
+            let line = "./core run --reindex-offchain";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the run command")
+                .command;
+
+            // then
+            let super::Fuel::Run(run::Command {
+                reindex_offchain, ..
+            }) = command
+            else {
+                panic!("Expected a run command");
+            };
+
+            assert!(reindex_offchain);
+        }
+
         #[test]
         fn can_give_a_snapshot() {
             // given
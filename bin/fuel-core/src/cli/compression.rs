@@ -0,0 +1,459 @@
+use crate::cli::default_db_path;
+use anyhow::Context;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    database::{
+        database_description::da_decompression::DaDecompression,
+        Database,
+    },
+    fuel_core_graphql_api::da_compression::{
+        da_compress_block_with_stats,
+        da_decompress_block,
+        spent_coin_events,
+        DaCompressionStats,
+    },
+    service::da_import::compressed_block_path,
+    state::historical_rocksdb::StateRewindPolicy,
+};
+use fuel_core_compression::{
+    RegistryKeyspace,
+    VersionedCompressedBlock,
+};
+use fuel_core_storage::{
+    codec::{
+        postcard::Postcard,
+        Decode,
+        Encode,
+        Encoder,
+    },
+    tables::ConsensusParametersVersions,
+    transactional::{
+        AtomicView,
+        HistoricalView,
+        WriteTransaction,
+    },
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::{
+            Block,
+            PartialFuelBlock,
+        },
+        header::PartialBlockHeader,
+    },
+    fuel_tx::{
+        field::{
+            InputContract,
+            MintAmount,
+            MintAssetId,
+            MintGasPrice,
+        },
+        Transaction,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
+};
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+const KEYSPACES: [RegistryKeyspace; 5] = [
+    RegistryKeyspace::Address,
+    RegistryKeyspace::AssetId,
+    RegistryKeyspace::ContractId,
+    RegistryKeyspace::ScriptCode,
+    RegistryKeyspace::PredicateCode,
+];
+
+/// Compresses the blocks of the database with the DA compression
+/// and checks the compressed blocks without running the node.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = default_db_path().into_os_string()
+    )]
+    pub database_path: PathBuf,
+
+    /// The retention time of the temporal registry, the same as
+    /// the `--da-compression` argument of the node.
+    #[clap(long = "retention")]
+    pub retention: humantime::Duration,
+
+    /// The first block height of the range.
+    #[clap(long = "from")]
+    pub from: u32,
+
+    /// The last block height of the range.
+    #[clap(long = "to")]
+    pub to: u32,
+
+    /// The sub-command of the compression tooling.
+    #[command(subcommand)]
+    pub subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Compresses the blocks into postcard encoded `{height}.bin` files, starting
+    /// with an empty temporal registry, and prints the statistics of each block.
+    /// If the range starts right after the genesis block, the files can be
+    /// imported by a node with the `--da-import-directory` argument.
+    Compress {
+        /// The directory where to store the compressed blocks.
+        #[clap(long = "output")]
+        output: PathBuf,
+    },
+    /// Decompresses the `{height}.bin` files and compares the decompressed blocks
+    /// with the blocks in the database. The files must be compressed starting from
+    /// the same height. The database must have the state rewind history for the range.
+    Decompress {
+        /// The directory with the compressed blocks.
+        #[clap(long = "input")]
+        input: PathBuf,
+    },
+}
+
+fn open(path: &Path) -> anyhow::Result<CombinedDatabase> {
+    CombinedDatabase::open(path, 64 * 1024 * 1024, StateRewindPolicy::RewindFullRange)
+        .map_err(Into::<anyhow::Error>::into)
+        .context(format!("failed to open combined database at path {path:?}"))
+}
+
+fn full_block(db: &CombinedDatabase, height: &BlockHeight) -> anyhow::Result<Block> {
+    db.on_chain()
+        .latest_view()?
+        .get_full_block(height)?
+        .ok_or_else(|| anyhow::anyhow!("the block at the height {height} doesn't exist"))
+}
+
+fn add_stats(total: &mut DaCompressionStats, stats: &DaCompressionStats) {
+    total.raw_size = total.raw_size.saturating_add(stats.raw_size);
+    total.compressed_size = total.compressed_size.saturating_add(stats.compressed_size);
+    for keyspace in KEYSPACES {
+        total.registry.hits[keyspace] =
+            total.registry.hits[keyspace].saturating_add(stats.registry.hits[keyspace]);
+        total.registry.misses[keyspace] = total.registry.misses[keyspace]
+            .saturating_add(stats.registry.misses[keyspace]);
+        total.evictions[keyspace] =
+            total.evictions[keyspace].saturating_add(stats.evictions[keyspace]);
+    }
+}
+
+fn print_stats(title: &str, stats: &DaCompressionStats) {
+    println!(
+        "{title}: raw {} bytes, compressed {} bytes",
+        stats.raw_size, stats.compressed_size
+    );
+    for keyspace in KEYSPACES {
+        println!(
+            "  {keyspace:?}: hits {}, misses {}, evictions {}",
+            stats.registry.hits[keyspace],
+            stats.registry.misses[keyspace],
+            stats.evictions[keyspace]
+        );
+    }
+}
+
+fn compress(
+    db: &CombinedDatabase,
+    config: fuel_core_compression::Config,
+    heights: impl Iterator<Item = BlockHeight>,
+    output: &Path,
+) -> anyhow::Result<()> {
+    std::fs::create_dir_all(output)
+        .context(format!("failed to create the directory {output:?}"))?;
+
+    let mut registry = Database::<DaDecompression>::in_memory();
+    let mut total = DaCompressionStats::default();
+    for height in heights {
+        let block = full_block(db, &height)?;
+
+        let mut transaction = registry.write_transaction();
+        let (compressed, stats) = da_compress_block_with_stats(
+            config,
+            &block,
+            &spent_coin_events(&block),
+            &mut transaction,
+        )
+        .context(format!(
+            "failed to compress the block at the height {height}"
+        ))?;
+        transaction.commit()?;
+
+        let path = compressed_block_path(output, *height);
+        std::fs::write(&path, Postcard::encode(&compressed).as_bytes())
+            .context(format!("failed to write the compressed block to {path:?}"))?;
+
+        print_stats(&format!("Block {height}"), &stats);
+        add_stats(&mut total, &stats);
+    }
+    print_stats("Total", &total);
+
+    Ok(())
+}
+
+fn chain_id(db: &CombinedDatabase, block: &Block) -> anyhow::Result<ChainId> {
+    let version = block.header().application().consensus_parameters_version;
+    let consensus_parameters = db
+        .on_chain()
+        .storage_as_ref::<ConsensusParametersVersions>()
+        .get(&version)?
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "the consensus parameters of the version {version} don't exist"
+            )
+        })?;
+    Ok(consensus_parameters.chain_id())
+}
+
+/// Returns the differences between the decompressed and the original blocks.
+/// The fields that the DA compression doesn't preserve are filled by the executor,
+/// so the transactions are compared by their ids, which don't include such fields.
+/// Only the fields of the mint transaction that are compressed are compared.
+fn block_differences(
+    original: &Block,
+    decompressed: &PartialFuelBlock,
+    chain_id: &ChainId,
+) -> Vec<String> {
+    let mut differences = vec![];
+    if PartialBlockHeader::from(original.header()) != decompressed.header {
+        differences.push("the header is different".to_string());
+    }
+
+    let original_transactions = original.transactions();
+    if original_transactions.len() != decompressed.transactions.len() {
+        differences.push(format!(
+            "{} transactions instead of {}",
+            decompressed.transactions.len(),
+            original_transactions.len()
+        ));
+        return differences
+    }
+
+    for (index, (original, decompressed)) in original_transactions
+        .iter()
+        .zip(decompressed.transactions.iter())
+        .enumerate()
+    {
+        let matches = match (original, decompressed) {
+            (Transaction::Mint(original), Transaction::Mint(decompressed)) => {
+                original.input_contract().contract_id
+                    == decompressed.input_contract().contract_id
+                    && original.mint_amount() == decompressed.mint_amount()
+                    && original.mint_asset_id() == decompressed.mint_asset_id()
+                    && original.gas_price() == decompressed.gas_price()
+            }
+            (original, decompressed) => {
+                original.id(chain_id) == decompressed.id(chain_id)
+            }
+        };
+        if !matches {
+            differences.push(format!("the transaction {index} is different"));
+        }
+    }
+
+    differences
+}
+
+fn decompress(
+    db: &CombinedDatabase,
+    config: fuel_core_compression::Config,
+    heights: impl Iterator<Item = BlockHeight>,
+    input: &Path,
+) -> anyhow::Result<()> {
+    let mut registry = Database::<DaDecompression>::in_memory();
+    let mut blocks = 0usize;
+    let mut mismatched_blocks = 0usize;
+    for height in heights {
+        let path = compressed_block_path(input, *height);
+        let bytes = std::fs::read(&path)
+            .context(format!("failed to read the compressed block from {path:?}"))?;
+        let compressed: VersionedCompressedBlock = Postcard::decode(&bytes)
+            .context(format!("failed to decode the compressed block {path:?}"))?;
+
+        let previous_height = height.pred().ok_or_else(|| {
+            anyhow::anyhow!("the block at the height 0 can't be decompressed")
+        })?;
+        let on_chain_view = db.on_chain().view_at(&previous_height).context(format!(
+            "the state at the height {previous_height} is not available"
+        ))?;
+
+        let mut transaction = registry.write_transaction();
+        let decompressed =
+            da_decompress_block(config, compressed, on_chain_view, &mut transaction)
+                .context(format!(
+                    "failed to decompress the block at the height {height}"
+                ))?;
+        transaction.commit()?;
+
+        let original = full_block(db, &height)?;
+        let differences =
+            block_differences(&original, &decompressed, &chain_id(db, &original)?);
+        if differences.is_empty() {
+            println!("Block {height}: matches the original block");
+        } else {
+            println!("Block {height}: {}", differences.join(", "));
+            mismatched_blocks = mismatched_blocks.saturating_add(1);
+        }
+        blocks = blocks.saturating_add(1);
+    }
+
+    anyhow::ensure!(
+        mismatched_blocks == 0,
+        "{mismatched_blocks} of {blocks} decompressed blocks don't match the original blocks"
+    );
+    Ok(())
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        command.from <= command.to,
+        "the first height {} is greater than the last height {}",
+        command.from,
+        command.to
+    );
+
+    let db = open(command.database_path.as_path())?;
+    let config = fuel_core_compression::Config {
+        temporal_registry_retention: command.retention.into(),
+    };
+    let heights = (command.from..=command.to).map(BlockHeight::new);
+
+    match command.subcommand {
+        SubCommands::Compress { output } => compress(&db, config, heights, &output),
+        SubCommands::Decompress { input } => decompress(&db, config, heights, &input),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use fuel_core::service::{
+        Config,
+        FuelService,
+    };
+    use fuel_core_poa::service::Mode;
+    use tempfile::TempDir;
+
+    const BLOCKS: u32 = 5;
+
+    async fn produce_blocks(path: &Path) {
+        let db = open(path).unwrap();
+        let service = FuelService::from_combined_database(db, Config::local_node())
+            .await
+            .unwrap();
+        service
+            .shared
+            .poa_adapter
+            .manually_produce_blocks(
+                None,
+                Mode::Blocks {
+                    number_of_blocks: BLOCKS,
+                },
+            )
+            .await
+            .unwrap();
+        service.send_stop_signal_and_await_shutdown().await.unwrap();
+    }
+
+    fn command(path: &Path, subcommand: SubCommands) -> Command {
+        Command {
+            database_path: path.to_path_buf(),
+            retention: std::time::Duration::from_secs(3600).into(),
+            from: 1,
+            to: BLOCKS,
+            subcommand,
+        }
+    }
+
+    #[tokio::test]
+    async fn compress__writes_compressed_blocks_of_the_range() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+        let output_dir = TempDir::new().unwrap();
+        produce_blocks(db_dir.path()).await;
+
+        // When
+        exec(command(
+            db_dir.path(),
+            SubCommands::Compress {
+                output: output_dir.path().to_path_buf(),
+            },
+        ))
+        .await
+        .unwrap();
+
+        // Then
+        for height in 1..=BLOCKS {
+            let bytes =
+                std::fs::read(compressed_block_path(output_dir.path(), height)).unwrap();
+            let block: VersionedCompressedBlock = Postcard::decode(&bytes).unwrap();
+            let VersionedCompressedBlock::V0(block) = block;
+            assert_eq!(block.header.consensus.height, height.into());
+        }
+        assert!(!compressed_block_path(output_dir.path(), 0).exists());
+    }
+
+    #[tokio::test]
+    async fn decompress__decompressed_blocks_match_original_blocks() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+        let compressed_dir = TempDir::new().unwrap();
+        produce_blocks(db_dir.path()).await;
+        exec(command(
+            db_dir.path(),
+            SubCommands::Compress {
+                output: compressed_dir.path().to_path_buf(),
+            },
+        ))
+        .await
+        .unwrap();
+
+        // When
+        let result = exec(command(
+            db_dir.path(),
+            SubCommands::Decompress {
+                input: compressed_dir.path().to_path_buf(),
+            },
+        ))
+        .await;
+
+        // Then
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn decompress__fails_if_compressed_block_is_missing() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+        let compressed_dir = TempDir::new().unwrap();
+        produce_blocks(db_dir.path()).await;
+
+        // When
+        let result = exec(command(
+            db_dir.path(),
+            SubCommands::Decompress {
+                input: compressed_dir.path().to_path_buf(),
+            },
+        ))
+        .await;
+
+        // Then
+        assert!(result.is_err());
+    }
+}
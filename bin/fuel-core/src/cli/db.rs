@@ -0,0 +1,370 @@
+use crate::cli::default_db_path;
+use anyhow::Context;
+use clap::{
+    Parser,
+    Subcommand,
+    ValueEnum,
+};
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    database::{
+        database_description::DatabaseDescription,
+        inspection::DecodeEntry,
+        Database,
+    },
+    state::historical_rocksdb::StateRewindPolicy,
+};
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+/// Manages the database of the node.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = default_db_path().into_os_string()
+    )]
+    pub database_path: PathBuf,
+
+    /// The sub-command of the database operation.
+    #[command(subcommand)]
+    pub subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Creates a consistent backup of all databases of the node.
+    /// The database can't be opened while the node is running,
+    /// use the `createDatabaseCheckpoint` admin GraphQL mutation
+    /// to back up a running node.
+    Backup {
+        /// The directory where to store the backup. It must be empty or not exist.
+        #[clap(long = "to")]
+        to: PathBuf,
+    },
+    /// Restores all databases of the node from the backup.
+    /// The database path must not contain any databases.
+    Restore {
+        /// The directory with the backup.
+        #[clap(long = "from")]
+        from: PathBuf,
+    },
+    /// Prints the number of keys and the size of keys and values of each column.
+    Columns {
+        /// The database to inspect. All databases are inspected if not specified.
+        #[clap(long = "database", value_enum)]
+        database: Option<DatabaseKind>,
+    },
+    /// Reads the entry from the column and decodes it with the codecs of the table.
+    Get {
+        /// The database that contains the column.
+        #[clap(long = "database", value_enum)]
+        database: DatabaseKind,
+        /// The name of the column.
+        #[clap(long = "column")]
+        column: String,
+        /// The hex encoded key of the entry.
+        #[clap(long = "key")]
+        key: String,
+    },
+    /// Removes the off-chain database and marks it to be rebuilt from the on-chain
+    /// database on the next start of the node. The node must be started with the
+    /// snapshot used for the genesis and the state rewind history for all blocks.
+    /// The journal and the blacklist changes of the transaction pool are kept.
+    ReindexOffchain,
+    /// Checks the version of the databases and that the off-chain and
+    /// gas price databases are not ahead of the on-chain database.
+    Check {
+        /// Rolls back the databases that are ahead of the on-chain database.
+        #[clap(long = "repair")]
+        repair: bool,
+    },
+}
+
+/// The database of the node.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum DatabaseKind {
+    OnChain,
+    OffChain,
+    Relayer,
+    GasPrice,
+}
+
+fn open(path: &Path) -> anyhow::Result<CombinedDatabase> {
+    CombinedDatabase::open(path, 64 * 1024 * 1024, StateRewindPolicy::RewindFullRange)
+        .map_err(Into::<anyhow::Error>::into)
+        .context(format!("failed to open combined database at path {path:?}"))
+}
+
+fn print_columns<Description>(db: &Database<Description>) -> anyhow::Result<()>
+where
+    Description: DatabaseDescription,
+{
+    println!("{}:", Description::name());
+    for statistics in db.column_statistics()? {
+        println!(
+            "  {}: keys {}, keys size {} bytes, values size {} bytes",
+            statistics.name,
+            statistics.keys,
+            statistics.keys_size,
+            statistics.values_size
+        );
+    }
+    Ok(())
+}
+
+fn print_entry<Description>(
+    db: &Database<Description>,
+    column: &str,
+    key: &[u8],
+) -> anyhow::Result<()>
+where
+    Description: DecodeEntry,
+{
+    let column = Database::<Description>::column_by_name(column).ok_or_else(|| {
+        anyhow::anyhow!(
+            "the column `{column}` doesn't exist in the `{}` database",
+            Description::name()
+        )
+    })?;
+    let entry = db
+        .inspect_entry(column, key)?
+        .ok_or_else(|| anyhow::anyhow!("the entry doesn't exist"))?;
+
+    println!("raw value: 0x{}", hex::encode(&entry.raw_value));
+    match entry.decoded {
+        Some(Ok(decoded)) => {
+            println!("key: {}", decoded.key);
+            println!("value: {}", decoded.value);
+        }
+        Some(Err(err)) => println!("failed to decode the entry: {err:?}"),
+        None => println!("the table of the column is unknown"),
+    }
+    Ok(())
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let path = command.database_path.as_path();
+
+    match command.subcommand {
+        SubCommands::Backup { to } => {
+            let db = open(path)?;
+            let height = db
+                .checkpoint(&to)
+                .context(format!("failed to create the backup at path {to:?}"))?;
+            tracing::info!("Created the backup at {to:?} with the height {height:?}");
+        }
+        SubCommands::Restore { from } => {
+            let height = CombinedDatabase::restore(&from, path).context(format!(
+                "failed to restore the database from the backup at path {from:?}"
+            ))?;
+            tracing::info!(
+                "Restored the database at {path:?} with the height {height:?}"
+            );
+        }
+        SubCommands::Columns { database } => {
+            let db = open(path)?;
+            let all = database.is_none();
+            if all || database == Some(DatabaseKind::OnChain) {
+                print_columns(db.on_chain())?;
+            }
+            if all || database == Some(DatabaseKind::OffChain) {
+                print_columns(db.off_chain())?;
+            }
+            if all || database == Some(DatabaseKind::Relayer) {
+                print_columns(db.relayer())?;
+            }
+            if all || database == Some(DatabaseKind::GasPrice) {
+                print_columns(db.gas_price())?;
+            }
+        }
+        SubCommands::Get {
+            database,
+            column,
+            key,
+        } => {
+            let key = hex::decode(key.trim_start_matches("0x"))
+                .context("the key is not a valid hex")?;
+            let db = open(path)?;
+            match database {
+                DatabaseKind::OnChain => print_entry(db.on_chain(), &column, &key)?,
+                DatabaseKind::OffChain => print_entry(db.off_chain(), &column, &key)?,
+                DatabaseKind::Relayer => print_entry(db.relayer(), &column, &key)?,
+                DatabaseKind::GasPrice => print_entry(db.gas_price(), &column, &key)?,
+            }
+        }
+        SubCommands::ReindexOffchain => {
+            CombinedDatabase::reindex_off_chain(path).context(format!(
+                "failed to reset the off-chain database at path {path:?}"
+            ))?;
+            tracing::info!(
+                "The off-chain database at {path:?} will be rebuilt on the next start"
+            );
+        }
+        SubCommands::Check { repair } => {
+            use crate::cli::ShutdownListener;
+
+            let db = open(path)?;
+            db.check_version()?;
+            let heights = db.heights()?;
+            println!("{heights:#?}");
+
+            if !heights.is_consistent() {
+                if !repair {
+                    anyhow::bail!(
+                        "the off-chain or gas price database is ahead of the on-chain database, \
+                        use `--repair` to roll them back"
+                    );
+                }
+                db.sync_aux_db_heights(&mut ShutdownListener::spawn())?;
+                println!("{:#?}", db.heights()?);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use fuel_core::database::{
+        database_description::on_chain::OnChain,
+        Database,
+    };
+    use fuel_core_storage::{
+        tables::FuelBlocks,
+        StorageAsMut,
+        StorageAsRef,
+    };
+    use fuel_core_types::{
+        blockchain::block::CompressedBlock,
+        fuel_types::BlockHeight,
+    };
+    use tempfile::TempDir;
+
+    fn command(database_path: &std::path::Path, subcommand: SubCommands) -> Command {
+        Command {
+            database_path: database_path.to_path_buf(),
+            subcommand,
+        }
+    }
+
+    #[tokio::test]
+    async fn restore__restores_database_from_backup() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+        let backup_dir = TempDir::new().unwrap();
+        let restored_dir = TempDir::new().unwrap();
+        let height = BlockHeight::new(0);
+        {
+            let mut db = CombinedDatabase::open(
+                db_dir.path(),
+                64 * 1024 * 1024,
+                StateRewindPolicy::NoRewind,
+            )
+            .unwrap();
+            db.on_chain_mut()
+                .storage_as_mut::<FuelBlocks>()
+                .insert(&height, &CompressedBlock::default())
+                .unwrap();
+        }
+        exec(command(
+            db_dir.path(),
+            SubCommands::Backup {
+                to: backup_dir.path().to_path_buf(),
+            },
+        ))
+        .await
+        .unwrap();
+
+        // When
+        exec(command(
+            restored_dir.path(),
+            SubCommands::Restore {
+                from: backup_dir.path().to_path_buf(),
+            },
+        ))
+        .await
+        .unwrap();
+
+        // Then
+        let restored = Database::<OnChain>::open_rocksdb(
+            restored_dir.path(),
+            None,
+            StateRewindPolicy::NoRewind,
+        )
+        .unwrap();
+        assert_eq!(
+            restored.latest_height_from_metadata().unwrap(),
+            Some(height)
+        );
+        let block = restored
+            .storage_as_ref::<FuelBlocks>()
+            .get(&height)
+            .unwrap();
+        assert!(block.is_some());
+    }
+
+    #[tokio::test]
+    async fn backup__fails_if_target_directory_is_not_empty() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+        let backup_dir = TempDir::new().unwrap();
+        std::fs::write(backup_dir.path().join("file"), b"data").unwrap();
+
+        // When
+        let result = exec(command(
+            db_dir.path(),
+            SubCommands::Backup {
+                to: backup_dir.path().to_path_buf(),
+            },
+        ))
+        .await;
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn check__succeeds_for_consistent_database() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+        open(db_dir.path()).unwrap();
+
+        // When
+        let result =
+            exec(command(db_dir.path(), SubCommands::Check { repair: false })).await;
+
+        // Then
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn get__fails_for_unknown_column() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+
+        // When
+        let result = exec(command(
+            db_dir.path(),
+            SubCommands::Get {
+                database: DatabaseKind::OnChain,
+                column: "UnknownColumn".to_string(),
+                key: "0x00".to_string(),
+            },
+        ))
+        .await;
+
+        // Then
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("UnknownColumn"), "{err:?}");
+    }
+}
@@ -1,8 +1,15 @@
-use crate::cli::default_db_path;
+use crate::cli::{
+    db::DatabaseKind,
+    default_db_path,
+};
 use anyhow::Context;
 use clap::Parser;
 use fuel_core::{
-    combined_database::CombinedDatabase,
+    combined_database::{
+        CombinedDatabase,
+        RollbackDatabase,
+        RollbackPlan,
+    },
     state::historical_rocksdb::StateRewindPolicy,
 };
 use std::path::PathBuf;
@@ -19,14 +26,72 @@ pub struct Command {
     )]
     pub database_path: PathBuf,
 
-    /// The path to the database.
+    /// The height of the block to roll back to.
     #[clap(long = "target-block-height")]
     pub target_block_height: u32,
+
+    /// The databases to roll back. The relayer database follows the DA layer
+    /// and can't be rolled back. If only the off-chain and gas price databases
+    /// are rolled back, the node processes the rolled back blocks again on start.
+    /// If they are not rolled back with the on-chain database, the node rolls
+    /// them back to the on-chain height on start.
+    #[clap(
+        long = "databases",
+        value_enum,
+        value_delimiter = ',',
+        default_values_t = [DatabaseKind::OnChain, DatabaseKind::OffChain, DatabaseKind::GasPrice]
+    )]
+    pub databases: Vec<DatabaseKind>,
+
+    /// Prints the heights of the databases, the available rewind window,
+    /// and the number of blocks to roll back without modifying the databases.
+    #[clap(long = "dry-run")]
+    pub dry_run: bool,
+}
+
+fn print_plan(plan: &RollbackPlan) {
+    println!("Rollback to the height {}:", plan.target_height);
+    for database in plan.databases.iter() {
+        let rewind_window = match (database.oldest_changes_height, database.height) {
+            (Some(oldest), Some(height)) => {
+                format!("{}..={height}", oldest.saturating_sub(1))
+            }
+            _ => "none".to_string(),
+        };
+        println!(
+            "  {:?}: height {:?}, rewind window {rewind_window}, blocks to roll back {}{}",
+            database.database,
+            database.height,
+            database.blocks_to_rollback,
+            if database.is_possible() {
+                ""
+            } else {
+                " (not enough history)"
+            }
+        );
+    }
+    println!(
+        "  Relayer: height {:?}, not rolled back",
+        plan.relayer_height
+    );
 }
 
 pub async fn exec(command: Command) -> anyhow::Result<()> {
     use crate::cli::ShutdownListener;
 
+    let databases = command
+        .databases
+        .iter()
+        .map(|database| match database {
+            DatabaseKind::OnChain => Ok(RollbackDatabase::OnChain),
+            DatabaseKind::OffChain => Ok(RollbackDatabase::OffChain),
+            DatabaseKind::GasPrice => Ok(RollbackDatabase::GasPrice),
+            DatabaseKind::Relayer => {
+                Err(anyhow::anyhow!("the relayer database can't be rolled back"))
+            }
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
     let path = command.database_path.as_path();
     let db = CombinedDatabase::open(
         path,
@@ -36,10 +101,147 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     .map_err(Into::<anyhow::Error>::into)
     .context(format!("failed to open combined database at path {path:?}"))?;
 
-    let mut shutdown_listener = ShutdownListener::spawn();
     let target_block_height = command.target_block_height.into();
+    let plan = db.rollback_plan(target_block_height, &databases)?;
+    print_plan(&plan);
+    if databases.contains(&RollbackDatabase::OnChain) {
+        for database in [RollbackDatabase::OffChain, RollbackDatabase::GasPrice] {
+            if !databases.contains(&database) {
+                println!(
+                    "  {database:?}: not rolled back, the node rolls it back \
+                    to the on-chain height on startup"
+                );
+            }
+        }
+    }
 
-    db.rollback_to(target_block_height, &mut shutdown_listener)?;
+    if command.dry_run {
+        return Ok(())
+    }
+
+    let mut shutdown_listener = ShutdownListener::spawn();
+    db.rollback_databases_to(target_block_height, &databases, &mut shutdown_listener)?;
+
+    db.check_integrity()
+        .context("the on-chain database is corrupted after the rollback")?;
+    println!("{:#?}", db.heights()?);
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use fuel_core::service::{
+        Config,
+        FuelService,
+    };
+    use fuel_core_poa::service::Mode;
+    use fuel_core_storage::transactional::HistoricalView;
+    use fuel_core_types::fuel_types::BlockHeight;
+    use tempfile::TempDir;
+
+    const BLOCKS: u32 = 5;
+
+    async fn produce_blocks(path: &std::path::Path) {
+        let db = CombinedDatabase::open(
+            path,
+            64 * 1024 * 1024,
+            StateRewindPolicy::RewindFullRange,
+        )
+        .unwrap();
+        let service = FuelService::from_combined_database(db, Config::local_node())
+            .await
+            .unwrap();
+        service
+            .shared
+            .poa_adapter
+            .manually_produce_blocks(
+                None,
+                Mode::Blocks {
+                    number_of_blocks: BLOCKS,
+                },
+            )
+            .await
+            .unwrap();
+        service.send_stop_signal_and_await_shutdown().await.unwrap();
+    }
+
+    fn command(path: &std::path::Path, databases: Vec<DatabaseKind>) -> Command {
+        Command {
+            database_path: path.to_path_buf(),
+            target_block_height: 2,
+            databases,
+            dry_run: false,
+        }
+    }
+
+    fn open(path: &std::path::Path) -> CombinedDatabase {
+        CombinedDatabase::open(path, 64 * 1024 * 1024, StateRewindPolicy::NoRewind)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn exec__dry_run_doesnt_modify_databases() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+        produce_blocks(db_dir.path()).await;
+        let mut command = command(
+            db_dir.path(),
+            vec![
+                DatabaseKind::OnChain,
+                DatabaseKind::OffChain,
+                DatabaseKind::GasPrice,
+            ],
+        );
+        command.dry_run = true;
+
+        // When
+        exec(command).await.unwrap();
+
+        // Then
+        let db = open(db_dir.path());
+        assert_eq!(
+            db.on_chain().latest_height(),
+            Some(BlockHeight::new(BLOCKS))
+        );
+        assert_eq!(
+            db.off_chain().latest_height(),
+            Some(BlockHeight::new(BLOCKS))
+        );
+    }
+
+    #[tokio::test]
+    async fn exec__rolls_back_only_selected_databases() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+        produce_blocks(db_dir.path()).await;
+
+        // When
+        exec(command(db_dir.path(), vec![DatabaseKind::OffChain]))
+            .await
+            .unwrap();
+
+        // Then
+        let db = open(db_dir.path());
+        assert_eq!(
+            db.on_chain().latest_height(),
+            Some(BlockHeight::new(BLOCKS))
+        );
+        assert_eq!(db.off_chain().latest_height(), Some(BlockHeight::new(2)));
+    }
+
+    #[tokio::test]
+    async fn exec__fails_to_roll_back_relayer_database() {
+        // Given
+        let db_dir = TempDir::new().unwrap();
+
+        // When
+        let result = exec(command(db_dir.path(), vec![DatabaseKind::Relayer])).await;
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -5,7 +5,10 @@ use crate::{
         default_db_path,
         run::{
             consensus::PoATriggerArgs,
+            da_import::DaImportArgs,
+            gas_price::GasPriceArgs,
             graphql::GraphQLArgs,
+            pruning::PruningArgs,
             tx_pool::TxPoolArgs,
         },
         ShutdownListener,
@@ -37,6 +40,14 @@ use fuel_core::{
         config::BlackList,
         Config as TxPoolConfig,
     },
+    txpool_v2::{
+        config::{
+            BlackList as BlackListV2,
+            PoolLimits,
+            SelectionConfig,
+        },
+        Config as TxPoolV2Config,
+    },
     types::{
         fuel_tx::ContractId,
         fuel_vm::SecretKey,
@@ -79,8 +90,11 @@ use super::DEFAULT_DATABASE_CACHE_SIZE;
 mod p2p;
 
 mod consensus;
+mod da_import;
+mod gas_price;
 mod graphql;
 mod profiling;
+mod pruning;
 #[cfg(feature = "relayer")]
 mod relayer;
 mod tx_pool;
@@ -141,6 +155,22 @@ pub struct Command {
     #[arg(name = "DB_PRUNE", long = "db-prune", env, default_value = "false")]
     pub db_prune: bool,
 
+    /// Removes the off-chain database and rebuilds it from the on-chain database.
+    /// The off-chain part of the genesis state is imported from the snapshot
+    /// used for the genesis, and all blocks are re-executed to index them again.
+    /// The re-execution requires the state rewind history for all blocks,
+    /// see `--state-rewind-duration`, and fails if old blocks were pruned.
+    /// The journal and the blacklist changes of the transaction pool are kept.
+    /// If the node is stopped before the rebuild is finished, it continues
+    /// on the next start without this flag.
+    #[arg(
+        name = "REINDEX_OFFCHAIN",
+        long = "reindex-offchain",
+        env,
+        default_value = "false"
+    )]
+    pub reindex_offchain: bool,
+
     /// The determines whether to continue the services on internal error or not.
     #[clap(long = "continue-services-on-error", default_value = "false", env)]
     pub continue_on_error: bool,
@@ -181,6 +211,10 @@ pub struct Command {
     #[arg(long = "gas-price-threshold-percent", default_value = "50", env)]
     pub gas_price_threshold_percent: u64,
 
+    /// The cli arguments supported by the gas price service.
+    #[clap(flatten)]
+    pub gas_price: GasPriceArgs,
+
     /// The signing key used when producing blocks.
     /// Setting via the `CONSENSUS_KEY_SECRET` ENV var is preferred.
     #[arg(long = "consensus-key", env = "CONSENSUS_KEY_SECRET")]
@@ -198,6 +232,14 @@ pub struct Command {
     #[arg(long = "da-compression", env)]
     pub da_compression: Option<humantime::Duration>,
 
+    /// The cli arguments supported by the import of blocks from the DA compressed blocks.
+    #[clap(flatten)]
+    pub da_import: DaImportArgs,
+
+    /// The cli arguments supported by the pruning service.
+    #[clap(flatten)]
+    pub pruning: PruningArgs,
+
     /// A new block is produced instantly when transactions are available.
     #[clap(flatten)]
     pub poa_trigger: PoATriggerArgs,
@@ -267,6 +309,7 @@ impl Command {
             #[cfg(feature = "rocksdb")]
             state_rewind_duration,
             db_prune,
+            reindex_offchain,
             snapshot,
             continue_on_error,
             vm_backtrace,
@@ -277,10 +320,13 @@ impl Command {
             gas_price_change_percent,
             min_gas_price,
             gas_price_threshold_percent,
+            gas_price,
             consensus_key,
             #[cfg(feature = "aws-kms")]
             consensus_aws_kms,
             da_compression,
+            da_import,
+            pruning,
             poa_trigger,
             predefined_blocks_path,
             coinbase_recipient,
@@ -437,6 +483,7 @@ impl Command {
         };
 
         let TxPoolArgs {
+            tx_pool_v2,
             tx_pool_ttl,
             tx_max_number,
             tx_max_depth,
@@ -445,8 +492,38 @@ impl Command {
             tx_blacklist_coins,
             tx_blacklist_messages,
             tx_blacklist_contracts,
+            tx_selection_strategy,
+            tx_max_per_owner_per_block,
+            tx_min_tip_bump_percent,
+            tx_pool_persist,
+            tx_blacklist_persist,
         } = tx_pool;
 
+        let txpool_v2 = tx_pool_v2.then(|| TxPoolV2Config {
+            utxo_validation,
+            max_txs_chain_count: tx_max_depth,
+            pool_limits: PoolLimits {
+                max_txs: tx_max_number,
+                ..TxPoolV2Config::default().pool_limits
+            },
+            max_txs_ttl: tx_pool_ttl.into(),
+            black_list: BlackListV2::new(
+                tx_blacklist_addresses.clone(),
+                tx_blacklist_coins.clone(),
+                tx_blacklist_messages.clone(),
+                tx_blacklist_contracts.clone(),
+            ),
+            selection: SelectionConfig {
+                strategy: tx_selection_strategy.into(),
+                max_txs_per_owner: tx_max_per_owner_per_block,
+            },
+            min_tip_bump_percent: tx_min_tip_bump_percent,
+            metrics,
+            persist_transactions: tx_pool_persist,
+            persist_black_list: tx_blacklist_persist,
+            ..TxPoolV2Config::default()
+        });
+
         let blacklist = BlackList::new(
             tx_blacklist_addresses,
             tx_blacklist_coins,
@@ -464,6 +541,9 @@ impl Command {
                 request_body_bytes_limit: graphql.graphql_request_body_bytes_limit,
                 api_request_timeout: graphql.api_request_timeout.into(),
                 query_log_threshold_time: graphql.query_log_threshold_time.into(),
+                admin_token: graphql.graphql_admin_token,
+                health_max_block_lag: graphql.health_max_block_lag,
+                health_max_da_lag: graphql.health_max_da_lag,
             },
             combined_db_config,
             snapshot_reader,
@@ -485,6 +565,7 @@ impl Command {
                 tx_number_active_subscriptions,
                 blacklist,
             ),
+            txpool_v2,
             block_producer: ProducerConfig {
                 coinbase_recipient,
                 metrics,
@@ -493,8 +574,11 @@ impl Command {
             gas_price_change_percent,
             min_gas_price,
             gas_price_threshold_percent,
+            gas_price_v1: gas_price.v1_config()?,
             block_importer,
             da_compression,
+            da_import: da_import.config(),
+            pruning: pruning.config()?,
             #[cfg(feature = "relayer")]
             relayer: relayer_cfg,
             #[cfg(feature = "p2p")]
@@ -520,6 +604,13 @@ pub async fn get_service_with_shutdown_listeners(
         fuel_core::combined_database::CombinedDatabase::prune(&command.database_path)?;
     }
 
+    #[cfg(feature = "rocksdb")]
+    if command.reindex_offchain && command.database_path.exists() {
+        fuel_core::combined_database::CombinedDatabase::reindex_off_chain(
+            &command.database_path,
+        )?;
+    }
+
     let profiling = command.profiling.clone();
     let config = command.get_config().await?;
 
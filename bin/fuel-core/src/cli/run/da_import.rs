@@ -0,0 +1,125 @@
+//! Clap configuration related to the import of blocks from the DA compressed blocks.
+
+use fuel_core::service::da_import::{
+    DaImportConfig,
+    DaImportSource,
+};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct DaImportArgs {
+    /// If set, the node rebuilds the chain from the DA compressed blocks stored
+    /// in the directory as postcard encoded `{height}.bin` files.
+    /// The block production is disabled, and the P2P sync should be disabled too.
+    #[clap(
+        long = "da-import-directory",
+        env,
+        conflicts_with = "da_import_url",
+        requires = "da_import_retention"
+    )]
+    pub da_import_directory: Option<PathBuf>,
+
+    /// If set, the node rebuilds the chain from the DA compressed blocks
+    /// served by the GraphQL API of another node with the given URL.
+    /// The block production is disabled, and the P2P sync should be disabled too.
+    #[clap(long = "da-import-url", env, requires = "da_import_retention")]
+    pub da_import_url: Option<String>,
+
+    /// The retention time of the temporal registry used by the node that
+    /// compressed the blocks, the same as its `--da-compression` argument.
+    #[clap(long = "da-import-retention", env)]
+    pub da_import_retention: Option<humantime::Duration>,
+
+    /// How often the source is polled for new blocks after all available blocks are imported.
+    #[clap(long = "da-import-interval", default_value = "1s", env)]
+    pub da_import_interval: humantime::Duration,
+}
+
+impl DaImportArgs {
+    /// Returns the configuration of the DA import service if the source is set.
+    pub fn config(self) -> Option<DaImportConfig> {
+        let DaImportArgs {
+            da_import_directory,
+            da_import_url,
+            da_import_retention,
+            da_import_interval,
+        } = self;
+
+        let source = match (da_import_directory, da_import_url) {
+            (Some(path), _) => DaImportSource::Directory(path),
+            (None, Some(url)) => DaImportSource::Node(url),
+            (None, None) => return None,
+        };
+
+        da_import_retention.map(|retention| DaImportConfig {
+            source,
+            compression: fuel_core_compression::Config {
+                temporal_registry_retention: retention.into(),
+            },
+            interval: da_import_interval.into(),
+        })
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct Command {
+        #[clap(flatten)]
+        da_import: DaImportArgs,
+    }
+
+    #[test]
+    fn config__is_none_by_default() {
+        let command = Command::try_parse_from([""]).unwrap();
+
+        assert!(command.da_import.config().is_none());
+    }
+
+    #[test]
+    fn config__is_enabled_by_url() {
+        let command = Command::try_parse_from([
+            "",
+            "--da-import-url=http://127.0.0.1:4000",
+            "--da-import-retention=1h",
+            "--da-import-interval=10s",
+        ])
+        .unwrap();
+
+        let config = command.da_import.config().unwrap();
+
+        assert_eq!(
+            config.source,
+            DaImportSource::Node("http://127.0.0.1:4000".to_string())
+        );
+        assert_eq!(
+            config.compression.temporal_registry_retention,
+            Duration::from_secs(3600)
+        );
+        assert_eq!(config.interval, Duration::from_secs(10));
+    }
+
+    #[test]
+    fn parse__fails_without_retention() {
+        let result = Command::try_parse_from(["", "--da-import-directory=blocks"]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse__fails_with_both_sources() {
+        let result = Command::try_parse_from([
+            "",
+            "--da-import-directory=blocks",
+            "--da-import-url=http://127.0.0.1:4000",
+            "--da-import-retention=1h",
+        ]);
+
+        assert!(result.is_err());
+    }
+}
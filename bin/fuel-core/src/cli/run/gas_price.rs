@@ -0,0 +1,207 @@
+//! Clap configuration related to the gas price service.
+
+use clap::ValueEnum;
+use fuel_core::service::config::{
+    DaSourceConfig,
+    GasPriceV1Config,
+};
+use std::num::NonZeroU64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GasPriceAlgorithmVersion {
+    /// The algorithm that only takes into account the fullness of the blocks.
+    V0,
+    /// The algorithm that also takes into account the cost of the DA commitments.
+    V1,
+}
+
+/// The default scale factor for the gas price used by the V1 algorithm.
+const DEFAULT_GAS_PRICE_FACTOR: u64 = 100;
+/// The default maximum percentage change of the DA gas price between blocks.
+const DEFAULT_MAX_DA_GAS_PRICE_CHANGE_PERCENT: u16 = 10;
+/// The default proportional component of the DA gas price change.
+const DEFAULT_DA_P_COMPONENT: i64 = 1;
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct GasPriceArgs {
+    /// The version of the gas price algorithm.
+    ///
+    /// The V1 algorithm migrates the metadata of the V0 algorithm on the first start.
+    /// Switching back to the V0 algorithm keeps only the execution gas price
+    /// of the V1 algorithm, the state of the DA gas price is lost.
+    #[clap(long = "gas-price-algorithm", value_enum, default_value = "v0", env)]
+    pub gas_price_algorithm: GasPriceAlgorithmVersion,
+
+    /// The scale factor for the gas price used by the V1 algorithm. Defaults to 100.
+    /// Only allowed with `--gas-price-algorithm=v1`.
+    #[clap(long = "gas-price-factor", env)]
+    pub gas_price_factor: Option<NonZeroU64>,
+
+    /// The minimum gas price to cover the DA commitment of the block. Defaults to 0.
+    /// Only allowed with `--gas-price-algorithm=v1`.
+    #[clap(long = "min-da-gas-price", env)]
+    pub min_da_gas_price: Option<u64>,
+
+    /// The maximum percentage change of the DA gas price between blocks. Defaults to 10.
+    /// Only allowed with `--gas-price-algorithm=v1`.
+    #[clap(long = "max-da-gas-price-change-percent", env)]
+    pub max_da_gas_price_change_percent: Option<u16>,
+
+    /// The proportional component of the DA gas price change. Defaults to 1.
+    /// Only allowed with `--gas-price-algorithm=v1`.
+    #[clap(long = "da-p-component", env)]
+    pub da_p_component: Option<i64>,
+
+    /// The derivative component of the DA gas price change. Defaults to 0.
+    /// Only allowed with `--gas-price-algorithm=v1`.
+    #[clap(long = "da-d-component", env)]
+    pub da_d_component: Option<i64>,
+
+    /// The URL of the block committer HTTP API used to fetch the costs of the DA commitments.
+    /// If not set, the V1 algorithm relies only on the projected costs.
+    /// Only allowed with `--gas-price-algorithm=v1`.
+    #[clap(long = "da-committer-url", env)]
+    pub da_committer_url: Option<String>,
+
+    /// How often to poll the DA source for new costs.
+    /// Only allowed with `--gas-price-algorithm=v1`.
+    #[clap(long = "da-poll-interval", env)]
+    pub da_poll_interval: Option<humantime::Duration>,
+}
+
+impl GasPriceArgs {
+    /// Returns the configuration of the V1 algorithm if it is selected.
+    /// Fails if the arguments of the V1 algorithm are set for another algorithm.
+    pub fn v1_config(self) -> anyhow::Result<Option<GasPriceV1Config>> {
+        let GasPriceArgs {
+            gas_price_algorithm,
+            gas_price_factor,
+            min_da_gas_price,
+            max_da_gas_price_change_percent,
+            da_p_component,
+            da_d_component,
+            da_committer_url,
+            da_poll_interval,
+        } = self;
+
+        if gas_price_algorithm != GasPriceAlgorithmVersion::V1 {
+            let v1_args_are_set = gas_price_factor.is_some()
+                || min_da_gas_price.is_some()
+                || max_da_gas_price_change_percent.is_some()
+                || da_p_component.is_some()
+                || da_d_component.is_some()
+                || da_committer_url.is_some()
+                || da_poll_interval.is_some();
+            anyhow::ensure!(
+                !v1_args_are_set,
+                "The arguments of the V1 gas price algorithm require `--gas-price-algorithm=v1`"
+            );
+            return Ok(None);
+        }
+
+        let gas_price_factor = match gas_price_factor {
+            Some(gas_price_factor) => gas_price_factor,
+            None => NonZeroU64::new(DEFAULT_GAS_PRICE_FACTOR)
+                .expect("The default gas price factor is not zero"),
+        };
+        let da_source = match da_committer_url {
+            Some(url) => DaSourceConfig::BlockCommitter { url },
+            None => DaSourceConfig::Dummy,
+        };
+
+        Ok(Some(GasPriceV1Config {
+            gas_price_factor,
+            min_da_gas_price: min_da_gas_price.unwrap_or_default(),
+            max_da_gas_price_change_percent: max_da_gas_price_change_percent
+                .unwrap_or(DEFAULT_MAX_DA_GAS_PRICE_CHANGE_PERCENT),
+            da_p_component: da_p_component.unwrap_or(DEFAULT_DA_P_COMPONENT),
+            da_d_component: da_d_component.unwrap_or_default(),
+            da_source,
+            da_poll_interval: da_poll_interval.map(Into::into),
+        }))
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct Command {
+        #[clap(flatten)]
+        gas_price: GasPriceArgs,
+    }
+
+    #[test]
+    fn v1_config__is_none_by_default() {
+        let command = Command::try_parse_from([""]).unwrap();
+
+        assert!(command.gas_price.v1_config().unwrap().is_none());
+    }
+
+    #[test]
+    fn v1_config__uses_block_committer_when_url_is_set() {
+        let command = Command::try_parse_from([
+            "",
+            "--gas-price-algorithm=v1",
+            "--da-committer-url=http://localhost:8080",
+        ])
+        .unwrap();
+
+        let config = command.gas_price.v1_config().unwrap().unwrap();
+
+        assert_eq!(
+            config.da_source,
+            DaSourceConfig::BlockCommitter {
+                url: "http://localhost:8080".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn v1_config__uses_dummy_source_without_url() {
+        let command = Command::try_parse_from(["", "--gas-price-algorithm=v1"]).unwrap();
+
+        let config = command.gas_price.v1_config().unwrap().unwrap();
+
+        assert_eq!(config.da_source, DaSourceConfig::Dummy);
+    }
+
+    #[test]
+    fn v1_config__uses_defaults_without_arguments() {
+        let command = Command::try_parse_from(["", "--gas-price-algorithm=v1"]).unwrap();
+
+        let config = command.gas_price.v1_config().unwrap().unwrap();
+
+        assert_eq!(config.gas_price_factor.get(), 100);
+        assert_eq!(config.min_da_gas_price, 0);
+        assert_eq!(config.max_da_gas_price_change_percent, 10);
+        assert_eq!(config.da_p_component, 1);
+        assert_eq!(config.da_d_component, 0);
+    }
+
+    #[test]
+    fn v1_config__fails_with_v1_arguments_for_v0_algorithm() {
+        let command = Command::try_parse_from([
+            "",
+            "--gas-price-algorithm=v0",
+            "--gas-price-factor=5",
+        ])
+        .unwrap();
+
+        let result = command.gas_price.v1_config();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn v1_config__fails_with_v1_arguments_without_algorithm() {
+        let command = Command::try_parse_from(["", "--da-p-component=2"]).unwrap();
+
+        let result = command.gas_price.v1_config();
+
+        assert!(result.is_err());
+    }
+}
@@ -43,4 +43,21 @@ pub struct GraphQLArgs {
     /// Timeout before drop the request.
     #[clap(long = "api-request-timeout", default_value = "30s", env)]
     pub api_request_timeout: humantime::Duration,
+
+    /// Enables the admin API that manages the transaction pool at runtime.
+    /// Requests to the admin API must carry the token in the
+    /// `Authorization: Bearer <token>` header.
+    #[clap(long = "graphql-admin-token", env)]
+    pub graphql_admin_token: Option<String>,
+
+    /// The max number of blocks the node may lag behind its peers or the off-chain
+    /// worker may lag behind the on-chain database before `/health/ready`
+    /// reports that the node isn't ready.
+    #[clap(long = "health-max-block-lag", default_value = "10", env)]
+    pub health_max_block_lag: u32,
+
+    /// The max number of DA blocks the relayer may lag behind the DA layer
+    /// before `/health/ready` reports that the node isn't ready.
+    #[clap(long = "health-max-da-lag", default_value = "10", env)]
+    pub health_max_da_lag: u64,
 }
@@ -199,6 +199,10 @@ pub struct SyncArgs {
     /// The maximum number of headers to request in a single batch.
     #[clap(long = "sync-header-batch-size", default_value = "10", env)]
     pub header_batch_size: u32,
+    /// The maximum number of times the part of the batch that the peer failed
+    /// to provide is re-requested from other peers.
+    #[clap(long = "sync-max-batch-retries", default_value = "3", env)]
+    pub max_batch_retries: usize,
 }
 
 #[derive(Clone, Debug)]
@@ -237,6 +241,7 @@ impl From<SyncArgs> for fuel_core::sync::Config {
         Self {
             block_stream_buffer_size: value.block_stream_buffer_size,
             header_batch_size: value.header_batch_size as usize,
+            max_batch_retries: value.max_batch_retries,
         }
     }
 }
@@ -0,0 +1,108 @@
+//! Clap configuration related to the pruning of historical data.
+
+use fuel_core::service::pruning::PruningConfig;
+use std::{
+    num::NonZeroU32,
+    time::Duration,
+};
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct PruningArgs {
+    /// If set, the node prunes transactions, receipts, and owner indexes of blocks
+    /// older than the given number of the latest blocks.
+    /// Block headers and merkle data are never pruned.
+    /// The value is raised to the state rewind window if it is smaller, because
+    /// the rollback and the re-execution of blocks require their transactions.
+    /// The pruned blocks can't be reindexed or served to peers.
+    #[clap(long = "pruning-retained-blocks", env)]
+    pub pruning_retained_blocks: Option<NonZeroU32>,
+
+    /// How often the pruning service checks for new blocks to prune. Must be non-zero.
+    #[clap(long = "pruning-interval", default_value = "10s", env)]
+    pub pruning_interval: humantime::Duration,
+
+    /// The maximum number of blocks pruned in one database commit.
+    #[clap(long = "pruning-batch-size", default_value = "1000", env)]
+    pub pruning_batch_size: NonZeroU32,
+}
+
+impl PruningArgs {
+    /// Returns the configuration of the pruning service if pruning is enabled.
+    pub fn config(self) -> anyhow::Result<Option<PruningConfig>> {
+        let PruningArgs {
+            pruning_retained_blocks,
+            pruning_interval,
+            pruning_batch_size,
+        } = self;
+
+        let interval: Duration = pruning_interval.into();
+        anyhow::ensure!(
+            !interval.is_zero(),
+            "The `--pruning-interval` must be non-zero"
+        );
+
+        let config = pruning_retained_blocks.map(|retained_blocks| PruningConfig {
+            retained_blocks,
+            interval,
+            batch_size: pruning_batch_size,
+        });
+        Ok(config)
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct Command {
+        #[clap(flatten)]
+        pruning: PruningArgs,
+    }
+
+    #[test]
+    fn config__is_none_by_default() {
+        let command = Command::try_parse_from([""]).unwrap();
+
+        assert!(command.pruning.config().unwrap().is_none());
+    }
+
+    #[test]
+    fn config__is_enabled_by_retained_blocks() {
+        let command = Command::try_parse_from([
+            "",
+            "--pruning-retained-blocks=100",
+            "--pruning-interval=1m",
+            "--pruning-batch-size=10",
+        ])
+        .unwrap();
+
+        let config = command.pruning.config().unwrap().unwrap();
+
+        assert_eq!(
+            config,
+            PruningConfig {
+                retained_blocks: NonZeroU32::new(100).unwrap(),
+                interval: Duration::from_secs(60),
+                batch_size: NonZeroU32::new(10).unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn config__fails_with_zero_interval() {
+        let command = Command::try_parse_from([
+            "",
+            "--pruning-retained-blocks=100",
+            "--pruning-interval=0s",
+        ])
+        .unwrap();
+
+        let result = command.pruning.config();
+
+        assert!(result.is_err());
+    }
+}
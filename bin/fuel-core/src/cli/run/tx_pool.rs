@@ -1,6 +1,10 @@
 //! Clap configuration related to TxPool service.
 
-use fuel_core::txpool::types::ContractId;
+use clap::ValueEnum;
+use fuel_core::{
+    txpool::types::ContractId,
+    txpool_v2::config::SelectionStrategy,
+};
 use fuel_core_types::{
     fuel_tx::{
         Address,
@@ -9,8 +13,32 @@ use fuel_core_types::{
     fuel_types::Nonce,
 };
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TxSelectionStrategy {
+    /// Transactions with the highest tip per unit of gas go first.
+    RatioTipGas,
+    /// Transactions with the highest tip per byte go first.
+    RatioTipBytes,
+    /// Transactions go in the order of their arrival.
+    Fifo,
+}
+
+impl From<TxSelectionStrategy> for SelectionStrategy {
+    fn from(strategy: TxSelectionStrategy) -> Self {
+        match strategy {
+            TxSelectionStrategy::RatioTipGas => SelectionStrategy::RatioTipGas,
+            TxSelectionStrategy::RatioTipBytes => SelectionStrategy::RatioTipBytes,
+            TxSelectionStrategy::Fifo => SelectionStrategy::Fifo,
+        }
+    }
+}
+
 #[derive(Debug, Clone, clap::Args)]
 pub struct TxPoolArgs {
+    /// Use the transaction pool v2 instead of the legacy `TxPool`.
+    #[clap(long = "tx-pool-v2", env)]
+    pub tx_pool_v2: bool,
+
     /// The max time to live of the transaction inside of the `TxPool`.
     #[clap(long = "tx-pool-ttl", default_value = "5m", env)]
     pub tx_pool_ttl: humantime::Duration,
@@ -42,6 +70,37 @@ pub struct TxPoolArgs {
     /// The list of banned contracts ignored by the `TxPool`.
     #[clap(long = "tx-blacklist-contracts", value_delimiter = ',', env)]
     pub tx_blacklist_contracts: Vec<ContractId>,
+
+    /// The order in which the `TxPool` v2 selects transactions for the block.
+    #[clap(
+        long = "tx-selection-strategy",
+        value_enum,
+        default_value = "ratio-tip-gas",
+        env
+    )]
+    pub tx_selection_strategy: TxSelectionStrategy,
+
+    /// The max number of transactions of the same owner that the `TxPool` v2
+    /// selects for one block. Not limited by default.
+    #[clap(long = "tx-max-per-owner-per-block", env)]
+    pub tx_max_per_owner_per_block: Option<u16>,
+
+    /// The minimum increase of the tip per unit of gas, in percent, required by
+    /// the `TxPool` v2 to replace a transaction that uses the same inputs.
+    /// By default, any increase of the tip per unit of gas is enough.
+    #[clap(long = "tx-min-tip-bump-percent", default_value = "0", env)]
+    pub tx_min_tip_bump_percent: u16,
+
+    /// Persists transactions of the `TxPool` v2 in the off-chain database and
+    /// re-inserts them into the pool after the restart of the node.
+    /// Transactions older than `--tx-pool-ttl` are not restored.
+    #[clap(long = "tx-pool-persist", env)]
+    pub tx_pool_persist: bool,
+
+    /// Persists changes of the `TxPool` v2 blacklist made via the admin API
+    /// in the off-chain database and re-applies them after the restart of the node.
+    #[clap(long = "tx-blacklist-persist", env)]
+    pub tx_blacklist_persist: bool,
 }
 
 #[cfg(test)]
@@ -3,11 +3,16 @@ use anyhow::Context;
 use clap::{
     Parser,
     Subcommand,
+    ValueEnum,
 };
 use fuel_core::{
     combined_database::CombinedDatabase,
+    service::genesis::StateTable,
     state::historical_rocksdb::StateRewindPolicy,
-    types::fuel_types::ContractId,
+    types::fuel_types::{
+        Address,
+        ContractId,
+    },
 };
 use fuel_core_chain_config::ChainConfig;
 use std::path::{
@@ -17,6 +22,8 @@ use std::path::{
 
 use super::local_testnet_chain_config;
 
+mod diff;
+
 /// Print a snapshot of blockchain state to stdout.
 #[derive(Debug, Clone, Parser)]
 pub struct Command {
@@ -29,7 +36,8 @@ pub struct Command {
     )]
     pub database_path: PathBuf,
 
-    /// Where to save the snapshot
+    /// Where to save the snapshot. The `verify` and `diff` commands
+    /// read the snapshot from there.
     #[arg(name = "OUTPUT_DIR", long = "output-directory")]
     pub output_dir: PathBuf,
 
@@ -91,6 +99,30 @@ impl EncodingCommand {
     }
 }
 
+/// The part of the chain state to include into the snapshot.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Table {
+    Coins,
+    Messages,
+    /// The code, the latest UTXO and the storage slots of contracts.
+    Contracts,
+    Blobs,
+    /// The balances of contracts.
+    Balances,
+}
+
+impl From<Table> for StateTable {
+    fn from(table: Table) -> Self {
+        match table {
+            Table::Coins => StateTable::Coins,
+            Table::Messages => StateTable::Messages,
+            Table::Contracts => StateTable::Contracts,
+            Table::Blobs => StateTable::Blobs,
+            Table::Balances => StateTable::Balances,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Subcommand)]
 pub enum SubCommands {
     /// Creates a snapshot of the entire database and produces a chain config.
@@ -110,11 +142,73 @@ pub enum SubCommands {
         #[clap(long = "id")]
         contract_id: ContractId,
     },
+    /// Creates a snapshot of the chain state at the specified height. The snapshot
+    /// can be restricted to some tables, owners of coins and messages or contracts.
+    State {
+        /// The height of the chain state. Defaults to the latest height.
+        /// Older heights require the database to store the history of changes,
+        /// i.e. to be run with the state rewind enabled.
+        #[clap(long = "height")]
+        height: Option<u32>,
+        /// The comma-separated list of tables to include. All tables are included
+        /// if not specified. The JSON encoding stores the balances inside
+        /// the contracts, so they can't be exported without the contracts.
+        #[clap(long = "tables", value_enum, value_delimiter = ',')]
+        tables: Vec<Table>,
+        /// The comma-separated list of owners of the exported coins and messages.
+        #[clap(long = "owners", value_delimiter = ',')]
+        owners: Vec<Address>,
+        /// The comma-separated list of the exported contracts.
+        #[clap(long = "contracts", value_delimiter = ',')]
+        contracts: Vec<ContractId>,
+        /// Specify a path to the chain config. Defaults used if no path
+        /// is provided.
+        #[clap(name = "CHAIN_CONFIG", long = "chain")]
+        chain_config: Option<PathBuf>,
+        /// Encoding format for the chain state files.
+        #[clap(subcommand)]
+        encoding_command: Option<EncodingCommand>,
+    },
+    /// Creates a snapshot of the blocks, their consensus data and transactions
+    /// in the range of heights. Only the parquet encoding can hold blocks.
+    Blocks {
+        /// The height of the first exported block.
+        #[clap(long = "from", default_value = "0")]
+        from: u32,
+        /// The height of the last exported block. Defaults to the latest height.
+        #[clap(long = "to")]
+        to: Option<u32>,
+        /// Specify a path to the chain config. Defaults used if no path
+        /// is provided.
+        #[clap(name = "CHAIN_CONFIG", long = "chain")]
+        chain_config: Option<PathBuf>,
+        /// Encoding format for the chain state files.
+        #[clap(subcommand)]
+        encoding_command: Option<EncodingCommand>,
+    },
+    /// Verifies that the state in the snapshot matches the state in the database
+    /// at the height of the last block of the snapshot by comparing the merkle roots
+    /// of coins, messages, contracts and balances.
+    Verify,
+    /// Reports the entries added, removed and changed in the snapshot
+    /// compared to the base snapshot, table by table.
+    #[command(arg_required_else_help = true)]
+    Diff {
+        /// The path to the base snapshot.
+        #[clap(long = "base")]
+        base: PathBuf,
+        /// The maximum number of reported entries per table.
+        #[clap(long = "limit", default_value = "10")]
+        limit: usize,
+    },
 }
 
 #[cfg(feature = "rocksdb")]
 pub async fn exec(command: Command) -> anyhow::Result<()> {
-    use fuel_core::service::genesis::Exporter;
+    use fuel_core::service::genesis::{
+        Exporter,
+        StateFilter,
+    };
     use fuel_core_chain_config::{
         SnapshotWriter,
         MAX_GROUP_SIZE,
@@ -122,10 +216,9 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
 
     use crate::cli::ShutdownListener;
 
-    let db = open_db(
-        &command.database_path,
-        Some(command.max_database_cache_size),
-    )?;
+    let database_path = command.database_path;
+    let max_database_cache_size = command.max_database_cache_size;
+    let db = || open_db(&database_path, Some(max_database_cache_size));
     let output_dir = command.output_dir;
     let shutdown_listener = ShutdownListener::spawn();
 
@@ -135,20 +228,10 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
             encoding_command,
             ..
         } => {
-            let encoding = encoding_command
-                .map(|f| f.encoding())
-                .unwrap_or_else(|| Encoding::Json);
-
-            let group_size = encoding.group_size().unwrap_or(MAX_GROUP_SIZE);
-            let writer = move || match encoding {
-                Encoding::Json => Ok(SnapshotWriter::json(output_dir.clone())),
-                #[cfg(feature = "parquet")]
-                Encoding::Parquet { compression, .. } => {
-                    SnapshotWriter::parquet(output_dir.clone(), compression.try_into()?)
-                }
-            };
+            let encoding = encoding_or_json(encoding_command);
+            let (writer, group_size) = snapshot_writer(output_dir, encoding);
             Exporter::new(
-                db,
+                db()?,
                 load_chain_config_or_use_testnet(chain_config.as_deref())?,
                 writer,
                 group_size,
@@ -160,7 +243,7 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
         SubCommands::Contract { contract_id } => {
             let writer = move || Ok(SnapshotWriter::json(output_dir.clone()));
             Exporter::new(
-                db,
+                db()?,
                 local_testnet_chain_config(),
                 writer,
                 MAX_GROUP_SIZE,
@@ -169,9 +252,157 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
             .write_contract_snapshot(contract_id)
             .await
         }
+        SubCommands::State {
+            height,
+            tables,
+            owners,
+            contracts,
+            chain_config,
+            encoding_command,
+        } => {
+            let encoding = encoding_or_json(encoding_command);
+            if matches!(encoding, Encoding::Json)
+                && tables.contains(&Table::Balances)
+                && !tables.contains(&Table::Contracts)
+            {
+                anyhow::bail!(
+                    "The JSON encoding can't hold the balances without the contracts"
+                );
+            }
+
+            let filter = StateFilter {
+                tables: tables.into_iter().map(Into::into).collect(),
+                owners,
+                contracts,
+            };
+            let (writer, group_size) = snapshot_writer(output_dir, encoding);
+            Exporter::new(
+                db()?,
+                load_chain_config_or_use_testnet(chain_config.as_deref())?,
+                writer,
+                group_size,
+                shutdown_listener,
+            )
+            .write_state_snapshot(height.map(Into::into), filter)
+            .await
+        }
+        SubCommands::Blocks {
+            from,
+            to,
+            chain_config,
+            encoding_command,
+        } => {
+            let encoding = encoding_or_json(encoding_command);
+            if matches!(encoding, Encoding::Json) {
+                anyhow::bail!(
+                    "The JSON encoding can't hold blocks, use the parquet encoding"
+                );
+            }
+
+            let (writer, group_size) = snapshot_writer(output_dir, encoding);
+            Exporter::new(
+                db()?,
+                load_chain_config_or_use_testnet(chain_config.as_deref())?,
+                writer,
+                group_size,
+                shutdown_listener,
+            )
+            .write_blocks_snapshot(from.into(), to.map(Into::into))
+            .await
+        }
+        SubCommands::Verify => verify(&db()?, &output_dir),
+        SubCommands::Diff { base, limit } => diff::exec(&base, &output_dir, limit),
     }
 }
 
+#[cfg(feature = "rocksdb")]
+fn verify(db: &CombinedDatabase, snapshot_dir: &Path) -> anyhow::Result<()> {
+    use fuel_core::{
+        service::genesis::StateRoots,
+        types::fuel_types::Bytes32,
+    };
+    use fuel_core_chain_config::{
+        SnapshotMetadata,
+        SnapshotReader,
+    };
+    use fuel_core_storage::transactional::AtomicView;
+
+    let reader = SnapshotReader::open(SnapshotMetadata::read(snapshot_dir)?)?;
+    let height = reader
+        .last_block_config()
+        .context("The snapshot doesn't contain the last block")?
+        .block_height;
+    let latest_height = db.on_chain().latest_view()?.latest_height()?;
+    anyhow::ensure!(
+        height <= latest_height,
+        "The database at the height {latest_height} is behind the snapshot at the height {height}"
+    );
+
+    let snapshot_roots = StateRoots::from_snapshot(&reader)?;
+    let db_roots = StateRoots::from_view(&db.on_chain().iterable_view_at(&height)?)?;
+
+    let roots = [
+        ("coins", snapshot_roots.coins, db_roots.coins),
+        ("messages", snapshot_roots.messages, db_roots.messages),
+        ("contracts", snapshot_roots.contracts, db_roots.contracts),
+        ("balances", snapshot_roots.balances, db_roots.balances),
+    ];
+    let mut mismatched = vec![];
+    for (name, snapshot_root, db_root) in roots {
+        let status = if snapshot_root == db_root {
+            "ok"
+        } else {
+            mismatched.push(name);
+            "mismatch"
+        };
+        println!(
+            "{name:<10} snapshot: {} database: {} {status}",
+            Bytes32::from(snapshot_root),
+            Bytes32::from(db_root)
+        );
+    }
+
+    anyhow::ensure!(
+        mismatched.is_empty(),
+        "The snapshot doesn't match the database at the height {height}, the roots of {} differ",
+        mismatched.join(", ")
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "rocksdb")]
+fn encoding_or_json(encoding_command: Option<EncodingCommand>) -> Encoding {
+    encoding_command
+        .map(|f| f.encoding())
+        .unwrap_or_else(|| Encoding::Json)
+}
+
+#[cfg(feature = "rocksdb")]
+fn snapshot_writer(
+    output_dir: PathBuf,
+    encoding: Encoding,
+) -> (
+    impl Fn() -> anyhow::Result<fuel_core_chain_config::SnapshotWriter>,
+    usize,
+) {
+    use fuel_core_chain_config::{
+        SnapshotWriter,
+        MAX_GROUP_SIZE,
+    };
+
+    let group_size = encoding.group_size().unwrap_or(MAX_GROUP_SIZE);
+    let writer = move || match encoding {
+        Encoding::Json => Ok(SnapshotWriter::json(output_dir.clone())),
+        #[cfg(feature = "parquet")]
+        Encoding::Parquet { compression, .. } => {
+            SnapshotWriter::parquet(output_dir.clone(), compression.try_into()?)
+        }
+    };
+
+    (writer, group_size)
+}
+
 fn load_chain_config_or_use_testnet(path: Option<&Path>) -> anyhow::Result<ChainConfig> {
     if let Some(path) = path {
         ChainConfig::load(path)
@@ -192,6 +423,7 @@ fn open_db(path: &Path, capacity: Option<usize>) -> anyhow::Result<CombinedDatab
 
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
 
     use std::iter::repeat_with;
 
@@ -207,6 +439,7 @@ mod tests {
         AddTable,
         AsTable,
         LastBlockConfig,
+        Randomize,
         SnapshotMetadata,
         SnapshotReader,
         StateConfig,
@@ -225,7 +458,10 @@ mod tests {
             Messages,
             Transactions,
         },
-        transactional::AtomicView,
+        transactional::{
+            AtomicView,
+            WriteTransaction,
+        },
         ContractsAssetKey,
         ContractsStateKey,
         StorageAsMut,
@@ -253,7 +489,10 @@ mod tests {
             UniqueIdentifier,
             UtxoId,
         },
-        fuel_types::ChainId,
+        fuel_types::{
+            BlockHeight,
+            ChainId,
+        },
         services::txpool::TransactionStatus,
         tai64::Tai64,
     };
@@ -649,6 +888,78 @@ mod tests {
             TableEntry { key, value: amount }
         }
 
+        fn given_block_with_coin(
+            &mut self,
+            height: BlockHeight,
+            spent_coin: Option<UtxoId>,
+        ) -> TableEntry<Coins> {
+            let mut block = CompressedBlock::default();
+            block.header_mut().set_block_height(height);
+            let key = UtxoId::new(self.rng.gen(), self.rng.gen());
+            let coin = CompressedCoin::V1(CompressedCoinV1 {
+                owner: self.rng.gen(),
+                amount: self.rng.gen(),
+                asset_id: self.rng.gen(),
+                tx_pointer: self.rng.gen(),
+            });
+
+            let mut transaction = self.db.on_chain_mut().write_transaction();
+            transaction
+                .storage_as_mut::<FuelBlocks>()
+                .insert(&height, &block)
+                .unwrap();
+            transaction
+                .storage_as_mut::<Coins>()
+                .insert(&key, &coin)
+                .unwrap();
+            if let Some(spent_coin) = spent_coin {
+                transaction
+                    .storage_as_mut::<Coins>()
+                    .remove(&spent_coin)
+                    .unwrap();
+            }
+            transaction.commit().unwrap();
+
+            TableEntry { key, value: coin }
+        }
+
+        fn given_block_with_transaction(
+            &mut self,
+            height: BlockHeight,
+        ) -> (TableEntry<FuelBlocks>, TableEntry<Transactions>) {
+            let tx = TransactionBuilder::script(
+                self.generate_data(100),
+                self.generate_data(100),
+            )
+            .finalize_as_transaction();
+            let tx_id = tx.id(&ChainId::default());
+            let mut block = CompressedBlock::default();
+            block.header_mut().set_block_height(height);
+            block.transactions_mut().push(tx_id);
+
+            let mut transaction = self.db.on_chain_mut().write_transaction();
+            transaction
+                .storage_as_mut::<FuelBlocks>()
+                .insert(&height, &block)
+                .unwrap();
+            transaction
+                .storage_as_mut::<Transactions>()
+                .insert(&tx_id, &tx)
+                .unwrap();
+            transaction.commit().unwrap();
+
+            (
+                TableEntry {
+                    key: height,
+                    value: block,
+                },
+                TableEntry {
+                    key: tx_id,
+                    value: tx,
+                },
+            )
+        }
+
         fn generate_data(&mut self, max_amount: usize) -> Vec<u8> {
             let mut data = vec![0u8; self.rng.gen_range(0..=max_amount)];
             self.rng.fill(data.as_mut_slice());
@@ -810,6 +1121,352 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn state_snapshot__exports_state_at_historical_height() -> anyhow::Result<()> {
+        // Given
+        let temp_dir = tempfile::tempdir()?;
+        let snapshot_dir = temp_dir.path().join("snapshot");
+        let db_path = temp_dir.path().join("db");
+        let db = CombinedDatabase::open(
+            &db_path,
+            DEFAULT_DATABASE_CACHE_SIZE,
+            StateRewindPolicy::RewindFullRange,
+        )?;
+        let mut db = DbPopulator::new(db, StdRng::seed_from_u64(2));
+
+        let old_coin = db.given_block_with_coin(0u32.into(), None);
+        let _new_coin = db.given_block_with_coin(1u32.into(), Some(old_coin.key));
+        db.flush();
+
+        // When
+        exec(Command {
+            database_path: db_path,
+            output_dir: snapshot_dir.clone(),
+            max_database_cache_size: DEFAULT_DATABASE_CACHE_SIZE,
+            subcommand: SubCommands::State {
+                height: Some(0),
+                tables: vec![],
+                owners: vec![],
+                contracts: vec![],
+                chain_config: None,
+                encoding_command: None,
+            },
+        })
+        .await?;
+
+        // Then
+        let metadata = SnapshotMetadata::read(&snapshot_dir)?;
+        let snapshot_state = StateConfig::from_snapshot_metadata(metadata)?;
+
+        assert_eq!(snapshot_state.coins, vec![old_coin.into()]);
+        assert_eq!(
+            snapshot_state.last_block.map(|block| block.block_height),
+            Some(0u32.into())
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn state_snapshot__exports_only_requested_tables_owners_and_contracts(
+    ) -> anyhow::Result<()> {
+        // Given
+        let temp_dir = tempfile::tempdir()?;
+        let snapshot_dir = temp_dir.path().join("snapshot");
+
+        let db_path = temp_dir.path().join("db");
+        let mut db = DbPopulator::new(open_db(&db_path, None)?, StdRng::seed_from_u64(2));
+
+        let original_state = db.given_persisted_data().sorted().into_state_config();
+
+        let owner = original_state.coins[0].owner;
+        let expected_coins = original_state
+            .coins
+            .iter()
+            .filter(|coin| coin.owner == owner)
+            .cloned()
+            .collect_vec();
+        let mut expected_contract = original_state
+            .contracts
+            .choose(&mut db.rng)
+            .unwrap()
+            .clone();
+        expected_contract.balances = vec![];
+        let mut latest_block = original_state.last_block.unwrap();
+        latest_block.blocks_root = db
+            .db
+            .on_chain()
+            .latest_view()
+            .unwrap()
+            .block_header_merkle_root(&latest_block.block_height)
+            .unwrap();
+        db.flush();
+
+        // When
+        exec(Command {
+            database_path: db_path,
+            output_dir: snapshot_dir.clone(),
+            max_database_cache_size: DEFAULT_DATABASE_CACHE_SIZE,
+            subcommand: SubCommands::State {
+                height: None,
+                tables: vec![Table::Coins, Table::Contracts],
+                owners: vec![owner],
+                contracts: vec![expected_contract.contract_id],
+                chain_config: None,
+                encoding_command: None,
+            },
+        })
+        .await?;
+
+        // Then
+        let metadata = SnapshotMetadata::read(&snapshot_dir)?;
+        let snapshot_state = StateConfig::from_snapshot_metadata(metadata)?;
+
+        pretty_assertions::assert_eq!(
+            snapshot_state,
+            StateConfig {
+                coins: expected_coins,
+                messages: vec![],
+                blobs: vec![],
+                contracts: vec![expected_contract],
+                last_block: Some(latest_block),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn state_snapshot__json_rejects_balances_without_contracts(
+    ) -> anyhow::Result<()> {
+        // Given
+        let temp_dir = tempfile::tempdir()?;
+        let snapshot_dir = temp_dir.path().join("snapshot");
+        let db_path = temp_dir.path().join("db");
+        let mut db = DbPopulator::new(open_db(&db_path, None)?, StdRng::seed_from_u64(2));
+        db.given_persisted_data();
+        db.flush();
+
+        // When
+        let result = exec(Command {
+            database_path: db_path,
+            output_dir: snapshot_dir,
+            max_database_cache_size: DEFAULT_DATABASE_CACHE_SIZE,
+            subcommand: SubCommands::State {
+                height: None,
+                tables: vec![Table::Balances],
+                owners: vec![],
+                contracts: vec![],
+                chain_config: None,
+                encoding_command: None,
+            },
+        })
+        .await;
+
+        // Then
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "parquet")]
+    #[tokio::test]
+    async fn blocks_snapshot__exports_blocks_and_transactions_in_range(
+    ) -> anyhow::Result<()> {
+        // Given
+        let temp_dir = tempfile::tempdir()?;
+        let snapshot_dir = temp_dir.path().join("snapshot");
+        let db_path = temp_dir.path().join("db");
+        let mut db = DbPopulator::new(open_db(&db_path, None)?, StdRng::seed_from_u64(2));
+
+        let (blocks, transactions): (Vec<_>, Vec<_>) = (0u32..4)
+            .map(|height| db.given_block_with_transaction(height.into()))
+            .unzip();
+        db.flush();
+
+        // When
+        exec(Command {
+            database_path: db_path,
+            output_dir: snapshot_dir.clone(),
+            max_database_cache_size: DEFAULT_DATABASE_CACHE_SIZE,
+            subcommand: SubCommands::Blocks {
+                from: 1,
+                to: Some(2),
+                chain_config: None,
+                encoding_command: Some(EncodingCommand::Encoding {
+                    encoding: Encoding::Parquet {
+                        group_size: 10,
+                        compression: 1,
+                    },
+                }),
+            },
+        })
+        .await?;
+
+        // Then
+        let metadata = SnapshotMetadata::read(&snapshot_dir)?;
+        let mut reader = SnapshotReader::open(metadata)?;
+
+        let exported_blocks: Vec<TableEntry<FuelBlocks>> = reader
+            .read::<FuelBlocks>()?
+            .into_iter()
+            .flatten_ok()
+            .try_collect()?;
+        let exported_transactions: Vec<TableEntry<Transactions>> = reader
+            .read::<Transactions>()?
+            .into_iter()
+            .flatten_ok()
+            .try_collect()?;
+
+        assert_eq!(exported_blocks, blocks[1..=2]);
+        assert_eq!(exported_transactions, transactions[1..=2]);
+        assert_eq!(
+            reader.last_block_config().map(|block| block.block_height),
+            Some(2u32.into())
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn blocks_snapshot__json_encoding_is_rejected() -> anyhow::Result<()> {
+        // Given
+        let temp_dir = tempfile::tempdir()?;
+        let snapshot_dir = temp_dir.path().join("snapshot");
+        let db_path = temp_dir.path().join("db");
+        let mut db = DbPopulator::new(open_db(&db_path, None)?, StdRng::seed_from_u64(2));
+        db.given_block_with_transaction(0u32.into());
+        db.flush();
+
+        // When
+        let result = exec(Command {
+            database_path: db_path,
+            output_dir: snapshot_dir,
+            max_database_cache_size: DEFAULT_DATABASE_CACHE_SIZE,
+            subcommand: SubCommands::Blocks {
+                from: 0,
+                to: None,
+                chain_config: None,
+                encoding_command: None,
+            },
+        })
+        .await;
+
+        // Then
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "parquet", test_case(Encoding::Parquet { group_size: 2, compression: 1 }; "parquet"))]
+    #[test_case(Encoding::Json; "json")]
+    fn verify__snapshot_of_the_database_matches_it(
+        encoding: Encoding,
+    ) -> anyhow::Result<()> {
+        // Given
+        let temp_dir = tempfile::tempdir()?;
+        let snapshot_dir = temp_dir.path().join("snapshot");
+        let db_path = temp_dir.path().join("db");
+        let mut db = DbPopulator::new(open_db(&db_path, None)?, StdRng::seed_from_u64(2));
+        db.given_persisted_data();
+        db.flush();
+
+        let runtime = tokio::runtime::Runtime::new()?;
+        runtime.block_on(exec(Command {
+            database_path: db_path.clone(),
+            output_dir: snapshot_dir.clone(),
+            max_database_cache_size: DEFAULT_DATABASE_CACHE_SIZE,
+            subcommand: SubCommands::Everything {
+                chain_config: None,
+                encoding_command: Some(EncodingCommand::Encoding { encoding }),
+            },
+        }))?;
+
+        // When
+        let result = runtime.block_on(exec(Command {
+            database_path: db_path,
+            output_dir: snapshot_dir,
+            max_database_cache_size: DEFAULT_DATABASE_CACHE_SIZE,
+            subcommand: SubCommands::Verify,
+        }));
+
+        // Then
+        assert!(result.is_ok(), "{result:?}");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn verify__modified_snapshot_does_not_match_the_database() -> anyhow::Result<()>
+    {
+        // Given
+        let temp_dir = tempfile::tempdir()?;
+        let snapshot_dir = temp_dir.path().join("snapshot");
+        let modified_snapshot_dir = temp_dir.path().join("modified_snapshot");
+        let db_path = temp_dir.path().join("db");
+        let mut db = DbPopulator::new(open_db(&db_path, None)?, StdRng::seed_from_u64(2));
+        db.given_persisted_data();
+        db.flush();
+
+        exec(Command {
+            database_path: db_path.clone(),
+            output_dir: snapshot_dir.clone(),
+            max_database_cache_size: DEFAULT_DATABASE_CACHE_SIZE,
+            subcommand: SubCommands::Everything {
+                chain_config: None,
+                encoding_command: None,
+            },
+        })
+        .await?;
+        let mut state =
+            StateConfig::from_snapshot_metadata(SnapshotMetadata::read(&snapshot_dir)?)?;
+        state.coins[0].amount = state.coins[0].amount.wrapping_add(1);
+        fuel_core_chain_config::SnapshotWriter::json(&modified_snapshot_dir)
+            .write_state_config(state, &local_testnet_chain_config())?;
+
+        // When
+        let result = exec(Command {
+            database_path: db_path,
+            output_dir: modified_snapshot_dir,
+            max_database_cache_size: DEFAULT_DATABASE_CACHE_SIZE,
+            subcommand: SubCommands::Verify,
+        })
+        .await;
+
+        // Then
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn diff__does_not_open_the_database() -> anyhow::Result<()> {
+        // Given
+        let temp_dir = tempfile::tempdir()?;
+        let snapshot_dir = temp_dir.path().join("snapshot");
+        let db_path = temp_dir.path().join("db");
+        let state = StateConfig::randomize(StdRng::seed_from_u64(2));
+        fuel_core_chain_config::SnapshotWriter::json(&snapshot_dir)
+            .write_state_config(state, &local_testnet_chain_config())?;
+
+        // When
+        exec(Command {
+            database_path: db_path.clone(),
+            output_dir: snapshot_dir.clone(),
+            max_database_cache_size: DEFAULT_DATABASE_CACHE_SIZE,
+            subcommand: SubCommands::Diff {
+                base: snapshot_dir,
+                limit: 10,
+            },
+        })
+        .await?;
+
+        // Then
+        assert!(!db_path.exists());
+
+        Ok(())
+    }
+
     #[cfg(feature = "parquet")]
     fn assert_groups_as_expected<T>(
         expected_group_size: usize,
@@ -0,0 +1,334 @@
+use fuel_core::types::fuel_vm::BlobData;
+use fuel_core_chain_config::{
+    AsTable,
+    SnapshotMetadata,
+    SnapshotReader,
+    StateConfig,
+    TableEntry,
+};
+use fuel_core_storage::{
+    kv_store::StorageColumn,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        Coins,
+        ContractsAssets,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
+        FuelBlocks,
+        Messages,
+        SealedBlockConsensus,
+        Transactions,
+    },
+};
+use itertools::Itertools;
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    path::Path,
+};
+
+/// The kind of difference between the entries of two snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    /// The entry exists only in the target snapshot.
+    Added,
+    /// The entry exists only in the base snapshot.
+    Removed,
+    /// The entry exists in both snapshots but with different values.
+    Changed,
+}
+
+/// The differences between the entries of the same table in two snapshots.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TableDiff<Key> {
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize,
+    /// The first differences, up to the limit.
+    pub reported: Vec<(Change, Key)>,
+}
+
+impl<Key> Default for TableDiff<Key> {
+    fn default() -> Self {
+        Self {
+            added: 0,
+            removed: 0,
+            changed: 0,
+            reported: vec![],
+        }
+    }
+}
+
+impl<Key> TableDiff<Key> {
+    fn record(&mut self, change: Change, key: Key, limit: usize) {
+        let counter = match change {
+            Change::Added => &mut self.added,
+            Change::Removed => &mut self.removed,
+            Change::Changed => &mut self.changed,
+        };
+        *counter = counter.saturating_add(1);
+
+        if self.reported.len() < limit {
+            self.reported.push((change, key));
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added == 0 && self.removed == 0 && self.changed == 0
+    }
+}
+
+/// Compares the `target` snapshot with the `base` snapshot table by table and prints
+/// the differences. At most `limit` differing keys are printed per table.
+pub fn exec(base: &Path, target: &Path, limit: usize) -> anyhow::Result<()> {
+    let base = SnapshotReader::open(SnapshotMetadata::read(base)?)?;
+    let target = SnapshotReader::open(SnapshotMetadata::read(target)?)?;
+
+    macro_rules! diff {
+        ($($table: ty),*) => {
+            $(print_diff::<$table>(&diff_table::<$table>(&base, &target, limit)?);)*
+        };
+    }
+
+    diff!(
+        Coins,
+        Messages,
+        BlobData,
+        ContractsRawCode,
+        ContractsLatestUtxo,
+        ContractsState,
+        ContractsAssets,
+        FuelBlocks,
+        SealedBlockConsensus,
+        Transactions
+    );
+
+    let base_block = base.last_block_config();
+    let target_block = target.last_block_config();
+    if base_block != target_block {
+        println!(
+            "Last block: {:?} -> {:?}",
+            base_block.map(|block| block.block_height),
+            target_block.map(|block| block.block_height)
+        );
+    }
+
+    Ok(())
+}
+
+fn print_diff<T>(diff: &TableDiff<T::OwnedKey>)
+where
+    T: TableWithBlueprint,
+    T::OwnedKey: Debug,
+{
+    let name = T::column().name();
+    if diff.is_empty() {
+        println!("{name}: no differences");
+        return;
+    }
+
+    println!(
+        "{name}: {} added, {} removed, {} changed",
+        diff.added, diff.removed, diff.changed
+    );
+    for (change, key) in &diff.reported {
+        let sign = match change {
+            Change::Added => '+',
+            Change::Removed => '-',
+            Change::Changed => '~',
+        };
+        println!("  {sign} {key:?}");
+    }
+}
+
+/// Finds the differences between the entries of the table `T` in two snapshots.
+/// Both snapshots are streamed side by side, so their entries must be sorted by key,
+/// as they are written by the exporter.
+pub fn diff_table<T>(
+    base: &SnapshotReader,
+    target: &SnapshotReader,
+    limit: usize,
+) -> anyhow::Result<TableDiff<T::OwnedKey>>
+where
+    T: TableWithBlueprint + 'static,
+    T::OwnedKey: Ord + Clone,
+    T::OwnedValue: PartialEq,
+    StateConfig: AsTable<T>,
+    TableEntry<T>: serde::de::DeserializeOwned,
+{
+    let mut base_entries = SortedEntries::<T>::new(base)?;
+    let mut target_entries = SortedEntries::<T>::new(target)?;
+    let mut base_entry = base_entries.next()?;
+    let mut target_entry = target_entries.next()?;
+    let mut diff = TableDiff::default();
+
+    loop {
+        let order = match (&base_entry, &target_entry) {
+            (None, None) => break,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(base), Some(target)) => base.key.cmp(&target.key),
+        };
+
+        match order {
+            Ordering::Less => {
+                if let Some(base) = base_entry {
+                    diff.record(Change::Removed, base.key, limit);
+                }
+                base_entry = base_entries.next()?;
+            }
+            Ordering::Greater => {
+                if let Some(target) = target_entry {
+                    diff.record(Change::Added, target.key, limit);
+                }
+                target_entry = target_entries.next()?;
+            }
+            Ordering::Equal => {
+                if let (Some(base), Some(target)) = (base_entry, target_entry) {
+                    if base.value != target.value {
+                        diff.record(Change::Changed, target.key, limit);
+                    }
+                }
+                base_entry = base_entries.next()?;
+                target_entry = target_entries.next()?;
+            }
+        }
+    }
+
+    Ok(diff)
+}
+
+/// Reads the entries of the table group by group and checks that they are sorted by key.
+struct SortedEntries<T>
+where
+    T: TableWithBlueprint,
+{
+    entries: Box<dyn Iterator<Item = anyhow::Result<TableEntry<T>>>>,
+    last_key: Option<T::OwnedKey>,
+}
+
+impl<T> SortedEntries<T>
+where
+    T: TableWithBlueprint + 'static,
+    T::OwnedKey: Ord + Clone,
+    StateConfig: AsTable<T>,
+    TableEntry<T>: serde::de::DeserializeOwned,
+{
+    fn new(reader: &SnapshotReader) -> anyhow::Result<Self> {
+        let entries = reader.read::<T>()?.into_iter().flatten_ok();
+        Ok(Self {
+            entries: Box::new(entries),
+            last_key: None,
+        })
+    }
+
+    fn next(&mut self) -> anyhow::Result<Option<TableEntry<T>>> {
+        let Some(entry) = self.entries.next().transpose()? else {
+            return Ok(None);
+        };
+
+        if let Some(last_key) = &self.last_key {
+            anyhow::ensure!(
+                last_key < &entry.key,
+                "The entries of the `{}` table are not sorted by key",
+                T::column().name()
+            );
+        }
+        self.last_key = Some(entry.key.clone());
+
+        Ok(Some(entry))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use fuel_core_chain_config::{
+        ChainConfig,
+        CoinConfig,
+        Randomize,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    fn reader(state: StateConfig) -> SnapshotReader {
+        SnapshotReader::new_in_memory(ChainConfig::local_testnet(), state)
+    }
+
+    #[test]
+    fn diff_table__same_snapshots_have_no_differences() {
+        // Given
+        let state = StateConfig::randomize(StdRng::seed_from_u64(2)).sorted();
+
+        // When
+        let diff =
+            diff_table::<Coins>(&reader(state.clone()), &reader(state), 10).unwrap();
+
+        // Then
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn diff_table__reports_added_removed_and_changed_entries() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(2);
+        let base = StateConfig::randomize(&mut rng).sorted();
+        let mut target = base.clone();
+        let removed = target.coins.remove(0);
+        target.coins[0].amount = target.coins[0].amount.wrapping_add(1);
+        let changed = target.coins[0].clone();
+        let added: CoinConfig = Randomize::randomize(&mut rng);
+        target.coins.push(added.clone());
+        let target = target.sorted();
+
+        // When
+        let diff = diff_table::<Coins>(&reader(base), &reader(target), 10).unwrap();
+
+        // Then
+        assert_eq!(diff.added, 1);
+        assert_eq!(diff.removed, 1);
+        assert_eq!(diff.changed, 1);
+        assert!(diff
+            .reported
+            .contains(&(Change::Removed, removed.utxo_id())));
+        assert!(diff
+            .reported
+            .contains(&(Change::Changed, changed.utxo_id())));
+        assert!(diff.reported.contains(&(Change::Added, added.utxo_id())));
+    }
+
+    #[test]
+    fn diff_table__reports_at_most_limit_entries() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(2);
+        let base = StateConfig::randomize(&mut rng).sorted();
+        let target = StateConfig::randomize(&mut rng).sorted();
+
+        // When
+        let diff = diff_table::<Coins>(&reader(base), &reader(target), 1).unwrap();
+
+        // Then
+        assert_eq!(diff.added, 2);
+        assert_eq!(diff.removed, 2);
+        assert_eq!(diff.reported.len(), 1);
+    }
+
+    #[test]
+    fn diff_table__fails_on_unsorted_snapshot() {
+        // Given
+        let state = StateConfig::randomize(StdRng::seed_from_u64(2)).sorted();
+        let mut unsorted = state.clone();
+        unsorted.coins.reverse();
+
+        // When
+        let result = diff_table::<Coins>(&reader(state), &reader(unsorted), 10);
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -90,6 +90,12 @@ impl ChainConfig {
             Vec::new()
         };
         chain_config.state_transition_bytecode = bytecode;
+        chain_config.consensus.validate().map_err(|e| {
+            e.context(format!(
+                "the consensus in the chain config is invalid: {:?}",
+                path.to_str()
+            ))
+        })?;
 
         Ok(chain_config)
     }
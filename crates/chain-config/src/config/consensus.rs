@@ -18,6 +18,7 @@ use fuel_core_chain_config::default_consensus_dev_key;
 pub enum ConsensusConfig {
     PoA { signing_key: Address },
     PoAV2(PoAV2),
+    RoundRobinPoA(RoundRobinPoA),
 }
 
 impl ConsensusConfig {
@@ -27,6 +28,14 @@ impl ConsensusConfig {
             signing_key_overrides: Default::default(),
         })
     }
+
+    /// Validates the parameters of the consensus.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        match self {
+            ConsensusConfig::PoA { .. } | ConsensusConfig::PoAV2(_) => Ok(()),
+            ConsensusConfig::RoundRobinPoA(round_robin) => round_robin.validate(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
@@ -71,6 +80,126 @@ impl PoAV2 {
     }
 }
 
+/// The Proof of Authority where the authorized producers take turns producing blocks.
+///
+/// The block at the height `h` is scheduled for the producer at the index
+/// `h % producers.len()`. If the scheduled producer doesn't produce the block
+/// during its slot, the next producers in the rotation take over one by one,
+/// each after its own `takeover_timeout`.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct RoundRobinPoA {
+    /// The ordered set of the authorized block producers.
+    producers: Vec<Address>,
+    /// The duration of the slot of the producer in seconds.
+    slot_duration: u64,
+    /// The time in seconds that each next producer in the rotation waits
+    /// after the end of the slot before taking over the block production.
+    takeover_timeout: u64,
+}
+
+impl RoundRobinPoA {
+    pub fn new(
+        producers: Vec<Address>,
+        slot_duration: u64,
+        takeover_timeout: u64,
+    ) -> Self {
+        RoundRobinPoA {
+            producers,
+            slot_duration,
+            takeover_timeout,
+        }
+    }
+
+    /// Returns the ordered set of the authorized block producers.
+    pub fn producers(&self) -> &[Address] {
+        &self.producers
+    }
+
+    /// Returns the duration of the slot of the producer in seconds.
+    pub fn slot_duration(&self) -> u64 {
+        self.slot_duration
+    }
+
+    /// Returns the takeover timeout in seconds.
+    pub fn takeover_timeout(&self) -> u64 {
+        self.takeover_timeout
+    }
+
+    /// Validates that the schedule has producers and non-zero timings.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            !self.producers.is_empty(),
+            "The round-robin PoA requires at least one block producer"
+        );
+        anyhow::ensure!(
+            self.slot_duration > 0,
+            "The slot duration of the round-robin PoA should be greater than zero"
+        );
+        anyhow::ensure!(
+            self.takeover_timeout > 0,
+            "The takeover timeout of the round-robin PoA should be greater than zero"
+        );
+        Ok(())
+    }
+
+    /// Returns `true` if the `address` is one of the authorized block producers.
+    pub fn is_producer(&self, address: &Address) -> bool {
+        self.producers.contains(address)
+    }
+
+    /// Returns the producer scheduled to produce the block at the given height.
+    pub fn scheduled_producer(&self, height: BlockHeight) -> Option<Address> {
+        let index = self.scheduled_index(height)?;
+        self.producers.get(index).cloned()
+    }
+
+    /// Returns the position of the `producer` in the rotation that starts at the
+    /// scheduled producer of the given height, so the scheduled producer has
+    /// the rank `0`. Returns `None` if the `producer` is not authorized.
+    pub fn rotation_rank(
+        &self,
+        height: BlockHeight,
+        producer: &Address,
+    ) -> Option<usize> {
+        let scheduled = self.scheduled_index(height)?;
+        let len = self.producers.len();
+        self.producers
+            .iter()
+            .enumerate()
+            .filter(|(_, address)| *address == producer)
+            .filter_map(|(index, _)| {
+                index
+                    .checked_add(len)?
+                    .checked_sub(scheduled)?
+                    .checked_rem(len)
+            })
+            .min()
+    }
+
+    /// Returns the minimal time in seconds between the previous block and the block
+    /// at the given height for the `producer` to be allowed to produce it.
+    /// Returns `None` if the `producer` is not authorized.
+    pub fn production_delay(
+        &self,
+        height: BlockHeight,
+        producer: &Address,
+    ) -> Option<u64> {
+        let rank = u64::try_from(self.rotation_rank(height, producer)?).ok()?;
+        let takeover = rank.saturating_mul(self.takeover_timeout);
+        Some(self.slot_duration.saturating_add(takeover))
+    }
+
+    #[cfg(feature = "test-helpers")]
+    pub fn set_producers(&mut self, producers: Vec<Address>) {
+        self.producers = producers;
+    }
+
+    fn scheduled_index(&self, height: BlockHeight) -> Option<usize> {
+        let height = usize::try_from(u32::from(height)).ok()?;
+        height.checked_rem(self.producers.len())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,4 +237,87 @@ mod tests {
             signing_key_after_30
         );
     }
+
+    fn round_robin(producers: &[Address]) -> RoundRobinPoA {
+        RoundRobinPoA::new(producers.to_vec(), 2, 3)
+    }
+
+    #[test]
+    fn scheduled_producer_rotates_over_producers() {
+        // Given
+        let producers = [
+            Address::from([1; 32]),
+            Address::from([2; 32]),
+            Address::from([3; 32]),
+        ];
+        let poa = round_robin(&producers);
+
+        // When/Then
+        assert_eq!(poa.scheduled_producer(0u32.into()), Some(producers[0]));
+        assert_eq!(poa.scheduled_producer(1u32.into()), Some(producers[1]));
+        assert_eq!(poa.scheduled_producer(2u32.into()), Some(producers[2]));
+        assert_eq!(poa.scheduled_producer(3u32.into()), Some(producers[0]));
+        assert_eq!(poa.scheduled_producer(301u32.into()), Some(producers[1]));
+    }
+
+    #[test]
+    fn production_delay_grows_along_the_rotation() {
+        // Given
+        let producers = [
+            Address::from([1; 32]),
+            Address::from([2; 32]),
+            Address::from([3; 32]),
+        ];
+        let poa = round_robin(&producers);
+        let height = 2u32.into();
+
+        // When/Then
+        assert_eq!(poa.production_delay(height, &producers[2]), Some(2));
+        assert_eq!(poa.production_delay(height, &producers[0]), Some(5));
+        assert_eq!(poa.production_delay(height, &producers[1]), Some(8));
+    }
+
+    #[test]
+    fn production_delay_is_none_for_unauthorized_producer() {
+        // Given
+        let poa = round_robin(&[Address::from([1; 32])]);
+        let no_producers = round_robin(&[]);
+
+        // When/Then
+        assert_eq!(
+            poa.production_delay(1u32.into(), &Address::from([2; 32])),
+            None
+        );
+        assert_eq!(
+            no_producers.production_delay(1u32.into(), &Address::from([1; 32])),
+            None
+        );
+        assert_eq!(no_producers.scheduled_producer(1u32.into()), None);
+    }
+
+    #[test]
+    fn validate_accepts_valid_schedule() {
+        // Given
+        let poa = round_robin(&[Address::from([1; 32])]);
+
+        // When
+        let result = ConsensusConfig::RoundRobinPoA(poa).validate();
+
+        // Then
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_invalid_schedule() {
+        // Given
+        let producers = vec![Address::from([1; 32])];
+        let no_producers = RoundRobinPoA::new(vec![], 2, 3);
+        let zero_slot_duration = RoundRobinPoA::new(producers.clone(), 0, 3);
+        let zero_takeover_timeout = RoundRobinPoA::new(producers, 2, 0);
+
+        // When/Then
+        assert!(no_producers.validate().is_err());
+        assert!(zero_slot_duration.validate().is_err());
+        assert!(zero_takeover_timeout.validate().is_err());
+    }
 }
@@ -1,20 +1,41 @@
 use crate::client::{
     schema::{
+        admin::{
+            CreateDatabaseCheckpointArgs,
+            RemoveTxPoolTransactionsArgs,
+            TxPoolBlackListArgs,
+            TxPoolTransactionIdsArgs,
+        },
         block::BlockByHeightArgs,
         coins::{
             ExcludeInput,
             SpendQueryElementInput,
         },
-        contract::ContractBalanceQueryArgs,
-        gas_price::EstimateGasPrice,
+        contract::{
+            ContractBalanceAtHeightQueryArgs,
+            ContractBalanceQueryArgs,
+            ContractByIdAtHeightArgs,
+            ContractStorageSlotsQueryArgs,
+        },
+        gas_price::{
+            EstimateGasPrice,
+            EstimateTipArgs,
+        },
         message::MessageStatusArgs,
         relayed_tx::RelayedTransactionStatusArgs,
-        tx::DryRunArg,
+        tx::{
+            DryRunArg,
+            DryRunAtHeightArg,
+        },
+        tx_pool::TxPoolDependenciesArgs,
         Tai64Timestamp,
         TransactionId,
     },
     types::{
-        gas_price::LatestGasPrice,
+        gas_price::{
+            EstimateTip,
+            LatestGasPrice,
+        },
         message::MessageStatus,
         primitives::{
             Address,
@@ -79,7 +100,10 @@ use schema::{
     balance::BalanceArgs,
     blob::BlobByIdArgs,
     block::BlockByIdArgs,
-    coins::CoinByIdArgs,
+    coins::{
+        CoinByIdArgs,
+        CoinByIdAtHeightArgs,
+    },
     contract::ContractByIdArgs,
     da_compressed::DaCompressedBlockByHeightArgs,
     tx::{
@@ -145,6 +169,7 @@ pub struct FuelClient {
     #[cfg(feature = "subscriptions")]
     cookie: std::sync::Arc<reqwest::cookie::Jar>,
     url: reqwest::Url,
+    admin_token: Option<String>,
 }
 
 impl FromStr for FuelClient {
@@ -172,13 +197,18 @@ impl FromStr for FuelClient {
                 client,
                 cookie,
                 url,
+                admin_token: None,
             })
         }
 
         #[cfg(not(feature = "subscriptions"))]
         {
             let client = reqwest::Client::new();
-            Ok(Self { client, url })
+            Ok(Self {
+                client,
+                url,
+                admin_token: None,
+            })
         }
     }
 }
@@ -206,11 +236,54 @@ pub fn from_strings_errors_to_std_error(errors: Vec<String>) -> io::Error {
     io::Error::new(io::ErrorKind::Other, e)
 }
 
+type SpendQueryArgs = (
+    schema::Address,
+    Vec<SpendQueryElementInput>,
+    Option<ExcludeInput>,
+);
+
+fn coins_to_spend_args(
+    owner: &Address,
+    spend_query: Vec<(AssetId, u64, Option<u32>)>,
+    // (Utxos, Messages Nonce)
+    excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+) -> io::Result<SpendQueryArgs> {
+    let owner: schema::Address = (*owner).into();
+    let spend_query: Vec<SpendQueryElementInput> = spend_query
+        .iter()
+        .map(|(asset_id, amount, max)| -> Result<_, ConversionError> {
+            Ok(SpendQueryElementInput {
+                asset_id: (*asset_id).into(),
+                amount: (*amount).into(),
+                max: (*max).map(|max| max.into()),
+            })
+        })
+        .try_collect()?;
+    let excluded_ids: Option<ExcludeInput> = excluded_ids
+        .map(
+            |(utxos, nonces)| -> (Vec<schema::UtxoId>, Vec<schema::Nonce>) {
+                (
+                    utxos.into_iter().map(Into::into).collect(),
+                    nonces.into_iter().map(Into::into).collect(),
+                )
+            },
+        )
+        .map(Into::into);
+    Ok((owner, spend_query, excluded_ids))
+}
+
 impl FuelClient {
     pub fn new(url: impl AsRef<str>) -> anyhow::Result<Self> {
         Self::from_str(url.as_ref())
     }
 
+    /// Sets the token sent to the admin API of the node
+    /// in the `Authorization: Bearer <token>` header.
+    pub fn with_admin_token(mut self, token: impl Into<String>) -> Self {
+        self.admin_token = Some(token.into());
+        self
+    }
+
     /// Send the GraphQL query to the client.
     pub async fn query<ResponseData, Vars>(
         &self,
@@ -220,9 +293,11 @@ impl FuelClient {
         Vars: serde::Serialize,
         ResponseData: serde::de::DeserializeOwned + 'static,
     {
-        let response = self
-            .client
-            .post(self.url.clone())
+        let mut request = self.client.post(self.url.clone());
+        if let Some(token) = &self.admin_token {
+            request = request.bearer_auth(token);
+        }
+        let response = request
             .run_graphql(q)
             .await
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
@@ -371,6 +446,11 @@ impl FuelClient {
         self.query(query).await.map(|r| r.health)
     }
 
+    pub async fn node_health(&self) -> io::Result<types::NodeHealth> {
+        let query = schema::health::QueryNodeHealth::build(());
+        self.query(query).await.map(|r| r.node_health.into())
+    }
+
     pub async fn node_info(&self) -> io::Result<types::NodeInfo> {
         let query = schema::node_info::QueryNodeInfo::build(());
         self.query(query).await.map(|r| r.node_info.into())
@@ -389,6 +469,26 @@ impl FuelClient {
         self.query(query).await.map(|r| r.estimate_gas_price)
     }
 
+    /// Estimates the tip required to get the transaction with the `max_gas`
+    /// included within `target_blocks` blocks. The `percentile` of tips paid
+    /// in recent blocks is 50 by default. The `size` of the transaction is required
+    /// if the pool orders transactions by the ratio of the tip to the size.
+    pub async fn estimate_tip(
+        &self,
+        target_blocks: u32,
+        percentile: Option<u32>,
+        max_gas: u64,
+        size: Option<u64>,
+    ) -> io::Result<EstimateTip> {
+        let query = schema::gas_price::QueryEstimateTip::build(EstimateTipArgs {
+            target_blocks: target_blocks.into(),
+            percentile: percentile.map(Into::into),
+            max_gas: max_gas.into(),
+            size: size.map(Into::into),
+        });
+        self.query(query).await.map(|r| r.estimate_tip.into())
+    }
+
     #[cfg(feature = "std")]
     pub async fn connected_peers_info(
         &self,
@@ -407,6 +507,13 @@ impl FuelClient {
         })
     }
 
+    /// Returns the range of blocks whose transactions and receipts were pruned, if any.
+    pub async fn pruned_blocks(&self) -> io::Result<Option<types::PrunedBlocks>> {
+        let query = schema::chain::PrunedBlocksQuery::build(());
+        let pruned_blocks = self.query(query).await?.chain.pruned_blocks;
+        Ok(pruned_blocks.map(Into::into))
+    }
+
     pub async fn consensus_parameters(
         &self,
         version: i32,
@@ -493,6 +600,33 @@ impl FuelClient {
             .collect()
     }
 
+    /// Dry run on top of the state after the block at the `at_height`
+    /// with options to override the node behavior
+    pub async fn dry_run_at_height(
+        &self,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        gas_price: Option<u64>,
+        at_height: BlockHeight,
+    ) -> io::Result<Vec<TransactionExecutionStatus>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.to_bytes())))
+            .collect::<Vec<HexString>>();
+        let query = schema::tx::DryRunAtHeight::build(DryRunAtHeightArg {
+            txs,
+            utxo_validation,
+            gas_price: gas_price.map(|gp| gp.into()),
+            block_height: Some(at_height.into()),
+        });
+        let tx_statuses = self.query(query).await.map(|r| r.dry_run)?;
+        tx_statuses
+            .into_iter()
+            .map(|tx_status| tx_status.try_into().map_err(Into::into))
+            .collect()
+    }
+
     /// Estimate predicates for the transaction
     pub async fn estimate_predicates(&self, tx: &mut Transaction) -> io::Result<()> {
         let serialized_tx = tx.to_bytes();
@@ -848,6 +982,123 @@ impl FuelClient {
         Ok(new_height.into())
     }
 
+    /// Returns the number of transactions in the transaction pool and their cumulative
+    /// gas and size.
+    pub async fn txpool_info(&self) -> io::Result<types::TxPoolInfo> {
+        let query = schema::tx_pool::TxPoolInfoQuery::build(());
+        self.query(query).await.map(|r| r.tx_pool.into())
+    }
+
+    /// Returns transactions from the transaction pool sorted by `order_by`.
+    /// If the `owner` is set, returns only transactions with inputs owned by it.
+    pub async fn txpool_transactions(
+        &self,
+        owner: Option<&Address>,
+        order_by: types::TxPoolOrder,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::PooledTransaction, String>> {
+        let owner: Option<schema::Address> = owner.map(|owner| (*owner).into());
+        let query = schema::tx_pool::TxPoolTransactionsQuery::build(
+            (owner, order_by, request).into(),
+        );
+        let transactions = self.query(query).await?.tx_pool.transactions.into();
+        Ok(transactions)
+    }
+
+    /// Returns transactions from the transaction pool that are directly connected
+    /// to the transaction. Returns `None` if the transaction is not in the pool.
+    pub async fn txpool_dependencies(
+        &self,
+        id: &TxId,
+    ) -> io::Result<Option<types::TxPoolDependencies>> {
+        let query =
+            schema::tx_pool::TxPoolDependenciesQuery::build(TxPoolDependenciesArgs {
+                id: (*id).into(),
+            });
+        let dependencies = self.query(query).await?.tx_pool_dependencies;
+        Ok(dependencies.map(Into::into))
+    }
+
+    /// Returns the blacklist of the transaction pool.
+    /// Requires the admin token, see [`FuelClient::with_admin_token`].
+    pub async fn txpool_black_list(&self) -> io::Result<types::TxPoolBlackList> {
+        let query = schema::admin::TxPoolBlackListQuery::build(());
+        let black_list = self.query(query).await?.tx_pool_black_list;
+        Ok(black_list.into())
+    }
+
+    /// Adds entries to the blacklist of the transaction pool.
+    /// Returns ids of transactions removed from the pool because of the new entries.
+    /// Requires the admin token, see [`FuelClient::with_admin_token`].
+    pub async fn add_to_txpool_black_list(
+        &self,
+        entries: types::TxPoolBlackList,
+    ) -> io::Result<Vec<TxId>> {
+        let query = schema::admin::AddToTxPoolBlackList::build(TxPoolBlackListArgs {
+            entries: entries.into(),
+        });
+        let removed = self.query(query).await?.add_to_tx_pool_black_list;
+        Ok(removed.into_iter().map(Into::into).collect())
+    }
+
+    /// Removes entries from the blacklist of the transaction pool.
+    /// Requires the admin token, see [`FuelClient::with_admin_token`].
+    pub async fn remove_from_txpool_black_list(
+        &self,
+        entries: types::TxPoolBlackList,
+    ) -> io::Result<()> {
+        let query =
+            schema::admin::RemoveFromTxPoolBlackList::build(TxPoolBlackListArgs {
+                entries: entries.into(),
+            });
+        self.query(query).await?;
+        Ok(())
+    }
+
+    /// Returns up to `first` ids of transactions in the transaction pool.
+    /// Requires the admin token, see [`FuelClient::with_admin_token`].
+    pub async fn txpool_transaction_ids(&self, first: u32) -> io::Result<Vec<TxId>> {
+        let query =
+            schema::admin::TxPoolTransactionIdsQuery::build(TxPoolTransactionIdsArgs {
+                first: first.into(),
+            });
+        let tx_ids = self.query(query).await?.tx_pool_transaction_ids;
+        Ok(tx_ids.into_iter().map(Into::into).collect())
+    }
+
+    /// Removes transactions along with their dependents from the transaction pool.
+    /// Returns ids of removed transactions.
+    /// Requires the admin token, see [`FuelClient::with_admin_token`].
+    pub async fn remove_txpool_transactions(
+        &self,
+        ids: &[TxId],
+        reason: Option<String>,
+    ) -> io::Result<Vec<TxId>> {
+        let query = schema::admin::RemoveTxPoolTransactions::build(
+            RemoveTxPoolTransactionsArgs {
+                ids: ids.iter().map(|id| (*id).into()).collect(),
+                reason,
+            },
+        );
+        let removed = self.query(query).await?.remove_tx_pool_transactions;
+        Ok(removed.into_iter().map(Into::into).collect())
+    }
+
+    /// Creates a consistent checkpoint of all databases of the node in the `path`
+    /// directory on the node's machine. Returns the height of the on-chain database
+    /// in the checkpoint.
+    /// Requires the admin token, see [`FuelClient::with_admin_token`].
+    pub async fn create_database_checkpoint(
+        &self,
+        path: impl Into<String>,
+    ) -> io::Result<Option<BlockHeight>> {
+        let query = schema::admin::CreateDatabaseCheckpoint::build(
+            CreateDatabaseCheckpointArgs { path: path.into() },
+        );
+        let height = self.query(query).await?.create_database_checkpoint;
+        Ok(height.map(Into::into))
+    }
+
     pub async fn block(&self, id: &BlockId) -> io::Result<Option<types::Block>> {
         let query = schema::block::BlockByIdQuery::build(BlockByIdArgs {
             id: Some((*id).into()),
@@ -923,6 +1174,79 @@ impl FuelClient {
         Ok(blocks)
     }
 
+    #[cfg(feature = "subscriptions")]
+    /// Subscribes to the blocks imported by the node. If `from_height` is set,
+    /// the stream starts with the already imported blocks from this height.
+    pub async fn subscribe_blocks(
+        &self,
+        from_height: Option<BlockHeight>,
+    ) -> io::Result<impl Stream<Item = io::Result<types::Block>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::block::BlocksSubscription::build(
+            schema::block::BlocksSubscriptionArgs {
+                from_height: from_height.map(|height| U32(height.into())),
+            },
+        );
+
+        let stream = self.subscribe(s).await?.map(
+            |r: io::Result<schema::block::BlocksSubscription>| {
+                let block: types::Block = r?.blocks.try_into()?;
+                Result::<_, io::Error>::Ok(block)
+            },
+        );
+
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Subscribes to the headers of the blocks imported by the node.
+    /// Works the same way as the [`Self::subscribe_blocks`].
+    pub async fn subscribe_block_headers(
+        &self,
+        from_height: Option<BlockHeight>,
+    ) -> io::Result<impl Stream<Item = io::Result<types::block::Header>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::block::BlockHeadersSubscription::build(
+            schema::block::BlocksSubscriptionArgs {
+                from_height: from_height.map(|height| U32(height.into())),
+            },
+        );
+
+        let stream = self.subscribe(s).await?.map(
+            |r: io::Result<schema::block::BlockHeadersSubscription>| {
+                let header: types::block::Header = r?.block_headers.try_into()?;
+                Result::<_, io::Error>::Ok(header)
+            },
+        );
+
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Subscribes to the receipts emitted by the contract that match the `filter`.
+    /// If `from_height` of the filter is set, the stream starts with the receipts
+    /// from the already imported blocks.
+    pub async fn subscribe_contract_receipts(
+        &self,
+        filter: types::ContractReceiptFilter,
+    ) -> io::Result<impl Stream<Item = io::Result<types::ContractReceipt>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::receipts::ContractReceiptsSubscription::build(
+            schema::receipts::ContractReceiptsSubscriptionArgs {
+                filter: filter.into(),
+            },
+        );
+
+        let stream = self.subscribe(s).await?.map(
+            |r: io::Result<schema::receipts::ContractReceiptsSubscription>| {
+                let receipt: types::ContractReceipt = r?.receipts.try_into()?;
+                Result::<_, io::Error>::Ok(receipt)
+            },
+        );
+
+        Ok(stream)
+    }
+
     pub async fn coin(&self, id: &UtxoId) -> io::Result<Option<types::Coin>> {
         let query = schema::coins::CoinByIdQuery::build(CoinByIdArgs {
             utxo_id: (*id).into(),
@@ -931,6 +1255,20 @@ impl FuelClient {
         Ok(coin)
     }
 
+    /// Returns the coin if it is unspent after the block at the `at_height`.
+    pub async fn coin_at_height(
+        &self,
+        id: &UtxoId,
+        at_height: BlockHeight,
+    ) -> io::Result<Option<types::Coin>> {
+        let query = schema::coins::CoinByIdAtHeightQuery::build(CoinByIdAtHeightArgs {
+            utxo_id: (*id).into(),
+            block_height: Some(at_height.into()),
+        });
+        let coin = self.query(query).await?.coin.map(Into::into);
+        Ok(coin)
+    }
+
     /// Retrieve a page of coins by their owner
     pub async fn coins(
         &self,
@@ -957,27 +1295,8 @@ impl FuelClient {
         // (Utxos, Messages Nonce)
         excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
     ) -> io::Result<Vec<Vec<types::CoinType>>> {
-        let owner: schema::Address = (*owner).into();
-        let spend_query: Vec<SpendQueryElementInput> = spend_query
-            .iter()
-            .map(|(asset_id, amount, max)| -> Result<_, ConversionError> {
-                Ok(SpendQueryElementInput {
-                    asset_id: (*asset_id).into(),
-                    amount: (*amount).into(),
-                    max: (*max).map(|max| max.into()),
-                })
-            })
-            .try_collect()?;
-        let excluded_ids: Option<ExcludeInput> = excluded_ids
-            .map(
-                |(utxos, nonces)| -> (Vec<schema::UtxoId>, Vec<schema::Nonce>) {
-                    (
-                        utxos.into_iter().map(Into::into).collect(),
-                        nonces.into_iter().map(Into::into).collect(),
-                    )
-                },
-            )
-            .map(Into::into);
+        let (owner, spend_query, excluded_ids) =
+            coins_to_spend_args(owner, spend_query, excluded_ids)?;
         let query = schema::coins::CoinsToSpendQuery::build(
             (owner, spend_query, excluded_ids).into(),
         );
@@ -992,6 +1311,75 @@ impl FuelClient {
         Ok(coins_per_asset)
     }
 
+    /// Retrieve coins to spend in a transaction selected with the `strategy`.
+    /// If `cost_per_input` is set, the target of the base asset grows by it
+    /// with every input.
+    pub async fn coins_to_spend_with_strategy(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u64, Option<u32>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        strategy: types::CoinSelectionStrategy,
+        cost_per_input: Option<u64>,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        let (owner, spend_query, excluded_ids) =
+            coins_to_spend_args(owner, spend_query, excluded_ids)?;
+        let query = schema::coins::CoinsToSpendWithStrategyQuery::build(
+            (
+                owner,
+                spend_query,
+                excluded_ids,
+                strategy,
+                cost_per_input.map(Into::into),
+            )
+                .into(),
+        );
+
+        let coins_per_asset = self
+            .query(query)
+            .await?
+            .coins_to_spend
+            .into_iter()
+            .map(|v| v.into_iter().map(Into::into).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Ok(coins_per_asset)
+    }
+
+    /// The same as [`Self::coins_to_spend_with_strategy`], but also returns the total
+    /// amount of selected coins and the estimated change for each asset.
+    pub async fn coins_to_spend_with_change(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u64, Option<u32>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        strategy: types::CoinSelectionStrategy,
+        cost_per_input: Option<u64>,
+    ) -> io::Result<Vec<types::CoinsSelection>> {
+        let (owner, spend_query, excluded_ids) =
+            coins_to_spend_args(owner, spend_query, excluded_ids)?;
+        let query = schema::coins::CoinsToSpendWithChangeQuery::build(
+            (
+                owner,
+                spend_query,
+                excluded_ids,
+                strategy,
+                cost_per_input.map(Into::into),
+            )
+                .into(),
+        );
+
+        let selections = self
+            .query(query)
+            .await?
+            .coins_to_spend_with_change
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(selections)
+    }
+
     pub async fn contract(&self, id: &ContractId) -> io::Result<Option<types::Contract>> {
         let query = schema::contract::ContractByIdQuery::build(ContractByIdArgs {
             id: (*id).into(),
@@ -1000,6 +1388,22 @@ impl FuelClient {
         Ok(contract)
     }
 
+    /// Returns the contract if it exists after the block at the `at_height`.
+    pub async fn contract_at_height(
+        &self,
+        id: &ContractId,
+        at_height: BlockHeight,
+    ) -> io::Result<Option<types::Contract>> {
+        let query = schema::contract::ContractByIdAtHeightQuery::build(
+            ContractByIdAtHeightArgs {
+                id: (*id).into(),
+                block_height: Some(at_height.into()),
+            },
+        );
+        let contract = self.query(query).await?.contract.map(Into::into);
+        Ok(contract)
+    }
+
     pub async fn contract_balance(
         &self,
         id: &ContractId,
@@ -1021,6 +1425,31 @@ impl FuelClient {
         Ok(balance.amount)
     }
 
+    /// Returns the balance of the contract after the block at the `at_height`.
+    pub async fn contract_balance_at_height(
+        &self,
+        id: &ContractId,
+        asset: Option<&AssetId>,
+        at_height: BlockHeight,
+    ) -> io::Result<u64> {
+        let asset_id: schema::AssetId = match asset {
+            Some(asset) => (*asset).into(),
+            None => schema::AssetId::default(),
+        };
+
+        let query = schema::contract::ContractBalanceAtHeightQuery::build(
+            ContractBalanceAtHeightQueryArgs {
+                id: (*id).into(),
+                asset: asset_id,
+                block_height: Some(at_height.into()),
+            },
+        );
+
+        let balance: types::ContractBalance =
+            self.query(query).await?.contract_balance.into();
+        Ok(balance.amount)
+    }
+
     pub async fn balance(
         &self,
         owner: &Address,
@@ -1063,6 +1492,49 @@ impl FuelClient {
         Ok(balances)
     }
 
+    /// Returns the values of the contract's storage slots after the block at the `at_height`.
+    /// The latest block is used if the `at_height` is not set.
+    /// Slots that are not set are not returned.
+    pub async fn contract_storage_slots(
+        &self,
+        contract_id: &ContractId,
+        storage_slots: impl Iterator<Item = Bytes32>,
+        at_height: Option<BlockHeight>,
+    ) -> io::Result<Vec<(Bytes32, Vec<u8>)>> {
+        let query = schema::contract::ContractStorageSlotsQuery::build(
+            ContractStorageSlotsQueryArgs {
+                contract_id: (*contract_id).into(),
+                storage_slots: storage_slots.map(Into::into).collect(),
+                block_height: at_height.map(|height| height.into()),
+            },
+        );
+
+        let slots = self
+            .query(query)
+            .await?
+            .contract_storage_slots
+            .into_iter()
+            .map(|slot| (slot.key.into(), slot.value.into()))
+            .collect();
+
+        Ok(slots)
+    }
+
+    /// Returns receipts emitted by the contract that match the `filter`.
+    pub async fn contract_receipts(
+        &self,
+        filter: types::ContractReceiptFilter,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::ContractReceipt, String>> {
+        let filter: schema::receipts::ReceiptFilterInput = filter.into();
+        let query =
+            schema::receipts::ContractReceiptsQuery::build((filter, request).into());
+
+        let receipts = self.query(query).await?.receipts.try_into()?;
+
+        Ok(receipts)
+    }
+
     // Retrieve a message by its nonce
     pub async fn message(&self, nonce: &Nonce) -> io::Result<Option<types::Message>> {
         let query = schema::message::MessageQuery::build(NonceArgs {
@@ -26,6 +26,7 @@ use crate::client::pagination::{
 };
 pub use primitives::*;
 
+pub mod admin;
 pub mod balance;
 pub mod blob;
 pub mod block;
@@ -33,13 +34,16 @@ pub mod chain;
 pub mod coins;
 pub mod contract;
 pub mod da_compressed;
+pub mod health;
 pub mod message;
 pub mod node_info;
+pub mod receipts;
 pub mod upgrades;
 
 pub mod gas_price;
 pub mod primitives;
 pub mod tx;
+pub mod tx_pool;
 
 pub mod relayed_tx;
 
@@ -0,0 +1,149 @@
+use crate::client::schema::{
+    schema,
+    Address,
+    ContractId,
+    Nonce,
+    TransactionId,
+    UtxoId,
+    U32,
+};
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolBlackList {
+    pub owners: Vec<Address>,
+    pub coins: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct TxPoolBlackListQuery {
+    pub tx_pool_black_list: TxPoolBlackList,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TxPoolTransactionIdsArgs {
+    pub first: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxPoolTransactionIdsArgs"
+)]
+pub struct TxPoolTransactionIdsQuery {
+    #[arguments(first: $first)]
+    pub tx_pool_transaction_ids: Vec<TransactionId>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolBlackListInput {
+    pub owners: Vec<Address>,
+    pub coins: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TxPoolBlackListArgs {
+    pub entries: TxPoolBlackListInput,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TxPoolBlackListArgs"
+)]
+pub struct AddToTxPoolBlackList {
+    #[arguments(entries: $entries)]
+    pub add_to_tx_pool_black_list: Vec<TransactionId>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TxPoolBlackListArgs"
+)]
+pub struct RemoveFromTxPoolBlackList {
+    #[arguments(entries: $entries)]
+    pub remove_from_tx_pool_black_list: bool,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct RemoveTxPoolTransactionsArgs {
+    pub ids: Vec<TransactionId>,
+    pub reason: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "RemoveTxPoolTransactionsArgs"
+)]
+pub struct RemoveTxPoolTransactions {
+    #[arguments(ids: $ids, reason: $reason)]
+    pub remove_tx_pool_transactions: Vec<TransactionId>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CreateDatabaseCheckpointArgs {
+    pub path: String,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "CreateDatabaseCheckpointArgs"
+)]
+pub struct CreateDatabaseCheckpoint {
+    #[arguments(path: $path)]
+    pub create_database_checkpoint: Option<U32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tx_pool_black_list_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TxPoolBlackListQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn add_to_tx_pool_black_list_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = AddToTxPoolBlackList::build(TxPoolBlackListArgs {
+            entries: TxPoolBlackListInput::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn remove_tx_pool_transactions_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = RemoveTxPoolTransactions::build(RemoveTxPoolTransactionsArgs {
+            ids: vec![],
+            reason: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn create_database_checkpoint_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = CreateDatabaseCheckpoint::build(CreateDatabaseCheckpointArgs {
+            path: "checkpoint".to_string(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -59,6 +59,33 @@ pub struct BlocksQuery {
     pub blocks: BlockConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlocksSubscriptionArgs {
+    pub from_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "BlocksSubscriptionArgs"
+)]
+pub struct BlocksSubscription {
+    #[arguments(fromHeight: $from_height)]
+    pub blocks: Block,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "BlocksSubscriptionArgs"
+)]
+pub struct BlockHeadersSubscription {
+    #[arguments(fromHeight: $from_height)]
+    pub block_headers: Header,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct BlockConnection {
@@ -516,6 +516,25 @@ pub struct ChainInfo {
     pub consensus_parameters: ConsensusParameters,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct PrunedBlocksQuery {
+    pub chain: ChainPrunedBlocks,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "ChainInfo")]
+pub struct ChainPrunedBlocks {
+    pub pruned_blocks: Option<PrunedBlocks>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PrunedBlocks {
+    pub first: U32,
+    pub last: U32,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -526,4 +545,11 @@ mod tests {
         let operation = ChainQuery::build(());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn pruned_blocks_gql_query_output() {
+        use cynic::QueryBuilder;
+        let operation = PrunedBlocksQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -30,6 +30,23 @@ pub struct CoinByIdQuery {
     pub coin: Option<Coin>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinByIdAtHeightArgs {
+    pub utxo_id: UtxoId,
+    pub block_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinByIdAtHeightArgs"
+)]
+pub struct CoinByIdAtHeightQuery {
+    #[arguments(utxoId: $utxo_id, blockHeight: $block_height)]
+    pub coin: Option<Coin>,
+}
+
 #[derive(cynic::InputObject, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct CoinFilterInput {
@@ -202,6 +219,50 @@ impl From<CoinsToSpendArgsTuple> for CoinsToSpendArgs {
     }
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsToSpendWithStrategyArgs {
+    /// The `Address` of the assets' coins owner.
+    owner: Address,
+    /// The total amount of each asset type to spend.
+    query_per_asset: Vec<SpendQueryElementInput>,
+    /// A list of ids to exclude from the selection.
+    excluded_ids: Option<ExcludeInput>,
+    /// The strategy of the selection of coins.
+    strategy: CoinSelectionStrategy,
+    /// The cost of one input paid in the base asset.
+    cost_per_input: Option<U64>,
+}
+
+pub(crate) type CoinsToSpendWithStrategyArgsTuple = (
+    Address,
+    Vec<SpendQueryElementInput>,
+    Option<ExcludeInput>,
+    CoinSelectionStrategy,
+    Option<U64>,
+);
+
+impl From<CoinsToSpendWithStrategyArgsTuple> for CoinsToSpendWithStrategyArgs {
+    fn from(r: CoinsToSpendWithStrategyArgsTuple) -> Self {
+        CoinsToSpendWithStrategyArgs {
+            owner: r.0,
+            query_per_asset: r.1,
+            excluded_ids: r.2,
+            strategy: r.3,
+            cost_per_input: r.4,
+        }
+    }
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum CoinSelectionStrategy {
+    RandomImprove,
+    LargestFirst,
+    SmallestFirst,
+    MinimizeInputs,
+    ConsolidateDust,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -213,6 +274,37 @@ pub struct CoinsToSpendQuery {
     pub coins_to_spend: Vec<Vec<CoinType>>,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendWithStrategyArgs"
+)]
+pub struct CoinsToSpendWithStrategyQuery {
+    #[arguments(owner: $ owner, queryPerAsset: $ query_per_asset, excludedIds: $ excluded_ids, strategy: $ strategy, costPerInput: $ cost_per_input)]
+    pub coins_to_spend: Vec<Vec<CoinType>>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinsSelection {
+    pub asset_id: AssetId,
+    pub coins: Vec<CoinType>,
+    pub amount: U64,
+    pub change: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendWithStrategyArgs"
+)]
+pub struct CoinsToSpendWithChangeQuery {
+    #[arguments(owner: $ owner, queryPerAsset: $ query_per_asset, excludedIds: $ excluded_ids, strategy: $ strategy, costPerInput: $ cost_per_input)]
+    pub coins_to_spend_with_change: Vec<CoinsSelection>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,6 +318,16 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn coin_by_id_at_height_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = CoinByIdAtHeightQuery::build(CoinByIdAtHeightArgs {
+            utxo_id: UtxoId::default(),
+            block_height: Some(1.into()),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn coins_connection_query_gql_output() {
         use cynic::QueryBuilder;
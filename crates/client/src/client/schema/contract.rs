@@ -2,10 +2,12 @@ use crate::client::{
     schema::{
         schema,
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         PageInfo,
         Salt,
+        U32,
         U64,
     },
     PageDirection,
@@ -28,6 +30,23 @@ pub struct ContractByIdQuery {
     pub contract: Option<Contract>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractByIdAtHeightArgs {
+    pub id: ContractId,
+    pub block_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractByIdAtHeightArgs"
+)]
+pub struct ContractByIdAtHeightQuery {
+    #[arguments(id: $id, blockHeight: $block_height)]
+    pub contract: Option<Contract>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct ContractBalance {
@@ -53,6 +72,49 @@ pub struct ContractBalanceQuery {
     pub contract_balance: ContractBalance,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractBalanceAtHeightQueryArgs {
+    pub id: ContractId,
+    pub asset: AssetId,
+    pub block_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractBalanceAtHeightQueryArgs"
+)]
+pub struct ContractBalanceAtHeightQuery {
+    #[arguments(contract: $id, asset: $asset, blockHeight: $block_height)]
+    pub contract_balance: ContractBalance,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractStorageSlotsQueryArgs {
+    pub contract_id: ContractId,
+    pub storage_slots: Vec<Bytes32>,
+    pub block_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlot {
+    pub key: Bytes32,
+    pub value: HexString,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStorageSlotsQueryArgs"
+)]
+pub struct ContractStorageSlotsQuery {
+    #[arguments(contractId: $contract_id, storageSlots: $storage_slots, blockHeight: $block_height)]
+    pub contract_storage_slots: Vec<ContractStorageSlot>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Contract {
@@ -147,4 +209,25 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn contract_by_id_at_height_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractByIdAtHeightQuery::build(ContractByIdAtHeightArgs {
+            id: ContractId::default(),
+            block_height: Some(1.into()),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn contract_storage_slots_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractStorageSlotsQuery::build(ContractStorageSlotsQueryArgs {
+            contract_id: ContractId::default(),
+            storage_slots: vec![Bytes32::default()],
+            block_height: Some(1.into()),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -47,6 +47,33 @@ pub struct QueryEstimateGasPrice {
     pub estimate_gas_price: EstimateGasPrice,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct EstimateTip {
+    pub tip: U64,
+    pub recent_tip: U64,
+    pub pool_tip: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct EstimateTipArgs {
+    pub target_blocks: U32,
+    pub percentile: Option<U32>,
+    pub max_gas: U64,
+    pub size: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "EstimateTipArgs"
+)]
+pub struct QueryEstimateTip {
+    #[arguments(targetBlocks: $target_blocks, percentile: $percentile, maxGas: $max_gas, size: $size)]
+    pub estimate_tip: EstimateTip,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,4 +92,16 @@ mod tests {
         let operation = QueryEstimateGasPrice::build(arbitrary_horizon.into());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn estimate_tip_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryEstimateTip::build(EstimateTipArgs {
+            target_blocks: 3.into(),
+            percentile: Some(90.into()),
+            max_gas: 1000u64.into(),
+            size: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -0,0 +1,58 @@
+use crate::client::schema::{
+    schema,
+    U32,
+    U64,
+};
+
+#[derive(cynic::Enum, Copy, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum ServiceState {
+    NotStarted,
+    Starting,
+    Started,
+    Stopping,
+    Stopped,
+    StoppedWithError,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ServiceHealth {
+    pub name: String,
+    pub state: ServiceState,
+    pub error: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct NodeHealth {
+    pub live: bool,
+    pub ready: bool,
+    pub services: Vec<ServiceHealth>,
+    pub on_chain_height: Option<U32>,
+    pub off_chain_height: Option<U32>,
+    pub best_peer_height: Option<U32>,
+    pub sync_lag: Option<U32>,
+    pub off_chain_lag: Option<U32>,
+    pub da_height: Option<U64>,
+    pub remote_da_height: Option<U64>,
+    pub da_lag: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryNodeHealth {
+    pub node_health: NodeHealth,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn node_health_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryNodeHealth::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+}
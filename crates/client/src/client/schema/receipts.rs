@@ -0,0 +1,183 @@
+use crate::client::{
+    schema::{
+        schema,
+        tx::transparent_receipt::{
+            Receipt,
+            ReceiptType,
+        },
+        ContractId,
+        ConversionError,
+        PageInfo,
+        TransactionId,
+        U32,
+        U64,
+    },
+    types,
+    PageDirection,
+    PaginatedResult,
+    PaginationRequest,
+};
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ReceiptFilterInput {
+    /// The contract that emitted receipts.
+    pub contract_id: ContractId,
+    /// Returns only receipts of this type.
+    pub receipt_type: Option<ReceiptType>,
+    /// Returns only receipts with this value of the `ra` register.
+    pub ra: Option<U64>,
+    /// Returns only receipts with this value of the `rb` register.
+    pub rb: Option<U64>,
+    /// Returns only receipts from blocks starting from this height (inclusive).
+    pub from_height: Option<U32>,
+    /// Returns only receipts from blocks up to this height (inclusive).
+    pub to_height: Option<U32>,
+}
+
+impl From<types::ContractReceiptFilter> for ReceiptFilterInput {
+    fn from(filter: types::ContractReceiptFilter) -> Self {
+        Self {
+            contract_id: filter.contract_id.into(),
+            receipt_type: filter.receipt_type,
+            ra: filter.ra.map(Into::into),
+            rb: filter.rb.map(Into::into),
+            from_height: filter.from_height.map(|height| U32(height.into())),
+            to_height: filter.to_height.map(|height| U32(height.into())),
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractReceipt {
+    pub block_height: U32,
+    pub transaction_id: TransactionId,
+    pub receipt: Receipt,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractReceiptsConnectionArgs {
+    /// Filter receipts based on a filter
+    filter: ReceiptFilterInput,
+    /// Skip until the cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until the cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n receipts in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n receipts in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(ReceiptFilterInput, PaginationRequest<String>)>
+    for ContractReceiptsConnectionArgs
+{
+    fn from(r: (ReceiptFilterInput, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => ContractReceiptsConnectionArgs {
+                filter: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => ContractReceiptsConnectionArgs {
+                filter: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractReceiptEdge {
+    pub cursor: String,
+    pub node: ContractReceipt,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractReceiptConnection {
+    pub edges: Vec<ContractReceiptEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractReceiptsConnectionArgs"
+)]
+pub struct ContractReceiptsQuery {
+    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last)]
+    pub receipts: ContractReceiptConnection,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractReceiptsSubscriptionArgs {
+    pub filter: ReceiptFilterInput,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "ContractReceiptsSubscriptionArgs"
+)]
+pub struct ContractReceiptsSubscription {
+    #[arguments(filter: $filter)]
+    pub receipts: ContractReceipt,
+}
+
+impl TryFrom<ContractReceiptConnection>
+    for PaginatedResult<types::ContractReceipt, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(conn: ContractReceiptConnection) -> Result<Self, Self::Error> {
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn
+                .edges
+                .into_iter()
+                .map(|e| e.node.try_into())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contract_receipts_query_gql_output() {
+        use cynic::QueryBuilder;
+        let filter = ReceiptFilterInput {
+            contract_id: ContractId::default(),
+            receipt_type: Some(ReceiptType::LogData),
+            ra: None,
+            rb: None,
+            from_height: None,
+            to_height: None,
+        };
+        let operation =
+            ContractReceiptsQuery::build(ContractReceiptsConnectionArgs::from((
+                filter,
+                PaginationRequest {
+                    cursor: None,
+                    results: 10,
+                    direction: PageDirection::Forward,
+                },
+            )));
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -410,6 +410,25 @@ pub struct DryRun {
     pub dry_run: Vec<DryRunTransactionExecutionStatus>,
 }
 
+#[derive(cynic::QueryVariables)]
+pub struct DryRunAtHeightArg {
+    pub txs: Vec<HexString>,
+    pub utxo_validation: Option<bool>,
+    pub gas_price: Option<U64>,
+    pub block_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunAtHeightArg"
+)]
+pub struct DryRunAtHeight {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, gasPrice: $gas_price, blockHeight: $block_height)]
+    pub dry_run: Vec<DryRunTransactionExecutionStatus>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -515,6 +534,19 @@ pub mod tests {
         insta::assert_snapshot!(query.query)
     }
 
+    #[test]
+    fn dry_run_at_height_tx_gql_output() {
+        use cynic::MutationBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = DryRunAtHeight::build(DryRunAtHeightArg {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+            utxo_validation: Some(true),
+            gas_price: Some(123u64.into()),
+            block_height: Some(1.into()),
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
     #[test]
     fn submit_tx_gql_output() {
         use cynic::MutationBuilder;
@@ -0,0 +1,182 @@
+use crate::client::{
+    schema::{
+        schema,
+        Address,
+        PageInfo,
+        Tai64Timestamp,
+        TransactionId,
+        U32,
+        U64,
+    },
+    PageDirection,
+    PaginationRequest,
+};
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolInfo {
+    pub count: U64,
+    pub total_gas: U64,
+    pub total_bytes: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct TxPoolInfoQuery {
+    pub tx_pool: TxPoolInfo,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum TxPoolOrder {
+    Tip,
+    GasPrice,
+    Age,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TxPoolTransactionsConnectionArgs {
+    /// Filter transactions by the owner of inputs
+    pub owner: Option<Address>,
+    /// The order of transactions
+    pub order_by: Option<TxPoolOrder>,
+    /// Skip until transaction id (forward pagination)
+    pub after: Option<String>,
+    /// Skip until transaction id (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(Option<Address>, TxPoolOrder, PaginationRequest<String>)>
+    for TxPoolTransactionsConnectionArgs
+{
+    fn from(r: (Option<Address>, TxPoolOrder, PaginationRequest<String>)) -> Self {
+        match r.2.direction {
+            PageDirection::Forward => TxPoolTransactionsConnectionArgs {
+                owner: r.0,
+                order_by: Some(r.1),
+                after: r.2.cursor,
+                before: None,
+                first: Some(r.2.results),
+                last: None,
+            },
+            PageDirection::Backward => TxPoolTransactionsConnectionArgs {
+                owner: r.0,
+                order_by: Some(r.1),
+                after: None,
+                before: r.2.cursor,
+                first: None,
+                last: Some(r.2.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxPoolTransactionsConnectionArgs"
+)]
+pub struct TxPoolTransactionsQuery {
+    pub tx_pool: TxPoolTransactions,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "TxPoolInfo",
+    variables = "TxPoolTransactionsConnectionArgs"
+)]
+pub struct TxPoolTransactions {
+    #[arguments(owner: $owner, orderBy: $order_by, after: $after, before: $before, first: $first, last: $last)]
+    pub transactions: PooledTransactionConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PooledTransactionConnection {
+    pub edges: Vec<PooledTransactionEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PooledTransactionEdge {
+    pub cursor: String,
+    pub node: PooledTransaction,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PooledTransaction {
+    pub position: U32,
+    pub id: TransactionId,
+    pub tip: U64,
+    pub max_gas: U64,
+    pub bytes_size: U64,
+    pub submitted_at: Tai64Timestamp,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TxPoolDependenciesArgs {
+    pub id: TransactionId,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxPoolDependenciesArgs"
+)]
+pub struct TxPoolDependenciesQuery {
+    #[arguments(id: $id)]
+    pub tx_pool_dependencies: Option<TxPoolDependencies>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolDependencies {
+    pub dependencies: Vec<TransactionId>,
+    pub dependents: Vec<TransactionId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tx_pool_info_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TxPoolInfoQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn tx_pool_transactions_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            TxPoolTransactionsQuery::build(TxPoolTransactionsConnectionArgs {
+                owner: None,
+                order_by: Some(TxPoolOrder::GasPrice),
+                after: None,
+                before: None,
+                first: None,
+                last: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn tx_pool_dependencies_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TxPoolDependenciesQuery::build(TxPoolDependenciesArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
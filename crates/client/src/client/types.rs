@@ -5,12 +5,14 @@ pub mod chain_info;
 pub mod coins;
 pub mod contract;
 pub mod gas_costs;
+pub mod health;
 pub mod upgrades;
 
 pub mod gas_price;
 pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
+pub mod tx_pool;
 
 pub use balance::Balance;
 pub use blob::Blob;
@@ -18,26 +20,45 @@ pub use block::{
     Block,
     Consensus,
 };
-pub use chain_info::ChainInfo;
+pub use chain_info::{
+    ChainInfo,
+    PrunedBlocks,
+};
 pub use coins::{
     Coin,
+    CoinSelectionStrategy,
     CoinType,
+    CoinsSelection,
     MessageCoin,
 };
 pub use contract::{
     Contract,
     ContractBalance,
+    ContractReceipt,
+    ContractReceiptFilter,
 };
 pub use gas_costs::{
     DependentCost,
     GasCosts,
 };
+pub use health::{
+    NodeHealth,
+    ServiceHealth,
+    ServiceState,
+};
 pub use merkle_proof::MerkleProof;
 pub use message::{
     Message,
     MessageProof,
 };
 pub use node_info::NodeInfo;
+pub use tx_pool::{
+    PooledTransaction,
+    TxPoolBlackList,
+    TxPoolDependencies,
+    TxPoolInfo,
+    TxPoolOrder,
+};
 
 use crate::client::schema::{
     relayed_tx::RelayedTransactionStatus as SchemaRelayedTransactionStatus,
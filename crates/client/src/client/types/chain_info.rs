@@ -6,6 +6,7 @@ use crate::client::{
 use fuel_core_types::{
     self,
     fuel_tx::ConsensusParameters,
+    fuel_types::BlockHeight,
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -16,6 +17,13 @@ pub struct ChainInfo {
     pub consensus_parameters: ConsensusParameters,
 }
 
+/// The inclusive range of blocks whose transactions and receipts were pruned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrunedBlocks {
+    pub first: BlockHeight,
+    pub last: BlockHeight,
+}
+
 // GraphQL Translation
 
 impl TryFrom<schema::chain::ChainInfo> for ChainInfo {
@@ -30,3 +38,12 @@ impl TryFrom<schema::chain::ChainInfo> for ChainInfo {
         })
     }
 }
+
+impl From<schema::chain::PrunedBlocks> for PrunedBlocks {
+    fn from(value: schema::chain::PrunedBlocks) -> Self {
+        Self {
+            first: value.first.into(),
+            last: value.last.into(),
+        }
+    }
+}
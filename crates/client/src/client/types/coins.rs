@@ -9,6 +9,8 @@ use crate::client::{
     PaginatedResult,
 };
 
+pub use schema::coins::CoinSelectionStrategy;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CoinType {
     Coin(Coin),
@@ -94,3 +96,25 @@ impl From<schema::coins::CoinConnection> for PaginatedResult<Coin, String> {
         }
     }
 }
+
+/// Selected coins of the asset along with the estimated change.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinsSelection {
+    pub asset_id: AssetId,
+    pub coins: Vec<CoinType>,
+    /// The total amount of selected coins.
+    pub amount: u64,
+    /// The estimated amount of the change output.
+    pub change: u64,
+}
+
+impl From<schema::coins::CoinsSelection> for CoinsSelection {
+    fn from(value: schema::coins::CoinsSelection) -> Self {
+        Self {
+            asset_id: value.asset_id.into(),
+            coins: value.coins.into_iter().map(Into::into).collect(),
+            amount: value.amount.into(),
+            change: value.change.into(),
+        }
+    }
+}
@@ -1,13 +1,23 @@
 use crate::client::{
-    schema,
+    schema::{
+        self,
+        ConversionError,
+    },
     types::primitives::{
         AssetId,
         Bytes,
         ContractId,
         Salt,
+        TransactionId,
     },
     PaginatedResult,
 };
+use fuel_core_types::{
+    fuel_tx::Receipt,
+    fuel_types::BlockHeight,
+};
+
+pub use schema::tx::transparent_receipt::ReceiptType;
 
 pub struct Contract {
     pub id: ContractId,
@@ -22,6 +32,31 @@ pub struct ContractBalance {
     pub asset_id: AssetId,
 }
 
+/// The receipt emitted by the contract along with its position in the chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractReceipt {
+    pub block_height: BlockHeight,
+    pub transaction_id: TransactionId,
+    pub receipt: Receipt,
+}
+
+/// The filter for receipts emitted by the contract.
+#[derive(Clone, Debug, Default)]
+pub struct ContractReceiptFilter {
+    /// The contract that emitted receipts.
+    pub contract_id: ContractId,
+    /// Returns only receipts of this type.
+    pub receipt_type: Option<ReceiptType>,
+    /// Returns only receipts with this value of the `ra` register.
+    pub ra: Option<u64>,
+    /// Returns only receipts with this value of the `rb` register.
+    pub rb: Option<u64>,
+    /// Returns only receipts from blocks starting from this height (inclusive).
+    pub from_height: Option<BlockHeight>,
+    /// Returns only receipts from blocks up to this height (inclusive).
+    pub to_height: Option<BlockHeight>,
+}
+
 // GraphQL Translation
 
 impl From<schema::contract::Contract> for Contract {
@@ -56,3 +91,15 @@ impl From<schema::contract::ContractBalanceConnection>
         }
     }
 }
+
+impl TryFrom<schema::receipts::ContractReceipt> for ContractReceipt {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::receipts::ContractReceipt) -> Result<Self, Self::Error> {
+        Ok(Self {
+            block_height: value.block_height.into(),
+            transaction_id: value.transaction_id.into(),
+            receipt: value.receipt.try_into()?,
+        })
+    }
+}
@@ -28,3 +28,24 @@ impl From<schema::gas_price::EstimateGasPrice> for EstimateGasPrice {
         }
     }
 }
+
+#[derive(Debug, Copy, Clone)]
+pub struct EstimateTip {
+    /// The tip expected to get the transaction included within the target blocks.
+    pub tip: u64,
+    /// The tip at the requested percentile of transactions in recent blocks.
+    pub recent_tip: u64,
+    /// The tip required to outbid transactions in the pool that don't fit
+    /// into the target blocks.
+    pub pool_tip: u64,
+}
+
+impl From<schema::gas_price::EstimateTip> for EstimateTip {
+    fn from(value: schema::gas_price::EstimateTip) -> Self {
+        Self {
+            tip: value.tip.into(),
+            recent_tip: value.recent_tip.into(),
+            pool_tip: value.pool_tip.into(),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::client::schema;
+
+pub use schema::health::ServiceState;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServiceHealth {
+    pub name: String,
+    pub state: ServiceState,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodeHealth {
+    pub live: bool,
+    pub ready: bool,
+    pub services: Vec<ServiceHealth>,
+    pub on_chain_height: Option<u32>,
+    pub off_chain_height: Option<u32>,
+    pub best_peer_height: Option<u32>,
+    pub sync_lag: Option<u32>,
+    pub off_chain_lag: Option<u32>,
+    pub da_height: Option<u64>,
+    pub remote_da_height: Option<u64>,
+    pub da_lag: Option<u64>,
+}
+
+// GraphQL Translation
+
+impl From<schema::health::ServiceHealth> for ServiceHealth {
+    fn from(value: schema::health::ServiceHealth) -> Self {
+        Self {
+            name: value.name,
+            state: value.state,
+            error: value.error,
+        }
+    }
+}
+
+impl From<schema::health::NodeHealth> for NodeHealth {
+    fn from(value: schema::health::NodeHealth) -> Self {
+        Self {
+            live: value.live,
+            ready: value.ready,
+            services: value.services.into_iter().map(Into::into).collect(),
+            on_chain_height: value.on_chain_height.map(Into::into),
+            off_chain_height: value.off_chain_height.map(Into::into),
+            best_peer_height: value.best_peer_height.map(Into::into),
+            sync_lag: value.sync_lag.map(Into::into),
+            off_chain_lag: value.off_chain_lag.map(Into::into),
+            da_height: value.da_height.map(Into::into),
+            remote_da_height: value.remote_da_height.map(Into::into),
+            da_lag: value.da_lag.map(Into::into),
+        }
+    }
+}
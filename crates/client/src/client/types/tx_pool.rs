@@ -0,0 +1,117 @@
+use crate::client::{
+    schema,
+    types::primitives::{
+        Address,
+        ContractId,
+        Nonce,
+        TransactionId,
+        UtxoId,
+    },
+    PaginatedResult,
+};
+use tai64::Tai64;
+
+pub use schema::tx_pool::TxPoolOrder;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TxPoolBlackList {
+    pub owners: Vec<Address>,
+    pub coins: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TxPoolInfo {
+    pub count: u64,
+    pub total_gas: u64,
+    pub total_bytes: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PooledTransaction {
+    /// The position of the transaction in the pool in the requested order.
+    pub position: u32,
+    pub id: TransactionId,
+    pub tip: u64,
+    pub max_gas: u64,
+    pub bytes_size: u64,
+    pub submitted_at: Tai64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TxPoolDependencies {
+    /// Transactions in the pool whose outputs are spent by the transaction.
+    pub dependencies: Vec<TransactionId>,
+    /// Transactions in the pool that spend outputs of the transaction.
+    pub dependents: Vec<TransactionId>,
+}
+
+// GraphQL Translation
+
+impl From<schema::admin::TxPoolBlackList> for TxPoolBlackList {
+    fn from(value: schema::admin::TxPoolBlackList) -> Self {
+        Self {
+            owners: value.owners.into_iter().map(Into::into).collect(),
+            coins: value.coins.into_iter().map(Into::into).collect(),
+            messages: value.messages.into_iter().map(Into::into).collect(),
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<TxPoolBlackList> for schema::admin::TxPoolBlackListInput {
+    fn from(value: TxPoolBlackList) -> Self {
+        Self {
+            owners: value.owners.into_iter().map(Into::into).collect(),
+            coins: value.coins.into_iter().map(Into::into).collect(),
+            messages: value.messages.into_iter().map(Into::into).collect(),
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<schema::tx_pool::TxPoolInfo> for TxPoolInfo {
+    fn from(value: schema::tx_pool::TxPoolInfo) -> Self {
+        Self {
+            count: value.count.into(),
+            total_gas: value.total_gas.into(),
+            total_bytes: value.total_bytes.into(),
+        }
+    }
+}
+
+impl From<schema::tx_pool::PooledTransaction> for PooledTransaction {
+    fn from(value: schema::tx_pool::PooledTransaction) -> Self {
+        Self {
+            position: value.position.into(),
+            id: value.id.into(),
+            tip: value.tip.into(),
+            max_gas: value.max_gas.into(),
+            bytes_size: value.bytes_size.into(),
+            submitted_at: value.submitted_at.0,
+        }
+    }
+}
+
+impl From<schema::tx_pool::PooledTransactionConnection>
+    for PaginatedResult<PooledTransaction, String>
+{
+    fn from(conn: schema::tx_pool::PooledTransactionConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
+
+impl From<schema::tx_pool::TxPoolDependencies> for TxPoolDependencies {
+    fn from(value: schema::tx_pool::TxPoolDependencies) -> Self {
+        Self {
+            dependencies: value.dependencies.into_iter().map(Into::into).collect(),
+            dependents: value.dependents.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -45,14 +45,36 @@ use std::collections::{
 pub trait CompressDb: TemporalRegistryAll + EvictorDbAll + UtxoIdToPointer {}
 impl<T> CompressDb for T where T: TemporalRegistryAll + EvictorDbAll + UtxoIdToPointer {}
 
+/// The usage of the temporal registry during the compression of a block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompressionStats {
+    /// The number of values replaced with the keys already present in the registry.
+    pub hits: PerRegistryKeyspace<usize>,
+    /// The number of values missing in the registry and registered by the block.
+    pub misses: PerRegistryKeyspace<usize>,
+}
+
 /// This must be called for all new blocks in sequence, otherwise the result will be garbage, since
 /// the registry is valid for only the current block height. On any other height you could be
 /// referring to keys that have already been overwritten, or have not been written to yet.
 pub async fn compress<D>(
     config: Config,
-    mut db: D,
+    db: D,
     block: &Block,
 ) -> anyhow::Result<VersionedCompressedBlock>
+where
+    D: CompressDb,
+{
+    let (compressed, _) = compress_with_stats(config, db, block).await?;
+    Ok(compressed)
+}
+
+/// The same as [`compress`], but also returns the usage of the temporal registry.
+pub async fn compress_with_stats<D>(
+    config: Config,
+    mut db: D,
+    block: &Block,
+) -> anyhow::Result<(VersionedCompressedBlock, CompressionStats)>
 where
     D: CompressDb,
 {
@@ -68,13 +90,14 @@ where
 
     let mut ctx = prepare_ctx.into_compression_context()?;
     let transactions = target.compress_with(&mut ctx).await?;
-    let registrations: RegistrationsPerTable = ctx.finalize()?;
+    let (registrations, stats) = ctx.finalize()?;
 
-    Ok(VersionedCompressedBlock::V0(CompressedBlockPayloadV0 {
+    let compressed = VersionedCompressedBlock::V0(CompressedBlockPayloadV0 {
         registrations,
         header: block.header().into(),
         transactions,
-    }))
+    });
+    Ok((compressed, stats))
 }
 
 /// Preparation pass through the block to collect all keys accessed during compression.
@@ -125,6 +148,8 @@ macro_rules! compression {
             timestamp: Tai64,
             db: D,
             $($ident: CompressCtxKeyspace<$type>,)*
+            /// Values found in the registry, per keyspace
+            hits: PerRegistryKeyspace<usize>,
         }
 
         impl<D> PrepareCtx<D> where D: CompressDb {
@@ -143,23 +168,30 @@ macro_rules! compression {
                     config: self.config,
                     timestamp: self.timestamp,
                     db: self.db,
+                    hits: Default::default(),
                 })
             }
         }
 
         impl<D> CompressCtx<D> where D: CompressDb {
-            /// Finalizes the compression context, returning the changes to the registry.
+            /// Finalizes the compression context, returning the changes to the registry
+            /// and the usage statistics of the registry.
             /// Commits the registrations and cache evictor states to the database.
-            fn finalize(mut self) -> anyhow::Result<RegistrationsPerTable> {
+            fn finalize(mut self) -> anyhow::Result<(RegistrationsPerTable, CompressionStats)> {
                 let mut registrations = RegistrationsPerTable::default();
+                let mut stats = CompressionStats {
+                    hits: self.hits,
+                    misses: Default::default(),
+                };
                 $(
+                    stats.misses.$ident = self.$ident.changes.len();
                     self.$ident.cache_evictor.commit(&mut self.db)?;
                     for (key, value) in self.$ident.changes.into_iter() {
                         registrations.$ident.push((key, value));
                     }
                 )*
                 registrations.write_to_registry(&mut self.db, self.timestamp)?;
-                Ok(registrations)
+                Ok((registrations, stats))
             }
         }
 
@@ -206,6 +238,7 @@ macro_rules! compression {
                         let key_timestamp = ctx.db.read_timestamp(&found)
                             .context("Database invariant violated: no timestamp stored but key found")?;
                         if ctx.config.is_timestamp_accessible(ctx.timestamp, key_timestamp)? {
+                            ctx.hits.$ident = ctx.hits.$ident.saturating_add(1);
                             return Ok(found);
                         }
                     }
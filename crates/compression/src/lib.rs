@@ -11,7 +11,10 @@ pub mod ports;
 mod registry;
 
 pub use config::Config;
-pub use registry::RegistryKeyspace;
+pub use registry::{
+    PerRegistryKeyspace,
+    RegistryKeyspace,
+};
 
 use fuel_core_types::{
     blockchain::header::PartialBlockHeader,
@@ -40,6 +40,8 @@ pub enum CoinsQueryError {
     MaxCoinsReached,
     #[error("the query contains duplicate assets")]
     DuplicateAssets(AssetId),
+    #[error("the cost per input is paid in the base asset {0}, but the query doesn't contain it")]
+    BaseAssetNotQueried(AssetId),
 }
 
 #[cfg(test)]
@@ -55,6 +57,8 @@ pub struct SpendQuery {
     query_per_asset: Vec<AssetSpendTarget>,
     exclude: Exclude,
     base_asset_id: AssetId,
+    cost_per_input: Word,
+    max_inputs: usize,
 }
 
 impl SpendQuery {
@@ -81,9 +85,25 @@ impl SpendQuery {
             query_per_asset: query_per_asset.to_vec(),
             exclude,
             base_asset_id,
+            cost_per_input: 0,
+            max_inputs: usize::MAX,
         })
     }
 
+    /// Sets the cost of one input paid in the base asset. The target of the base asset
+    /// grows by the cost with every input of the transaction.
+    pub fn with_cost_per_input(mut self, cost_per_input: Word) -> Self {
+        self.cost_per_input = cost_per_input;
+        self
+    }
+
+    /// Sets the maximum number of inputs of the transaction.
+    /// Only the [`CoinSelectionStrategy::ConsolidateDust`] strategy relies on it.
+    pub fn with_max_inputs(mut self, max_inputs: usize) -> Self {
+        self.max_inputs = max_inputs;
+        self
+    }
+
     /// Return `Asset`s.
     pub fn assets(&self) -> &Vec<AssetSpendTarget> {
         &self.query_per_asset
@@ -114,26 +134,77 @@ impl SpendQuery {
     pub fn owner(&self) -> &Address {
         &self.owner
     }
+
+    /// Returns the cost of one input paid in the base asset.
+    pub fn cost_per_input(&self) -> Word {
+        self.cost_per_input
+    }
 }
 
-/// Returns the biggest inputs of the `owner` to satisfy the required `target` of the asset. The
-/// number of inputs for each asset can't exceed `max_inputs`, otherwise throw an error that query
-/// can't be satisfied.
-pub fn largest_first(query: &AssetQuery) -> Result<Vec<CoinType>, CoinsQueryError> {
-    let mut inputs: Vec<_> = query.coins().try_collect()?;
-    inputs.sort_by_key(|coin| Reverse(coin.amount()));
+/// The strategy to select coins that reach the target of the asset.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CoinSelectionStrategy {
+    /// See [`random_improve`].
+    #[default]
+    RandomImprove,
+    /// See [`largest_first`].
+    LargestFirst,
+    /// See [`smallest_first`].
+    SmallestFirst,
+    /// See [`minimize_inputs`].
+    MinimizeInputs,
+    /// Reaches the target with [`largest_first`] and adds the smallest remaining coins
+    /// to merge them into the change output. See [`consolidate_dust`].
+    ConsolidateDust,
+}
+
+/// The amount that the selected coins should reach. If the asset pays the fee,
+/// the amount grows by `cost_per_input` with every input of the transaction,
+/// including inputs of other assets.
+#[derive(Debug, Clone, Copy)]
+pub struct SpendTarget {
+    pub amount: Word,
+    pub cost_per_input: Word,
+    pub other_inputs: usize,
+}
+
+impl SpendTarget {
+    /// The target that doesn't depend on the number of inputs.
+    pub fn fixed(amount: Word) -> Self {
+        Self {
+            amount,
+            cost_per_input: 0,
+            other_inputs: 0,
+        }
+    }
 
+    /// Returns the amount to collect with `inputs` coins of the asset.
+    pub fn amount_with_inputs(&self, inputs: usize) -> Word {
+        let inputs =
+            Word::try_from(inputs.saturating_add(self.other_inputs)).unwrap_or(Word::MAX);
+        self.amount
+            .saturating_add(self.cost_per_input.saturating_mul(inputs))
+    }
+}
+
+/// Collects coins in the order of `inputs` until they reach the `target`.
+/// Returns the collected coins and their total amount.
+fn collect_in_order(
+    inputs: Vec<CoinType>,
+    target: &SpendTarget,
+    max: usize,
+) -> Result<(Vec<CoinType>, Word), CoinsQueryError> {
     let mut collected_amount = 0u64;
     let mut coins = vec![];
 
     for coin in inputs {
         // Break if we don't need any more coins
-        if collected_amount >= query.asset.target {
+        if collected_amount >= target.amount_with_inputs(coins.len()) {
             break
         }
 
         // Error if we can't fit more coins
-        if coins.len() >= query.asset.max {
+        if coins.len() >= max {
             return Err(CoinsQueryError::MaxCoinsReached)
         }
 
@@ -142,69 +213,299 @@ pub fn largest_first(query: &AssetQuery) -> Result<Vec<CoinType>, CoinsQueryErro
         coins.push(coin);
     }
 
-    if collected_amount < query.asset.target {
+    Ok((coins, collected_amount))
+}
+
+fn ensure_target_reached(
+    query: &AssetQuery,
+    target: &SpendTarget,
+    coins: &[CoinType],
+    collected_amount: Word,
+) -> Result<(), CoinsQueryError> {
+    if collected_amount < target.amount_with_inputs(coins.len()) {
         return Err(CoinsQueryError::InsufficientCoins {
             asset_id: query.asset.id,
             collected_amount,
         })
     }
+    Ok(())
+}
+
+/// Returns the biggest inputs of the `owner` to satisfy the required `target` of the asset. The
+/// number of inputs for each asset can't exceed `max_inputs`, otherwise throw an error that query
+/// can't be satisfied.
+pub fn largest_first(
+    query: &AssetQuery,
+    target: &SpendTarget,
+) -> Result<Vec<CoinType>, CoinsQueryError> {
+    let mut inputs: Vec<_> = query.coins().try_collect()?;
+    inputs.sort_by_key(|coin| Reverse(coin.amount()));
+
+    let (coins, collected_amount) = collect_in_order(inputs, target, query.asset.max)?;
+    ensure_target_reached(query, target, &coins, collected_amount)?;
+
+    Ok(coins)
+}
+
+/// Returns the smallest inputs of the `owner` to satisfy the required `target` of the asset.
+/// Coins that don't cover the cost of their input are skipped. Falls back to
+/// [`largest_first`] if the target can't be reached with `max` coins.
+pub fn smallest_first(
+    query: &AssetQuery,
+    target: &SpendTarget,
+) -> Result<Vec<CoinType>, CoinsQueryError> {
+    let mut inputs: Vec<_> = query
+        .coins()
+        .filter_ok(|coin| coin.amount() > target.cost_per_input)
+        .try_collect()?;
+    inputs.sort_by_key(|coin| coin.amount());
+
+    match collect_in_order(inputs, target, query.asset.max) {
+        Ok((coins, collected_amount)) => {
+            ensure_target_reached(query, target, &coins, collected_amount)?;
+            Ok(coins)
+        }
+        Err(CoinsQueryError::MaxCoinsReached) => largest_first(query, target),
+        Err(err) => Err(err),
+    }
+}
+
+/// Returns the minimal number of inputs of the `owner` to satisfy the required `target`
+/// of the asset. The biggest coins are taken until the smallest coin covering the rest of
+/// the target is found, which keeps the change as small as possible.
+pub fn minimize_inputs(
+    query: &AssetQuery,
+    target: &SpendTarget,
+) -> Result<Vec<CoinType>, CoinsQueryError> {
+    let mut inputs: Vec<_> = query.coins().try_collect()?;
+    inputs.sort_by_key(|coin| Reverse(coin.amount()));
+
+    let mut collected_amount = 0u64;
+    let mut coins = vec![];
+
+    while collected_amount < target.amount_with_inputs(coins.len()) && !inputs.is_empty()
+    {
+        if coins.len() >= query.asset.max {
+            return Err(CoinsQueryError::MaxCoinsReached)
+        }
+
+        let missing_amount = target
+            .amount_with_inputs(coins.len().saturating_add(1))
+            .saturating_sub(collected_amount);
+        // Inputs are sorted in descending order, so the last coin covering
+        // the missing amount is the smallest one. Otherwise, take the biggest coin.
+        let index = inputs
+            .iter()
+            .rposition(|coin| coin.amount() >= missing_amount)
+            .unwrap_or_default();
+        let coin = inputs.remove(index);
+
+        collected_amount = collected_amount.saturating_add(coin.amount());
+        coins.push(coin);
+    }
+
+    ensure_target_reached(query, target, &coins, collected_amount)?;
 
     Ok(coins)
 }
 
 // An implementation of the method described on: https://iohk.io/en/blog/posts/2018/07/03/self-organisation-in-coin-selection/
+fn random_improve_asset(
+    query: &AssetQuery,
+    target: &SpendTarget,
+) -> Result<Vec<CoinType>, CoinsQueryError> {
+    let mut inputs: Vec<_> = query.coins().try_collect()?;
+    inputs.shuffle(&mut thread_rng());
+    inputs.truncate(query.asset.max);
+
+    let mut collected_amount = 0;
+    let mut coins = vec![];
+
+    for coin in inputs {
+        // Set parameters according to spec
+        let target_amount = target.amount_with_inputs(coins.len());
+        let upper_target = target_amount.saturating_mul(2);
+
+        // Try to improve the result by adding dust to the result.
+        if collected_amount >= target_amount {
+            // Break if found coin exceeds max `u64` or the upper limit
+            if collected_amount == u64::MAX || coin.amount() > upper_target {
+                break
+            }
+
+            // Break if adding doesn't improve the distance
+            let change_amount = collected_amount
+                .checked_sub(target_amount)
+                .expect("We checked it above");
+            let distance = target_amount.abs_diff(change_amount);
+            let next_distance =
+                target_amount.abs_diff(change_amount.saturating_add(coin.amount()));
+            if next_distance >= distance {
+                break
+            }
+        }
+
+        // Add to list
+        collected_amount = collected_amount.saturating_add(coin.amount());
+        coins.push(coin);
+    }
+
+    // Fallback to largest_first if we can't fit more coins
+    if collected_amount < target.amount_with_inputs(coins.len()) {
+        swap(&mut coins, &mut largest_first(query, target)?);
+    }
+
+    Ok(coins)
+}
+
+/// Selects coins for each asset of the `spend_query` with the
+/// [`CoinSelectionStrategy::RandomImprove`] strategy.
 pub fn random_improve(
     db: &ReadView,
     spend_query: &SpendQuery,
 ) -> Result<Vec<Vec<CoinType>>, CoinsQueryError> {
-    let mut coins_per_asset = vec![];
+    select_coins(db, spend_query, CoinSelectionStrategy::RandomImprove)
+}
+
+/// Selects coins for each asset of the `spend_query` with the `strategy`.
+/// If the query contains the base asset, its target grows by the cost per input of the query
+/// with every selected input. To know the total number of inputs, the base asset is
+/// selected last.
+pub fn select_coins(
+    db: &ReadView,
+    spend_query: &SpendQuery,
+    strategy: CoinSelectionStrategy,
+) -> Result<Vec<Vec<CoinType>>, CoinsQueryError> {
+    let queries = spend_query.asset_queries(db);
+    let mut coins_per_asset = vec![vec![]; queries.len()];
+    let fee_asset_index = queries
+        .iter()
+        .position(|query| query.asset.id == spend_query.base_asset_id);
+    if fee_asset_index.is_none() && spend_query.cost_per_input > 0 {
+        return Err(CoinsQueryError::BaseAssetNotQueried(
+            spend_query.base_asset_id,
+        ))
+    }
 
-    for query in spend_query.asset_queries(db) {
-        let mut inputs: Vec<_> = query.coins().try_collect()?;
-        inputs.shuffle(&mut thread_rng());
-        inputs.truncate(query.asset.max);
+    let mut other_inputs = 0usize;
+    for (index, query) in queries.iter().enumerate() {
+        if Some(index) == fee_asset_index {
+            continue
+        }
+        let coins =
+            select_asset_coins(query, &SpendTarget::fixed(query.asset.target), strategy)?;
+        other_inputs = other_inputs.saturating_add(coins.len());
+        coins_per_asset[index] = coins;
+    }
 
-        let mut collected_amount = 0;
-        let mut coins = vec![];
+    if let Some(index) = fee_asset_index {
+        let query = &queries[index];
+        let target = SpendTarget {
+            amount: query.asset.target,
+            cost_per_input: spend_query.cost_per_input,
+            other_inputs,
+        };
+        coins_per_asset[index] = select_asset_coins(query, &target, strategy)?;
+    }
 
-        // Set parameters according to spec
-        let target = query.asset.target;
-        let upper_target = query.asset.target.saturating_mul(2);
-
-        for coin in inputs {
-            // Try to improve the result by adding dust to the result.
-            if collected_amount >= target {
-                // Break if found coin exceeds max `u64` or the upper limit
-                if collected_amount == u64::MAX || coin.amount() > upper_target {
-                    break
-                }
+    if strategy == CoinSelectionStrategy::ConsolidateDust {
+        consolidate_dust(&queries, spend_query, fee_asset_index, &mut coins_per_asset)?;
+    }
+
+    Ok(coins_per_asset)
+}
+
+fn select_asset_coins(
+    query: &AssetQuery,
+    target: &SpendTarget,
+    strategy: CoinSelectionStrategy,
+) -> Result<Vec<CoinType>, CoinsQueryError> {
+    match strategy {
+        CoinSelectionStrategy::RandomImprove => random_improve_asset(query, target),
+        CoinSelectionStrategy::LargestFirst | CoinSelectionStrategy::ConsolidateDust => {
+            largest_first(query, target)
+        }
+        CoinSelectionStrategy::SmallestFirst => smallest_first(query, target),
+        CoinSelectionStrategy::MinimizeInputs => minimize_inputs(query, target),
+    }
+}
+
+/// Adds the smallest remaining coins to the selection while the total number of inputs
+/// is below the `max_inputs` of the query and the number of coins of the asset is below
+/// its `max`. Coins of the base asset are added only if they cover the cost of their input.
+/// Coins of other assets are added only while the change of the base asset covers
+/// the cost of their inputs.
+fn consolidate_dust(
+    queries: &[AssetQuery],
+    spend_query: &SpendQuery,
+    fee_asset_index: Option<usize>,
+    coins_per_asset: &mut [Vec<CoinType>],
+) -> Result<(), CoinsQueryError> {
+    let total_inputs = coins_per_asset
+        .iter()
+        .fold(0usize, |total, coins| total.saturating_add(coins.len()));
+    let mut inputs_left = spend_query.max_inputs.saturating_sub(total_inputs);
+
+    // The cost of inputs is only paid if the base asset is a part of the query.
+    let (cost_per_input, mut fee_change) = match fee_asset_index {
+        Some(index) => {
+            let collected_amount = coins_per_asset[index]
+                .iter()
+                .fold(0u64, |total, coin| total.saturating_add(coin.amount()));
+            let cost_of_inputs = spend_query
+                .cost_per_input
+                .saturating_mul(Word::try_from(total_inputs).unwrap_or(Word::MAX));
+            let required_amount =
+                queries[index].asset.target.saturating_add(cost_of_inputs);
+            (
+                spend_query.cost_per_input,
+                collected_amount.saturating_sub(required_amount),
+            )
+        }
+        None => (0, 0),
+    };
 
-                // Break if adding doesn't improve the distance
-                let change_amount = collected_amount
-                    .checked_sub(target)
-                    .expect("We checked it above");
-                let distance = target.abs_diff(change_amount);
-                let next_distance =
-                    target.abs_diff(change_amount.saturating_add(coin.amount()));
-                if next_distance >= distance {
+    // The base asset goes first to increase the change that pays for other inputs.
+    let order = fee_asset_index
+        .into_iter()
+        .chain((0..queries.len()).filter(|index| Some(*index) != fee_asset_index));
+
+    for index in order {
+        let query = &queries[index];
+        let coins = &mut coins_per_asset[index];
+        let selected: HashSet<CoinId> = coins.iter().map(CoinType::coin_id).collect();
+        let mut dust: Vec<_> = query
+            .coins()
+            .filter_ok(|coin| !selected.contains(&coin.coin_id()))
+            .try_collect()?;
+        dust.sort_by_key(|coin| coin.amount());
+
+        let is_fee_asset = Some(index) == fee_asset_index;
+        for coin in dust {
+            if inputs_left == 0 || coins.len() >= query.asset.max {
+                break
+            }
+
+            if is_fee_asset {
+                if coin.amount() <= cost_per_input {
+                    continue
+                }
+                fee_change = fee_change
+                    .saturating_add(coin.amount())
+                    .saturating_sub(cost_per_input);
+            } else {
+                if fee_change < cost_per_input {
                     break
                 }
+                fee_change = fee_change.saturating_sub(cost_per_input);
             }
 
-            // Add to list
-            collected_amount = collected_amount.saturating_add(coin.amount());
+            inputs_left = inputs_left.saturating_sub(1);
             coins.push(coin);
         }
-
-        // Fallback to largest_first if we can't fit more coins
-        if collected_amount < query.asset.target {
-            swap(&mut coins, &mut largest_first(&query)?);
-        }
-
-        coins_per_asset.push(coins);
     }
 
-    Ok(coins_per_asset)
+    Ok(())
 }
 
 impl From<StorageError> for CoinsQueryError {
@@ -220,8 +521,11 @@ mod tests {
         coins_query::{
             largest_first,
             random_improve,
+            select_coins,
+            CoinSelectionStrategy,
             CoinsQueryError,
             SpendQuery,
+            SpendTarget,
         },
         combined_database::CombinedDatabase,
         fuel_core_graphql_api::{
@@ -333,13 +637,16 @@ mod tests {
             let result: Vec<_> = spend_query
                 .iter()
                 .map(|asset| {
-                    largest_first(&AssetQuery::new(
-                        owner,
-                        asset,
-                        base_asset_id,
-                        None,
-                        &db.test_view(),
-                    ))
+                    largest_first(
+                        &AssetQuery::new(
+                            owner,
+                            asset,
+                            base_asset_id,
+                            None,
+                            &db.test_view(),
+                        ),
+                        &SpendTarget::fixed(asset.target),
+                    )
                     .map(|coins| {
                         coins
                             .iter()
@@ -674,6 +981,162 @@ mod tests {
         }
     }
 
+    mod strategies {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        fn select(
+            db: &TestDatabase,
+            spend_query: &SpendQuery,
+            strategy: CoinSelectionStrategy,
+        ) -> Result<Vec<Vec<Word>>, CoinsQueryError> {
+            let coins =
+                select_coins(&db.service_database().test_view(), spend_query, strategy)?;
+            Ok(coins
+                .into_iter()
+                .map(|coins| coins.iter().map(|coin| coin.amount()).collect())
+                .collect())
+        }
+
+        fn single_asset_query(
+            owner: Address,
+            asset_id: AssetId,
+            target: Word,
+            base_asset_id: AssetId,
+        ) -> SpendQuery {
+            SpendQuery::new(
+                owner,
+                &[AssetSpendTarget::new(asset_id, target, usize::MAX)],
+                None,
+                base_asset_id,
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn smallest_first__selects_smallest_coins() {
+            // Given
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let query = single_asset_query(owner, asset_ids[0], 4, base_asset_id);
+
+            // When
+            let coins = select(&db, &query, CoinSelectionStrategy::SmallestFirst);
+
+            // Then
+            assert_matches!(coins, Ok(coins) if coins == vec![vec![1, 2, 3]]);
+        }
+
+        #[test]
+        fn minimize_inputs__selects_smallest_coin_covering_the_rest() {
+            // Given
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let single_coin = single_asset_query(owner, asset_ids[0], 4, base_asset_id);
+            let two_coins = single_asset_query(owner, asset_ids[0], 7, base_asset_id);
+
+            // When
+            let single_coin =
+                select(&db, &single_coin, CoinSelectionStrategy::MinimizeInputs);
+            let two_coins =
+                select(&db, &two_coins, CoinSelectionStrategy::MinimizeInputs);
+
+            // Then
+            assert_matches!(single_coin, Ok(coins) if coins == vec![vec![4]]);
+            assert_matches!(two_coins, Ok(coins) if coins == vec![vec![5, 2]]);
+        }
+
+        #[test]
+        fn largest_first__target_of_base_asset_grows_with_inputs() {
+            // Given
+            let (owner, asset_ids, _, db) = setup_coins();
+            let base_asset_id = asset_ids[0];
+            let query = single_asset_query(owner, base_asset_id, 5, base_asset_id)
+                .with_cost_per_input(1);
+
+            // When
+            let coins = select(&db, &query, CoinSelectionStrategy::LargestFirst);
+
+            // Then
+            // One coin of 5 doesn't cover the target and the cost of its input.
+            assert_matches!(coins, Ok(coins) if coins == vec![vec![5, 4]]);
+        }
+
+        #[test]
+        fn select_coins__base_asset_pays_for_inputs_of_other_assets() {
+            // Given
+            let (owner, asset_ids, _, db) = setup_coins();
+            let base_asset_id = asset_ids[0];
+            let query = SpendQuery::new(
+                owner,
+                &[
+                    AssetSpendTarget::new(base_asset_id, 5, usize::MAX),
+                    AssetSpendTarget::new(asset_ids[1], 12, usize::MAX),
+                ],
+                None,
+                base_asset_id,
+            )
+            .unwrap()
+            .with_cost_per_input(1);
+
+            // When
+            let coins = select(&db, &query, CoinSelectionStrategy::LargestFirst);
+
+            // Then
+            // The base asset covers 5 plus the cost of 6 inputs.
+            assert_matches!(
+                coins,
+                Ok(coins) if coins == vec![vec![5, 4, 3], vec![5, 4, 3]]
+            );
+        }
+
+        #[test]
+        fn select_coins__fails_if_cost_per_input_is_set_without_base_asset() {
+            // Given
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let query = single_asset_query(owner, asset_ids[1], 5, base_asset_id)
+                .with_cost_per_input(1);
+
+            // When
+            let coins = select(&db, &query, CoinSelectionStrategy::LargestFirst);
+
+            // Then
+            assert_matches!(
+                coins,
+                Err(CoinsQueryError::BaseAssetNotQueried(asset_id)) if asset_id == base_asset_id
+            );
+        }
+
+        #[test]
+        fn consolidate_dust__adds_smallest_coins_up_to_max_inputs() {
+            // Given
+            let (owner, asset_ids, _, db) = setup_coins();
+            let base_asset_id = asset_ids[0];
+            let query = single_asset_query(owner, base_asset_id, 5, base_asset_id)
+                .with_cost_per_input(1)
+                .with_max_inputs(3);
+
+            // When
+            let coins = select(&db, &query, CoinSelectionStrategy::ConsolidateDust);
+
+            // Then
+            // The coin of 1 doesn't cover the cost of its input.
+            assert_matches!(coins, Ok(coins) if coins == vec![vec![5, 4, 2]]);
+        }
+
+        #[test]
+        fn consolidate_dust__without_cost_adds_all_remaining_coins() {
+            // Given
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let query = single_asset_query(owner, asset_ids[1], 5, base_asset_id);
+
+            // When
+            let coins = select(&db, &query, CoinSelectionStrategy::ConsolidateDust);
+
+            // Then
+            assert_matches!(coins, Ok(coins) if coins == vec![vec![5, 1, 2, 3, 4]]);
+        }
+    }
+
     mod exclusion {
         use super::*;
         use fuel_core_types::entities::coins::CoinId;
@@ -28,10 +28,105 @@ use fuel_core_storage::tables::{
     ContractsState,
     Messages,
 };
-use fuel_core_storage::Result as StorageResult;
-use fuel_core_types::fuel_types::BlockHeight;
+use fuel_core_storage::{
+    iter::{
+        IterDirection,
+        IteratorOverTable,
+    },
+    tables::{
+        merkle::{
+            DenseMetadataKey,
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata,
+        },
+        FuelBlocks,
+        SealedBlockConsensus,
+    },
+    transactional::AtomicView,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_merkle::binary::{
+        verify,
+        MerkleTree,
+    },
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
+};
 use std::path::PathBuf;
 
+/// The heights of all databases of the node.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CombinedDatabaseHeights {
+    pub on_chain: Option<BlockHeight>,
+    pub off_chain: Option<BlockHeight>,
+    pub gas_price: Option<BlockHeight>,
+    pub relayer: Option<DaBlockHeight>,
+}
+
+impl CombinedDatabaseHeights {
+    /// Returns `true` if the off-chain and gas price databases are not ahead
+    /// of the on-chain database. The databases that are behind are caught up
+    /// by the node on startup, while the databases that are ahead are rolled back.
+    pub fn is_consistent(&self) -> bool {
+        self.off_chain <= self.on_chain && self.gas_price <= self.on_chain
+    }
+}
+
+/// The database of the node that is rolled back by the block height.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RollbackDatabase {
+    OnChain,
+    OffChain,
+    GasPrice,
+}
+
+/// The preview of the rollback of one database.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DatabaseRollbackPlan {
+    pub database: RollbackDatabase,
+    /// The current height of the database.
+    pub height: Option<BlockHeight>,
+    /// The height of the oldest block whose changes are stored in the history.
+    /// The database can be rolled back to the height before it.
+    pub oldest_changes_height: Option<u64>,
+    /// The number of blocks to roll back to reach the target height.
+    pub blocks_to_rollback: u32,
+}
+
+impl DatabaseRollbackPlan {
+    /// Returns `true` if the history contains the changes of all blocks to roll back.
+    pub fn is_possible(&self) -> bool {
+        let Some(height) = self.height else {
+            return true
+        };
+        if self.blocks_to_rollback == 0 {
+            return true
+        }
+        let first_block_to_rollback = u64::from(*height)
+            .saturating_sub(u64::from(self.blocks_to_rollback))
+            .saturating_add(1);
+        matches!(
+            self.oldest_changes_height,
+            Some(oldest) if oldest <= first_block_to_rollback
+        )
+    }
+}
+
+/// The preview of the rollback of the databases to the target height.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RollbackPlan {
+    pub target_height: BlockHeight,
+    pub databases: Vec<DatabaseRollbackPlan>,
+    /// The height of the relayer database. It follows the DA layer
+    /// and is not rolled back together with other databases.
+    pub relayer_height: Option<DaBlockHeight>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CombinedDatabaseConfig {
     pub database_path: PathBuf,
@@ -94,6 +189,153 @@ impl CombinedDatabase {
         })
     }
 
+    /// Creates a checkpoint of all databases in the `path` directory.
+    /// The `path` can be used as the database path of another node.
+    ///
+    /// The databases can't be checkpointed atomically, so the caller must pause
+    /// the commits of new blocks during the checkpoint. The on-chain database is
+    /// checkpointed first. The off-chain and gas price databases are updated after it,
+    /// so they are never ahead of it in the checkpoint. If they are behind, the node
+    /// catches them up on startup. The relayer database is updated before
+    /// the on-chain database, so it always contains the DA height of the latest block.
+    ///
+    /// Returns the height of the on-chain database in the checkpoint.
+    #[cfg(feature = "rocksdb")]
+    pub fn checkpoint(
+        &self,
+        path: &std::path::Path,
+    ) -> anyhow::Result<Option<BlockHeight>> {
+        if path.exists() && path.read_dir()?.next().is_some() {
+            return Err(anyhow::anyhow!(
+                "The checkpoint directory {path:?} is not empty"
+            ));
+        }
+
+        let height = self.on_chain.latest_height_from_metadata()?;
+        self.on_chain.checkpoint(path)?;
+        self.off_chain.checkpoint(path)?;
+        self.gas_price.checkpoint(path)?;
+        self.relayer.checkpoint(path)?;
+
+        Ok(height)
+    }
+
+    /// Restores all databases from the checkpoint created by [`Self::checkpoint`]
+    /// into the `path` directory. The `path` must not contain any of the databases.
+    ///
+    /// Returns the height of the restored on-chain database.
+    #[cfg(feature = "rocksdb")]
+    pub fn restore(
+        checkpoint_path: &std::path::Path,
+        path: &std::path::Path,
+    ) -> anyhow::Result<Option<BlockHeight>> {
+        use crate::database::database_description::DatabaseDescription;
+
+        let names = [
+            OnChain::name(),
+            OffChain::name(),
+            Relayer::name(),
+            GasPriceDatabase::name(),
+        ];
+
+        for name in &names {
+            let from = checkpoint_path.join(name);
+            if !from.is_dir() {
+                return Err(anyhow::anyhow!(
+                    "The checkpoint doesn't contain the {name} database at {from:?}"
+                ));
+            }
+            let to = path.join(name);
+            if to.exists() {
+                return Err(anyhow::anyhow!(
+                    "The {name} database already exists at {to:?}"
+                ));
+            }
+        }
+
+        for name in &names {
+            copy_dir(&checkpoint_path.join(name), &path.join(name))?;
+        }
+
+        let on_chain =
+            Database::<OnChain>::open_rocksdb(path, None, StateRewindPolicy::NoRewind)?;
+        on_chain.check_version()?;
+        Ok(on_chain.latest_height_from_metadata()?)
+    }
+
+    /// Removes the off-chain database at the `path` and marks the new empty one
+    /// for reindexing. The node rebuilds it from the on-chain database on the next start,
+    /// see [`Database::mark_for_reindex`]. The journal and the blacklist changes of
+    /// the transaction pool are not derived from the blocks, so they are kept.
+    ///
+    /// Fails if the node pruned the transactions of old blocks, because
+    /// the blocks can't be re-executed without them.
+    #[cfg(feature = "rocksdb")]
+    pub fn reindex_off_chain(path: &std::path::Path) -> anyhow::Result<()> {
+        use crate::fuel_core_graphql_api::storage::{
+            pruning::{
+                PrunedBlocks,
+                PRUNED_BLOCKS,
+            },
+            statistic::StatisticTable,
+            txpool_journal::{
+                TxPoolBlackListChanges,
+                TxPoolJournal,
+            },
+        };
+        use fuel_core_storage::{
+            transactional::ReadTransaction,
+            StorageAsMut,
+        };
+        use itertools::Itertools;
+
+        let (journal, blacklist_changes) = {
+            let off_chain = Database::<OffChain>::open_rocksdb(
+                path,
+                None,
+                StateRewindPolicy::NoRewind,
+            )?;
+            let pruned_blocks = off_chain
+                .storage_as_ref::<StatisticTable<PrunedBlocks>>()
+                .get(PRUNED_BLOCKS)?;
+            if let Some(pruned_blocks) = pruned_blocks {
+                return Err(anyhow::anyhow!(
+                    "The off-chain database can't be reindexed, because the \
+                    transactions of the blocks from {} to {} were pruned",
+                    pruned_blocks.first,
+                    pruned_blocks.last
+                ));
+            }
+
+            let journal: Vec<_> =
+                off_chain.iter_all::<TxPoolJournal>(None).try_collect()?;
+            let blacklist_changes: Vec<_> = off_chain
+                .iter_all::<TxPoolBlackListChanges>(None)
+                .try_collect()?;
+            (journal, blacklist_changes)
+        };
+
+        crate::state::rocks_db::RocksDb::<OffChain>::prune(path)?;
+        let off_chain =
+            Database::<OffChain>::open_rocksdb(path, None, StateRewindPolicy::NoRewind)?;
+
+        let mut transaction = off_chain.read_transaction();
+        for (tx_id, entry) in journal {
+            transaction
+                .storage_as_mut::<TxPoolJournal>()
+                .insert(&tx_id, &entry)?;
+        }
+        for (key, added) in blacklist_changes {
+            transaction
+                .storage_as_mut::<TxPoolBlackListChanges>()
+                .insert(&key, &added)?;
+        }
+        off_chain.commit_changes_without_height(transaction.into_changes())?;
+
+        off_chain.mark_for_reindex()?;
+        Ok(())
+    }
+
     pub fn from_config(config: &CombinedDatabaseConfig) -> DatabaseResult<Self> {
         let combined_database = match config.database_type {
             #[cfg(feature = "rocksdb")]
@@ -142,6 +384,16 @@ impl CombinedDatabase {
         Ok(())
     }
 
+    /// Returns the heights of all databases from their metadata.
+    pub fn heights(&self) -> StorageResult<CombinedDatabaseHeights> {
+        Ok(CombinedDatabaseHeights {
+            on_chain: self.on_chain.latest_height_from_metadata()?,
+            off_chain: self.off_chain.latest_height_from_metadata()?,
+            gas_price: self.gas_price.latest_height_from_metadata()?,
+            relayer: self.relayer.latest_height_from_metadata()?,
+        })
+    }
+
     pub fn on_chain(&self) -> &Database<OnChain> {
         &self.on_chain
     }
@@ -182,7 +434,6 @@ impl CombinedDatabase {
     pub fn read_state_config(&self) -> StorageResult<StateConfig> {
         use fuel_core_chain_config::AddTable;
         use fuel_core_producer::ports::BlockProducerDatabase;
-        use fuel_core_storage::transactional::AtomicView;
         use fuel_core_types::fuel_vm::BlobData;
         use itertools::Itertools;
         let mut builder = StateConfigBuilder::default();
@@ -231,6 +482,17 @@ impl CombinedDatabase {
         }
     }
 
+    /// Converts only the off-chain database into a genesis combined database.
+    /// It is used to import the off-chain part of the genesis state again when
+    /// the on-chain database is already initialized, so the on-chain and relayer
+    /// databases of the result are empty.
+    pub fn off_chain_into_genesis(self) -> CombinedGenesisDatabase {
+        CombinedGenesisDatabase {
+            off_chain: self.off_chain.into_genesis(),
+            ..Default::default()
+        }
+    }
+
     /// Rollbacks the state of the blockchain to a specific block height.
     pub fn rollback_to<S>(
         &self,
@@ -312,6 +574,217 @@ impl CombinedDatabase {
         Ok(())
     }
 
+    /// Returns the preview of the rollback of the `databases` to the `target_height`
+    /// without modifying them.
+    pub fn rollback_plan(
+        &self,
+        target_height: BlockHeight,
+        databases: &[RollbackDatabase],
+    ) -> StorageResult<RollbackPlan> {
+        let databases = databases
+            .iter()
+            .map(|database| {
+                let height = self.rollback_database_height(*database)?;
+                let oldest_changes_height = match database {
+                    RollbackDatabase::OnChain => self.on_chain.oldest_changes_height()?,
+                    RollbackDatabase::OffChain => {
+                        self.off_chain.oldest_changes_height()?
+                    }
+                    RollbackDatabase::GasPrice => {
+                        self.gas_price.oldest_changes_height()?
+                    }
+                };
+                let blocks_to_rollback = height
+                    .map(|height| height.saturating_sub(*target_height))
+                    .unwrap_or_default();
+
+                Ok(DatabaseRollbackPlan {
+                    database: *database,
+                    height,
+                    oldest_changes_height,
+                    blocks_to_rollback,
+                })
+            })
+            .collect::<StorageResult<Vec<_>>>()?;
+
+        Ok(RollbackPlan {
+            target_height,
+            databases,
+            relayer_height: self.relayer.latest_height_from_metadata()?,
+        })
+    }
+
+    /// Rollbacks only the `databases` to the `target_height`. Unlike [`Self::rollback_to`],
+    /// it allows rolling back the auxiliary databases without the on-chain database.
+    /// The databases without a height are skipped.
+    pub fn rollback_databases_to<S>(
+        &self,
+        target_height: BlockHeight,
+        databases: &[RollbackDatabase],
+        shutdown_listener: &mut S,
+    ) -> anyhow::Result<()>
+    where
+        S: ShutdownListener,
+    {
+        let plan = self.rollback_plan(target_height, databases)?;
+        for database_plan in plan.databases.iter() {
+            let database = database_plan.database;
+            if let Some(height) = database_plan.height {
+                if height < target_height {
+                    return Err(anyhow::anyhow!(
+                        "{database:?} database height({height}) \
+                        is less than target height({target_height})"
+                    ));
+                }
+            }
+            if !database_plan.is_possible() {
+                return Err(anyhow::anyhow!(
+                    "The history of the {database:?} database doesn't contain \
+                    all blocks to roll back, the oldest block in the history is {:?}",
+                    database_plan.oldest_changes_height
+                ));
+            }
+        }
+
+        while !shutdown_listener.is_cancelled() {
+            let mut rolled_back = true;
+            for database in databases {
+                let height = self.rollback_database_height(*database)?;
+                if height.map_or(false, |height| height > target_height) {
+                    match database {
+                        RollbackDatabase::OnChain => {
+                            self.on_chain.rollback_last_block()?
+                        }
+                        RollbackDatabase::OffChain => {
+                            self.off_chain.rollback_last_block()?
+                        }
+                        RollbackDatabase::GasPrice => {
+                            self.gas_price.rollback_last_block()?
+                        }
+                    }
+                    rolled_back = false;
+                }
+            }
+
+            if rolled_back {
+                return Ok(())
+            }
+        }
+
+        Err(anyhow::anyhow!(
+            "Stop the rollback due to shutdown signal received"
+        ))
+    }
+
+    fn rollback_database_height(
+        &self,
+        database: RollbackDatabase,
+    ) -> StorageResult<Option<BlockHeight>> {
+        match database {
+            RollbackDatabase::OnChain => self.on_chain.latest_height_from_metadata(),
+            RollbackDatabase::OffChain => self.off_chain.latest_height_from_metadata(),
+            RollbackDatabase::GasPrice => self.gas_price.latest_height_from_metadata(),
+        }
+    }
+
+    /// Checks the integrity of the latest block of the on-chain database:
+    /// - the height from the metadata matches the latest stored block;
+    /// - the block has the consensus seal;
+    /// - the latest root of the blocks merkle tree is the root after the latest block;
+    /// - the blocks merkle tree recomputed from its nodes has the latest root
+    ///   and contains the latest block as the last leaf;
+    /// - the previous root of the header is the root before the latest block.
+    ///
+    /// It is useful after the rollback to verify that the state wasn't corrupted.
+    pub fn check_integrity(&self) -> anyhow::Result<()> {
+        let view = self.on_chain.latest_view()?;
+        let Some(height) = self.on_chain.latest_height_from_metadata()? else {
+            return Ok(())
+        };
+
+        let latest_block_height = view
+            .iter_all_keys::<FuelBlocks>(Some(IterDirection::Reverse))
+            .next()
+            .transpose()?;
+        if latest_block_height != Some(height) {
+            return Err(anyhow::anyhow!(
+                "The height of the on-chain database({height}) doesn't match \
+                the latest block({latest_block_height:?})"
+            ));
+        }
+
+        let block = view
+            .storage::<FuelBlocks>()
+            .get(&height)?
+            .ok_or(anyhow::anyhow!("The block at {height} is not found"))?;
+        let header = block.header();
+        if !view
+            .storage::<SealedBlockConsensus>()
+            .contains_key(&height)?
+        {
+            return Err(anyhow::anyhow!(
+                "The consensus of the block at {height} is not found"
+            ));
+        }
+        let latest_metadata = view
+            .storage::<FuelBlockMerkleMetadata>()
+            .get(&DenseMetadataKey::Latest)?
+            .ok_or(anyhow::anyhow!(
+                "The latest blocks merkle metadata is not found"
+            ))?;
+        let block_metadata = view
+            .storage::<FuelBlockMerkleMetadata>()
+            .get(&DenseMetadataKey::Primary(height))?
+            .ok_or(anyhow::anyhow!(
+                "The blocks merkle metadata of the block at {height} is not found"
+            ))?;
+        if latest_metadata.root() != block_metadata.root()
+            || latest_metadata.version() != block_metadata.version()
+        {
+            return Err(anyhow::anyhow!(
+                "The latest blocks merkle root doesn't match the root after the block at {height}"
+            ));
+        }
+
+        let leaves_count = latest_metadata.version();
+        let tree: MerkleTree<FuelBlockMerkleData, _> =
+            MerkleTree::load(&view, leaves_count).map_err(|e| {
+                anyhow::anyhow!("Failed to load the blocks merkle tree: {e:?}")
+            })?;
+        let last_leaf_index = leaves_count.saturating_sub(1);
+        let (root, proof_set) = tree.prove(last_leaf_index).map_err(|e| {
+            anyhow::anyhow!("Failed to recompute the blocks merkle tree: {e:?}")
+        })?;
+        if &root != latest_metadata.root() {
+            return Err(anyhow::anyhow!(
+                "The recomputed blocks merkle root doesn't match the latest root"
+            ));
+        }
+        let block_id: Bytes32 = block.id().into();
+        if !verify(&root, &block_id, &proof_set, last_leaf_index, leaves_count) {
+            return Err(anyhow::anyhow!(
+                "The blocks merkle tree doesn't contain the block at {height} \
+                as the last leaf"
+            ));
+        }
+
+        if let Some(previous_height) = height.pred() {
+            let previous_metadata = view
+                .storage::<FuelBlockMerkleMetadata>()
+                .get(&DenseMetadataKey::Primary(previous_height))?;
+            if let Some(previous_metadata) = previous_metadata {
+                if previous_metadata.root() != &**header.prev_root() {
+                    return Err(anyhow::anyhow!(
+                        "The previous root of the block at {height} doesn't match \
+                        the blocks merkle root after the block at {previous_height}"
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// This function is fundamentally different from `rollback_to` in that it
     /// will rollback the off-chain/gas-price databases if they are ahead of the
     /// on-chain database. If they don't have a height or are behind the on-chain
@@ -357,6 +830,22 @@ impl CombinedDatabase {
     }
 }
 
+/// Copies the content of the `from` directory into the new `to` directory.
+#[cfg(feature = "rocksdb")]
+fn copy_dir(from: &std::path::Path, to: &std::path::Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
 /// A trait for listening to shutdown signals.
 pub trait ShutdownListener {
     /// Returns true if the shutdown signal has been received.
@@ -1,6 +1,7 @@
 use crate::{
     database::{
         database_description::{
+            da_decompression::DaDecompression,
             off_chain::OffChain,
             on_chain::OnChain,
             relayer::Relayer,
@@ -11,7 +12,10 @@ use crate::{
         metadata::MetadataTable,
         Error as DatabaseError,
     },
-    graphql_api::storage::blocks::FuelBlockIdsToHeights,
+    graphql_api::storage::{
+        blocks::FuelBlockIdsToHeights,
+        da_compression::DaCompressedBlocks,
+    },
     state::{
         data_source::{
             DataSource,
@@ -22,7 +26,6 @@ use crate::{
         ChangesIterator,
         ColumnType,
         IterableKeyValueView,
-        KeyValueView,
     },
 };
 use fuel_core_chain_config::TableEntry;
@@ -76,7 +79,6 @@ use crate::state::{
     rocks_db::RocksDb,
 };
 use fuel_core_gas_price_service::common::fuel_core_storage_adapter::storage::GasPriceMetadata;
-#[cfg(feature = "rocksdb")]
 use std::path::Path;
 
 // Storages implementation
@@ -86,6 +88,7 @@ pub mod coin;
 pub mod contracts;
 pub mod database_description;
 pub mod genesis_progress;
+pub mod inspection;
 pub mod message;
 pub mod metadata;
 pub mod sealed_block;
@@ -293,6 +296,25 @@ where
 
         Ok(())
     }
+
+    /// Commits the `changes` that are not linked to any block height,
+    /// like the journal of the transaction pool or the pruning of old blocks.
+    /// Such changes are not tracked by the history and are not affected by rollbacks.
+    pub fn commit_changes_without_height(&self, changes: Changes) -> StorageResult<()> {
+        self.inner_storage().data.commit_changes(None, changes)
+    }
+
+    /// Creates a consistent on-disk checkpoint of the database in the `path` directory.
+    /// The `path` can be used later to open the database from the checkpoint.
+    pub fn checkpoint(&self, path: &Path) -> StorageResult<()> {
+        self.inner_storage().data.checkpoint(path)
+    }
+
+    /// Returns the height of the oldest block that can be rolled back.
+    /// It is `None` if the database doesn't store the history of changes.
+    pub fn oldest_changes_height(&self) -> StorageResult<Option<u64>> {
+        self.inner_storage().data.oldest_changes_height()
+    }
 }
 
 impl<Description> AtomicView for Database<Description>
@@ -311,19 +333,32 @@ where
     Description: DatabaseDescription,
 {
     type Height = Description::Height;
-    type ViewAtHeight = KeyValueView<ColumnType<Description>>;
+    type ViewAtHeight = IterableKeyValueView<ColumnType<Description>>;
 
     fn latest_height(&self) -> Option<Self::Height> {
         *self.inner_storage().stage.height.lock()
     }
 
     fn view_at(&self, height: &Self::Height) -> StorageResult<Self::ViewAtHeight> {
+        self.iterable_view_at(height)
+    }
+}
+
+impl<Description> Database<Description>
+where
+    Description: DatabaseDescription,
+{
+    /// Returns the view of the database at the `height` that allows iteration over the entries.
+    pub fn iterable_view_at(
+        &self,
+        height: &Description::Height,
+    ) -> StorageResult<IterableKeyValueView<ColumnType<Description>>> {
         let lock = self.inner_storage().stage.height.lock();
 
         match *lock {
-            None => return self.latest_view().map(|view| view.into_key_value_view()),
+            None => return self.latest_view(),
             Some(current_height) if &current_height == height => {
-                return self.latest_view().map(|view| view.into_key_value_view())
+                return self.latest_view()
             }
             _ => {}
         };
@@ -360,6 +395,15 @@ impl Modifiable for Database<GasPriceDatabase> {
     }
 }
 
+impl Modifiable for Database<DaDecompression> {
+    fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
+        commit_changes_with_height_update(self, changes, |iter| {
+            iter.iter_all_keys::<DaCompressedBlocks>(Some(IterDirection::Reverse))
+                .try_collect()
+        })
+    }
+}
+
 #[cfg(feature = "relayer")]
 impl Modifiable for Database<Relayer> {
     fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
@@ -0,0 +1,36 @@
+use crate::{
+    database::database_description::{
+        off_chain::OffChain,
+        DatabaseDescription,
+    },
+    fuel_core_graphql_api,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+
+/// The database of the node that imports blocks from the DA layer.
+/// It stores the temporal registry used to decompress DA compressed blocks
+/// and the imported compressed blocks. It reuses the columns of the off-chain
+/// database, so the same tables are used for the compression and the decompression.
+#[derive(Copy, Clone, Debug)]
+pub struct DaDecompression;
+
+impl DatabaseDescription for DaDecompression {
+    type Column = fuel_core_graphql_api::storage::Column;
+    type Height = BlockHeight;
+
+    fn version() -> u32 {
+        0
+    }
+
+    fn name() -> String {
+        "da_decompression".to_string()
+    }
+
+    fn metadata_column() -> Self::Column {
+        Self::Column::Metadata
+    }
+
+    fn prefix(column: &Self::Column) -> Option<usize> {
+        OffChain::prefix(column)
+    }
+}
@@ -6,6 +6,7 @@ use super::{
         on_chain::OnChain,
         DatabaseDescription,
     },
+    Database,
     GenesisDatabase,
 };
 use fuel_core_chain_config::GenesisCommitment;
@@ -22,16 +23,22 @@ use fuel_core_storage::{
         Messages,
         ProcessedTransactions,
     },
+    transactional::ReadTransaction,
     Error as StorageError,
     Mappable,
     MerkleRoot,
     Result,
     StorageAsMut,
+    StorageAsRef,
     StorageInspect,
     StorageMutate,
 };
 use fuel_core_types::fuel_merkle::binary::root_calculator::MerkleRootCalculator;
 
+/// The key of the [`GenesisMetadata`] entry that marks the off-chain database for reindexing.
+/// The entry is removed together with the progress of the off-chain genesis import.
+pub const OFF_CHAIN_REINDEX: &str = "off_chain_reindex";
+
 pub struct GenesisMetadata<Description>(core::marker::PhantomData<Description>);
 
 impl<Description> Mappable for GenesisMetadata<Description> {
@@ -152,3 +159,22 @@ impl GenesisDatabase {
         Ok(root_calculator.root())
     }
 }
+
+impl Database<OffChain> {
+    /// Marks the empty off-chain database for reindexing. On start, the node imports
+    /// the off-chain part of the genesis state from the snapshot, and the off-chain
+    /// worker processes all blocks from the on-chain database after it.
+    pub fn mark_for_reindex(&self) -> Result<()> {
+        let mut transaction = self.read_transaction();
+        transaction
+            .storage_as_mut::<GenesisMetadata<OffChain>>()
+            .insert(OFF_CHAIN_REINDEX, &0)?;
+        self.commit_changes_without_height(transaction.into_changes())
+    }
+
+    /// Returns `true` if the off-chain genesis state should be imported again.
+    pub fn is_marked_for_reindex(&self) -> Result<bool> {
+        self.storage_as_ref::<GenesisMetadata<OffChain>>()
+            .contains_key(OFF_CHAIN_REINDEX)
+    }
+}
@@ -0,0 +1,398 @@
+//! The tools to inspect the content of the database without running the node.
+
+use crate::{
+    database::{
+        database_description::{
+            gas_price::GasPriceDatabase,
+            off_chain::OffChain,
+            on_chain::OnChain,
+            relayer::Relayer,
+            DatabaseDescription,
+        },
+        genesis_progress::GenesisMetadata,
+        metadata::MetadataTable,
+        Database,
+    },
+    graphql_api::storage::{
+        blocks::FuelBlockIdsToHeights,
+        coins::OwnedCoins,
+        contracts::ContractsInfo,
+        messages::{
+            OwnedMessageIds,
+            SpentMessages,
+        },
+        old::{
+            OldFuelBlockConsensus,
+            OldFuelBlocks,
+            OldTransactions,
+        },
+        receipts::ContractReceipts,
+        relayed_transactions::RelayedTransactionStatuses,
+        tips::BlockTips,
+        transactions::{
+            OwnedTransactions,
+            TransactionStatuses,
+        },
+        txpool_journal::{
+            TxPoolBlackListChanges,
+            TxPoolJournal,
+        },
+    },
+};
+use fuel_core_gas_price_service::common::fuel_core_storage_adapter::storage::GasPriceMetadata;
+use fuel_core_storage::{
+    blueprint::BlueprintInspect,
+    codec::Decode,
+    iter::{
+        IterDirection,
+        IterableStore,
+    },
+    kv_store::{
+        KeyValueInspect,
+        StorageColumn,
+        Value,
+    },
+    structured_storage::TableWithBlueprint,
+    tables::{
+        merkle::{
+            ContractsAssetsMerkleData,
+            ContractsAssetsMerkleMetadata,
+            ContractsStateMerkleData,
+            ContractsStateMerkleMetadata,
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata,
+        },
+        BlobData,
+        Coins,
+        ConsensusParametersVersions,
+        ContractsAssets,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
+        FuelBlocks,
+        Messages,
+        ProcessedTransactions,
+        SealedBlockConsensus,
+        StateTransitionBytecodeVersions,
+        Transactions,
+        UploadedBytecodes,
+    },
+    Result as StorageResult,
+};
+use std::fmt::Debug;
+
+/// The statistics of the column of the database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnStatistics {
+    /// The name of the column.
+    pub name: String,
+    /// The number of keys in the column.
+    pub keys: u64,
+    /// The total size of all keys in bytes.
+    pub keys_size: u64,
+    /// The total size of all values in bytes.
+    pub values_size: u64,
+}
+
+/// The entry of the database column.
+#[derive(Debug)]
+pub struct InspectedEntry {
+    /// The raw value of the entry.
+    pub raw_value: Vec<u8>,
+    /// The entry decoded with the codecs of the table stored in the column.
+    /// It is `None` if the table of the column is unknown.
+    pub decoded: Option<anyhow::Result<DecodedEntry>>,
+}
+
+/// The entry decoded with the codecs of the table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedEntry {
+    /// The debug representation of the key.
+    pub key: String,
+    /// The debug representation of the value.
+    pub value: String,
+}
+
+/// The database description that knows the tables stored in its columns.
+pub trait DecodeEntry: DatabaseDescription {
+    /// Decodes the `key` and the `value` of the entry from the `column` with the codecs
+    /// of the table stored in the column. Returns `None` if the table is unknown.
+    fn decode_entry(
+        column: Self::Column,
+        key: &[u8],
+        value: Value,
+    ) -> Option<anyhow::Result<DecodedEntry>>;
+}
+
+fn decode<M, S>(key: &[u8], value: Value) -> anyhow::Result<DecodedEntry>
+where
+    M: TableWithBlueprint,
+    M::Blueprint: BlueprintInspect<M, S>,
+    M::OwnedKey: Debug,
+    M::OwnedValue: Debug,
+    S: KeyValueInspect,
+{
+    let key = <M::Blueprint as BlueprintInspect<M, S>>::KeyCodec::decode(key)?;
+    let value =
+        <M::Blueprint as BlueprintInspect<M, S>>::ValueCodec::decode_from_value(value)?;
+    Ok(DecodedEntry {
+        key: format!("{key:?}"),
+        value: format!("{value:#?}"),
+    })
+}
+
+macro_rules! decode_entry {
+    ($description:ty, $column:expr, $key:expr, $value:expr, [$($table:ty),* $(,)?]) => {{
+        $(
+            if $column.id() == <$table as TableWithBlueprint>::column().id() {
+                return Some(decode::<$table, Database<$description>>($key, $value))
+            }
+        )*
+        None
+    }};
+}
+
+impl DecodeEntry for OnChain {
+    fn decode_entry(
+        column: Self::Column,
+        key: &[u8],
+        value: Value,
+    ) -> Option<anyhow::Result<DecodedEntry>> {
+        decode_entry!(
+            OnChain,
+            column,
+            key,
+            value,
+            [
+                MetadataTable<OnChain>,
+                GenesisMetadata<OnChain>,
+                ContractsRawCode,
+                ContractsState,
+                ContractsLatestUtxo,
+                ContractsAssets,
+                Coins,
+                Transactions,
+                FuelBlocks,
+                FuelBlockMerkleData,
+                FuelBlockMerkleMetadata,
+                ContractsAssetsMerkleData,
+                ContractsAssetsMerkleMetadata,
+                ContractsStateMerkleData,
+                ContractsStateMerkleMetadata,
+                Messages,
+                ProcessedTransactions,
+                SealedBlockConsensus,
+                ConsensusParametersVersions,
+                StateTransitionBytecodeVersions,
+                UploadedBytecodes,
+                BlobData,
+            ]
+        )
+    }
+}
+
+impl DecodeEntry for OffChain {
+    fn decode_entry(
+        column: Self::Column,
+        key: &[u8],
+        value: Value,
+    ) -> Option<anyhow::Result<DecodedEntry>> {
+        decode_entry!(
+            OffChain,
+            column,
+            key,
+            value,
+            [
+                MetadataTable<OffChain>,
+                GenesisMetadata<OffChain>,
+                OwnedCoins,
+                TransactionStatuses,
+                OwnedTransactions,
+                OwnedMessageIds,
+                FuelBlockIdsToHeights,
+                ContractsInfo,
+                OldFuelBlocks,
+                OldFuelBlockConsensus,
+                OldTransactions,
+                RelayedTransactionStatuses,
+                SpentMessages,
+                ContractReceipts,
+                TxPoolJournal,
+                TxPoolBlackListChanges,
+                BlockTips,
+            ]
+        )
+    }
+}
+
+impl DecodeEntry for Relayer {
+    fn decode_entry(
+        column: Self::Column,
+        key: &[u8],
+        value: Value,
+    ) -> Option<anyhow::Result<DecodedEntry>> {
+        #[cfg(feature = "relayer")]
+        {
+            decode_entry!(
+                Relayer,
+                column,
+                key,
+                value,
+                [
+                    MetadataTable<Relayer>,
+                    fuel_core_relayer::storage::EventsHistory,
+                ]
+            )
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            decode_entry!(Relayer, column, key, value, [MetadataTable<Relayer>])
+        }
+    }
+}
+
+impl DecodeEntry for GasPriceDatabase {
+    fn decode_entry(
+        column: Self::Column,
+        key: &[u8],
+        value: Value,
+    ) -> Option<anyhow::Result<DecodedEntry>> {
+        decode_entry!(
+            GasPriceDatabase,
+            column,
+            key,
+            value,
+            [MetadataTable<GasPriceDatabase>, GasPriceMetadata]
+        )
+    }
+}
+
+impl<Description> Database<Description>
+where
+    Description: DatabaseDescription,
+{
+    /// Returns the column with the `name`.
+    pub fn column_by_name(name: &str) -> Option<Description::Column> {
+        enum_iterator::all::<Description::Column>().find(|column| column.name() == name)
+    }
+
+    /// Iterates over all entries of the database and collects statistics of each column.
+    pub fn column_statistics(&self) -> StorageResult<Vec<ColumnStatistics>> {
+        enum_iterator::all::<Description::Column>()
+            .map(|column| {
+                let mut statistics = ColumnStatistics {
+                    name: column.name(),
+                    keys: 0,
+                    keys_size: 0,
+                    values_size: 0,
+                };
+                for entry in self.iter_store(column, None, None, IterDirection::Forward) {
+                    let (key, value) = entry?;
+                    statistics.keys = statistics.keys.saturating_add(1);
+                    statistics.keys_size =
+                        statistics.keys_size.saturating_add(key.len() as u64);
+                    statistics.values_size =
+                        statistics.values_size.saturating_add(value.len() as u64);
+                }
+                Ok(statistics)
+            })
+            .collect()
+    }
+
+    /// Reads the entry by the raw `key` from the `column` and decodes it
+    /// if the table stored in the column is known.
+    pub fn inspect_entry(
+        &self,
+        column: Description::Column,
+        key: &[u8],
+    ) -> StorageResult<Option<InspectedEntry>>
+    where
+        Description: DecodeEntry,
+    {
+        let Some(value) = KeyValueInspect::get(self, key, column)? else {
+            return Ok(None)
+        };
+
+        Ok(Some(InspectedEntry {
+            raw_value: value.as_ref().clone(),
+            decoded: Description::decode_entry(column, key, value),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use fuel_core_storage::{
+        column::Column,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        blockchain::block::CompressedBlock,
+        fuel_types::BlockHeight,
+    };
+
+    #[test]
+    fn column_statistics__counts_keys_and_sizes() {
+        // Given
+        let mut db = Database::<OnChain>::default();
+        db.storage_as_mut::<FuelBlocks>()
+            .insert(&BlockHeight::new(0), &CompressedBlock::default())
+            .unwrap();
+
+        // When
+        let statistics = db.column_statistics().unwrap();
+
+        // Then
+        let blocks = statistics
+            .iter()
+            .find(|statistics| statistics.name == Column::FuelBlocks.name())
+            .unwrap();
+        assert_eq!(blocks.keys, 1);
+        assert_eq!(blocks.keys_size, 4);
+        assert!(blocks.values_size > 0);
+        let coins = statistics
+            .iter()
+            .find(|statistics| statistics.name == Column::Coins.name())
+            .unwrap();
+        assert_eq!(coins.keys, 0);
+    }
+
+    #[test]
+    fn inspect_entry__decodes_entry_of_known_table() {
+        // Given
+        let mut db = Database::<OnChain>::default();
+        let height = BlockHeight::new(0);
+        db.storage_as_mut::<FuelBlocks>()
+            .insert(&height, &CompressedBlock::default())
+            .unwrap();
+        let column = Database::<OnChain>::column_by_name("FuelBlocks").unwrap();
+
+        // When
+        let entry = db
+            .inspect_entry(column, &height.to_bytes())
+            .unwrap()
+            .expect("The entry should exist");
+
+        // Then
+        assert!(!entry.raw_value.is_empty());
+        let decoded = entry.decoded.expect("The table is known").unwrap();
+        assert_eq!(decoded.key, format!("{height:?}"));
+    }
+
+    #[test]
+    fn inspect_entry__returns_none_for_missing_key() {
+        // Given
+        let db = Database::<OnChain>::default();
+
+        // When
+        let entry = db
+            .inspect_entry(Column::FuelBlocks, &BlockHeight::new(1).to_bytes())
+            .unwrap();
+
+        // Then
+        assert!(entry.is_none());
+    }
+}
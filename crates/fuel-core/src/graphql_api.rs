@@ -8,11 +8,13 @@ use std::{
 };
 
 pub mod api_service;
-mod da_compression;
+pub mod da_compression;
 pub mod database;
+pub mod health;
 pub(crate) mod metrics_extension;
 pub mod ports;
 pub mod storage;
+pub mod tip_estimation;
 pub(crate) mod view_extension;
 pub mod worker_service;
 
@@ -27,6 +29,15 @@ pub struct ServiceConfig {
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
     pub api_request_timeout: Duration,
+    /// The token required by the admin API in the `Authorization: Bearer <token>` header.
+    /// The admin API is disabled if the token is not set.
+    pub admin_token: Option<String>,
+    /// The max number of blocks the node may lag behind its peers or the off-chain
+    /// worker may lag behind the on-chain database while the node is ready.
+    pub health_max_block_lag: u32,
+    /// The max number of DA blocks the relayer may lag behind the DA layer
+    /// while the node is ready.
+    pub health_max_da_lag: u64,
 }
 
 pub struct Costs {
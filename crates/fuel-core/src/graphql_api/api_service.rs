@@ -5,16 +5,22 @@ use crate::{
             BlockProducerPort,
             ConsensusModulePort,
             ConsensusProvider as ConsensusProviderTrait,
+            DatabaseCheckpointPort,
             GasPriceEstimate,
+            NodeHealthPort,
             OffChainDatabase,
             OnChainDatabase,
+            OnChainDatabaseAt,
             P2pPort,
             TxPoolPort,
         },
         view_extension::ViewExtension,
+        worker_service,
         Config,
+        ServiceConfig,
     },
     schema::{
+        admin::AdminToken,
         CoreSchema,
         CoreSchemaBuilder,
     },
@@ -41,8 +47,11 @@ use axum::{
             ACCESS_CONTROL_ALLOW_HEADERS,
             ACCESS_CONTROL_ALLOW_METHODS,
             ACCESS_CONTROL_ALLOW_ORIGIN,
+            AUTHORIZATION,
         },
+        HeaderMap,
         HeaderValue,
+        StatusCode,
     },
     response::{
         sse::Event,
@@ -62,7 +71,10 @@ use fuel_core_services::{
     RunnableTask,
     StateWatcher,
 };
-use fuel_core_storage::transactional::AtomicView;
+use fuel_core_storage::transactional::{
+    AtomicView,
+    HistoricalView,
+};
 use fuel_core_types::fuel_types::BlockHeight;
 use futures::Stream;
 use serde_json::json;
@@ -73,6 +85,7 @@ use std::{
         TcpListener,
     },
     pin::Pin,
+    sync::Arc,
 };
 use tokio_stream::StreamExt;
 use tower_http::{
@@ -96,6 +109,10 @@ pub type GasPriceProvider = Box<dyn GasPriceEstimate>;
 
 pub type ConsensusProvider = Box<dyn ConsensusProviderTrait>;
 
+pub type NodeHealthProvider = Arc<dyn NodeHealthPort>;
+
+pub type DatabaseCheckpointProvider = Box<dyn DatabaseCheckpointPort>;
+
 #[derive(Clone)]
 pub struct SharedState {
     pub bound_address: SocketAddr,
@@ -185,11 +202,15 @@ pub fn new_service<OnChain, OffChain>(
     gas_price_provider: GasPriceProvider,
     consensus_parameters_provider: ConsensusProvider,
     memory_pool: SharedMemoryPool,
+    worker: worker_service::SharedState,
+    health_provider: NodeHealthProvider,
+    database_checkpoint: DatabaseCheckpointProvider,
 ) -> anyhow::Result<Service>
 where
-    OnChain: AtomicView + 'static,
+    OnChain: HistoricalView<Height = BlockHeight> + 'static,
     OffChain: AtomicView + 'static,
     OnChain::LatestView: OnChainDatabase,
+    OnChain::ViewAtHeight: OnChainDatabaseAt,
     OffChain::LatestView: OffChainDatabase,
 {
     let network_addr = config.config.addr;
@@ -197,6 +218,7 @@ where
         ReadDatabase::new(genesis_block_height, on_database, off_database);
     let request_timeout = config.config.api_request_timeout;
     let body_limit = config.config.request_body_bytes_limit;
+    let service_config = config.config.clone();
 
     let schema = schema
         .limit_complexity(config.config.max_queries_complexity)
@@ -215,6 +237,9 @@ where
         .data(gas_price_provider)
         .data(consensus_parameters_provider)
         .data(memory_pool)
+        .data(worker)
+        .data(health_provider.clone())
+        .data(database_checkpoint)
         .extension(async_graphql::extensions::Tracing)
         .extension(ViewExtension::new())
         .finish();
@@ -229,7 +254,11 @@ where
         .route("/v1/metrics", get(metrics))
         .route("/v1/health", get(health))
         .route("/health", get(health))
+        .route("/health/live", get(liveness))
+        .route("/health/ready", get(readiness))
         .layer(Extension(schema))
+        .layer(Extension(health_provider))
+        .layer(Extension(service_config))
         .layer(TraceLayer::new_for_http())
         .layer(TimeoutLayer::new(request_timeout))
         .layer(SetResponseHeaderLayer::<_>::overriding(
@@ -267,11 +296,76 @@ async fn health() -> Json<serde_json::Value> {
     Json(json!({ "up": true }))
 }
 
+async fn liveness(health: Extension<NodeHealthProvider>) -> impl IntoResponse {
+    let live = match health.health().await {
+        Ok(health) => health.is_live(),
+        Err(err) => {
+            tracing::error!("Failed to check the health of the node: {err:?}");
+            false
+        }
+    };
+    let status = if live {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(json!({ "live": live })))
+}
+
+async fn readiness(
+    health: Extension<NodeHealthProvider>,
+    config: Extension<ServiceConfig>,
+) -> impl IntoResponse {
+    let health = match health.health().await {
+        Ok(health) => health,
+        Err(err) => {
+            tracing::error!("Failed to check the health of the node: {err:?}");
+            return (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(json!({ "ready": false })),
+            )
+        }
+    };
+    let ready = health.is_ready(config.health_max_block_lag, config.health_max_da_lag);
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    let services: serde_json::Map<_, _> = health
+        .services
+        .iter()
+        .map(|service| {
+            (
+                service.name.to_string(),
+                json!(format!("{:?}", service.state)),
+            )
+        })
+        .collect();
+    let body = json!({
+        "ready": ready,
+        "services": services,
+        "sync_lag": health.sync_lag(),
+        "off_chain_lag": health.off_chain_lag(),
+        "da_lag": health.da_lag(),
+    });
+    (status, Json(body))
+}
+
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
+    headers: HeaderMap,
     req: Json<Request>,
 ) -> Json<Response> {
-    schema.execute(req.0).await.into()
+    let mut request = req.0;
+    let admin_token = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    if let Some(token) = admin_token {
+        request = request.data(AdminToken(token.to_string()));
+    }
+    schema.execute(request).await.into()
 }
 
 async fn graphql_subscription_handler(
@@ -10,33 +10,78 @@ use crate::fuel_core_graphql_api::{
     },
 };
 use fuel_core_compression::{
-    compress::compress,
+    compress::{
+        compress_with_stats,
+        CompressionStats,
+    },
     config::Config,
+    decompress::decompress,
     ports::{
+        CoinInfo,
         EvictorDb,
+        HistoryLookup,
+        MessageInfo,
         TemporalRegistry,
         UtxoIdToPointer,
     },
+    PerRegistryKeyspace,
+    RegistryKeyspace,
+    VersionedCompressedBlock,
 };
 use fuel_core_storage::{
+    codec::{
+        postcard::Postcard,
+        Encode,
+        Encoder,
+    },
     not_found,
+    tables::{
+        Coins,
+        FuelBlocks,
+        Messages,
+    },
+    Error as StorageError,
     StorageAsMut,
     StorageAsRef,
+    StorageInspect,
 };
 use fuel_core_types::{
-    blockchain::block::Block,
+    blockchain::block::{
+        Block,
+        PartialFuelBlock,
+    },
+    entities::coins::coin::Coin,
     fuel_tx::{
+        field::Inputs,
         input::PredicateCode,
         Address,
         AssetId,
+        CompressedUtxoId,
         ContractId,
+        Input,
         ScriptCode,
+        Transaction,
+        UtxoId,
     },
+    fuel_types::Nonce,
     services::executor::Event,
     tai64::Tai64,
 };
 use futures::FutureExt;
 
+/// The statistics of the DA compression of a block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DaCompressionStats {
+    /// The size of the postcard encoded block in bytes.
+    pub raw_size: usize,
+    /// The size of the postcard encoded compressed block in bytes.
+    pub compressed_size: usize,
+    /// The usage of the temporal registry by the block.
+    pub registry: CompressionStats,
+    /// The number of registry keys reassigned to new values, evicting the old ones.
+    pub evictions: PerRegistryKeyspace<usize>,
+}
+
 /// Performs DA compression for a block and stores it in the database.
 pub fn da_compress_block<T>(
     config: Config,
@@ -47,32 +92,154 @@ pub fn da_compress_block<T>(
 where
     T: OffChainDatabaseTransaction,
 {
-    let compressed = compress(
-        config,
-        CompressTx {
-            db_tx,
-            block_events,
-        },
-        block,
-    )
-    .now_or_never()
-    .expect("The current implementation resolved all futures instantly")?;
+    compress_block(config, block, block_events, db_tx)?;
+    Ok(())
+}
+
+/// The same as [`da_compress_block`], but also returns the compressed block
+/// and the statistics of its compression.
+pub fn da_compress_block_with_stats<T>(
+    config: Config,
+    block: &Block,
+    block_events: &[Event],
+    db_tx: &mut T,
+) -> anyhow::Result<(VersionedCompressedBlock, DaCompressionStats)>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let (compressed, registry, evictions) =
+        compress_block(config, block, block_events, db_tx)?;
+
+    let stats = DaCompressionStats {
+        raw_size: Postcard::encode(block).as_bytes().len(),
+        compressed_size: Postcard::encode(&compressed).as_bytes().len(),
+        registry,
+        evictions,
+    };
+    Ok((compressed, stats))
+}
+
+fn compress_block<T>(
+    config: Config,
+    block: &Block,
+    block_events: &[Event],
+    db_tx: &mut T,
+) -> anyhow::Result<(
+    VersionedCompressedBlock,
+    CompressionStats,
+    PerRegistryKeyspace<usize>,
+)>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let mut compress_tx = CompressTx {
+        db_tx,
+        block_events,
+        evictions: Default::default(),
+    };
+    let (compressed, stats) = compress_with_stats(config, &mut compress_tx, block)
+        .now_or_never()
+        .expect("The current implementation resolved all futures instantly")?;
+    let evictions = compress_tx.evictions;
 
     db_tx
         .storage_as_mut::<DaCompressedBlocks>()
         .insert(&block.header().consensus().height, &compressed)?;
 
-    Ok(())
+    Ok((compressed, stats, evictions))
+}
+
+/// Restores the events about spent coins of the committed block from its coin inputs.
+/// The executor sets the `tx_pointer` of the coin inputs to the pointer of the spent coin,
+/// so the events are enough to compress the block without executing it again.
+pub fn spent_coin_events(block: &Block) -> Vec<Event> {
+    block
+        .transactions()
+        .iter()
+        .filter_map(|tx| match tx {
+            Transaction::Script(tx) => Some(tx.inputs()),
+            Transaction::Create(tx) => Some(tx.inputs()),
+            Transaction::Upgrade(tx) => Some(tx.inputs()),
+            Transaction::Upload(tx) => Some(tx.inputs()),
+            Transaction::Blob(tx) => Some(tx.inputs()),
+            Transaction::Mint(_) => None,
+        })
+        .flatten()
+        .filter_map(|input| match input {
+            Input::CoinSigned(coin) => Some(Coin {
+                utxo_id: coin.utxo_id,
+                owner: coin.owner,
+                amount: coin.amount,
+                asset_id: coin.asset_id,
+                tx_pointer: coin.tx_pointer,
+            }),
+            Input::CoinPredicate(coin) => Some(Coin {
+                utxo_id: coin.utxo_id,
+                owner: coin.owner,
+                amount: coin.amount,
+                asset_id: coin.asset_id,
+                tx_pointer: coin.tx_pointer,
+            }),
+            _ => None,
+        })
+        .map(Event::CoinConsumed)
+        .collect()
+}
+
+/// Decompresses the DA compressed block and stores it in the database.
+/// The temporal registry in the `db_tx` is updated with the registrations of the block.
+/// The blocks must be decompressed in sequence, and the `onchain_db` must contain
+/// the state at the height of the previous block.
+pub fn da_decompress_block<T, OnChain>(
+    config: Config,
+    block: VersionedCompressedBlock,
+    onchain_db: OnChain,
+    db_tx: &mut T,
+) -> anyhow::Result<PartialFuelBlock>
+where
+    T: OffChainDatabaseTransaction,
+    OnChain: StorageInspect<FuelBlocks, Error = StorageError>
+        + StorageInspect<Coins, Error = StorageError>
+        + StorageInspect<Messages, Error = StorageError>,
+{
+    let VersionedCompressedBlock::V0(compressed) = &block;
+    let height = compressed.header.consensus.height;
+
+    db_tx
+        .storage_as_mut::<DaCompressedBlocks>()
+        .insert(&height, &block)?;
+
+    let block = decompress(config, DecompressTx { db_tx, onchain_db }, block)
+        .now_or_never()
+        .expect("The current implementation resolved all futures instantly")?;
+
+    Ok(block)
 }
 
 struct CompressTx<'a, Tx> {
     db_tx: &'a mut Tx,
     block_events: &'a [Event],
+    evictions: PerRegistryKeyspace<usize>,
+}
+
+impl<'a, Tx> CompressTx<'a, Tx> {
+    fn on_eviction(&mut self, keyspace: RegistryKeyspace) {
+        self.evictions[keyspace] = self.evictions[keyspace].saturating_add(1);
+    }
+}
+
+struct DecompressTx<'a, Tx, OnChain> {
+    db_tx: &'a mut Tx,
+    onchain_db: OnChain,
+}
+
+impl<'a, Tx, OnChain> DecompressTx<'a, Tx, OnChain> {
+    fn on_eviction(&mut self, _: RegistryKeyspace) {}
 }
 
 macro_rules! impl_temporal_registry {
-    ($type:ty) => { paste::paste! {
-        impl<'a, Tx> TemporalRegistry<$type> for CompressTx<'a, Tx>
+    ($tx:ident, $type:ty $(, $generic:ident)*) => { paste::paste! {
+        impl<'a, Tx $(, $generic)*> TemporalRegistry<$type> for $tx<'a, Tx $(, $generic)*>
         where
             Tx: OffChainDatabaseTransaction,
         {
@@ -116,6 +283,7 @@ macro_rules! impl_temporal_registry {
 
                 // Remove the overwritten value from index, if any
                 if let Some(old_value) = old_value {
+                    self.on_eviction(RegistryKeyspace::$type);
                     let old_reverse_key = (&old_value).into();
                     self.db_tx
                         .storage_as_mut::<DaCompressionTemporalRegistryIndex>()
@@ -149,7 +317,11 @@ macro_rules! impl_temporal_registry {
                     .map(|v| v.into_owned()))
             }
         }
+    }};
+}
 
+macro_rules! impl_evictor_db {
+    ($type:ident) => {
         impl<'a, Tx> EvictorDb<$type> for CompressTx<'a, Tx>
         where
             Tx: OffChainDatabaseTransaction,
@@ -166,24 +338,35 @@ macro_rules! impl_temporal_registry {
 
             fn get_latest_assigned_key(
                 &self,
-            ) -> anyhow::Result<Option<fuel_core_types::fuel_compression::RegistryKey>> {
+            ) -> anyhow::Result<Option<fuel_core_types::fuel_compression::RegistryKey>>
+            {
                 Ok(self
                     .db_tx
                     .storage_as_ref::<DaCompressionTemporalRegistryEvictorCache>()
                     .get(&MetadataKey::$type)?
-                    .map(|v| v.into_owned())
-                )
+                    .map(|v| v.into_owned()))
             }
         }
-
-    }};
+    };
 }
 
-impl_temporal_registry!(Address);
-impl_temporal_registry!(AssetId);
-impl_temporal_registry!(ContractId);
-impl_temporal_registry!(ScriptCode);
-impl_temporal_registry!(PredicateCode);
+impl_temporal_registry!(CompressTx, Address);
+impl_temporal_registry!(CompressTx, AssetId);
+impl_temporal_registry!(CompressTx, ContractId);
+impl_temporal_registry!(CompressTx, ScriptCode);
+impl_temporal_registry!(CompressTx, PredicateCode);
+
+impl_temporal_registry!(DecompressTx, Address, OnChain);
+impl_temporal_registry!(DecompressTx, AssetId, OnChain);
+impl_temporal_registry!(DecompressTx, ContractId, OnChain);
+impl_temporal_registry!(DecompressTx, ScriptCode, OnChain);
+impl_temporal_registry!(DecompressTx, PredicateCode, OnChain);
+
+impl_evictor_db!(Address);
+impl_evictor_db!(AssetId);
+impl_evictor_db!(ContractId);
+impl_evictor_db!(ScriptCode);
+impl_evictor_db!(PredicateCode);
 
 impl<'a, Tx> UtxoIdToPointer for CompressTx<'a, Tx>
 where
@@ -210,3 +393,56 @@ where
         anyhow::bail!("UtxoId not found in the block events");
     }
 }
+
+impl<'a, Tx, OnChain> HistoryLookup for DecompressTx<'a, Tx, OnChain>
+where
+    Tx: OffChainDatabaseTransaction,
+    OnChain: StorageInspect<FuelBlocks, Error = StorageError>
+        + StorageInspect<Coins, Error = StorageError>
+        + StorageInspect<Messages, Error = StorageError>,
+{
+    fn utxo_id(&self, c: CompressedUtxoId) -> anyhow::Result<UtxoId> {
+        let block = self
+            .onchain_db
+            .storage_as_ref::<FuelBlocks>()
+            .get(&c.tx_pointer.block_height())?
+            .ok_or(not_found!(FuelBlocks))?;
+        let tx_id = block
+            .transactions()
+            .get(usize::from(c.tx_pointer.tx_index()))
+            .ok_or_else(|| {
+                anyhow::anyhow!("The transaction at {:?} doesn't exist", c.tx_pointer)
+            })?;
+
+        Ok(UtxoId::new(*tx_id, c.output_index))
+    }
+
+    fn coin(&self, utxo_id: UtxoId) -> anyhow::Result<CoinInfo> {
+        let coin = self
+            .onchain_db
+            .storage_as_ref::<Coins>()
+            .get(&utxo_id)?
+            .ok_or(not_found!(Coins))?;
+
+        Ok(CoinInfo {
+            owner: *coin.owner(),
+            amount: *coin.amount(),
+            asset_id: *coin.asset_id(),
+        })
+    }
+
+    fn message(&self, nonce: Nonce) -> anyhow::Result<MessageInfo> {
+        let message = self
+            .onchain_db
+            .storage_as_ref::<Messages>()
+            .get(&nonce)?
+            .ok_or(not_found!(Messages))?;
+
+        Ok(MessageInfo {
+            sender: *message.sender(),
+            recipient: *message.recipient(),
+            amount: message.amount(),
+            data: message.data().clone(),
+        })
+    }
+}
@@ -9,6 +9,14 @@ use crate::fuel_core_graphql_api::{
         DatabaseRelayedTransactions,
         OffChainDatabase,
         OnChainDatabase,
+        OnChainDatabaseAt,
+    },
+    storage::{
+        pruning::PrunedBlocks,
+        receipts::{
+            ContractReceipt,
+            ContractReceiptCursor,
+        },
     },
 };
 use fuel_core_storage::{
@@ -19,7 +27,10 @@ use fuel_core_storage::{
     },
     not_found,
     tables::Transactions,
-    transactional::AtomicView,
+    transactional::{
+        AtomicView,
+        HistoricalView,
+    },
     Error as StorageError,
     IsNotFound,
     Mappable,
@@ -82,6 +93,8 @@ mod arc_wrapper;
 pub type OnChainView = Arc<dyn OnChainDatabase>;
 /// The off-chain view of the database used by the [`ReadView`] to fetch off-chain data.
 pub type OffChainView = Arc<dyn OffChainDatabase>;
+/// The view of the on-chain state at some block height.
+pub type OnChainViewAt = Arc<dyn OnChainDatabaseAt>;
 
 /// The container of the on-chain and off-chain database view provides.
 /// It is used only by `ViewExtension` to create a [`ReadView`].
@@ -89,7 +102,13 @@ pub struct ReadDatabase {
     /// The height of the genesis block.
     genesis_height: BlockHeight,
     /// The on-chain database view provider.
-    on_chain: Box<dyn AtomicView<LatestView = OnChainView>>,
+    on_chain: Box<
+        dyn HistoricalView<
+            Height = BlockHeight,
+            LatestView = OnChainView,
+            ViewAtHeight = OnChainViewAt,
+        >,
+    >,
     /// The off-chain database view provider.
     off_chain: Box<dyn AtomicView<LatestView = OffChainView>>,
 }
@@ -102,9 +121,10 @@ impl ReadDatabase {
         off_chain: OffChain,
     ) -> Self
     where
-        OnChain: AtomicView + 'static,
+        OnChain: HistoricalView<Height = BlockHeight> + 'static,
         OffChain: AtomicView + 'static,
         OnChain::LatestView: OnChainDatabase,
+        OnChain::ViewAtHeight: OnChainDatabaseAt,
         OffChain::LatestView: OffChainDatabase,
     {
         Self {
@@ -126,6 +146,22 @@ impl ReadDatabase {
         })
     }
 
+    /// Creates a view of the on-chain state at the `height`.
+    /// The history is available only for the heights
+    /// allowed by the state rewind policy of the database.
+    pub fn view_at(&self, height: &BlockHeight) -> StorageResult<OnChainViewAt> {
+        let latest_height = self.on_chain.latest_height().unwrap_or(self.genesis_height);
+
+        if *height > latest_height {
+            return Err(anyhow::anyhow!(
+                "The requested block height {height} is higher than the latest block height {latest_height}"
+            )
+            .into());
+        }
+
+        self.on_chain.view_at(height)
+    }
+
     #[cfg(feature = "test-helpers")]
     pub fn test_view(&self) -> ReadView {
         self.view().expect("The latest view always should exist")
@@ -139,6 +175,30 @@ pub struct ReadView {
     off_chain: OffChainView,
 }
 
+impl ReadView {
+    /// Returns an error if the block at the `height` was pruned.
+    pub fn ensure_block_is_not_pruned(&self, height: &BlockHeight) -> StorageResult<()> {
+        match self.off_chain.pruned_blocks()? {
+            Some(pruned_blocks) if pruned_blocks.contains(height) => Err(anyhow::anyhow!(
+                "The transactions and receipts of the block at height {height} were pruned"
+            )
+            .into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn ensure_transaction_is_not_pruned(&self, tx_id: &TxId) -> StorageResult<()> {
+        let block_height = match self.off_chain.tx_status(tx_id) {
+            Ok(TransactionStatus::Success { block_height, .. })
+            | Ok(TransactionStatus::Failed { block_height, .. }) => block_height,
+            Ok(_) => return Ok(()),
+            Err(err) if err.is_not_found() => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        self.ensure_block_is_not_pruned(&block_height)
+    }
+}
+
 impl DatabaseBlocks for ReadView {
     fn transaction(&self, tx_id: &TxId) -> StorageResult<Transaction> {
         let result = self.on_chain.transaction(tx_id);
@@ -146,6 +206,7 @@ impl DatabaseBlocks for ReadView {
             if let Some(tx) = self.old_transaction(tx_id)? {
                 Ok(tx)
             } else {
+                self.ensure_transaction_is_not_pruned(tx_id)?;
                 Err(not_found!(Transactions))
             }
         } else {
@@ -320,6 +381,8 @@ impl DatabaseMessageProof for ReadView {
 
 impl OnChainDatabase for ReadView {}
 
+impl OnChainDatabaseAt for ReadView {}
+
 impl OffChainDatabase for ReadView {
     fn block_height(&self, block_id: &BlockId) -> StorageResult<BlockHeight> {
         self.off_chain.block_height(block_id)
@@ -362,6 +425,16 @@ impl OffChainDatabase for ReadView {
             .owned_transactions_ids(owner, start, direction)
     }
 
+    fn contract_receipts(
+        &self,
+        contract_id: &ContractId,
+        start: Option<ContractReceiptCursor>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(ContractReceiptCursor, ContractReceipt)>> {
+        self.off_chain
+            .contract_receipts(contract_id, start, direction)
+    }
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
         self.off_chain.contract_salt(contract_id)
     }
@@ -399,4 +472,16 @@ impl OffChainDatabase for ReadView {
     fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool> {
         self.off_chain.message_is_spent(nonce)
     }
+
+    fn block_tips(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<Vec<u64>>> {
+        self.off_chain.block_tips(height, direction)
+    }
+
+    fn pruned_blocks(&self) -> StorageResult<Option<PrunedBlocks>> {
+        self.off_chain.pruned_blocks()
+    }
 }
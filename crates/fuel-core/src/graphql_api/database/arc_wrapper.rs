@@ -2,16 +2,22 @@ use crate::fuel_core_graphql_api::{
     database::{
         OffChainView,
         OnChainView,
+        OnChainViewAt,
     },
     ports::{
         OffChainDatabase,
         OnChainDatabase,
+        OnChainDatabaseAt,
     },
 };
 use fuel_core_storage::{
-    transactional::AtomicView,
+    transactional::{
+        AtomicView,
+        HistoricalView,
+    },
     Result as StorageResult,
 };
+use fuel_core_types::fuel_types::BlockHeight;
 use std::sync::Arc;
 
 /// The GraphQL can't work with the generics in [`async_graphql::Context::data_unchecked`] and requires a known type.
@@ -42,6 +48,25 @@ where
     }
 }
 
+impl<Provider, View, ViewAt> HistoricalView for ArcWrapper<Provider, OnChainView>
+where
+    Provider:
+        HistoricalView<Height = BlockHeight, LatestView = View, ViewAtHeight = ViewAt>,
+    View: OnChainDatabase + 'static,
+    ViewAt: OnChainDatabaseAt + 'static,
+{
+    type Height = BlockHeight;
+    type ViewAtHeight = OnChainViewAt;
+
+    fn latest_height(&self) -> Option<Self::Height> {
+        self.inner.latest_height()
+    }
+
+    fn view_at(&self, height: &Self::Height) -> StorageResult<Self::ViewAtHeight> {
+        Ok(Arc::new(self.inner.view_at(height)?))
+    }
+}
+
 impl<Provider, View> AtomicView for ArcWrapper<Provider, OffChainView>
 where
     Provider: AtomicView<LatestView = View>,
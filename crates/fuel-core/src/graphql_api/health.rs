@@ -0,0 +1,185 @@
+use fuel_core_services::State;
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_types::BlockHeight,
+};
+
+/// The state of the sub-service of the node.
+#[derive(Clone, Debug)]
+pub struct ServiceHealth {
+    pub name: &'static str,
+    pub state: State,
+}
+
+/// The snapshot of the health of the node.
+#[derive(Clone, Debug, Default)]
+pub struct NodeHealth {
+    pub services: Vec<ServiceHealth>,
+    /// The height of the latest block in the on-chain database.
+    pub on_chain_height: Option<BlockHeight>,
+    /// The height of the latest block processed by the off-chain worker.
+    pub off_chain_height: Option<BlockHeight>,
+    /// The highest block height reported by peers in their heartbeats.
+    pub best_peer_height: Option<BlockHeight>,
+    /// The DA height synced by the relayer.
+    pub da_height: Option<DaBlockHeight>,
+    /// The finalized DA height observed by the relayer on the DA layer.
+    pub remote_da_height: Option<DaBlockHeight>,
+}
+
+impl NodeHealth {
+    /// The number of blocks the node is behind the best peer.
+    pub fn sync_lag(&self) -> Option<u32> {
+        let best_peer_height = self.best_peer_height?;
+        let on_chain_height = self.on_chain_height.unwrap_or_default();
+        Some(best_peer_height.saturating_sub(*on_chain_height))
+    }
+
+    /// The number of blocks the off-chain worker is behind the on-chain database.
+    pub fn off_chain_lag(&self) -> Option<u32> {
+        let on_chain_height = self.on_chain_height?;
+        let off_chain_height = self.off_chain_height.unwrap_or_default();
+        Some(on_chain_height.saturating_sub(*off_chain_height))
+    }
+
+    /// The number of DA blocks the relayer is behind the DA layer.
+    pub fn da_lag(&self) -> Option<u64> {
+        let remote_da_height = self.remote_da_height?;
+        let da_height = self.da_height.unwrap_or_default();
+        Some(remote_da_height.saturating_sub(*da_height))
+    }
+
+    /// The node is alive while none of its sub-services are stopped.
+    pub fn is_live(&self) -> bool {
+        self.services.iter().all(|service| !service.state.stopped())
+    }
+
+    /// The node is ready when all sub-services are started and it doesn't lag
+    /// behind the network, the DA layer and its own on-chain state more than
+    /// the configured thresholds.
+    pub fn is_ready(&self, max_block_lag: u32, max_da_lag: u64) -> bool {
+        let services_started =
+            self.services.iter().all(|service| service.state.started());
+        let block_lag = self
+            .sync_lag()
+            .unwrap_or_default()
+            .max(self.off_chain_lag().unwrap_or_default());
+        let da_lag = self.da_lag().unwrap_or_default();
+
+        services_started && block_lag <= max_block_lag && da_lag <= max_da_lag
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    const MAX_BLOCK_LAG: u32 = 2;
+    const MAX_DA_LAG: u64 = 2;
+
+    fn started(name: &'static str) -> ServiceHealth {
+        ServiceHealth {
+            name,
+            state: State::Started,
+        }
+    }
+
+    #[test]
+    fn is_ready__true_when_services_started_and_lags_within_thresholds() {
+        // Given
+        let health = NodeHealth {
+            services: vec![started("TxPool"), started("Sync")],
+            on_chain_height: Some(10.into()),
+            off_chain_height: Some(9.into()),
+            best_peer_height: Some(12.into()),
+            da_height: Some(5u64.into()),
+            remote_da_height: Some(7u64.into()),
+        };
+
+        // When
+        let ready = health.is_ready(MAX_BLOCK_LAG, MAX_DA_LAG);
+
+        // Then
+        assert!(ready);
+        assert!(health.is_live());
+    }
+
+    #[test]
+    fn is_ready__false_when_sync_lags_behind_best_peer() {
+        // Given
+        let health = NodeHealth {
+            services: vec![started("Sync")],
+            on_chain_height: Some(10.into()),
+            off_chain_height: Some(10.into()),
+            best_peer_height: Some(13.into()),
+            ..Default::default()
+        };
+
+        // When
+        let ready = health.is_ready(MAX_BLOCK_LAG, MAX_DA_LAG);
+
+        // Then
+        assert_eq!(health.sync_lag(), Some(3));
+        assert!(!ready);
+    }
+
+    #[test]
+    fn is_ready__false_when_off_chain_worker_lags_behind() {
+        // Given
+        let health = NodeHealth {
+            services: vec![started("GraphQL_Off_Chain_Worker")],
+            on_chain_height: Some(10.into()),
+            off_chain_height: Some(7.into()),
+            ..Default::default()
+        };
+
+        // When
+        let ready = health.is_ready(MAX_BLOCK_LAG, MAX_DA_LAG);
+
+        // Then
+        assert_eq!(health.off_chain_lag(), Some(3));
+        assert!(!ready);
+    }
+
+    #[test]
+    fn is_ready__false_when_relayer_lags_behind_da_layer() {
+        // Given
+        let health = NodeHealth {
+            services: vec![started("Relayer")],
+            da_height: Some(5u64.into()),
+            remote_da_height: Some(8u64.into()),
+            ..Default::default()
+        };
+
+        // When
+        let ready = health.is_ready(MAX_BLOCK_LAG, MAX_DA_LAG);
+
+        // Then
+        assert_eq!(health.da_lag(), Some(3));
+        assert!(!ready);
+    }
+
+    #[test]
+    fn is_live__false_when_service_stopped_with_error() {
+        // Given
+        let health = NodeHealth {
+            services: vec![
+                started("TxPool"),
+                ServiceHealth {
+                    name: "Relayer",
+                    state: State::StoppedWithError("Panic".to_string()),
+                },
+            ],
+            ..Default::default()
+        };
+
+        // When
+        let live = health.is_live();
+
+        // Then
+        assert!(!live);
+        assert!(!health.is_ready(MAX_BLOCK_LAG, MAX_DA_LAG));
+    }
+}
@@ -1,3 +1,13 @@
+use crate::fuel_core_graphql_api::{
+    health::NodeHealth,
+    storage::{
+        pruning::PrunedBlocks,
+        receipts::{
+            ContractReceipt,
+            ContractReceiptCursor,
+        },
+    },
+};
 use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
@@ -10,6 +20,7 @@ use fuel_core_storage::{
         Coins,
         ContractsAssets,
         ContractsRawCode,
+        ContractsState,
         Messages,
         StateTransitionBytecodeVersions,
         UploadedBytecodes,
@@ -20,6 +31,14 @@ use fuel_core_storage::{
     StorageRead,
 };
 use fuel_core_txpool::service::TxStatusMessage;
+use fuel_core_txpool_v2::{
+    config::{
+        BlackList,
+        BlackListEntry,
+    },
+    TxDependencies,
+    TxInfo,
+};
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
@@ -65,7 +84,10 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    path::PathBuf,
+    sync::Arc,
+};
 
 pub trait OffChainDatabase: Send + Sync {
     fn block_height(&self, block_id: &BlockId) -> StorageResult<BlockHeight>;
@@ -95,6 +117,14 @@ pub trait OffChainDatabase: Send + Sync {
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
 
+    /// Returns the receipts emitted by the `contract_id` starting from the `start` position.
+    fn contract_receipts(
+        &self,
+        contract_id: &ContractId,
+        start: Option<ContractReceiptCursor>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(ContractReceiptCursor, ContractReceipt)>>;
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt>;
 
     fn old_block(&self, height: &BlockHeight) -> StorageResult<CompressedBlock>;
@@ -115,6 +145,16 @@ pub trait OffChainDatabase: Send + Sync {
     ) -> StorageResult<Option<RelayedTransactionStatus>>;
 
     fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool>;
+
+    /// Returns tips of transactions included into blocks starting from the `height`.
+    fn block_tips(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<Vec<u64>>>;
+
+    /// Returns the range of blocks pruned by the pruning service, if any.
+    fn pruned_blocks(&self) -> StorageResult<Option<PrunedBlocks>>;
 }
 
 /// The on chain database port expected by GraphQL API service.
@@ -133,6 +173,12 @@ pub trait OnChainDatabase:
 {
 }
 
+/// The on-chain state at some block height used by the GraphQL API service.
+pub trait OnChainDatabaseAt:
+    Send + Sync + StorageInspect<Coins, Error = StorageError> + DatabaseContracts
+{
+}
+
 /// Trait that specifies all the getters required for blocks.
 pub trait DatabaseBlocks {
     /// Get a transaction by its id.
@@ -183,6 +229,7 @@ pub trait DatabaseRelayedTransactions {
 pub trait DatabaseContracts:
     StorageInspect<ContractsRawCode, Error = StorageError>
     + StorageInspect<ContractsAssets, Error = StorageError>
+    + StorageInspect<ContractsState, Error = StorageError>
 {
     fn contract_balances(
         &self,
@@ -212,6 +259,52 @@ pub trait TxPoolPort: Send + Sync {
         &self,
         tx_id: TxId,
     ) -> anyhow::Result<BoxStream<TxStatusMessage>>;
+
+    /// Returns up to `max_txs` ids of transactions from the pool.
+    fn tx_ids(&self, max_txs: usize) -> Vec<TxId>;
+
+    /// Returns the number of transactions in the pool.
+    fn tx_count(&self) -> usize;
+
+    /// Returns the cumulative max gas of all transactions in the pool.
+    fn total_gas(&self) -> u64;
+
+    /// Returns the cumulative size in bytes of all transactions in the pool.
+    fn total_bytes_size(&self) -> anyhow::Result<usize>;
+
+    /// Returns the minimal tip that a transaction with the `max_gas` and
+    /// the `metered_bytes_size` needs to be selected within the `available_gas`
+    /// ahead of the transactions from the pool.
+    /// Returns `None` if the tip can't be estimated without the `metered_bytes_size`.
+    fn tip_to_outbid(
+        &self,
+        available_gas: u64,
+        max_gas: u64,
+        metered_bytes_size: Option<u64>,
+    ) -> Option<u64>;
+
+    /// Returns all transactions from the pool.
+    fn transactions(&self) -> anyhow::Result<Vec<TxInfo>>;
+
+    /// Returns transactions from the pool that are directly connected to the transaction.
+    fn dependencies(&self, tx_id: TxId) -> anyhow::Result<Option<TxDependencies>>;
+
+    /// Removes transactions with their dependents from the pool.
+    /// Returns ids of removed transactions.
+    fn remove_txs(&self, tx_ids: Vec<TxId>, reason: String) -> Vec<TxId>;
+
+    /// Returns the current blacklist of the pool.
+    fn black_list(&self) -> anyhow::Result<BlackList>;
+
+    /// Adds entries to the blacklist and removes transactions that use them from the pool.
+    /// Returns ids of removed transactions.
+    fn add_to_black_list(
+        &self,
+        entries: Vec<BlackListEntry>,
+    ) -> anyhow::Result<Vec<TxId>>;
+
+    /// Removes entries from the blacklist.
+    fn remove_from_black_list(&self, entries: Vec<BlackListEntry>) -> anyhow::Result<()>;
 }
 
 #[async_trait]
@@ -251,6 +344,23 @@ pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
 }
 
+/// Trait that reports the health of the node and its sub-services.
+#[async_trait::async_trait]
+pub trait NodeHealthPort: Send + Sync {
+    async fn health(&self) -> anyhow::Result<NodeHealth>;
+}
+
+/// Trait that creates on-disk checkpoints of the node's databases.
+#[async_trait::async_trait]
+pub trait DatabaseCheckpointPort: Send + Sync {
+    /// Creates a checkpoint of all databases in the `path` directory.
+    /// Returns the height of the on-chain database in the checkpoint.
+    async fn create_checkpoint(
+        &self,
+        path: PathBuf,
+    ) -> anyhow::Result<Option<BlockHeight>>;
+}
+
 /// Trait for defining how to estimate gas price for future blocks
 #[async_trait::async_trait]
 pub trait GasPriceEstimate: Send + Sync {
@@ -285,7 +395,9 @@ pub mod worker {
                 OldFuelBlocks,
                 OldTransactions,
             },
+            receipts::ContractReceipts,
             relayed_transactions::RelayedTransactionStatuses,
+            tips::BlockTips,
         },
     };
     use fuel_core_services::stream::BoxStream;
@@ -333,6 +445,8 @@ pub mod worker {
         + StorageMutate<OldTransactions, Error = StorageError>
         + StorageMutate<SpentMessages, Error = StorageError>
         + StorageMutate<RelayedTransactionStatuses, Error = StorageError>
+        + StorageMutate<ContractReceipts, Error = StorageError>
+        + StorageMutate<BlockTips, Error = StorageError>
         + StorageMutate<DaCompressedBlocks, Error = StorageError>
         + StorageMutate<DaCompressionTemporalRegistryAddress, Error = StorageError>
         + StorageMutate<DaCompressionTemporalRegistryAssetId, Error = StorageError>
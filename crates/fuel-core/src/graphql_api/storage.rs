@@ -42,8 +42,12 @@ pub mod contracts;
 pub mod da_compression;
 pub mod messages;
 pub mod old;
+pub mod pruning;
+pub mod receipts;
 pub mod statistic;
+pub mod tips;
 pub mod transactions;
+pub mod txpool_journal;
 
 pub mod relayed_transactions;
 /// Tracks the total number of transactions written to the chain
@@ -113,6 +117,14 @@ pub enum Column {
     DaCompressionTemporalRegistryScriptCode = 21,
     /// See [`DaCompressionTemporalRegistryPredicateCode`](da_compression::DaCompressionTemporalRegistryPredicateCode)
     DaCompressionTemporalRegistryPredicateCode = 22,
+    /// See [`ContractReceipts`](receipts::ContractReceipts)
+    ContractReceipts = 23,
+    /// See [`TxPoolJournal`](txpool_journal::TxPoolJournal)
+    TxPoolJournal = 24,
+    /// See [`TxPoolBlackListChanges`](txpool_journal::TxPoolBlackListChanges)
+    TxPoolBlackListChanges = 25,
+    /// See [`BlockTips`](tips::BlockTips)
+    BlockTips = 26,
 }
 
 impl Column {
@@ -0,0 +1,22 @@
+use fuel_core_types::fuel_types::BlockHeight;
+
+/// The key of the [`StatisticTable`](super::statistic::StatisticTable) entry
+/// that stores the [`PrunedBlocks`].
+pub const PRUNED_BLOCKS: &str = "pruned_blocks";
+
+/// The inclusive range of blocks whose transactions, receipts and owner indexes
+/// were pruned. The headers of these blocks and the merkle data are never pruned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PrunedBlocks {
+    /// The first pruned block.
+    pub first: BlockHeight,
+    /// The last pruned block.
+    pub last: BlockHeight,
+}
+
+impl PrunedBlocks {
+    /// Returns `true` if the block at the `height` was pruned.
+    pub fn contains(&self, height: &BlockHeight) -> bool {
+        self.first <= *height && *height <= self.last
+    }
+}
@@ -0,0 +1,273 @@
+use fuel_core_chain_config::{
+    AddTable,
+    AsTable,
+    StateConfig,
+    StateConfigBuilder,
+    TableEntry,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        manual::Manual,
+        postcard::Postcard,
+        Decode,
+        Encode,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        ContractId,
+        Receipt,
+        TxId,
+    },
+    fuel_types::BlockHeight,
+};
+use std::{
+    array::TryFromSliceError,
+    mem::size_of,
+};
+
+/// The table allows iteration over all receipts emitted by the contract.
+/// Receipts are sorted by the block height, the transaction index and
+/// the index of the receipt inside of the transaction.
+pub struct ContractReceipts;
+
+impl Mappable for ContractReceipts {
+    type Key = ContractReceiptKey;
+    type OwnedKey = Self::Key;
+    type Value = ContractReceipt;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ContractReceipts {
+    type Blueprint = Plain<Manual<ContractReceiptKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ContractReceipts
+    }
+}
+
+impl AsTable<ContractReceipts> for StateConfig {
+    fn as_table(&self) -> Vec<TableEntry<ContractReceipts>> {
+        Vec::new() // Do not include these for now
+    }
+}
+
+impl AddTable<ContractReceipts> for StateConfigBuilder {
+    fn add(&mut self, _entries: Vec<TableEntry<ContractReceipts>>) {
+        // Do not include these for now
+    }
+}
+
+const BLOCK_HEIGHT_SIZE: usize = size_of::<BlockHeight>();
+const TX_INDEX_SIZE: usize = size_of::<u16>();
+const RECEIPT_INDEX_SIZE: usize = size_of::<u16>();
+const CURSOR_SIZE: usize = BLOCK_HEIGHT_SIZE + TX_INDEX_SIZE + RECEIPT_INDEX_SIZE;
+const KEY_SIZE: usize = ContractId::LEN + CURSOR_SIZE;
+
+////////////////////////////////////// Not storage part //////////////////////////////////////
+
+/// The receipt emitted by the contract along with the id of the transaction that emitted it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ContractReceipt {
+    pub tx_id: TxId,
+    pub receipt: Receipt,
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub struct ContractReceiptKey {
+    pub contract_id: ContractId,
+    pub cursor: ContractReceiptCursor,
+}
+
+impl ContractReceiptKey {
+    pub fn new(contract_id: ContractId, cursor: ContractReceiptCursor) -> Self {
+        Self {
+            contract_id,
+            cursor,
+        }
+    }
+}
+
+impl From<[u8; KEY_SIZE]> for ContractReceiptKey {
+    fn from(bytes: [u8; KEY_SIZE]) -> Self {
+        let contract_id: [u8; ContractId::LEN] = bytes[..ContractId::LEN]
+            .try_into()
+            .expect("It's an array of 32 bytes");
+        let cursor: [u8; CURSOR_SIZE] = bytes[ContractId::LEN..]
+            .try_into()
+            .expect("It's an array of 8 bytes");
+
+        Self {
+            contract_id: ContractId::from(contract_id),
+            cursor: cursor.into(),
+        }
+    }
+}
+
+impl From<&ContractReceiptKey> for [u8; KEY_SIZE] {
+    fn from(key: &ContractReceiptKey) -> Self {
+        let mut bytes = [0u8; KEY_SIZE];
+        // The contract id is the prefix of the key to allow iteration
+        // over the receipts of one contract.
+        bytes[..ContractId::LEN].copy_from_slice(key.contract_id.as_ref());
+        bytes[ContractId::LEN..].copy_from_slice(&<[u8; CURSOR_SIZE]>::from(&key.cursor));
+        bytes
+    }
+}
+
+impl TryFrom<&[u8]> for ContractReceiptKey {
+    type Error = TryFromSliceError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; KEY_SIZE] = bytes.try_into()?;
+        Ok(Self::from(bytes))
+    }
+}
+
+impl Encode<ContractReceiptKey> for Manual<ContractReceiptKey> {
+    type Encoder<'a> = [u8; KEY_SIZE];
+
+    fn encode(t: &ContractReceiptKey) -> Self::Encoder<'_> {
+        t.into()
+    }
+}
+
+impl Decode<ContractReceiptKey> for Manual<ContractReceiptKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<ContractReceiptKey> {
+        ContractReceiptKey::try_from(bytes)
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))
+    }
+}
+
+/// The position of the receipt in the chain.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct ContractReceiptCursor {
+    pub block_height: BlockHeight,
+    pub tx_idx: u16,
+    pub receipt_idx: u16,
+}
+
+impl ContractReceiptCursor {
+    pub fn new(block_height: BlockHeight, tx_idx: u16, receipt_idx: u16) -> Self {
+        Self {
+            block_height,
+            tx_idx,
+            receipt_idx,
+        }
+    }
+
+    /// The first possible receipt at the `block_height`.
+    pub fn first_at(block_height: BlockHeight) -> Self {
+        Self::new(block_height, 0, 0)
+    }
+
+    /// The last possible receipt at the `block_height`.
+    pub fn last_at(block_height: BlockHeight) -> Self {
+        Self::new(block_height, u16::MAX, u16::MAX)
+    }
+}
+
+impl From<[u8; CURSOR_SIZE]> for ContractReceiptCursor {
+    fn from(bytes: [u8; CURSOR_SIZE]) -> Self {
+        let mut block_height_bytes: [u8; 4] = Default::default();
+        block_height_bytes.copy_from_slice(&bytes[..4]);
+        let mut tx_idx_bytes: [u8; 2] = Default::default();
+        tx_idx_bytes.copy_from_slice(&bytes[4..6]);
+        let mut receipt_idx_bytes: [u8; 2] = Default::default();
+        receipt_idx_bytes.copy_from_slice(&bytes[6..8]);
+
+        Self {
+            block_height: u32::from_be_bytes(block_height_bytes).into(),
+            tx_idx: u16::from_be_bytes(tx_idx_bytes),
+            receipt_idx: u16::from_be_bytes(receipt_idx_bytes),
+        }
+    }
+}
+
+impl From<&ContractReceiptCursor> for [u8; CURSOR_SIZE] {
+    fn from(cursor: &ContractReceiptCursor) -> Self {
+        let mut bytes = [0u8; CURSOR_SIZE];
+        bytes[..4].copy_from_slice(&cursor.block_height.to_bytes());
+        bytes[4..6].copy_from_slice(&cursor.tx_idx.to_be_bytes());
+        bytes[6..8].copy_from_slice(&cursor.receipt_idx.to_be_bytes());
+        bytes
+    }
+}
+
+impl TryFrom<Vec<u8>> for ContractReceiptCursor {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        let bytes: [u8; CURSOR_SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Invalid length of the receipt cursor"))?;
+        Ok(bytes.into())
+    }
+}
+
+impl From<ContractReceiptCursor> for Vec<u8> {
+    fn from(cursor: ContractReceiptCursor) -> Self {
+        <[u8; CURSOR_SIZE]>::from(&cursor).to_vec()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn generate_key(rng: &mut impl rand::Rng) -> <ContractReceipts as Mappable>::Key {
+        let mut bytes = [0u8; KEY_SIZE];
+        rng.fill(bytes.as_mut());
+        bytes.into()
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        ContractReceipts,
+        [1u8; KEY_SIZE].into(),
+        ContractReceipt {
+            tx_id: Default::default(),
+            receipt: Receipt::log(Default::default(), 1, 2, 3, 4, 5, 6),
+        },
+        ContractReceipt {
+            tx_id: Default::default(),
+            receipt: Receipt::log(Default::default(), 1, 2, 3, 4, 5, 6),
+        },
+        generate_key
+    );
+
+    #[test]
+    fn key_encoding_preserves_order_of_cursors() {
+        let contract_id = ContractId::from([1u8; 32]);
+        let lower = ContractReceiptKey::new(
+            contract_id,
+            ContractReceiptCursor::new(1u32.into(), u16::MAX, u16::MAX),
+        );
+        let higher = ContractReceiptKey::new(
+            contract_id,
+            ContractReceiptCursor::new(256u32.into(), 0, 0),
+        );
+
+        let lower_bytes: [u8; KEY_SIZE] = (&lower).into();
+        let higher_bytes: [u8; KEY_SIZE] = (&higher).into();
+
+        assert!(lower_bytes < higher_bytes);
+        assert_eq!(ContractReceiptKey::from(lower_bytes), lower);
+    }
+}
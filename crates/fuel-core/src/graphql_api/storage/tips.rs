@@ -0,0 +1,38 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+
+/// Tips of transactions included into the block.
+/// The mint transaction doesn't pay a tip and is not tracked.
+/// It is used to estimate the tip required to get included under congestion.
+pub struct BlockTips;
+
+impl Mappable for BlockTips {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BlockHeight;
+    type Value = Self::OwnedValue;
+    type OwnedValue = Vec<u64>;
+}
+
+impl TableWithBlueprint for BlockTips {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::BlockTips
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    BlockTips,
+    <BlockTips as Mappable>::Key::default(),
+    vec![1, 2, 3]
+);
@@ -0,0 +1,127 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        raw::Raw,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_txpool_v2::config::BlackListEntry;
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        ContractId,
+        Transaction,
+        TxId,
+        UtxoId,
+    },
+    fuel_types::Nonce,
+    tai64::Tai64,
+};
+
+/// The journal of transactions accepted by the transaction pool.
+/// It is used to restore the pool after the restart of the node.
+/// The table is not linked to any block height and is not affected by rollbacks.
+pub struct TxPoolJournal;
+
+impl Mappable for TxPoolJournal {
+    type Key = Self::OwnedKey;
+    type OwnedKey = TxId;
+    type Value = Self::OwnedValue;
+    type OwnedValue = TxPoolJournalEntry;
+}
+
+impl TableWithBlueprint for TxPoolJournal {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::TxPoolJournal
+    }
+}
+
+/// The transaction along with the time when the pool accepted it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TxPoolJournalEntry {
+    pub transaction: Transaction,
+    pub submitted_time: Tai64,
+}
+
+/// Changes of the blacklist of the transaction pool made at runtime.
+/// The value is `true` if the entry was added to the blacklist and `false`
+/// if it was removed from it. Changes are re-applied after the restart of the node.
+pub struct TxPoolBlackListChanges;
+
+impl Mappable for TxPoolBlackListChanges {
+    type Key = Self::OwnedKey;
+    type OwnedKey = TxPoolBlackListKey;
+    type Value = Self::OwnedValue;
+    type OwnedValue = bool;
+}
+
+impl TableWithBlueprint for TxPoolBlackListChanges {
+    type Blueprint = Plain<Postcard, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::TxPoolBlackListChanges
+    }
+}
+
+/// The serializable representation of the [`BlackListEntry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum TxPoolBlackListKey {
+    Owner(Address),
+    Coin(UtxoId),
+    Message(Nonce),
+    Contract(ContractId),
+}
+
+impl From<BlackListEntry> for TxPoolBlackListKey {
+    fn from(entry: BlackListEntry) -> Self {
+        match entry {
+            BlackListEntry::Owner(owner) => Self::Owner(owner),
+            BlackListEntry::Coin(utxo_id) => Self::Coin(utxo_id),
+            BlackListEntry::Message(nonce) => Self::Message(nonce),
+            BlackListEntry::Contract(contract_id) => Self::Contract(contract_id),
+        }
+    }
+}
+
+impl From<TxPoolBlackListKey> for BlackListEntry {
+    fn from(key: TxPoolBlackListKey) -> Self {
+        match key {
+            TxPoolBlackListKey::Owner(owner) => Self::Owner(owner),
+            TxPoolBlackListKey::Coin(utxo_id) => Self::Coin(utxo_id),
+            TxPoolBlackListKey::Message(nonce) => Self::Message(nonce),
+            TxPoolBlackListKey::Contract(contract_id) => Self::Contract(contract_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fuel_core_storage::basic_storage_tests!(
+        TxPoolJournal,
+        <TxPoolJournal as Mappable>::Key::default(),
+        TxPoolJournalEntry {
+            transaction: Transaction::default_test_tx(),
+            submitted_time: Tai64::UNIX_EPOCH,
+        }
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        TxPoolBlackListChanges,
+        TxPoolBlackListKey::Owner(Address::zeroed()),
+        true,
+        true,
+        generate_key
+    );
+
+    fn generate_key(rng: &mut impl rand::Rng) -> TxPoolBlackListKey {
+        TxPoolBlackListKey::Owner(rng.gen())
+    }
+}
@@ -0,0 +1,46 @@
+/// The number of the latest blocks whose transactions are used to estimate the tip.
+pub const RECENT_BLOCKS: usize = 20;
+
+/// Returns the tip at the `percentile` of `tips` using the nearest-rank method.
+/// Returns `None` if there are no tips.
+pub fn percentile_tip(mut tips: Vec<u64>, percentile: u8) -> Option<u64> {
+    if tips.is_empty() {
+        return None
+    }
+    tips.sort_unstable();
+
+    let percentile = usize::from(percentile.min(100));
+    let rank = percentile.saturating_mul(tips.len()).div_ceil(100);
+    let index = rank.saturating_sub(1).min(tips.len().saturating_sub(1));
+    tips.get(index).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    #[test]
+    fn percentile_tip__returns_nearest_rank() {
+        // Given
+        let tips = vec![50, 10, 40, 20, 30];
+
+        // When
+        let min = percentile_tip(tips.clone(), 0);
+        let median = percentile_tip(tips.clone(), 50);
+        let high = percentile_tip(tips.clone(), 90);
+        let max = percentile_tip(tips, 100);
+
+        // Then
+        assert_eq!(min, Some(10));
+        assert_eq!(median, Some(30));
+        assert_eq!(high, Some(50));
+        assert_eq!(max, Some(50));
+    }
+
+    #[test]
+    fn percentile_tip__returns_none_without_tips() {
+        assert_eq!(percentile_tip(vec![], 50), None);
+    }
+}
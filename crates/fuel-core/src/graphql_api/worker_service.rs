@@ -24,6 +24,13 @@ use crate::{
                 OwnedMessageKey,
                 SpentMessages,
             },
+            receipts::{
+                ContractReceipt,
+                ContractReceiptCursor,
+                ContractReceiptKey,
+                ContractReceipts,
+            },
+            tips::BlockTips,
         },
     },
     graphql_api::storage::relayed_transactions::RelayedTransactionStatuses,
@@ -31,7 +38,6 @@ use crate::{
 use fuel_core_metrics::graphql_metrics::graphql_metrics;
 use fuel_core_services::{
     stream::BoxStream,
-    EmptyShared,
     RunnableService,
     RunnableTask,
     ServiceRunner,
@@ -58,6 +64,7 @@ use fuel_core_types::{
             Outputs,
             Salt,
             StorageSlots,
+            Tip,
         },
         input::coin::{
             CoinPredicate,
@@ -70,9 +77,11 @@ use fuel_core_types::{
         UniqueIdentifier,
     },
     fuel_types::{
+        Address,
         BlockHeight,
         Bytes32,
         ChainId,
+        ContractId,
     },
     services::{
         block_importer::{
@@ -94,16 +103,48 @@ use std::{
     borrow::Cow,
     ops::Deref,
 };
+use tokio::sync::broadcast;
 
 #[cfg(test)]
 mod tests;
 
+/// The capacity of the channel with processed blocks. If a subscriber falls behind
+/// by more than this number of blocks, it receives the "lagged" error.
+const PROCESSED_BLOCKS_CHANNEL_CAPACITY: usize = 1024;
+
 #[derive(Debug, Clone)]
 pub enum DaCompressionConfig {
     Disabled,
     Enabled(fuel_core_compression::config::Config),
 }
 
+/// The shared state of the off-chain worker.
+#[derive(Clone)]
+pub struct SharedState {
+    /// Notifies subscribers about blocks after the worker processes them.
+    processed_blocks: broadcast::Sender<CompressedBlock>,
+}
+
+impl SharedState {
+    fn new() -> Self {
+        let (processed_blocks, _) = broadcast::channel(PROCESSED_BLOCKS_CHANNEL_CAPACITY);
+        Self { processed_blocks }
+    }
+
+    /// Subscribes to the blocks processed by the worker. When a block is received,
+    /// all the off-chain information about it is already available in the database.
+    pub fn subscribe_blocks(&self) -> broadcast::Receiver<CompressedBlock> {
+        self.processed_blocks.subscribe()
+    }
+
+    fn notify_processed_block(&self, block: &Block, chain_id: &ChainId) {
+        // Compressing the block is not free, so skip it if nobody is listening.
+        if self.processed_blocks.receiver_count() > 0 {
+            let _ = self.processed_blocks.send(block.compress(chain_id));
+        }
+    }
+}
+
 /// The initialization task recovers the state of the GraphQL service database on startup.
 pub struct InitializeTask<TxPool, BlockImporter, OnChain, OffChain> {
     chain_id: ChainId,
@@ -114,6 +155,7 @@ pub struct InitializeTask<TxPool, BlockImporter, OnChain, OffChain> {
     block_importer: BlockImporter,
     on_chain_database: OnChain,
     off_chain_database: OffChain,
+    shared_state: SharedState,
 }
 
 /// The off-chain GraphQL API worker task processes the imported blocks
@@ -125,6 +167,7 @@ pub struct Task<TxPool, D> {
     chain_id: ChainId,
     da_compression_config: DaCompressionConfig,
     continue_on_error: bool,
+    shared_state: SharedState,
 }
 
 impl<TxPool, D> Task<TxPool, D>
@@ -141,9 +184,15 @@ where
         // save the associated owner for each transaction in the block
         index_tx_owners_for_block(block, &mut transaction, &self.chain_id)?;
 
+        // save the receipts emitted by contracts
+        index_contract_receipts(&result, &mut transaction)?;
+
         // save the transaction related information
         process_transactions(block.transactions().iter(), &mut transaction)?;
 
+        // save tips of included transactions for the tip estimation
+        index_block_tips(block, &mut transaction)?;
+
         let height = block.header().height();
         let block_id = block.id();
         transaction
@@ -174,6 +223,9 @@ where
             self.tx_pool.send_complete(tx_id, height, status);
         }
 
+        self.shared_state
+            .notify_processed_block(block, &self.chain_id);
+
         // update the importer metrics after the block is successfully committed
         graphql_metrics().total_txs_count.set(total_tx_count as i64);
 
@@ -253,65 +305,129 @@ where
 {
     for (tx_idx, tx) in block.transactions().iter().enumerate() {
         let block_height = *block.header().height();
-        let inputs;
-        let outputs;
         let tx_idx = u16::try_from(tx_idx).map_err(|e| {
             anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
         })?;
+        let owners = tx_owners(tx);
+        if owners.is_empty() {
+            continue
+        }
         let tx_id = tx.id(chain_id);
-        match tx {
-            Transaction::Script(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-            Transaction::Create(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-            Transaction::Mint(_) => continue,
-            Transaction::Upgrade(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-            Transaction::Upload(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-            Transaction::Blob(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
+        for owner in owners {
+            block_st_transaction.record_tx_id_owner(
+                &owner,
+                block_height,
+                tx_idx,
+                &tx_id,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Index the receipts emitted by contracts to allow querying them by the contract id.
+/// Receipts emitted by the script itself have the zero contract id and are not indexed.
+fn index_contract_receipts<T>(
+    import_result: &ImportResult,
+    block_st_transaction: &mut T,
+) -> anyhow::Result<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let block_height = *import_result.sealed_block.entity.header().height();
+    for (tx_idx, status) in import_result.tx_status.iter().enumerate() {
+        let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+            anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
+        })?;
+        for (receipt_idx, receipt) in status.result.receipts().iter().enumerate() {
+            let Some(contract_id) = receipt.id() else {
+                continue
+            };
+            if *contract_id == ContractId::zeroed() {
+                continue
             }
+            let receipt_idx = u16::try_from(receipt_idx).map_err(|e| {
+                anyhow::anyhow!(
+                    "The transaction has more than `u16::MAX` receipts, {}",
+                    e
+                )
+            })?;
+
+            let key = ContractReceiptKey::new(
+                *contract_id,
+                ContractReceiptCursor::new(block_height, tx_idx, receipt_idx),
+            );
+            let value = ContractReceipt {
+                tx_id: status.id,
+                receipt: receipt.clone(),
+            };
+            block_st_transaction
+                .storage_as_mut::<ContractReceipts>()
+                .insert(&key, &value)?;
         }
-        persist_owners_index(
-            block_height,
-            inputs,
-            outputs,
-            &tx_id,
-            tx_idx,
-            block_st_transaction,
-        )?;
     }
     Ok(())
 }
 
-/// Index the tx id by owner for all of the inputs and outputs
-fn persist_owners_index<T>(
-    block_height: BlockHeight,
-    inputs: &[Input],
-    outputs: &[Output],
-    tx_id: &Bytes32,
-    tx_idx: u16,
-    db: &mut T,
-) -> StorageResult<()>
+/// Index tips paid by transactions of the block to estimate the tip
+/// required for the inclusion.
+fn index_block_tips<T>(block: &Block, block_st_transaction: &mut T) -> StorageResult<()>
 where
     T: OffChainDatabaseTransaction,
 {
+    let tips = block
+        .transactions()
+        .iter()
+        .filter_map(|tx| match tx {
+            Transaction::Script(tx) => Some(tx.tip()),
+            Transaction::Create(tx) => Some(tx.tip()),
+            Transaction::Upgrade(tx) => Some(tx.tip()),
+            Transaction::Upload(tx) => Some(tx.tip()),
+            Transaction::Blob(tx) => Some(tx.tip()),
+            Transaction::Mint(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    block_st_transaction
+        .storage_as_mut::<BlockTips>()
+        .insert(block.header().height(), &tips)
+}
+
+/// Returns the owners of all coin inputs and outputs of the transaction.
+/// The transaction is indexed by these owners.
+pub(crate) fn tx_owners(tx: &Transaction) -> Vec<Address> {
+    let inputs;
+    let outputs;
+    match tx {
+        Transaction::Script(tx) => {
+            inputs = tx.inputs().as_slice();
+            outputs = tx.outputs().as_slice();
+        }
+        Transaction::Create(tx) => {
+            inputs = tx.inputs().as_slice();
+            outputs = tx.outputs().as_slice();
+        }
+        Transaction::Mint(_) => return vec![],
+        Transaction::Upgrade(tx) => {
+            inputs = tx.inputs().as_slice();
+            outputs = tx.outputs().as_slice();
+        }
+        Transaction::Upload(tx) => {
+            inputs = tx.inputs().as_slice();
+            outputs = tx.outputs().as_slice();
+        }
+        Transaction::Blob(tx) => {
+            inputs = tx.inputs().as_slice();
+            outputs = tx.outputs().as_slice();
+        }
+    }
+
     let mut owners = vec![];
     for input in inputs {
         if let Input::CoinSigned(CoinSigned { owner, .. })
         | Input::CoinPredicate(CoinPredicate { owner, .. }) = input
         {
-            owners.push(owner);
+            owners.push(*owner);
         }
     }
 
@@ -320,7 +436,7 @@ where
             Output::Coin { to, .. }
             | Output::Change { to, .. }
             | Output::Variable { to, .. } => {
-                owners.push(to);
+                owners.push(*to);
             }
             Output::Contract(_) | Output::ContractCreated { .. } => {}
         }
@@ -329,12 +445,7 @@ where
     // dedupe owners from inputs and outputs prior to indexing
     owners.sort();
     owners.dedup();
-
-    for owner in owners {
-        db.record_tx_id_owner(owner, block_height, tx_idx, tx_id)?;
-    }
-
-    Ok(())
+    owners
 }
 
 fn persist_transaction_status<T>(
@@ -454,12 +565,12 @@ where
     OffChain: ports::worker::OffChainDatabase,
 {
     const NAME: &'static str = "GraphQL_Off_Chain_Worker";
-    type SharedData = EmptyShared;
+    type SharedData = SharedState;
     type Task = Task<TxPool, OffChain>;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
-        EmptyShared
+        self.shared_state.clone()
     }
 
     async fn into_task(
@@ -482,6 +593,7 @@ where
             on_chain_database,
             off_chain_database,
             continue_on_error,
+            shared_state,
         } = self;
 
         let mut task = Task {
@@ -491,6 +603,7 @@ where
             chain_id,
             da_compression_config,
             continue_on_error,
+            shared_state,
         };
 
         let mut target_chain_height = on_chain_database.latest_height()?;
@@ -619,5 +732,6 @@ where
         chain_id,
         da_compression_config,
         continue_on_error,
+        shared_state: SharedState::new(),
     })
 }
@@ -59,6 +59,29 @@ async fn run__relayed_transaction_events_are_added_to_storage() {
     assert_eq!(*actual, expected);
 }
 
+#[tokio::test]
+async fn run__processed_block_is_sent_to_subscribers() {
+    let database = Database::in_memory();
+    let mut state_watcher = StateWatcher::started();
+
+    // given
+    let event = Event::ForcedTransactionFailed {
+        id: [1; 32].into(),
+        block_height: 8.into(),
+        failure: "blah blah blah".to_string(),
+    };
+    let block_importer = block_importer_for_event(event);
+    let mut task = worker_task_with_block_importer_and_db(block_importer, database);
+    let mut subscription = task.shared_state.subscribe_blocks();
+
+    // when
+    task.run(&mut state_watcher).await.unwrap();
+
+    // then
+    let block = subscription.try_recv().expect("The block should be sent");
+    assert_eq!(*block.header().height(), BlockHeight::default());
+}
+
 fn block_importer_for_event(event: Event) -> BoxStream<SharedImportResult> {
     let block = Arc::new(ImportResult {
         sealed_block: Default::default(),
@@ -83,5 +106,6 @@ fn worker_task_with_block_importer_and_db<D: ports::worker::OffChainDatabase>(
         chain_id,
         da_compression_config: DaCompressionConfig::Disabled,
         continue_on_error: false,
+        shared_state: SharedState::new(),
     }
 }
@@ -24,6 +24,8 @@ pub use fuel_core_sync as sync;
 #[doc(no_inline)]
 pub use fuel_core_txpool as txpool;
 #[doc(no_inline)]
+pub use fuel_core_txpool_v2 as txpool_v2;
+#[doc(no_inline)]
 pub use fuel_core_types as types;
 #[doc(no_inline)]
 pub use fuel_core_upgradable_executor as upgradable_executor;
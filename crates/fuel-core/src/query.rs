@@ -4,6 +4,7 @@ mod block;
 mod chain;
 mod coin;
 mod contract;
+mod historical;
 mod message;
 mod subscriptions;
 mod tx;
@@ -18,6 +19,7 @@ pub use block::*;
 pub use chain::*;
 pub use coin::*;
 pub use contract::*;
+pub use historical::*;
 pub use message::*;
 pub(crate) use subscriptions::*;
 pub use tx::*;
@@ -0,0 +1,112 @@
+use crate::fuel_core_graphql_api::ports::{
+    DatabaseContracts,
+    OnChainDatabaseAt,
+};
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IterDirection,
+    },
+    not_found,
+    tables::{
+        Coins,
+        ContractsAssets,
+        ContractsRawCode,
+        ContractsState,
+    },
+    ContractsStateKey,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    entities::coins::coin::Coin,
+    fuel_tx::UtxoId,
+    fuel_types::{
+        AssetId,
+        Bytes32,
+        ContractId,
+    },
+    services::graphql_api::ContractBalance,
+};
+
+/// The queries to the on-chain state at some block height.
+pub trait HistoricalQueryData: Send + Sync {
+    fn contract_exists(&self, id: ContractId) -> StorageResult<bool>;
+
+    fn contract_balance(
+        &self,
+        contract_id: ContractId,
+        asset_id: AssetId,
+    ) -> StorageResult<ContractBalance>;
+
+    fn contract_balances(
+        &self,
+        contract_id: ContractId,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    fn contract_storage_slot(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<Option<Vec<u8>>>;
+
+    fn coin(&self, utxo_id: UtxoId) -> StorageResult<Coin>;
+}
+
+impl<D: OnChainDatabaseAt + ?Sized> HistoricalQueryData for D {
+    fn contract_exists(&self, id: ContractId) -> StorageResult<bool> {
+        self.storage::<ContractsRawCode>().contains_key(&id)
+    }
+
+    fn contract_balance(
+        &self,
+        contract_id: ContractId,
+        asset_id: AssetId,
+    ) -> StorageResult<ContractBalance> {
+        let amount = self
+            .storage::<ContractsAssets>()
+            .get(&(&contract_id, &asset_id).into())?
+            .ok_or(not_found!(ContractsAssets))?
+            .into_owned();
+
+        Ok(ContractBalance {
+            owner: contract_id,
+            amount,
+            asset_id,
+        })
+    }
+
+    fn contract_balances(
+        &self,
+        contract_id: ContractId,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractBalance>> {
+        DatabaseContracts::contract_balances(self, contract_id, start_asset, direction)
+    }
+
+    fn contract_storage_slot(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<Option<Vec<u8>>> {
+        let value = self
+            .storage::<ContractsState>()
+            .get(&ContractsStateKey::new(&contract_id, &key))?
+            .map(|value| value.into_owned().into());
+
+        Ok(value)
+    }
+
+    fn coin(&self, utxo_id: UtxoId) -> StorageResult<Coin> {
+        let coin = self
+            .storage::<Coins>()
+            .get(&utxo_id)?
+            .ok_or(not_found!(Coins))?
+            .into_owned();
+
+        Ok(coin.uncompress(utxo_id))
+    }
+}
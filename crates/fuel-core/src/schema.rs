@@ -1,6 +1,12 @@
-use crate::fuel_core_graphql_api::{
-    api_service::ReadDatabase,
-    database::ReadView,
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::ReadDatabase,
+        database::{
+            OnChainViewAt,
+            ReadView,
+        },
+    },
+    schema::scalars::U32,
 };
 use anyhow::anyhow;
 use async_graphql::{
@@ -24,8 +30,12 @@ use fuel_core_storage::{
     Result as StorageResult,
 };
 use itertools::Itertools;
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    sync::Arc,
+};
 
+pub mod admin;
 pub mod balance;
 pub mod blob;
 pub mod block;
@@ -37,11 +47,13 @@ pub mod dap;
 pub mod health;
 pub mod message;
 pub mod node_info;
+pub mod receipts;
 pub mod upgrades;
 
 pub mod gas_price;
 pub mod scalars;
 pub mod tx;
+pub mod tx_pool;
 
 pub mod relayed_tx;
 
@@ -58,19 +70,32 @@ pub struct Query(
     da_compressed::DaCompressedBlockQuery,
     contract::ContractQuery,
     contract::ContractBalanceQuery,
+    contract::ContractStorageQuery,
     node_info::NodeQuery,
     gas_price::LatestGasPriceQuery,
     gas_price::EstimateGasPriceQuery,
     message::MessageQuery,
     relayed_tx::RelayedTransactionQuery,
     upgrades::UpgradeQuery,
+    receipts::ReceiptsQuery,
+    admin::AdminQuery,
+    tx_pool::TxPoolQuery,
 );
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    dap::DapMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+    admin::AdminMutation,
+);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(
+    tx::TxStatusSubscription,
+    block::BlockSubscription,
+    receipts::ReceiptsSubscription,
+);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -212,6 +237,10 @@ where
 pub trait ReadViewProvider {
     /// Returns the read view for the current operation.
     fn read_view(&self) -> StorageResult<Cow<ReadView>>;
+
+    /// Returns the view of the on-chain state at the `height`.
+    /// If the `height` is not specified, returns the on-chain state of the read view.
+    fn read_view_at(&self, height: Option<U32>) -> StorageResult<OnChainViewAt>;
 }
 
 impl<'a> ReadViewProvider for Context<'a> {
@@ -228,4 +257,14 @@ impl<'a> ReadViewProvider for Context<'a> {
             Ok(Cow::Borrowed(read_view))
         }
     }
+
+    fn read_view_at(&self, height: Option<U32>) -> StorageResult<OnChainViewAt> {
+        match height {
+            Some(height) => {
+                let database: &ReadDatabase = self.data_unchecked();
+                database.view_at(&height.into())
+            }
+            None => Ok(Arc::new(self.read_view()?.into_owned())),
+        }
+    }
 }
@@ -0,0 +1,204 @@
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::{
+            DatabaseCheckpointProvider,
+            TxPool,
+        },
+        Config as GraphQLConfig,
+    },
+    schema::scalars::{
+        Address,
+        ContractId,
+        Nonce,
+        TransactionId,
+        UtxoId,
+        U32,
+    },
+};
+use async_graphql::{
+    Context,
+    InputObject,
+    Object,
+    SimpleObject,
+};
+use fuel_core_txpool_v2::config::{
+    BlackList,
+    BlackListEntry,
+};
+use subtle::ConstantTimeEq;
+
+/// The token sent by the client in the `Authorization: Bearer <token>` header.
+pub struct AdminToken(pub String);
+
+/// Fails if the request doesn't carry the admin token configured for the node.
+fn require_admin(ctx: &Context<'_>) -> async_graphql::Result<()> {
+    let config = ctx.data_unchecked::<GraphQLConfig>();
+    let Some(expected) = config.config.admin_token.as_ref() else {
+        return Err(async_graphql::Error::new("The admin API is disabled"))
+    };
+
+    match ctx.data_opt::<AdminToken>() {
+        Some(AdminToken(token))
+            if bool::from(token.as_bytes().ct_eq(expected.as_bytes())) =>
+        {
+            Ok(())
+        }
+        _ => Err(async_graphql::Error::new("The admin token is invalid")),
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct TxPoolBlackList {
+    /// Blacklisted owners of coins, senders and recipients of messages.
+    owners: Vec<Address>,
+    /// Blacklisted coins.
+    coins: Vec<UtxoId>,
+    /// Blacklisted messages.
+    messages: Vec<Nonce>,
+    /// Blacklisted contracts.
+    contracts: Vec<ContractId>,
+}
+
+impl From<BlackList> for TxPoolBlackList {
+    fn from(black_list: BlackList) -> Self {
+        Self {
+            owners: black_list.owners.into_iter().map(Into::into).collect(),
+            coins: black_list.coins.into_iter().map(Into::into).collect(),
+            messages: black_list.messages.into_iter().map(Into::into).collect(),
+            contracts: black_list.contracts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct TxPoolBlackListInput {
+    #[graphql(default)]
+    owners: Vec<Address>,
+    #[graphql(default)]
+    coins: Vec<UtxoId>,
+    #[graphql(default)]
+    messages: Vec<Nonce>,
+    #[graphql(default)]
+    contracts: Vec<ContractId>,
+}
+
+impl From<TxPoolBlackListInput> for Vec<BlackListEntry> {
+    fn from(input: TxPoolBlackListInput) -> Self {
+        let owners = input
+            .owners
+            .into_iter()
+            .map(|owner| BlackListEntry::Owner(owner.into()));
+        let coins = input
+            .coins
+            .into_iter()
+            .map(|utxo_id| BlackListEntry::Coin(utxo_id.into()));
+        let messages = input
+            .messages
+            .into_iter()
+            .map(|nonce| BlackListEntry::Message(nonce.into()));
+        let contracts = input
+            .contracts
+            .into_iter()
+            .map(|contract_id| BlackListEntry::Contract(contract_id.into()));
+        owners
+            .chain(coins)
+            .chain(messages)
+            .chain(contracts)
+            .collect()
+    }
+}
+
+#[derive(Default)]
+pub struct AdminQuery;
+
+#[Object]
+impl AdminQuery {
+    /// Returns the current blacklist of the transaction pool.
+    /// Requires the admin token.
+    async fn tx_pool_black_list(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<TxPoolBlackList> {
+        require_admin(ctx)?;
+        let txpool = ctx.data_unchecked::<TxPool>();
+        Ok(txpool.black_list()?.into())
+    }
+
+    /// Returns up to `first` ids of transactions in the transaction pool.
+    /// Requires the admin token.
+    async fn tx_pool_transaction_ids(
+        &self,
+        ctx: &Context<'_>,
+        first: U32,
+    ) -> async_graphql::Result<Vec<TransactionId>> {
+        require_admin(ctx)?;
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let tx_ids = txpool.tx_ids(first.0 as usize);
+        Ok(tx_ids.into_iter().map(Into::into).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct AdminMutation;
+
+#[Object]
+impl AdminMutation {
+    /// Adds entries to the blacklist of the transaction pool and removes
+    /// transactions that use them along with their dependents.
+    /// Returns ids of removed transactions. Requires the admin token.
+    async fn add_to_tx_pool_black_list(
+        &self,
+        ctx: &Context<'_>,
+        entries: TxPoolBlackListInput,
+    ) -> async_graphql::Result<Vec<TransactionId>> {
+        require_admin(ctx)?;
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let removed = txpool.add_to_black_list(entries.into())?;
+        Ok(removed.into_iter().map(Into::into).collect())
+    }
+
+    /// Removes entries from the blacklist of the transaction pool.
+    /// Requires the admin token.
+    async fn remove_from_tx_pool_black_list(
+        &self,
+        ctx: &Context<'_>,
+        entries: TxPoolBlackListInput,
+    ) -> async_graphql::Result<bool> {
+        require_admin(ctx)?;
+        let txpool = ctx.data_unchecked::<TxPool>();
+        txpool.remove_from_black_list(entries.into())?;
+        Ok(true)
+    }
+
+    /// Removes transactions along with their dependents from the transaction pool.
+    /// The `reason` is reported to subscribers of removed transactions.
+    /// Returns ids of removed transactions. Requires the admin token.
+    async fn remove_tx_pool_transactions(
+        &self,
+        ctx: &Context<'_>,
+        ids: Vec<TransactionId>,
+        reason: Option<String>,
+    ) -> async_graphql::Result<Vec<TransactionId>> {
+        require_admin(ctx)?;
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let reason = reason.unwrap_or_else(|| "Removed by the node operator".to_string());
+        let removed = txpool.remove_txs(ids.into_iter().map(|id| id.0).collect(), reason);
+        Ok(removed.into_iter().map(Into::into).collect())
+    }
+
+    /// Creates a consistent checkpoint of all databases of the node in the `path`
+    /// directory on the node's machine. The directory must be empty or not exist.
+    /// The checkpoint can be used as the database of another node.
+    /// Returns the height of the on-chain database in the checkpoint.
+    /// Requires the admin token.
+    async fn create_database_checkpoint(
+        &self,
+        ctx: &Context<'_>,
+        path: String,
+    ) -> async_graphql::Result<Option<U32>> {
+        require_admin(ctx)?;
+        let database_checkpoint = ctx.data_unchecked::<DatabaseCheckpointProvider>();
+        let height = database_checkpoint.create_checkpoint(path.into()).await?;
+        Ok(height.map(Into::into))
+    }
+}
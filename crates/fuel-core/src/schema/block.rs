@@ -8,6 +8,7 @@ use crate::{
         api_service::ConsensusModule,
         database::ReadView,
         ports::OffChainDatabase,
+        worker_service,
         Config as GraphQLConfig,
         IntoApiResult,
         QUERY_COSTS,
@@ -39,6 +40,7 @@ use async_graphql::{
     Enum,
     Object,
     SimpleObject,
+    Subscription,
     Union,
 };
 use fuel_core_storage::{
@@ -57,6 +59,21 @@ use fuel_core_types::{
     fuel_types,
     fuel_types::BlockHeight,
 };
+use futures::Stream;
+use tokio_stream::{
+    wrappers::{
+        errors::BroadcastStreamRecvError,
+        BroadcastStream,
+    },
+    StreamExt,
+};
+
+/// The number of blocks fetched from the database at once
+/// while the subscription replays historical blocks.
+const HISTORICAL_BLOCKS_BATCH_SIZE: u32 = 64;
+
+/// The maximum number of already imported blocks the subscription can replay.
+const MAX_HISTORICAL_BLOCKS: u32 = 10_000;
 
 pub struct Block(pub(crate) CompressedBlock);
 
@@ -67,6 +84,7 @@ pub struct Header(pub(crate) BlockHeader);
 pub enum Consensus {
     Genesis(Genesis),
     PoA(PoAConsensus),
+    DaImport(DaImportConsensus),
 }
 
 type CoreGenesis = fuel_core_types::blockchain::consensus::Genesis;
@@ -91,6 +109,14 @@ pub struct PoAConsensus {
     signature: Signature,
 }
 
+/// The consensus of the block rebuilt from the DA compressed block.
+/// The block doesn't have the signature of the block producer.
+#[derive(SimpleObject)]
+pub struct DaImportConsensus {
+    /// The hash of the DA compressed block the block was rebuilt from.
+    pub compressed_block_hash: Bytes32,
+}
+
 #[derive(Clone, Copy, Debug, Enum, Eq, PartialEq)]
 pub enum BlockVersion {
     V1,
@@ -141,6 +167,7 @@ impl Block {
         ctx: &Context<'_>,
     ) -> async_graphql::Result<Vec<Transaction>> {
         let query = ctx.read_view()?;
+        query.ensure_block_is_not_pruned(self.0.header().height())?;
         self.0
             .transactions()
             .iter()
@@ -357,6 +384,120 @@ where
     blocks.into_boxed()
 }
 
+#[derive(Default)]
+pub struct BlockSubscription;
+
+#[Subscription]
+impl BlockSubscription {
+    /// Returns a stream of blocks imported by the node.
+    /// If `from_height` is set, the stream first returns all blocks starting
+    /// from this height and then continues with the newly imported ones.
+    /// At most 10 000 already imported blocks can be replayed.
+    ///
+    /// It is possible for the stream to miss blocks if it is polled slower
+    /// than the blocks arrive. In such a case the stream returns an error
+    /// and continues with the next available block.
+    #[graphql(complexity = "QUERY_COSTS.block_header + child_complexity")]
+    async fn blocks<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+        #[graphql(desc = "The height of the first returned block")] from_height: Option<
+            U32,
+        >,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<Block>> + 'a>
+    {
+        blocks_subscription(ctx, from_height)
+    }
+
+    /// Returns a stream of headers of blocks imported by the node.
+    /// Works the same way as the `blocks` subscription.
+    #[graphql(complexity = "QUERY_COSTS.block_header + child_complexity")]
+    async fn block_headers<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+        #[graphql(desc = "The height of the first returned header")] from_height: Option<
+            U32,
+        >,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<Header>> + 'a>
+    {
+        blocks_subscription(ctx, from_height)
+    }
+}
+
+fn blocks_subscription<'a, T>(
+    ctx: &'a Context<'a>,
+    from_height: Option<U32>,
+) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<T>> + 'a>
+where
+    T: From<CompressedBlock> + 'a,
+{
+    let worker = ctx.data_unchecked::<worker_service::SharedState>();
+    // Subscribe before reading the database to not miss the blocks
+    // imported in between.
+    let new_blocks = BroadcastStream::new(worker.subscribe_blocks());
+    let latest_height: u32 = ctx.read_view()?.latest_block_height()?.into();
+
+    let (historical_heights, first_new_height) = match from_height {
+        Some(from_height) => {
+            let from_height: u32 = from_height.into();
+            if latest_height.saturating_sub(from_height) >= MAX_HISTORICAL_BLOCKS {
+                return Err(async_graphql::Error::new(format!(
+                    "The subscription can replay at most {MAX_HISTORICAL_BLOCKS} blocks, \
+                    the `from_height` should be at least {}",
+                    latest_height
+                        .saturating_sub(MAX_HISTORICAL_BLOCKS)
+                        .saturating_add(1)
+                )))
+            }
+            let first_new_height = latest_height.saturating_add(1).max(from_height);
+            (Some(from_height..=latest_height), first_new_height)
+        }
+        // There is nothing to replay.
+        None => (None, 0),
+    };
+
+    // The historical blocks are fetched in batches, each batch uses its own view
+    // to not hold the database snapshot for the whole replay.
+    let historical_batches = historical_heights
+        .into_iter()
+        .flat_map(|heights| heights.step_by(HISTORICAL_BLOCKS_BATCH_SIZE as usize))
+        .map(move |start| {
+            let end = start
+                .saturating_add(HISTORICAL_BLOCKS_BATCH_SIZE.saturating_sub(1))
+                .min(latest_height);
+            let query = match ctx.read_view() {
+                Ok(query) => query,
+                Err(err) => return vec![Err(err.into())],
+            };
+            (start..=end)
+                .map(|height| {
+                    query
+                        .block(&height.into())
+                        .map(T::from)
+                        .map_err(async_graphql::Error::from)
+                })
+                .collect::<Vec<_>>()
+        });
+    let historical_blocks = futures::StreamExt::flat_map(
+        tokio_stream::iter(historical_batches),
+        tokio_stream::iter,
+    );
+
+    let new_blocks = new_blocks.filter_map(move |result| match result {
+        Ok(block) => {
+            let height: u32 = (*block.header().height()).into();
+            (height >= first_new_height).then(|| Ok(T::from(block)))
+        }
+        Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+            Some(Err(async_graphql::Error::new(format!(
+                "The subscription is too slow and skipped {skipped} blocks"
+            ))))
+        }
+    });
+
+    Ok(historical_blocks.chain(new_blocks))
+}
+
 #[derive(Default)]
 pub struct BlockMutation;
 
@@ -432,6 +573,11 @@ impl TryFrom<CoreConsensus> for Consensus {
             CoreConsensus::PoA(poa) => Ok(Consensus::PoA(PoAConsensus {
                 signature: poa.signature.into(),
             })),
+            CoreConsensus::DaImport(da_import) => {
+                Ok(Consensus::DaImport(DaImportConsensus {
+                    compressed_block_hash: da_import.compressed_block_hash.into(),
+                }))
+            }
             _ => Err(format!("Unknown consensus type: {:?}", consensus)),
         }
     }
@@ -1,6 +1,8 @@
 use crate::{
     fuel_core_graphql_api::{
         api_service::ConsensusProvider,
+        ports::OffChainDatabase,
+        storage,
         QUERY_COSTS,
     },
     graphql_api::Config,
@@ -822,6 +824,32 @@ impl ChainInfo {
 
         Ok(GasCosts(params.gas_costs().clone()))
     }
+
+    /// The range of blocks whose transactions and receipts were pruned.
+    /// The headers of these blocks are still available.
+    #[graphql(complexity = "QUERY_COSTS.storage_read + child_complexity")]
+    async fn pruned_blocks(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<PrunedBlocks>> {
+        let query = ctx.read_view()?;
+        Ok(query.pruned_blocks()?.map(PrunedBlocks))
+    }
+}
+
+pub struct PrunedBlocks(storage::pruning::PrunedBlocks);
+
+#[Object]
+impl PrunedBlocks {
+    /// The height of the first pruned block.
+    async fn first(&self) -> U32 {
+        self.0.first.into()
+    }
+
+    /// The height of the last pruned block.
+    async fn last(&self) -> U32 {
+        self.0.last.into()
+    }
 }
 
 #[derive(Default)]
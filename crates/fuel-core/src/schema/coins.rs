@@ -1,6 +1,6 @@
 use crate::{
     coins_query::{
-        random_improve,
+        self,
         SpendQuery,
     },
     fuel_core_graphql_api::{
@@ -11,6 +11,7 @@ use crate::{
     query::{
         asset_query::AssetSpendTarget,
         CoinQueryData,
+        HistoricalQueryData,
     },
     schema::{
         scalars::{
@@ -156,8 +157,12 @@ impl CoinQuery {
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "The ID of the coin")] utxo_id: UtxoId,
+        #[graphql(
+            desc = "The height of the block after which the coin should be unspent. The latest block is used by default."
+        )]
+        block_height: Option<U32>,
     ) -> async_graphql::Result<Option<Coin>> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(block_height)?;
         query.coin(utxo_id.0).into_api_result()
     }
 
@@ -221,59 +226,199 @@ impl CoinQuery {
         #[graphql(desc = "The excluded coins from the selection.")] excluded_ids: Option<
             ExcludeInput,
         >,
+        #[graphql(desc = "\
+            The strategy of the selection of coins. `RANDOM_IMPROVE` is used by default.")]
+        strategy: Option<CoinSelectionStrategy>,
+        #[graphql(desc = "\
+            The cost of one input paid in the base asset. If set, the target of the base \
+            asset grows by the cost with every input of the selection.")]
+        cost_per_input: Option<U64>,
     ) -> async_graphql::Result<Vec<Vec<CoinType>>> {
-        let params = ctx
-            .data_unchecked::<ConsensusProvider>()
-            .latest_consensus_params();
-
-        let owner: fuel_tx::Address = owner.0;
-        let query_per_asset = query_per_asset
+        let selection = select_coins(
+            ctx,
+            owner,
+            query_per_asset,
+            excluded_ids,
+            strategy,
+            cost_per_input,
+        )?;
+
+        let coins = selection
+            .coins_per_asset
             .into_iter()
-            .map(|e| {
-                AssetSpendTarget::new(
-                    e.asset_id.0,
-                    e.amount.0,
-                    e.max.map(|max| max.0 as usize).unwrap_or(usize::MAX),
-                )
-            })
-            .collect_vec();
-        let excluded_ids: Option<Vec<_>> = excluded_ids.map(|exclude| {
-            let utxos = exclude
-                .utxos
-                .into_iter()
-                .map(|utxo| coins::CoinId::Utxo(utxo.into()));
-            let messages = exclude
-                .messages
-                .into_iter()
-                .map(|message| coins::CoinId::Message(message.into()));
-            utxos.chain(messages).collect()
-        });
-
-        let base_asset_id = params.base_asset_id();
-        let spend_query =
-            SpendQuery::new(owner, &query_per_asset, excluded_ids, *base_asset_id)?;
+            .map(|coins| coins.into_iter().map(Into::into).collect_vec())
+            .collect();
 
-        let query = ctx.read_view()?;
+        Ok(coins)
+    }
 
-        let coins = random_improve(query.as_ref(), &spend_query)?
+    /// The same as `coins_to_spend`, but along with selected coins returns their
+    /// total amount and the estimated amount of the change output for each asset.
+    #[graphql(complexity = "QUERY_COSTS.coins_to_spend")]
+    async fn coins_to_spend_with_change(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "\
+            The list of requested assets` coins with asset ids, `target` amount the user wants \
+            to reach, and the `max` number of coins in the selection. Several entries with the \
+            same asset id are not allowed.")]
+        query_per_asset: Vec<SpendQueryElementInput>,
+        #[graphql(desc = "The excluded coins from the selection.")] excluded_ids: Option<
+            ExcludeInput,
+        >,
+        #[graphql(desc = "\
+            The strategy of the selection of coins. `RANDOM_IMPROVE` is used by default.")]
+        strategy: Option<CoinSelectionStrategy>,
+        #[graphql(desc = "\
+            The cost of one input paid in the base asset. If set, the target of the base \
+            asset grows by the cost with every input of the selection.")]
+        cost_per_input: Option<U64>,
+    ) -> async_graphql::Result<Vec<CoinsSelection>> {
+        let selection = select_coins(
+            ctx,
+            owner,
+            query_per_asset,
+            excluded_ids,
+            strategy,
+            cost_per_input,
+        )?;
+
+        let total_inputs = selection
+            .coins_per_asset
+            .iter()
+            .fold(0usize, |total, coins| total.saturating_add(coins.len()));
+        let cost_of_inputs = selection.cost_per_input.saturating_mul(total_inputs as u64);
+
+        let result = selection
+            .assets
             .into_iter()
-            .map(|coins| {
-                coins
-                    .into_iter()
-                    .map(|coin| match coin {
-                        coins::CoinType::Coin(coin) => CoinType::Coin(coin.into()),
-                        coins::CoinType::MessageCoin(coin) => {
-                            CoinType::MessageCoin(coin.into())
-                        }
-                    })
-                    .collect_vec()
+            .zip(selection.coins_per_asset)
+            .map(|(asset, coins)| {
+                let amount = coins
+                    .iter()
+                    .fold(0u64, |total, coin| total.saturating_add(coin.amount()));
+                let mut required_amount = asset.target;
+                if asset.id == selection.base_asset_id {
+                    required_amount = required_amount.saturating_add(cost_of_inputs);
+                }
+
+                CoinsSelection {
+                    asset_id: asset.id.into(),
+                    coins: coins.into_iter().map(Into::into).collect(),
+                    amount: amount.into(),
+                    change: amount.saturating_sub(required_amount).into(),
+                }
             })
             .collect();
 
-        Ok(coins)
+        Ok(result)
+    }
+}
+
+/// The strategy of the selection of coins for `coins_to_spend`.
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+pub enum CoinSelectionStrategy {
+    /// Random coins are selected until the target is reached, and then the selection
+    /// is improved to make the change close to the target.
+    RandomImprove,
+    /// The biggest coins are selected first.
+    LargestFirst,
+    /// The smallest coins are selected first.
+    SmallestFirst,
+    /// The minimal number of coins is selected with the smallest change.
+    MinimizeInputs,
+    /// The biggest coins are selected to reach the target, and then the smallest
+    /// remaining coins are added to merge them into the change.
+    ConsolidateDust,
+}
+
+impl From<CoinSelectionStrategy> for coins_query::CoinSelectionStrategy {
+    fn from(strategy: CoinSelectionStrategy) -> Self {
+        match strategy {
+            CoinSelectionStrategy::RandomImprove => Self::RandomImprove,
+            CoinSelectionStrategy::LargestFirst => Self::LargestFirst,
+            CoinSelectionStrategy::SmallestFirst => Self::SmallestFirst,
+            CoinSelectionStrategy::MinimizeInputs => Self::MinimizeInputs,
+            CoinSelectionStrategy::ConsolidateDust => Self::ConsolidateDust,
+        }
     }
 }
 
+/// Selected coins of the asset.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinsSelection {
+    asset_id: AssetId,
+    coins: Vec<CoinType>,
+    /// The total amount of selected coins.
+    amount: U64,
+    /// The estimated amount of the change output: the amount of selected coins minus
+    /// the target and, for the base asset, minus the cost of all inputs.
+    change: U64,
+}
+
+struct Selection {
+    assets: Vec<AssetSpendTarget>,
+    coins_per_asset: Vec<Vec<coins::CoinType>>,
+    base_asset_id: fuel_tx::AssetId,
+    cost_per_input: u64,
+}
+
+fn select_coins(
+    ctx: &Context<'_>,
+    owner: Address,
+    query_per_asset: Vec<SpendQueryElementInput>,
+    excluded_ids: Option<ExcludeInput>,
+    strategy: Option<CoinSelectionStrategy>,
+    cost_per_input: Option<U64>,
+) -> async_graphql::Result<Selection> {
+    let params = ctx
+        .data_unchecked::<ConsensusProvider>()
+        .latest_consensus_params();
+
+    let owner: fuel_tx::Address = owner.0;
+    let query_per_asset = query_per_asset
+        .into_iter()
+        .map(|e| {
+            AssetSpendTarget::new(
+                e.asset_id.0,
+                e.amount.0,
+                e.max.map(|max| max.0 as usize).unwrap_or(usize::MAX),
+            )
+        })
+        .collect_vec();
+    let excluded_ids: Option<Vec<_>> = excluded_ids.map(|exclude| {
+        let utxos = exclude
+            .utxos
+            .into_iter()
+            .map(|utxo| coins::CoinId::Utxo(utxo.into()));
+        let messages = exclude
+            .messages
+            .into_iter()
+            .map(|message| coins::CoinId::Message(message.into()));
+        utxos.chain(messages).collect()
+    });
+
+    let base_asset_id = *params.base_asset_id();
+    let cost_per_input = cost_per_input.map(|cost| cost.0).unwrap_or_default();
+    let spend_query =
+        SpendQuery::new(owner, &query_per_asset, excluded_ids, base_asset_id)?
+            .with_cost_per_input(cost_per_input)
+            .with_max_inputs(params.tx_params().max_inputs() as usize);
+
+    let query = ctx.read_view()?;
+    let strategy = strategy.unwrap_or(CoinSelectionStrategy::RandomImprove);
+    let coins_per_asset =
+        coins_query::select_coins(query.as_ref(), &spend_query, strategy.into())?;
+
+    Ok(Selection {
+        assets: query_per_asset,
+        coins_per_asset,
+        base_asset_id,
+        cost_per_input,
+    })
+}
+
 impl From<CoinModel> for Coin {
     fn from(value: CoinModel) -> Self {
         Coin(value)
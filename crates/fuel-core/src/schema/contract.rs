@@ -3,13 +3,18 @@ use crate::{
         IntoApiResult,
         QUERY_COSTS,
     },
-    query::ContractQueryData,
+    query::{
+        ContractQueryData,
+        HistoricalQueryData,
+    },
     schema::{
         scalars::{
             AssetId,
+            Bytes32,
             ContractId,
             HexString,
             Salt,
+            U32,
             U64,
         },
         ReadViewProvider,
@@ -76,8 +81,12 @@ impl ContractQuery {
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "ID of the Contract")] id: ContractId,
+        #[graphql(
+            desc = "The height of the block after which the contract should exist. The latest block is used by default."
+        )]
+        block_height: Option<U32>,
     ) -> async_graphql::Result<Option<Contract>> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(block_height)?;
         query
             .contract_exists(id.0)
             .and_then(|contract_exists| {
@@ -125,10 +134,14 @@ impl ContractBalanceQuery {
         ctx: &Context<'_>,
         contract: ContractId,
         asset: AssetId,
+        #[graphql(
+            desc = "The height of the block after which the balance is returned. The latest block is used by default."
+        )]
+        block_height: Option<U32>,
     ) -> async_graphql::Result<ContractBalance> {
         let contract_id = contract.into();
         let asset_id = asset.into();
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(block_height)?;
         query
             .contract_balance(contract_id, asset_id)
             .into_api_result()
@@ -149,6 +162,7 @@ impl ContractBalanceQuery {
         + (QUERY_COSTS.storage_read + first.unwrap_or_default() as usize) * child_complexity \
         + (QUERY_COSTS.storage_read + last.unwrap_or_default() as usize) * child_complexity\
     }")]
+    #[allow(clippy::too_many_arguments)]
     async fn contract_balances(
         &self,
         ctx: &Context<'_>,
@@ -157,10 +171,11 @@ impl ContractBalanceQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        block_height: Option<U32>,
     ) -> async_graphql::Result<
         Connection<AssetId, ContractBalance, EmptyFields, EmptyFields>,
     > {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(block_height)?;
 
         crate::schema::query_pagination(after, before, first, last, |start, direction| {
             let balances = query
@@ -182,6 +197,57 @@ impl ContractBalanceQuery {
     }
 }
 
+pub struct ContractStorageSlot {
+    key: fuel_types::Bytes32,
+    value: Vec<u8>,
+}
+
+#[Object]
+impl ContractStorageSlot {
+    async fn key(&self) -> Bytes32 {
+        self.key.into()
+    }
+
+    async fn value(&self) -> HexString {
+        HexString(self.value.clone())
+    }
+}
+
+#[derive(Default)]
+pub struct ContractStorageQuery;
+
+#[Object]
+impl ContractStorageQuery {
+    /// Returns the values of the contract's storage slots.
+    /// Slots that are not set are not returned.
+    #[graphql(complexity = "QUERY_COSTS.storage_read * storage_slots.len()")]
+    async fn contract_storage_slots(
+        &self,
+        ctx: &Context<'_>,
+        contract_id: ContractId,
+        storage_slots: Vec<Bytes32>,
+        #[graphql(
+            desc = "The height of the block after which the storage is returned. The latest block is used by default."
+        )]
+        block_height: Option<U32>,
+    ) -> async_graphql::Result<Vec<ContractStorageSlot>> {
+        let query = ctx.read_view_at(block_height)?;
+        let contract_id = contract_id.into();
+
+        storage_slots
+            .into_iter()
+            .filter_map(|key| {
+                let key = key.into();
+                query
+                    .contract_storage_slot(contract_id, key)
+                    .map(|value| value.map(|value| ContractStorageSlot { key, value }))
+                    .transpose()
+            })
+            .map(|result| result.map_err(Into::into))
+            .collect()
+    }
+}
+
 impl From<graphql_api::ContractBalance> for ContractBalance {
     fn from(balance: graphql_api::ContractBalance) -> Self {
         ContractBalance(balance)
@@ -4,7 +4,16 @@ use super::scalars::{
 };
 use crate::{
     graphql_api::{
-        api_service::GasPriceProvider,
+        api_service::{
+            ConsensusProvider,
+            GasPriceProvider,
+            TxPool,
+        },
+        ports::OffChainDatabase,
+        tip_estimation::{
+            self,
+            RECENT_BLOCKS,
+        },
         QUERY_COSTS,
     },
     query::{
@@ -17,6 +26,10 @@ use async_graphql::{
     Context,
     Object,
 };
+use fuel_core_storage::{
+    iter::IterDirection,
+    Result as StorageResult,
+};
 use fuel_core_types::{
     blockchain::block::Block,
     fuel_tx::{
@@ -79,6 +92,31 @@ impl EstimateGasPrice {
     }
 }
 
+pub struct EstimateTip {
+    pub recent_tip: U64,
+    pub pool_tip: U64,
+}
+
+#[Object]
+impl EstimateTip {
+    /// The tip that is expected to get the transaction included within the target
+    /// number of blocks. It is the maximum of `recent_tip` and `pool_tip`.
+    async fn tip(&self) -> U64 {
+        self.recent_tip.0.max(self.pool_tip.0).into()
+    }
+
+    /// The tip at the requested percentile of transactions included into recent blocks.
+    async fn recent_tip(&self) -> U64 {
+        self.recent_tip
+    }
+
+    /// The tip required to outbid transactions in the pool that don't leave space
+    /// for the transaction in the target number of blocks. Zero if all of them fit.
+    async fn pool_tip(&self) -> U64 {
+        self.pool_tip
+    }
+}
+
 #[derive(Default)]
 pub struct EstimateGasPriceQuery {}
 
@@ -114,4 +152,63 @@ impl EstimateGasPriceQuery {
             gas_price: gas_price.into(),
         })
     }
+
+    /// Estimates the tip required to get the transaction with the `max_gas`
+    /// included within `target_blocks` blocks. The estimation combines
+    /// the `percentile` (50 by default) of tips paid by transactions in recent blocks
+    /// with the content of the pool ordered by its selection strategy.
+    /// The `size` of the transaction is required if the pool orders transactions
+    /// by the ratio of the tip to the size.
+    #[graphql(
+        complexity = "QUERY_COSTS.storage_iterator + RECENT_BLOCKS * QUERY_COSTS.storage_read"
+    )]
+    async fn estimate_tip(
+        &self,
+        ctx: &Context<'_>,
+        target_blocks: U32,
+        percentile: Option<U32>,
+        max_gas: U64,
+        size: Option<U64>,
+    ) -> async_graphql::Result<EstimateTip> {
+        let percentile = percentile.map(|p| p.0).unwrap_or(50);
+        let percentile = u8::try_from(percentile)
+            .ok()
+            .filter(|percentile| *percentile <= 100)
+            .ok_or(async_graphql::Error::new(
+                "The percentile should be in the range from 0 to 100",
+            ))?;
+        if target_blocks.0 == 0 {
+            return Err(async_graphql::Error::new(
+                "The number of target blocks should be greater than zero",
+            ))
+        }
+
+        let query = ctx.read_view()?;
+        let recent_tips = query
+            .block_tips(None, IterDirection::Reverse)
+            .take(RECENT_BLOCKS)
+            .collect::<StorageResult<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+        let recent_tip =
+            tip_estimation::percentile_tip(recent_tips, percentile).unwrap_or_default();
+
+        let block_gas_limit = ctx
+            .data_unchecked::<ConsensusProvider>()
+            .latest_consensus_params()
+            .block_gas_limit();
+        let available_gas = block_gas_limit.saturating_mul(target_blocks.0.into());
+        let pool_tip = ctx
+            .data_unchecked::<TxPool>()
+            .tip_to_outbid(available_gas, max_gas.0, size.map(|size| size.0))
+            .ok_or(async_graphql::Error::new(
+                "The size of the transaction is required by the selection strategy of the pool",
+            ))?;
+
+        Ok(EstimateTip {
+            recent_tip: recent_tip.into(),
+            pool_tip: pool_tip.into(),
+        })
+    }
 }
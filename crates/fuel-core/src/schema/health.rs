@@ -1,4 +1,20 @@
-use async_graphql::Object;
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::NodeHealthProvider,
+        health,
+        Config as GraphQLConfig,
+    },
+    schema::scalars::{
+        U32,
+        U64,
+    },
+};
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+};
+use fuel_core_services::State;
 
 #[derive(Default)]
 pub struct HealthQuery;
@@ -9,4 +25,121 @@ impl HealthQuery {
     async fn health(&self) -> bool {
         true
     }
+
+    /// Returns the state of the node's sub-services and how far the node lags
+    /// behind its peers, the DA layer and its own on-chain state.
+    async fn node_health(&self, ctx: &Context<'_>) -> async_graphql::Result<NodeHealth> {
+        let provider = ctx.data_unchecked::<NodeHealthProvider>();
+        let config = &ctx.data_unchecked::<GraphQLConfig>().config;
+        let health = provider.health().await?;
+        let ready =
+            health.is_ready(config.health_max_block_lag, config.health_max_da_lag);
+        Ok(NodeHealth { health, ready })
+    }
+}
+
+pub struct NodeHealth {
+    health: health::NodeHealth,
+    ready: bool,
+}
+
+#[Object]
+impl NodeHealth {
+    /// None of the node's sub-services are stopped.
+    async fn live(&self) -> bool {
+        self.health.is_live()
+    }
+
+    /// All sub-services are started and the node doesn't lag behind more than
+    /// the configured thresholds.
+    async fn ready(&self) -> bool {
+        self.ready
+    }
+
+    async fn services(&self) -> Vec<ServiceHealth> {
+        self.health
+            .services
+            .iter()
+            .cloned()
+            .map(ServiceHealth)
+            .collect()
+    }
+
+    /// The height of the latest block in the on-chain database.
+    async fn on_chain_height(&self) -> Option<U32> {
+        self.health.on_chain_height.map(Into::into)
+    }
+
+    /// The height of the latest block processed by the off-chain worker.
+    async fn off_chain_height(&self) -> Option<U32> {
+        self.health.off_chain_height.map(Into::into)
+    }
+
+    /// The highest block height reported by peers.
+    async fn best_peer_height(&self) -> Option<U32> {
+        self.health.best_peer_height.map(Into::into)
+    }
+
+    /// The number of blocks the node is behind the best peer.
+    async fn sync_lag(&self) -> Option<U32> {
+        self.health.sync_lag().map(Into::into)
+    }
+
+    /// The number of blocks the off-chain worker is behind the on-chain database.
+    async fn off_chain_lag(&self) -> Option<U32> {
+        self.health.off_chain_lag().map(Into::into)
+    }
+
+    /// The DA height synced by the relayer.
+    async fn da_height(&self) -> Option<U64> {
+        self.health.da_height.map(|height| height.0.into())
+    }
+
+    /// The finalized DA height observed by the relayer on the DA layer.
+    async fn remote_da_height(&self) -> Option<U64> {
+        self.health.remote_da_height.map(|height| height.0.into())
+    }
+
+    /// The number of DA blocks the relayer is behind the DA layer.
+    async fn da_lag(&self) -> Option<U64> {
+        self.health.da_lag().map(Into::into)
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ServiceState {
+    NotStarted,
+    Starting,
+    Started,
+    Stopping,
+    Stopped,
+    StoppedWithError,
+}
+
+pub struct ServiceHealth(health::ServiceHealth);
+
+#[Object]
+impl ServiceHealth {
+    async fn name(&self) -> &str {
+        self.0.name
+    }
+
+    async fn state(&self) -> ServiceState {
+        match &self.0.state {
+            State::NotStarted => ServiceState::NotStarted,
+            State::Starting => ServiceState::Starting,
+            State::Started => ServiceState::Started,
+            State::Stopping => ServiceState::Stopping,
+            State::Stopped => ServiceState::Stopped,
+            State::StoppedWithError(_) => ServiceState::StoppedWithError,
+        }
+    }
+
+    /// The error that stopped the service.
+    async fn error(&self) -> Option<&str> {
+        match &self.0.state {
+            State::StoppedWithError(error) => Some(error.as_str()),
+            _ => None,
+        }
+    }
 }
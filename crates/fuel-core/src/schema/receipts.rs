@@ -0,0 +1,323 @@
+use crate::{
+    fuel_core_graphql_api::{
+        database::ReadView,
+        ports::OffChainDatabase,
+        storage::receipts::{
+            ContractReceipt as StorageContractReceipt,
+            ContractReceiptCursor,
+        },
+        worker_service,
+        QUERY_COSTS,
+    },
+    query::BlockQueryData,
+    schema::{
+        scalars::{
+            ContractId,
+            HexString,
+            TransactionId,
+            U32,
+            U64,
+        },
+        tx::receipt::{
+            Receipt,
+            ReceiptType,
+        },
+        ReadViewProvider,
+    },
+};
+use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
+    Context,
+    InputObject,
+    Object,
+    Subscription,
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    fuel_tx,
+    fuel_types,
+    fuel_types::BlockHeight,
+};
+use futures::{
+    stream,
+    Stream,
+};
+use tokio_stream::{
+    wrappers::{
+        errors::BroadcastStreamRecvError,
+        BroadcastStream,
+    },
+    StreamExt,
+};
+
+/// The number of receipts fetched from the database at once
+/// while the subscription replays historical receipts.
+const HISTORICAL_RECEIPTS_BATCH_SIZE: usize = 128;
+
+/// The filter for receipts emitted by the contract.
+#[derive(InputObject, Clone, Copy)]
+pub struct ReceiptFilterInput {
+    /// The contract that emitted receipts.
+    contract_id: ContractId,
+    /// Returns only receipts of this type.
+    receipt_type: Option<ReceiptType>,
+    /// Returns only receipts with this value of the `ra` register.
+    ra: Option<U64>,
+    /// Returns only receipts with this value of the `rb` register.
+    rb: Option<U64>,
+    /// Returns only receipts from blocks starting from this height (inclusive).
+    from_height: Option<U32>,
+    /// Returns only receipts from blocks up to this height (inclusive).
+    to_height: Option<U32>,
+}
+
+impl ReceiptFilterInput {
+    fn contract_id(&self) -> fuel_types::ContractId {
+        self.contract_id.into()
+    }
+
+    fn first_height(&self) -> BlockHeight {
+        self.from_height.map(|h| h.0).unwrap_or_default().into()
+    }
+
+    fn last_height(&self) -> BlockHeight {
+        self.to_height.map(|h| h.0).unwrap_or(u32::MAX).into()
+    }
+
+    fn contains_height(&self, height: &BlockHeight) -> bool {
+        *height >= self.first_height() && *height <= self.last_height()
+    }
+
+    fn matches(&self, receipt: &fuel_tx::Receipt) -> bool {
+        let type_matches = self
+            .receipt_type
+            .map_or(true, |ty| ReceiptType::from(receipt) == ty);
+        let ra_matches = self.ra.map_or(true, |ra| receipt.ra() == Some(ra.0));
+        let rb_matches = self.rb.map_or(true, |rb| receipt.rb() == Some(rb.0));
+        type_matches && ra_matches && rb_matches
+    }
+}
+
+/// The receipt emitted by the contract along with its position in the chain.
+pub struct ContractReceipt {
+    cursor: ContractReceiptCursor,
+    receipt: StorageContractReceipt,
+}
+
+#[Object]
+impl ContractReceipt {
+    /// The height of the block that contains the receipt.
+    async fn block_height(&self) -> U32 {
+        self.cursor.block_height.into()
+    }
+
+    /// The id of the transaction that emitted the receipt.
+    async fn transaction_id(&self) -> TransactionId {
+        self.receipt.tx_id.into()
+    }
+
+    /// The receipt emitted by the contract.
+    async fn receipt(&self) -> Receipt {
+        Receipt(self.receipt.receipt.clone())
+    }
+}
+
+/// Iterates over the receipts that match the `filter` starting from the `start` position.
+fn filtered_receipts(
+    query: &ReadView,
+    filter: ReceiptFilterInput,
+    start: Option<ContractReceiptCursor>,
+    direction: IterDirection,
+) -> impl Iterator<Item = StorageResult<ContractReceipt>> + '_ {
+    let start = start.unwrap_or_else(|| match direction {
+        IterDirection::Forward => ContractReceiptCursor::first_at(filter.first_height()),
+        IterDirection::Reverse => ContractReceiptCursor::last_at(filter.last_height()),
+    });
+
+    query
+        .contract_receipts(&filter.contract_id(), Some(start), direction)
+        .take_while(move |result| match result {
+            Ok((cursor, _)) => filter.contains_height(&cursor.block_height),
+            Err(_) => true,
+        })
+        .filter(move |result| match result {
+            Ok((_, receipt)) => filter.matches(&receipt.receipt),
+            Err(_) => true,
+        })
+        .map(|result| result.map(|(cursor, receipt)| ContractReceipt { cursor, receipt }))
+}
+
+#[derive(Default)]
+pub struct ReceiptsQuery;
+
+#[Object]
+impl ReceiptsQuery {
+    /// Returns receipts emitted by the contract that match the filter.
+    /// The receipts are sorted by their position in the chain.
+    #[graphql(complexity = "{\
+        QUERY_COSTS.storage_iterator\
+        + (QUERY_COSTS.storage_read + first.unwrap_or_default() as usize) * child_complexity \
+        + (QUERY_COSTS.storage_read + last.unwrap_or_default() as usize) * child_complexity\
+    }")]
+    async fn receipts(
+        &self,
+        ctx: &Context<'_>,
+        filter: ReceiptFilterInput,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<HexString, ContractReceipt, EmptyFields, EmptyFields>,
+    > {
+        let query = ctx.read_view()?;
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<HexString>, direction| {
+                let start = if let Some(start) = start.clone() {
+                    let start: Vec<u8> = start.into();
+                    Some(ContractReceiptCursor::try_from(start)?)
+                } else {
+                    None
+                };
+
+                let receipts =
+                    filtered_receipts(&query, filter, start, direction).map(|result| {
+                        result.map(|receipt| (Vec::from(receipt.cursor).into(), receipt))
+                    });
+
+                Ok(receipts)
+            },
+        )
+        .await
+    }
+}
+
+#[derive(Default)]
+pub struct ReceiptsSubscription;
+
+#[Subscription]
+impl ReceiptsSubscription {
+    /// Returns a stream of receipts emitted by the contract that match the filter.
+    /// If `from_height` of the filter is set, the stream first returns receipts
+    /// from the already imported blocks and then continues with the new ones.
+    /// The stream ends after the block at `to_height`.
+    ///
+    /// It is possible for the stream to miss receipts if it is polled slower
+    /// than the blocks arrive. In such a case the stream returns an error
+    /// and continues with the next available block.
+    #[graphql(complexity = "QUERY_COSTS.storage_iterator + child_complexity")]
+    async fn receipts<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+        filter: ReceiptFilterInput,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<ContractReceipt>> + 'a,
+    > {
+        let worker = ctx.data_unchecked::<worker_service::SharedState>();
+        // Subscribe before reading the database to not miss the blocks
+        // imported in between.
+        let new_blocks = BroadcastStream::new(worker.subscribe_blocks());
+        let query = ctx.read_view()?.into_owned();
+        let latest_height: u32 = query.latest_block_height()?.into();
+        let to_height = filter.last_height();
+
+        let (historical_start, first_new_height) = match filter.from_height {
+            Some(from_height) => {
+                let from_height: u32 = from_height.into();
+                let first_new_height = latest_height.saturating_add(1).max(from_height);
+                (
+                    Some(ContractReceiptCursor::first_at(from_height.into())),
+                    first_new_height,
+                )
+            }
+            // Nothing to replay.
+            None => (None, latest_height.saturating_add(1)),
+        };
+        let first_new_height = BlockHeight::from(first_new_height);
+
+        // The historical receipts are fetched in batches
+        // to not hold the whole range in memory.
+        let mut next_batch = historical_start.map(|start| (start, false));
+        let historical_receipts = std::iter::from_fn(move || {
+            let (start, skip_start) = next_batch.take()?;
+            let batch =
+                filtered_receipts(&query, filter, Some(start), IterDirection::Forward)
+                    .skip_while(|result| {
+                        // The `start` was already returned by the previous batch.
+                        skip_start
+                            && matches!(result, Ok(receipt) if receipt.cursor == start)
+                    })
+                    .take_while(|result| match result {
+                        Ok(receipt) => receipt.cursor.block_height < first_new_height,
+                        Err(_) => true,
+                    })
+                    .take(HISTORICAL_RECEIPTS_BATCH_SIZE)
+                    .map(|result| result.map_err(async_graphql::Error::from))
+                    .collect::<Vec<_>>();
+
+            if batch.len() == HISTORICAL_RECEIPTS_BATCH_SIZE {
+                if let Some(Ok(last)) = batch.last() {
+                    next_batch = Some((last.cursor, true));
+                }
+            }
+            Some(batch)
+        });
+        let historical_receipts = tokio_stream::iter(historical_receipts);
+
+        let new_receipts = new_blocks
+            .take_while(move |result| match result {
+                Ok(block) => *block.header().height() <= to_height,
+                Err(_) => true,
+            })
+            .filter_map(move |result| match result {
+                Ok(block) => {
+                    let height = *block.header().height();
+                    (height >= first_new_height)
+                        .then(|| block_receipts(ctx, filter, height))
+                }
+                Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                    Some(vec![Err(async_graphql::Error::new(format!(
+                        "The subscription is too slow and skipped {skipped} blocks"
+                    )))])
+                }
+            });
+
+        let receipts = historical_receipts.chain(new_receipts);
+        Ok(futures::StreamExt::flat_map(receipts, stream::iter))
+    }
+}
+
+/// Returns the receipts that match the `filter` from the block at the `height`.
+fn block_receipts(
+    ctx: &Context<'_>,
+    filter: ReceiptFilterInput,
+    height: BlockHeight,
+) -> Vec<async_graphql::Result<ContractReceipt>> {
+    let query = match ctx.read_view() {
+        Ok(query) => query,
+        Err(err) => return vec![Err(err.into())],
+    };
+
+    filtered_receipts(
+        query.as_ref(),
+        filter,
+        Some(ContractReceiptCursor::first_at(height)),
+        IterDirection::Forward,
+    )
+    .take_while(|result| match result {
+        Ok(receipt) => receipt.cursor.block_height == height,
+        Err(_) => true,
+    })
+    .map(|result| result.map_err(async_graphql::Error::from))
+    .collect()
+}
@@ -23,6 +23,7 @@ use crate::{
             SortedTxCursor,
             TransactionId,
             TxPointer,
+            U32,
         },
         tx::types::TransactionStatus,
         ReadViewProvider,
@@ -276,6 +277,9 @@ impl TxMutation {
         // for read-only calls.
         utxo_validation: Option<bool>,
         gas_price: Option<U64>,
+        // The transactions are executed on top of the state after the block at this height.
+        // The latest block is used by default.
+        block_height: Option<U32>,
     ) -> async_graphql::Result<Vec<DryRunTransactionExecutionStatus>> {
         let block_producer = ctx.data_unchecked::<BlockProducer>();
         let consensus_params = ctx
@@ -297,10 +301,18 @@ impl TxMutation {
             Ok(gas)
         })?;
 
+        let simulated_height = block_height
+            .map(|height| {
+                fuel_types::BlockHeight::from(height).succ().ok_or_else(|| {
+                    anyhow::anyhow!("The block height {} is too big", height.0)
+                })
+            })
+            .transpose()?;
+
         let tx_statuses = block_producer
             .dry_run_txs(
                 transactions,
-                None, // TODO(#1749): Pass parameter from API
+                simulated_height,
                 None, // TODO(#1749): Pass parameter from API
                 utxo_validation,
                 gas_price.map(|x| x.into()),
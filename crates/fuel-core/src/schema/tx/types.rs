@@ -204,7 +204,11 @@ impl SuccessStatus {
         self.result.map(Into::into)
     }
 
-    async fn receipts(&self) -> async_graphql::Result<Vec<Receipt>> {
+    async fn receipts(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Receipt>> {
+        if self.receipts.is_empty() {
+            ctx.read_view()?
+                .ensure_block_is_not_pruned(&self.block_height)?;
+        }
         Ok(self.receipts.iter().map(Into::into).collect())
     }
 
@@ -264,7 +268,11 @@ impl FailureStatus {
         self.state.map(Into::into)
     }
 
-    async fn receipts(&self) -> async_graphql::Result<Vec<Receipt>> {
+    async fn receipts(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Receipt>> {
+        if self.receipts.is_empty() {
+            ctx.read_view()?
+                .ensure_block_is_not_pruned(&self.block_height)?;
+        }
         Ok(self.receipts.iter().map(Into::into).collect())
     }
 
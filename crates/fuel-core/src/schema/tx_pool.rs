@@ -0,0 +1,228 @@
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::TxPool,
+        QUERY_COSTS,
+    },
+    schema::{
+        scalars::{
+            Address,
+            Tai64Timestamp,
+            TransactionId,
+            U32,
+            U64,
+        },
+        tx::types::Transaction,
+    },
+};
+use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
+    Context,
+    Enum,
+    Object,
+};
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_txpool_v2::{
+    TxDependencies,
+    TxInfo,
+};
+use std::{
+    cmp::Ordering,
+    ops::Deref,
+};
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum TxPoolOrder {
+    /// The highest tip first.
+    Tip,
+    /// The highest ratio of the tip to the max gas first.
+    GasPrice,
+    /// The oldest transaction first.
+    Age,
+}
+
+impl TxPoolOrder {
+    fn compare(&self, a: &TxInfo, b: &TxInfo) -> Ordering {
+        match self {
+            TxPoolOrder::Tip => b.tx().tip().cmp(&a.tx().tip()),
+            TxPoolOrder::GasPrice => {
+                let a_ratio =
+                    (a.tx().tip() as u128).saturating_mul(b.tx().max_gas() as u128);
+                let b_ratio =
+                    (b.tx().tip() as u128).saturating_mul(a.tx().max_gas() as u128);
+                b_ratio.cmp(&a_ratio)
+            }
+            TxPoolOrder::Age => a.created().cmp(&b.created()),
+        }
+    }
+}
+
+pub struct PooledTransaction {
+    position: usize,
+    info: TxInfo,
+}
+
+#[Object]
+impl PooledTransaction {
+    /// The position of the transaction in the pool in the requested order.
+    async fn position(&self) -> U32 {
+        U32(u32::try_from(self.position).unwrap_or(u32::MAX))
+    }
+
+    async fn id(&self) -> TransactionId {
+        self.info.tx().id().into()
+    }
+
+    async fn tip(&self) -> U64 {
+        self.info.tx().tip().into()
+    }
+
+    async fn max_gas(&self) -> U64 {
+        self.info.tx().max_gas().into()
+    }
+
+    async fn bytes_size(&self) -> U64 {
+        (self.info.tx().metered_bytes_size() as u64).into()
+    }
+
+    /// The time when the transaction was inserted into the pool.
+    async fn submitted_at(&self) -> Tai64Timestamp {
+        Tai64Timestamp(self.info.submitted_time())
+    }
+
+    async fn transaction(&self) -> Transaction {
+        let tx = self.info.tx();
+        Transaction(tx.deref().into(), tx.id())
+    }
+}
+
+pub struct TxPoolInfo;
+
+#[Object]
+impl TxPoolInfo {
+    /// The number of transactions in the pool.
+    async fn count(&self, ctx: &Context<'_>) -> U64 {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        (txpool.tx_count() as u64).into()
+    }
+
+    /// The cumulative max gas of all transactions in the pool.
+    async fn total_gas(&self, ctx: &Context<'_>) -> U64 {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        txpool.total_gas().into()
+    }
+
+    /// The cumulative size in bytes of all transactions in the pool.
+    async fn total_bytes(&self, ctx: &Context<'_>) -> async_graphql::Result<U64> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        Ok((txpool.total_bytes_size()? as u64).into())
+    }
+
+    /// Transactions in the pool sorted by `order_by`, optionally filtered by the `owner`
+    /// of inputs. By default, transactions are sorted by the gas price.
+    #[graphql(complexity = "{\
+        (QUERY_COSTS.tx_get + child_complexity) \
+        * (first.unwrap_or_default() as usize + last.unwrap_or_default() as usize)
+    }")]
+    #[allow(clippy::too_many_arguments)]
+    async fn transactions(
+        &self,
+        ctx: &Context<'_>,
+        owner: Option<Address>,
+        order_by: Option<TxPoolOrder>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<TransactionId, PooledTransaction, EmptyFields, EmptyFields>,
+    > {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let order_by = order_by.unwrap_or(TxPoolOrder::GasPrice);
+        let mut transactions = txpool.transactions()?;
+        transactions.sort_by(|a, b| order_by.compare(a, b));
+        let owner = owner.map(|owner| owner.0);
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TransactionId>, direction| {
+                let mut transactions = transactions
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(_, info)| {
+                        owner.map_or(true, |owner| {
+                            info.tx()
+                                .inputs()
+                                .iter()
+                                .any(|input| input.input_owner() == Some(&owner))
+                        })
+                    })
+                    .map(|(position, info)| {
+                        let id: TransactionId = info.tx().id().into();
+                        (id, PooledTransaction { position, info })
+                    })
+                    .collect::<Vec<_>>();
+
+                if direction == IterDirection::Reverse {
+                    transactions.reverse();
+                }
+
+                let start = *start;
+                let transactions = transactions
+                    .into_iter()
+                    .skip_while(move |(id, _)| start.map_or(false, |start| id != &start))
+                    .map(Ok);
+
+                Ok(transactions)
+            },
+        )
+        .await
+    }
+}
+
+pub struct TxPoolDependencies(TxDependencies);
+
+#[Object]
+impl TxPoolDependencies {
+    /// Transactions in the pool whose outputs are spent by the transaction.
+    async fn dependencies(&self) -> Vec<TransactionId> {
+        self.0
+            .dependencies
+            .iter()
+            .copied()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// Transactions in the pool that spend outputs of the transaction.
+    async fn dependents(&self) -> Vec<TransactionId> {
+        self.0.dependents.iter().copied().map(Into::into).collect()
+    }
+}
+
+#[derive(Default)]
+pub struct TxPoolQuery;
+
+#[Object]
+impl TxPoolQuery {
+    /// Returns the content of the transaction pool.
+    async fn tx_pool(&self) -> TxPoolInfo {
+        TxPoolInfo
+    }
+
+    /// Returns transactions in the pool that are directly connected to the transaction.
+    /// Returns `null` if the transaction is not in the pool.
+    async fn tx_pool_dependencies(
+        &self,
+        ctx: &Context<'_>,
+        id: TransactionId,
+    ) -> async_graphql::Result<Option<TxPoolDependencies>> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        Ok(txpool.dependencies(id.0)?.map(TxPoolDependencies))
+    }
+}
@@ -5,12 +5,10 @@ use crate::{
         ShutdownListener,
     },
     database::Database,
-    service::{
-        adapters::{
-            ExecutorAdapter,
-            PoAAdapter,
-        },
-        sub_services::TxPoolSharedState,
+    service::adapters::{
+        ExecutorAdapter,
+        PoAAdapter,
+        TxPoolSharedState,
     },
 };
 use fuel_core_chain_config::{
@@ -54,8 +52,10 @@ pub use fuel_core_services::Service as ServiceTrait;
 
 pub mod adapters;
 pub mod config;
+pub mod da_import;
 pub mod genesis;
 pub mod metrics;
+pub mod pruning;
 mod query;
 pub mod sub_services;
 pub mod vm_pool;
@@ -224,6 +224,9 @@ impl FuelService {
             ConsensusConfig::PoA { .. } => {
                 // We don't support overriding of the heights for PoA version 1.
             }
+            ConsensusConfig::RoundRobinPoA(_) => {
+                // The round-robin schedule doesn't have overrides of the heights.
+            }
             ConsensusConfig::PoAV2(poa) => {
                 let on_chain_view = combined_database.on_chain().latest_view()?;
 
@@ -331,6 +334,13 @@ impl FuelService {
 
                 self.shared.block_importer.commit_result(result).await?;
             }
+        } else if self.shared.database.off_chain().is_marked_for_reindex()? {
+            genesis::import_off_chain_genesis_state(
+                watcher.clone(),
+                &self.shared.config,
+                &self.shared.database,
+            )
+            .await?;
         }
 
         self.override_chain_config_if_needed()
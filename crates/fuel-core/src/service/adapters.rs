@@ -10,6 +10,7 @@ use fuel_core_poa::{
 };
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::transactional::Changes;
+use fuel_core_txpool::service::TxStatusChange;
 #[cfg(feature = "p2p")]
 use fuel_core_types::services::p2p::peer_reputation::AppScore;
 use fuel_core_types::{
@@ -40,7 +41,8 @@ use crate::{
     service::{
         sub_services::{
             BlockProducerService,
-            TxPoolSharedState,
+            TxPoolV1SharedState,
+            TxPoolV2SharedState,
         },
         vm_pool::MemoryPool,
     },
@@ -90,6 +92,32 @@ pub struct PoAAdapter {
     shared_state: Option<fuel_core_poa::service::SharedState>,
 }
 
+/// The shared state of the transaction pool selected by the node's configuration.
+#[derive(Clone)]
+pub enum TxPoolSharedState {
+    /// The legacy transaction pool.
+    V1(TxPoolV1SharedState),
+    /// The transaction pool v2.
+    V2 {
+        service: TxPoolV2SharedState,
+        tx_status_sender: TxStatusSender,
+    },
+}
+
+/// Propagates transaction status updates of the transaction pool v2 to the subscribers.
+/// It reuses the channels of the legacy transaction pool, so the GraphQL and PoA services
+/// work the same way for both implementations.
+#[derive(Clone)]
+pub struct TxStatusSender {
+    sender: TxStatusChange,
+}
+
+impl TxStatusSender {
+    pub fn new(sender: TxStatusChange) -> Self {
+        Self { sender }
+    }
+}
+
 #[derive(Clone)]
 pub struct TxPoolAdapter {
     service: TxPoolSharedState,
@@ -250,6 +278,8 @@ pub struct PeerReportConfig {
     pub bad_block_header: AppScore,
     pub missing_transactions: AppScore,
     pub invalid_transactions: AppScore,
+    pub fast_blocks_download: AppScore,
+    pub slow_blocks_download: AppScore,
 }
 
 #[cfg(not(feature = "p2p"))]
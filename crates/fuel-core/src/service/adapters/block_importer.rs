@@ -36,6 +36,10 @@ use fuel_core_txpool::ports::{
     WasmChecker,
     WasmValidityError,
 };
+use fuel_core_txpool_v2::ports::{
+    WasmChecker as WasmCheckerV2,
+    WasmValidityError as WasmValidityErrorV2,
+};
 use fuel_core_types::{
     blockchain::{
         block::Block,
@@ -138,3 +142,30 @@ impl WasmChecker for ExecutorAdapter {
         Err(WasmValidityError::NotEnabled)
     }
 }
+
+#[cfg(feature = "wasm-executor")]
+impl WasmCheckerV2 for ExecutorAdapter {
+    fn validate_uploaded_wasm(
+        &self,
+        wasm_root: &Bytes32,
+    ) -> Result<(), WasmValidityErrorV2> {
+        self.executor
+            .validate_uploaded_wasm(wasm_root)
+            .map_err(|err| match err {
+                fuel_core_upgradable_executor::error::UpgradableError::InvalidWasm(_) => {
+                    WasmValidityErrorV2::Validity
+                }
+                _ => WasmValidityErrorV2::NotFound,
+            })
+    }
+}
+
+#[cfg(not(feature = "wasm-executor"))]
+impl WasmCheckerV2 for ExecutorAdapter {
+    fn validate_uploaded_wasm(
+        &self,
+        _wasm_root: &Bytes32,
+    ) -> Result<(), WasmValidityErrorV2> {
+        Err(WasmValidityErrorV2::NotEnabled)
+    }
+}
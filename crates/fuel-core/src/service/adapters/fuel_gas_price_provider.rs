@@ -1,7 +1,11 @@
 use crate::fuel_core_graphql_api::ports::GasPriceEstimate as GraphqlGasPriceEstimate;
-use fuel_core_gas_price_service::common::gas_price_algorithm::{
-    GasPriceAlgorithm,
-    SharedGasPriceAlgo,
+use fuel_core_gas_price_service::{
+    common::gas_price_algorithm::{
+        GasPriceAlgorithm,
+        SharedGasPriceAlgo,
+    },
+    v0::uninitialized_task::AlgorithmV0,
+    v1::uninitialized_task::AlgorithmV1,
 };
 
 use fuel_core_producer::block_producer::gas_price::GasPriceProvider as ProducerGasPriceProvider;
@@ -9,6 +13,10 @@ use fuel_core_txpool::{
     ports::GasPriceProvider as TxPoolGasPriceProvider,
     Result as TxPoolResult,
 };
+use fuel_core_txpool_v2::{
+    ports::GasPriceProvider as TxPoolV2GasPriceProvider,
+    Error as TxPoolV2Error,
+};
 use fuel_core_types::fuel_types::BlockHeight;
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -79,6 +87,16 @@ where
     }
 }
 
+#[async_trait::async_trait]
+impl<A> TxPoolV2GasPriceProvider for FuelGasPriceProvider<A>
+where
+    A: GasPriceAlgorithm + Send + Sync,
+{
+    async fn next_gas_price(&self) -> Result<u64, TxPoolV2Error> {
+        Ok(self.next_gas_price().await)
+    }
+}
+
 #[async_trait::async_trait]
 impl<A> GraphqlGasPriceEstimate for FuelGasPriceProvider<A>
 where
@@ -88,3 +106,58 @@ where
         Some(self.algorithm.worst_case_gas_price(height).await)
     }
 }
+
+/// The gas price provider of the algorithm version selected on the start of the node
+#[derive(Debug, Clone)]
+pub enum VersionedGasPriceProvider {
+    V0(FuelGasPriceProvider<AlgorithmV0>),
+    V1(FuelGasPriceProvider<AlgorithmV1>),
+}
+
+impl VersionedGasPriceProvider {
+    async fn next_gas_price(&self) -> u64 {
+        match self {
+            VersionedGasPriceProvider::V0(provider) => provider.next_gas_price().await,
+            VersionedGasPriceProvider::V1(provider) => provider.next_gas_price().await,
+        }
+    }
+
+    async fn worst_case_gas_price(&self, height: BlockHeight) -> u64 {
+        match self {
+            VersionedGasPriceProvider::V0(provider) => {
+                provider.algorithm.worst_case_gas_price(height).await
+            }
+            VersionedGasPriceProvider::V1(provider) => {
+                provider.algorithm.worst_case_gas_price(height).await
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ProducerGasPriceProvider for VersionedGasPriceProvider {
+    async fn next_gas_price(&self) -> anyhow::Result<u64> {
+        Ok(self.next_gas_price().await)
+    }
+}
+
+#[async_trait::async_trait]
+impl TxPoolGasPriceProvider for VersionedGasPriceProvider {
+    async fn next_gas_price(&self) -> TxPoolResult<u64> {
+        Ok(self.next_gas_price().await)
+    }
+}
+
+#[async_trait::async_trait]
+impl TxPoolV2GasPriceProvider for VersionedGasPriceProvider {
+    async fn next_gas_price(&self) -> Result<u64, TxPoolV2Error> {
+        Ok(self.next_gas_price().await)
+    }
+}
+
+#[async_trait::async_trait]
+impl GraphqlGasPriceEstimate for VersionedGasPriceProvider {
+    async fn worst_case_gas_price(&self, height: BlockHeight) -> Option<u64> {
+        Some(self.worst_case_gas_price(height).await)
+    }
+}
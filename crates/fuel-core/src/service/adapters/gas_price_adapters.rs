@@ -18,6 +18,18 @@ use fuel_core_gas_price_service::{
         GasPriceServiceConfig,
         L2Data,
     },
+    v1::{
+        da_source_service::{
+            block_committer_costs::{
+                BlockCommitterDaBlockCosts,
+                BlockCommitterHttpApi,
+            },
+            dummy_costs::DummyDaBlockCosts,
+            service::DaBlockCostsSource,
+            DaBlockCosts,
+        },
+        metadata::V1AlgorithmConfig,
+    },
 };
 use fuel_core_storage::{
     transactional::HistoricalView,
@@ -37,7 +49,13 @@ use crate::{
         database_description::gas_price::GasPriceDatabase,
         Database,
     },
-    service::Config,
+    service::{
+        config::{
+            DaSourceConfig,
+            GasPriceV1Config,
+        },
+        Config,
+    },
 };
 
 #[cfg(test)]
@@ -73,6 +91,51 @@ impl From<Config> for GasPriceServiceConfig {
     }
 }
 
+/// Combines the common gas price parameters of the node with
+/// the parameters specific to the V1 algorithm.
+pub fn v1_algorithm_config(
+    config: &Config,
+    v1_config: &GasPriceV1Config,
+) -> V1AlgorithmConfig {
+    V1AlgorithmConfig {
+        new_exec_gas_price: config.starting_gas_price,
+        min_exec_gas_price: config.min_gas_price,
+        exec_gas_price_change_percent: config
+            .gas_price_change_percent
+            .try_into()
+            .unwrap_or(u16::MAX),
+        l2_block_fullness_threshold_percent: config
+            .gas_price_threshold_percent
+            .try_into()
+            .unwrap_or(u8::MAX),
+        gas_price_factor: v1_config.gas_price_factor,
+        min_da_gas_price: v1_config.min_da_gas_price,
+        max_da_gas_price_change_percent: v1_config.max_da_gas_price_change_percent,
+        da_p_component: v1_config.da_p_component,
+        da_d_component: v1_config.da_d_component,
+    }
+}
+
+/// Creates the source of the DA commitment costs selected by the `config`.
+pub fn da_block_costs_source(config: &DaSourceConfig) -> Box<dyn DaBlockCostsSource> {
+    match config {
+        DaSourceConfig::BlockCommitter { url } => {
+            Box::new(BlockCommitterDaBlockCosts::new(
+                BlockCommitterHttpApi::new(url.clone()),
+                None,
+            ))
+        }
+        DaSourceConfig::Dummy => {
+            // The empty range of blocks is ignored by the algorithm.
+            Box::new(DummyDaBlockCosts::new(Ok(DaBlockCosts {
+                l2_block_range: 0..0,
+                blob_size_bytes: 0,
+                blob_cost_wei: 0,
+            })))
+        }
+    }
+}
+
 impl GasPriceSettingsProvider for ConsensusParametersProvider {
     fn settings(
         &self,
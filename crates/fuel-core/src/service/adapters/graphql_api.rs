@@ -2,18 +2,28 @@ use super::{
     BlockImporterAdapter,
     BlockProducerAdapter,
     ConsensusParametersProvider,
+    MaybeRelayerAdapter,
     StaticGasPrice,
 };
 use crate::{
+    combined_database::CombinedDatabase,
     database::OnChainIterableKeyValueView,
-    fuel_core_graphql_api::ports::{
-        worker,
-        BlockProducerPort,
-        ConsensusProvider,
-        DatabaseMessageProof,
-        GasPriceEstimate,
-        P2pPort,
-        TxPoolPort,
+    fuel_core_graphql_api::{
+        health::{
+            NodeHealth,
+            ServiceHealth,
+        },
+        ports::{
+            worker,
+            BlockProducerPort,
+            ConsensusProvider,
+            DatabaseCheckpointPort,
+            DatabaseMessageProof,
+            GasPriceEstimate,
+            NodeHealthPort,
+            P2pPort,
+            TxPoolPort,
+        },
     },
     service::adapters::{
         import_result_provider::ImportResultProvider,
@@ -22,12 +32,26 @@ use crate::{
     },
 };
 use async_trait::async_trait;
-use fuel_core_services::stream::BoxStream;
-use fuel_core_storage::Result as StorageResult;
+use fuel_core_services::{
+    stream::BoxStream,
+    StateWatcher,
+};
+use fuel_core_storage::{
+    transactional::HistoricalView,
+    Result as StorageResult,
+};
 use fuel_core_txpool::{
     service::TxStatusMessage,
     types::TxId,
 };
+use fuel_core_txpool_v2::{
+    config::{
+        BlackList,
+        BlackListEntry,
+    },
+    TxDependencies,
+    TxInfo,
+};
 use fuel_core_types::{
     blockchain::header::ConsensusParametersVersion,
     entities::relayer::message::MerkleProof,
@@ -50,6 +74,7 @@ use fuel_core_types::{
 };
 use std::{
     ops::Deref,
+    path::PathBuf,
     sync::Arc,
 };
 
@@ -59,27 +84,18 @@ mod on_chain;
 #[async_trait]
 impl TxPoolPort for TxPoolAdapter {
     fn transaction(&self, id: TxId) -> Option<Transaction> {
-        self.service
-            .find_one(id)
-            .map(|info| info.tx().clone().deref().into())
+        self.service.find_one(id).map(|tx| tx.deref().into())
     }
 
     fn submission_time(&self, id: TxId) -> Option<Tai64> {
-        self.service
-            .find_one(id)
-            .map(|info| Tai64::from_unix(info.submitted_time().as_secs() as i64))
+        self.service.submission_time(id)
     }
 
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
     ) -> Vec<anyhow::Result<InsertionResult>> {
-        self.service
-            .insert(txs)
-            .await
-            .into_iter()
-            .map(|res| res.map_err(|e| anyhow::anyhow!(e)))
-            .collect()
+        self.service.insert(txs).await
     }
 
     fn tx_update_subscribe(
@@ -88,6 +104,65 @@ impl TxPoolPort for TxPoolAdapter {
     ) -> anyhow::Result<BoxStream<TxStatusMessage>> {
         self.service.tx_update_subscribe(id)
     }
+
+    fn tx_ids(&self, max_txs: usize) -> Vec<TxId> {
+        self.service.get_tx_ids(max_txs)
+    }
+
+    fn tx_count(&self) -> usize {
+        self.service.pending_number()
+    }
+
+    fn total_gas(&self) -> u64 {
+        self.service.total_consumable_gas()
+    }
+
+    fn total_bytes_size(&self) -> anyhow::Result<usize> {
+        self.service.total_bytes_size()
+    }
+
+    fn tip_to_outbid(
+        &self,
+        available_gas: u64,
+        max_gas: u64,
+        metered_bytes_size: Option<u64>,
+    ) -> Option<u64> {
+        self.service
+            .tip_to_outbid(available_gas, max_gas, metered_bytes_size)
+    }
+
+    fn transactions(&self) -> anyhow::Result<Vec<TxInfo>> {
+        self.service.get_all_transactions()
+    }
+
+    fn dependencies(&self, tx_id: TxId) -> anyhow::Result<Option<TxDependencies>> {
+        self.service.get_dependencies(tx_id)
+    }
+
+    fn remove_txs(&self, tx_ids: Vec<TxId>, reason: String) -> Vec<TxId> {
+        let tx_ids = tx_ids.into_iter().map(|id| (id, reason.clone())).collect();
+        self.service
+            .remove_txs(tx_ids)
+            .into_iter()
+            .map(|tx| tx.id())
+            .collect()
+    }
+
+    fn black_list(&self) -> anyhow::Result<BlackList> {
+        self.service.black_list()
+    }
+
+    fn add_to_black_list(
+        &self,
+        entries: Vec<BlackListEntry>,
+    ) -> anyhow::Result<Vec<TxId>> {
+        let removed = self.service.add_to_black_list(entries)?;
+        Ok(removed.into_iter().map(|tx| tx.id()).collect())
+    }
+
+    fn remove_from_black_list(&self, entries: Vec<BlackListEntry>) -> anyhow::Result<()> {
+        self.service.remove_from_black_list(entries)
+    }
 }
 
 impl DatabaseMessageProof for OnChainIterableKeyValueView {
@@ -215,3 +290,107 @@ impl worker::BlockImporter for GraphQLBlockImporter {
         self.import_result_provider_adapter.result_at_height(height)
     }
 }
+
+pub struct NodeHealthAdapter {
+    services: Vec<(&'static str, StateWatcher)>,
+    database: CombinedDatabase,
+    p2p_adapter: P2PAdapter,
+    relayer_adapter: MaybeRelayerAdapter,
+}
+
+impl NodeHealthAdapter {
+    pub fn new(
+        services: Vec<(&'static str, StateWatcher)>,
+        database: CombinedDatabase,
+        p2p_adapter: P2PAdapter,
+        relayer_adapter: MaybeRelayerAdapter,
+    ) -> Self {
+        Self {
+            services,
+            database,
+            p2p_adapter,
+            relayer_adapter,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl NodeHealthPort for NodeHealthAdapter {
+    async fn health(&self) -> anyhow::Result<NodeHealth> {
+        let services = self
+            .services
+            .iter()
+            .map(|(name, state)| ServiceHealth {
+                name,
+                state: state.borrow().clone(),
+            })
+            .collect();
+        let best_peer_height = self
+            .p2p_adapter
+            .all_peer_info()
+            .await?
+            .into_iter()
+            .filter_map(|peer| peer.heartbeat_data.block_height)
+            .max();
+
+        #[cfg(feature = "relayer")]
+        let (da_height, remote_da_height) = match &self.relayer_adapter.relayer_synced {
+            Some(relayer) => (
+                Some(relayer.get_finalized_da_height()),
+                relayer.get_remote_finalized_da_height(),
+            ),
+            None => (None, None),
+        };
+        #[cfg(not(feature = "relayer"))]
+        let (da_height, remote_da_height) = {
+            let _ = &self.relayer_adapter;
+            (None, None)
+        };
+
+        Ok(NodeHealth {
+            services,
+            on_chain_height: HistoricalView::latest_height(self.database.on_chain()),
+            off_chain_height: HistoricalView::latest_height(self.database.off_chain()),
+            best_peer_height,
+            da_height,
+            remote_da_height,
+        })
+    }
+}
+
+pub struct DatabaseCheckpointAdapter {
+    database: CombinedDatabase,
+    block_importer: BlockImporterAdapter,
+}
+
+impl DatabaseCheckpointAdapter {
+    pub fn new(database: CombinedDatabase, block_importer: BlockImporterAdapter) -> Self {
+        Self {
+            database,
+            block_importer,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DatabaseCheckpointPort for DatabaseCheckpointAdapter {
+    #[cfg(feature = "rocksdb")]
+    async fn create_checkpoint(
+        &self,
+        path: PathBuf,
+    ) -> anyhow::Result<Option<BlockHeight>> {
+        // The block importer doesn't commit new blocks while the checkpoint is created,
+        // so the on-chain database can't move ahead of other databases.
+        let _pause = self.block_importer.block_importer.pause_commits().await;
+        let database = self.database.clone();
+        tokio::task::spawn_blocking(move || database.checkpoint(&path)).await?
+    }
+
+    #[cfg(not(feature = "rocksdb"))]
+    async fn create_checkpoint(&self, _: PathBuf) -> anyhow::Result<Option<BlockHeight>> {
+        let _ = (&self.database, &self.block_importer);
+        Err(anyhow::anyhow!(
+            "Checkpoints are only supported by the RocksDB database"
+        ))
+    }
+}
@@ -12,7 +12,19 @@ use crate::{
         storage::{
             contracts::ContractsInfo,
             da_compression::DaCompressedBlocks,
+            pruning::{
+                PrunedBlocks,
+                PRUNED_BLOCKS,
+            },
+            receipts::{
+                ContractReceipt,
+                ContractReceiptCursor,
+                ContractReceiptKey,
+                ContractReceipts,
+            },
             relayed_transactions::RelayedTransactionStatuses,
+            statistic::StatisticTable,
+            tips::BlockTips,
             transactions::OwnedTransactionIndexCursor,
         },
     },
@@ -130,6 +142,22 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
             .into_boxed()
     }
 
+    fn contract_receipts(
+        &self,
+        contract_id: &ContractId,
+        start: Option<ContractReceiptCursor>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(ContractReceiptCursor, ContractReceipt)>> {
+        let start = start.map(|cursor| ContractReceiptKey::new(*contract_id, cursor));
+        self.iter_all_filtered::<ContractReceipts, _>(
+            Some(*contract_id),
+            start.as_ref(),
+            Some(direction),
+        )
+        .map(|result| result.map(|(key, receipt)| (key.cursor, receipt)))
+        .into_boxed()
+    }
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
         let salt = *self
             .storage_as_ref::<ContractsInfo>()
@@ -189,10 +217,31 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
     fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool> {
         self.message_is_spent(nonce)
     }
+
+    fn block_tips(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<Vec<u64>>> {
+        self.iter_all_by_start::<BlockTips>(height.as_ref(), Some(direction))
+            .map(|result| result.map(|(_, tips)| tips))
+            .into_boxed()
+    }
+
+    fn pruned_blocks(&self) -> StorageResult<Option<PrunedBlocks>> {
+        let pruned_blocks = self
+            .storage_as_ref::<StatisticTable<PrunedBlocks>>()
+            .get(PRUNED_BLOCKS)?
+            .map(|pruned_blocks| pruned_blocks.into_owned());
+        Ok(pruned_blocks)
+    }
 }
 
 impl worker::OffChainDatabase for Database<OffChain> {
-    type Transaction<'a> = StorageTransaction<&'a mut Self> where Self: 'a;
+    type Transaction<'a>
+        = StorageTransaction<&'a mut Self>
+    where
+        Self: 'a;
 
     fn latest_height(&self) -> StorageResult<Option<BlockHeight>> {
         Ok(fuel_core_storage::transactional::HistoricalView::latest_height(self))
@@ -10,6 +10,7 @@ use crate::{
         DatabaseContracts,
         DatabaseMessages,
         OnChainDatabase,
+        OnChainDatabaseAt,
     },
     graphql_api::ports::worker,
 };
@@ -138,6 +139,8 @@ impl DatabaseChain for OnChainIterableKeyValueView {
 
 impl OnChainDatabase for OnChainIterableKeyValueView {}
 
+impl OnChainDatabaseAt for OnChainIterableKeyValueView {}
+
 impl worker::OnChainDatabase for Database<OnChain> {
     fn latest_height(&self) -> StorageResult<Option<BlockHeight>> {
         Ok(fuel_core_storage::transactional::HistoricalView::latest_height(self))
@@ -14,15 +14,22 @@ use fuel_core_txpool::types::TxId;
 use fuel_core_types::{
     blockchain::{
         consensus::Genesis,
+        SealedBlock,
         SealedBlockHeader,
     },
     fuel_types::BlockHeight,
-    services::p2p::{
-        NetworkableTransactionPool,
-        Transactions,
+    services::{
+        block_importer::Source,
+        p2p::{
+            NetworkableTransactionPool,
+            Transactions,
+        },
     },
 };
-use std::ops::Range;
+use std::{
+    ops::Range,
+    sync::Arc,
+};
 
 impl P2pDb for OnChainIterableKeyValueView {
     fn get_sealed_headers(
@@ -56,6 +63,19 @@ impl BlockHeightImporter for BlockImporterAdapter {
                 .map(|result| *result.sealed_block.entity.header().height()),
         )
     }
+
+    fn next_produced_block(&self) -> BoxStream<Arc<SealedBlock>> {
+        use tokio_stream::{
+            wrappers::BroadcastStream,
+            StreamExt,
+        };
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|result| result.ok())
+                .filter(|result| result.source == Source::Local)
+                .map(|result| Arc::new(result.sealed_block.clone())),
+        )
+    }
 }
 
 impl TxPool for TxPoolAdapter {
@@ -67,11 +87,7 @@ impl TxPool for TxPoolAdapter {
         self.service
             .find(tx_ids)
             .into_iter()
-            .map(|tx_info| {
-                tx_info.map(|tx| {
-                    NetworkableTransactionPool::PoolTransaction(tx.tx().clone())
-                })
-            })
+            .map(|tx| tx.map(NetworkableTransactionPool::PoolTransaction))
             .collect()
     }
 }
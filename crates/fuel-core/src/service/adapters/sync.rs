@@ -23,6 +23,9 @@ use fuel_core_types::{
             AppScore,
             PeerReport,
         },
+        BlockGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
         PeerId,
         SourcePeer,
         Transactions,
@@ -49,9 +52,12 @@ impl PeerToPeerPort for P2PAdapter {
     async fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
+        excluded_peers: Vec<PeerId>,
     ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlockHeader>>>> {
         let result = if let Some(service) = &self.service {
-            service.get_sealed_block_headers(block_height_range).await
+            service
+                .get_sealed_block_headers(block_height_range, excluded_peers)
+                .await
         } else {
             Err(anyhow::anyhow!("No P2P service available"))
         };
@@ -90,6 +96,30 @@ impl PeerToPeerPort for P2PAdapter {
             Err(anyhow::anyhow!("No P2P service available"))
         }
     }
+
+    fn gossiped_block_stream(&self) -> BoxStream<BlockGossipData> {
+        use futures::StreamExt;
+        if let Some(service) = &self.service {
+            fuel_core_services::stream::IntoBoxStream::into_boxed(
+                tokio_stream::wrappers::BroadcastStream::new(service.subscribe_block())
+                    .filter_map(|r| futures::future::ready(r.ok())),
+            )
+        } else {
+            fuel_core_services::stream::IntoBoxStream::into_boxed(tokio_stream::pending())
+        }
+    }
+
+    fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.notify_gossip_block_validity(message_info, validity)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl P2PAdapter {
@@ -108,6 +138,12 @@ impl P2PAdapter {
             PeerReportReason::InvalidTransactions => {
                 self.peer_report_config.invalid_transactions
             }
+            PeerReportReason::FastBlocksDownload => {
+                self.peer_report_config.fast_blocks_download
+            }
+            PeerReportReason::SlowBlocksDownload => {
+                self.peer_report_config.slow_blocks_download
+            }
         };
         P2PAdapterPeerReport { score }
     }
@@ -1,5 +1,14 @@
 use crate::{
-    database::OnChainIterableKeyValueView,
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+        OnChainIterableKeyValueView,
+    },
+    graphql_api::storage::txpool_journal::{
+        TxPoolBlackListChanges,
+        TxPoolJournal,
+        TxPoolJournalEntry,
+    },
     service::{
         adapters::{
             BlockImporterAdapter,
@@ -7,18 +16,23 @@ use crate::{
             P2PAdapter,
             SharedMemoryPool,
             StaticGasPrice,
+            TxPoolSharedState,
+            TxStatusSender,
         },
         vm_pool::MemoryFromPool,
     },
 };
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
+    iter::IteratorOverTable,
     tables::{
         Coins,
         ContractsRawCode,
         Messages,
     },
+    transactional::ReadTransaction,
     Result as StorageResult,
+    StorageAsMut,
     StorageAsRef,
 };
 use fuel_core_txpool::{
@@ -28,9 +42,28 @@ use fuel_core_txpool::{
         GasPriceProvider,
         MemoryPool,
     },
+    service::TxStatusMessage,
     types::TxId,
     Result as TxPoolResult,
 };
+use fuel_core_txpool_v2::{
+    config::{
+        BlackList,
+        BlackListEntry,
+    },
+    ports::{
+        BlockImporter as BlockImporterV2,
+        ConsensusParametersProvider as ConsensusParametersProviderV2,
+        GasPriceProvider as GasPriceProviderV2,
+        MemoryPool as MemoryPoolV2,
+        TxPoolJournal as TxPoolJournalTrait,
+        TxPoolPersistentStorage,
+        TxStatusNotifier,
+        P2P as P2PV2,
+    },
+    TxDependencies,
+    TxInfo,
+};
 use fuel_core_types::{
     blockchain::header::ConsensusParametersVersion,
     entities::{
@@ -44,6 +77,7 @@ use fuel_core_types::{
         UtxoId,
     },
     fuel_types::{
+        BlockHeight,
         ContractId,
         Nonce,
     },
@@ -56,9 +90,260 @@ use fuel_core_types::{
             PeerId,
             TransactionGossipData,
         },
+        txpool::{
+            ArcPoolTx,
+            InsertionResult,
+            TransactionStatus,
+        },
     },
+    tai64::Tai64,
 };
 use std::sync::Arc;
+use tokio::sync::broadcast;
+
+fn black_list_is_not_supported() -> anyhow::Error {
+    anyhow::anyhow!(
+        "The blacklist can be changed at runtime only with the transaction pool v2"
+    )
+}
+
+fn pool_inspection_is_not_supported() -> anyhow::Error {
+    anyhow::anyhow!("The pool can be inspected only with the transaction pool v2")
+}
+
+impl TxPoolSharedState {
+    pub async fn insert(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        match self {
+            TxPoolSharedState::V1(service) => service
+                .insert(txs)
+                .await
+                .into_iter()
+                .map(|res| res.map_err(|e| anyhow::anyhow!(e)))
+                .collect(),
+            TxPoolSharedState::V2 { service, .. } => service
+                .insert(txs)
+                .await
+                .into_iter()
+                .map(|res| res.map_err(|e| anyhow::anyhow!(e)))
+                .collect(),
+        }
+    }
+
+    pub fn find(&self, ids: Vec<TxId>) -> Vec<Option<ArcPoolTx>> {
+        match self {
+            TxPoolSharedState::V1(service) => service
+                .find(ids)
+                .into_iter()
+                .map(|info| info.map(|info| info.tx().clone()))
+                .collect(),
+            TxPoolSharedState::V2 { service, .. } => service
+                .find(ids)
+                .into_iter()
+                .map(|info| info.map(|info| info.tx().clone()))
+                .collect(),
+        }
+    }
+
+    pub fn find_one(&self, id: TxId) -> Option<ArcPoolTx> {
+        match self {
+            TxPoolSharedState::V1(service) => {
+                service.find_one(id).map(|info| info.tx().clone())
+            }
+            TxPoolSharedState::V2 { service, .. } => {
+                service.find_one(id).map(|info| info.tx().clone())
+            }
+        }
+    }
+
+    pub fn submission_time(&self, id: TxId) -> Option<Tai64> {
+        match self {
+            TxPoolSharedState::V1(service) => service
+                .find_one(id)
+                .map(|info| Tai64::from_unix(info.submitted_time().as_secs() as i64)),
+            TxPoolSharedState::V2 { service, .. } => {
+                service.find_one(id).map(|info| info.submitted_time())
+            }
+        }
+    }
+
+    pub fn pending_number(&self) -> usize {
+        match self {
+            TxPoolSharedState::V1(service) => service.pending_number(),
+            TxPoolSharedState::V2 { service, .. } => service.pending_number(),
+        }
+    }
+
+    pub fn total_consumable_gas(&self) -> u64 {
+        match self {
+            TxPoolSharedState::V1(service) => service.total_consumable_gas(),
+            TxPoolSharedState::V2 { service, .. } => service.total_consumable_gas(),
+        }
+    }
+
+    pub fn total_bytes_size(&self) -> anyhow::Result<usize> {
+        match self {
+            TxPoolSharedState::V1(_) => Err(pool_inspection_is_not_supported()),
+            TxPoolSharedState::V2 { service, .. } => Ok(service.total_bytes_size()),
+        }
+    }
+
+    pub fn tip_to_outbid(
+        &self,
+        available_gas: u64,
+        max_gas: u64,
+        metered_bytes_size: Option<u64>,
+    ) -> Option<u64> {
+        match self {
+            TxPoolSharedState::V1(service) => {
+                Some(service.tip_to_outbid(available_gas, max_gas))
+            }
+            TxPoolSharedState::V2 { service, .. } => {
+                service.tip_to_outbid(available_gas, max_gas, metered_bytes_size)
+            }
+        }
+    }
+
+    pub fn get_all_transactions(&self) -> anyhow::Result<Vec<TxInfo>> {
+        match self {
+            TxPoolSharedState::V1(_) => Err(pool_inspection_is_not_supported()),
+            TxPoolSharedState::V2 { service, .. } => Ok(service.get_all_transactions()),
+        }
+    }
+
+    pub fn get_dependencies(&self, id: TxId) -> anyhow::Result<Option<TxDependencies>> {
+        match self {
+            TxPoolSharedState::V1(_) => Err(pool_inspection_is_not_supported()),
+            TxPoolSharedState::V2 { service, .. } => Ok(service.get_dependencies(id)),
+        }
+    }
+
+    pub fn get_tx_ids(&self, max_txs: usize) -> Vec<TxId> {
+        match self {
+            TxPoolSharedState::V1(service) => service.get_tx_ids(max_txs),
+            TxPoolSharedState::V2 { service, .. } => service.get_tx_ids(max_txs),
+        }
+    }
+
+    pub fn select_transactions(
+        &self,
+        max_gas: u64,
+        transactions_limit: u16,
+        block_transaction_size_limit: u32,
+    ) -> Vec<ArcPoolTx> {
+        match self {
+            TxPoolSharedState::V1(service) => service.select_transactions(
+                max_gas,
+                transactions_limit,
+                block_transaction_size_limit,
+            ),
+            TxPoolSharedState::V2 { service, .. } => service
+                .extract_transactions_for_block(
+                    max_gas,
+                    transactions_limit,
+                    block_transaction_size_limit,
+                )
+                .unwrap_or_else(|err| {
+                    tracing::error!(
+                        "Failed to extract transactions from the pool: {err}"
+                    );
+                    vec![]
+                }),
+        }
+    }
+
+    pub fn remove_txs(&self, ids: Vec<(TxId, String)>) -> Vec<ArcPoolTx> {
+        match self {
+            TxPoolSharedState::V1(service) => service.remove_txs(ids),
+            TxPoolSharedState::V2 { service, .. } => service.remove_txs(ids),
+        }
+    }
+
+    pub fn black_list(&self) -> anyhow::Result<BlackList> {
+        match self {
+            TxPoolSharedState::V1(_) => Err(black_list_is_not_supported()),
+            TxPoolSharedState::V2 { service, .. } => Ok(service.black_list()),
+        }
+    }
+
+    pub fn add_to_black_list(
+        &self,
+        entries: Vec<BlackListEntry>,
+    ) -> anyhow::Result<Vec<ArcPoolTx>> {
+        match self {
+            TxPoolSharedState::V1(_) => Err(black_list_is_not_supported()),
+            TxPoolSharedState::V2 { service, .. } => {
+                Ok(service.add_to_black_list(entries))
+            }
+        }
+    }
+
+    pub fn remove_from_black_list(
+        &self,
+        entries: Vec<BlackListEntry>,
+    ) -> anyhow::Result<()> {
+        match self {
+            TxPoolSharedState::V1(_) => Err(black_list_is_not_supported()),
+            TxPoolSharedState::V2 { service, .. } => {
+                service.remove_from_black_list(entries);
+                Ok(())
+            }
+        }
+    }
+
+    pub fn new_tx_notification_subscribe(&self) -> broadcast::Receiver<TxId> {
+        match self {
+            TxPoolSharedState::V1(service) => service.new_tx_notification_subscribe(),
+            TxPoolSharedState::V2 {
+                tx_status_sender, ..
+            } => tx_status_sender.sender.new_tx_notification_subscribe(),
+        }
+    }
+
+    pub fn tx_update_subscribe(
+        &self,
+        tx_id: TxId,
+    ) -> anyhow::Result<BoxStream<TxStatusMessage>> {
+        match self {
+            TxPoolSharedState::V1(service) => service.tx_update_subscribe(tx_id),
+            TxPoolSharedState::V2 {
+                tx_status_sender, ..
+            } => tx_status_sender.sender.tx_update_subscribe(tx_id),
+        }
+    }
+
+    pub fn send_complete(
+        &self,
+        id: TxId,
+        block_height: &BlockHeight,
+        status: TransactionStatus,
+    ) {
+        match self {
+            TxPoolSharedState::V1(service) => {
+                service.send_complete(id, block_height, status)
+            }
+            TxPoolSharedState::V2 {
+                tx_status_sender, ..
+            } => tx_status_sender.sender.send_complete(
+                id,
+                block_height,
+                TxStatusMessage::Status(status),
+            ),
+        }
+    }
+}
+
+impl TxStatusNotifier for TxStatusSender {
+    fn send_submitted(&self, tx_id: TxId, time: Tai64) {
+        self.sender.send_submitted(tx_id, time)
+    }
+
+    fn send_squeezed_out(&self, tx_id: TxId, reason: fuel_core_txpool_v2::Error) {
+        self.sender.send_squeezed_out(tx_id, reason)
+    }
+}
 
 impl BlockImporter for BlockImporterAdapter {
     fn block_events(&self) -> BoxStream<SharedImportResult> {
@@ -66,6 +351,12 @@ impl BlockImporter for BlockImporterAdapter {
     }
 }
 
+impl BlockImporterV2 for BlockImporterAdapter {
+    fn block_events(&self) -> BoxStream<SharedImportResult> {
+        self.events_shared_result()
+    }
+}
+
 #[cfg(feature = "p2p")]
 #[async_trait::async_trait]
 impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
@@ -185,6 +476,47 @@ impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
     }
 }
 
+#[async_trait::async_trait]
+impl P2PV2 for P2PAdapter {
+    type GossipedTransaction = TransactionGossipData;
+
+    fn broadcast_transaction(&self, transaction: Arc<Transaction>) -> anyhow::Result<()> {
+        fuel_core_txpool::ports::PeerToPeer::broadcast_transaction(self, transaction)
+    }
+
+    fn subscribe_new_peers(&self) -> BoxStream<PeerId> {
+        fuel_core_txpool::ports::PeerToPeer::subscribe_new_peers(self)
+    }
+
+    fn gossiped_transaction_events(&self) -> BoxStream<Self::GossipedTransaction> {
+        fuel_core_txpool::ports::PeerToPeer::gossiped_transaction_events(self)
+    }
+
+    fn notify_gossip_transaction_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        fuel_core_txpool::ports::PeerToPeer::notify_gossip_transaction_validity(
+            self,
+            message_info,
+            validity,
+        )
+    }
+
+    async fn request_tx_ids(&self, peer_id: PeerId) -> anyhow::Result<Vec<TxId>> {
+        fuel_core_txpool::ports::PeerToPeer::request_tx_ids(self, peer_id).await
+    }
+
+    async fn request_txs(
+        &self,
+        peer_id: PeerId,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Vec<Option<Transaction>>> {
+        fuel_core_txpool::ports::PeerToPeer::request_txs(self, peer_id, tx_ids).await
+    }
+}
+
 impl fuel_core_txpool::ports::TxPoolDb for OnChainIterableKeyValueView {
     fn utxo(&self, utxo_id: &UtxoId) -> StorageResult<Option<CompressedCoin>> {
         self.storage::<Coins>()
@@ -207,6 +539,81 @@ impl fuel_core_txpool::ports::TxPoolDb for OnChainIterableKeyValueView {
     }
 }
 
+impl TxPoolPersistentStorage for OnChainIterableKeyValueView {
+    fn utxo(&self, utxo_id: &UtxoId) -> StorageResult<Option<CompressedCoin>> {
+        fuel_core_txpool::ports::TxPoolDb::utxo(self, utxo_id)
+    }
+
+    fn contract_exist(&self, contract_id: &ContractId) -> StorageResult<bool> {
+        fuel_core_txpool::ports::TxPoolDb::contract_exist(self, contract_id)
+    }
+
+    fn blob_exist(&self, blob_id: &BlobId) -> StorageResult<bool> {
+        fuel_core_txpool::ports::TxPoolDb::blob_exist(self, blob_id)
+    }
+
+    fn message(&self, id: &Nonce) -> StorageResult<Option<Message>> {
+        fuel_core_txpool::ports::TxPoolDb::message(self, id)
+    }
+}
+
+impl TxPoolJournalTrait for Database<OffChain> {
+    fn record_inserted(
+        &self,
+        tx_id: TxId,
+        transaction: &Transaction,
+        submitted_time: Tai64,
+    ) -> StorageResult<()> {
+        let mut storage = self.read_transaction();
+        storage.storage_as_mut::<TxPoolJournal>().insert(
+            &tx_id,
+            &TxPoolJournalEntry {
+                transaction: transaction.clone(),
+                submitted_time,
+            },
+        )?;
+        self.commit_changes_without_height(storage.into_changes())
+    }
+
+    fn record_removed(&self, tx_ids: &[TxId]) -> StorageResult<()> {
+        let mut storage = self.read_transaction();
+        for tx_id in tx_ids {
+            storage.storage_as_mut::<TxPoolJournal>().remove(tx_id)?;
+        }
+        self.commit_changes_without_height(storage.into_changes())
+    }
+
+    fn recorded_transactions(&self) -> StorageResult<Vec<(TxId, Transaction, Tai64)>> {
+        self.iter_all::<TxPoolJournal>(None)
+            .map(|result| {
+                result.map(|(tx_id, entry)| {
+                    (tx_id, entry.transaction, entry.submitted_time)
+                })
+            })
+            .collect()
+    }
+
+    fn record_black_list_change(
+        &self,
+        entries: &[BlackListEntry],
+        blacklisted: bool,
+    ) -> StorageResult<()> {
+        let mut storage = self.read_transaction();
+        for entry in entries {
+            storage
+                .storage_as_mut::<TxPoolBlackListChanges>()
+                .insert(&(*entry).into(), &blacklisted)?;
+        }
+        self.commit_changes_without_height(storage.into_changes())
+    }
+
+    fn recorded_black_list_changes(&self) -> StorageResult<Vec<(BlackListEntry, bool)>> {
+        self.iter_all::<TxPoolBlackListChanges>(None)
+            .map(|result| result.map(|(key, blacklisted)| (key.into(), blacklisted)))
+            .collect()
+    }
+}
+
 #[async_trait::async_trait]
 impl GasPriceProvider for StaticGasPrice {
     async fn next_gas_price(&self) -> TxPoolResult<u64> {
@@ -214,6 +621,13 @@ impl GasPriceProvider for StaticGasPrice {
     }
 }
 
+#[async_trait::async_trait]
+impl GasPriceProviderV2 for StaticGasPrice {
+    async fn next_gas_price(&self) -> Result<u64, fuel_core_txpool_v2::Error> {
+        Ok(self.gas_price)
+    }
+}
+
 impl ConsensusParametersProviderTrait for ConsensusParametersProvider {
     fn latest_consensus_parameters(
         &self,
@@ -222,6 +636,14 @@ impl ConsensusParametersProviderTrait for ConsensusParametersProvider {
     }
 }
 
+impl ConsensusParametersProviderV2 for ConsensusParametersProvider {
+    fn latest_consensus_parameters(
+        &self,
+    ) -> (ConsensusParametersVersion, Arc<ConsensusParameters>) {
+        self.shared_state.latest_consensus_parameters_with_version()
+    }
+}
+
 #[async_trait::async_trait]
 impl MemoryPool for SharedMemoryPool {
     type Memory = MemoryFromPool;
@@ -230,3 +652,12 @@ impl MemoryPool for SharedMemoryPool {
         self.memory_pool.take_raw().await
     }
 }
+
+#[async_trait::async_trait]
+impl MemoryPoolV2 for SharedMemoryPool {
+    type Memory = MemoryFromPool;
+
+    async fn get_memory(&self) -> Self::Memory {
+        self.memory_pool.take_raw().await
+    }
+}
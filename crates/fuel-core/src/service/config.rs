@@ -1,4 +1,5 @@
 use std::{
+    num::NonZeroU64,
     path::PathBuf,
     time::Duration,
 };
@@ -11,12 +12,15 @@ use strum_macros::{
     EnumVariantNames,
 };
 
-use fuel_core_chain_config::SnapshotReader;
 #[cfg(feature = "test-helpers")]
 use fuel_core_chain_config::{
     ChainConfig,
     StateConfig,
 };
+use fuel_core_chain_config::{
+    ConsensusConfig,
+    SnapshotReader,
+};
 pub use fuel_core_consensus_module::RelayerConsensusConfig;
 pub use fuel_core_importer;
 #[cfg(feature = "p2p")]
@@ -35,6 +39,10 @@ use crate::{
         worker_service::DaCompressionConfig,
         ServiceConfig as GraphQLConfig,
     },
+    service::{
+        da_import::DaImportConfig,
+        pruning::PruningConfig,
+    },
 };
 
 #[derive(Clone, Debug)]
@@ -55,12 +63,24 @@ pub struct Config {
     pub predefined_blocks_path: Option<PathBuf>,
     pub vm: VMConfig,
     pub txpool: fuel_core_txpool::Config,
+    /// When set, the node uses the transaction pool v2 with this configuration
+    /// instead of the legacy `txpool`.
+    pub txpool_v2: Option<fuel_core_txpool_v2::Config>,
     pub block_producer: fuel_core_producer::Config,
     pub starting_gas_price: u64,
     pub gas_price_change_percent: u64,
     pub min_gas_price: u64,
     pub gas_price_threshold_percent: u64,
+    /// When set, the node uses the V1 gas price algorithm with this configuration
+    /// instead of the V0 algorithm.
+    pub gas_price_v1: Option<GasPriceV1Config>,
     pub da_compression: DaCompressionConfig,
+    /// When set, the node imports blocks reconstructed from the DA compressed blocks
+    /// instead of producing them.
+    pub da_import: Option<DaImportConfig>,
+    /// When set, the node prunes transactions, receipts, and indexes
+    /// of blocks outside of the retention window.
+    pub pruning: Option<PruningConfig>,
     pub block_importer: fuel_core_importer::Config,
     #[cfg(feature = "relayer")]
     pub relayer: Option<RelayerConfig>,
@@ -144,6 +164,9 @@ impl Config {
                 request_body_bytes_limit: 16 * 1024 * 1024,
                 query_log_threshold_time: Duration::from_secs(2),
                 api_request_timeout: Duration::from_secs(60),
+                admin_token: None,
+                health_max_block_lag: 10,
+                health_max_da_lag: 10,
             },
             combined_db_config,
             continue_on_error: false,
@@ -159,14 +182,18 @@ impl Config {
                 transaction_ttl: Duration::from_secs(60 * 100000000),
                 ..fuel_core_txpool::Config::default()
             },
+            txpool_v2: None,
             block_producer: fuel_core_producer::Config {
                 ..Default::default()
             },
             da_compression: DaCompressionConfig::Disabled,
+            da_import: None,
+            pruning: None,
             starting_gas_price,
             gas_price_change_percent,
             min_gas_price,
             gas_price_threshold_percent,
+            gas_price_v1: None,
             block_importer,
             #[cfg(feature = "relayer")]
             relayer: None,
@@ -199,27 +226,102 @@ impl Config {
             self.txpool.utxo_validation = self.utxo_validation;
         }
 
+        if let Some(txpool_v2) = &mut self.txpool_v2 {
+            if txpool_v2.utxo_validation != self.utxo_validation {
+                tracing::warn!("The `utxo_validation` of `TxPool` v2 was inconsistent");
+                txpool_v2.utxo_validation = self.utxo_validation;
+            }
+        }
+
+        // The re-execution of blocks, the rollback, and the reindexing of the off-chain
+        // database require the transactions of all blocks inside the state rewind window.
+        #[cfg(feature = "rocksdb")]
+        if let Some(mut pruning) = self.pruning.take() {
+            use crate::state::historical_rocksdb::StateRewindPolicy;
+
+            let rewind_blocks = match self.combined_db_config.state_rewind_policy {
+                StateRewindPolicy::NoRewind => Some(0),
+                StateRewindPolicy::RewindFullRange => None,
+                StateRewindPolicy::RewindRange { size } => u32::try_from(size.get()).ok(),
+            };
+            match rewind_blocks {
+                Some(rewind_blocks) => {
+                    if pruning.retained_blocks.get() < rewind_blocks {
+                        tracing::warn!(
+                            "The pruning retained blocks {} are less than the state \
+                            rewind window of {} blocks, retaining {} blocks instead",
+                            pruning.retained_blocks,
+                            rewind_blocks,
+                            rewind_blocks
+                        );
+                        pruning.retained_blocks = std::num::NonZeroU32::new(
+                            rewind_blocks,
+                        )
+                        .expect("The rewind window is greater than a non-zero value");
+                    }
+                    self.pruning = Some(pruning);
+                }
+                None => {
+                    tracing::warn!(
+                        "The pruning is disabled, because the state rewind window \
+                        covers all blocks"
+                    );
+                }
+            }
+        }
+
         self
     }
 }
 
 impl From<&Config> for fuel_core_poa::Config {
     fn from(config: &Config) -> Self {
+        let chain_config = config.snapshot_reader.chain_config();
+        let producer_schedule = match &chain_config.consensus {
+            ConsensusConfig::RoundRobinPoA(schedule) => Some(schedule.clone()),
+            ConsensusConfig::PoA { .. } | ConsensusConfig::PoAV2(_) => None,
+        };
         fuel_core_poa::Config {
             trigger: config.block_production,
             signer: config.consensus_signer.clone(),
             metrics: false,
             min_connected_reserved_peers: config.min_connected_reserved_peers,
             time_until_synced: config.time_until_synced,
-            chain_id: config
-                .snapshot_reader
-                .chain_config()
-                .consensus_parameters
-                .chain_id(),
+            chain_id: chain_config.consensus_parameters.chain_id(),
+            producer_schedule,
         }
     }
 }
 
+/// The parameters of the V1 gas price algorithm on top of the V0 ones.
+#[derive(Clone, Debug)]
+pub struct GasPriceV1Config {
+    /// The scale factor for the gas price.
+    pub gas_price_factor: NonZeroU64,
+    /// The minimum gas price to cover the DA commitment of the block.
+    pub min_da_gas_price: u64,
+    /// The maximum percentage change of the DA gas price between blocks.
+    pub max_da_gas_price_change_percent: u16,
+    /// The proportional component of the DA gas price change.
+    pub da_p_component: i64,
+    /// The derivative component of the DA gas price change.
+    pub da_d_component: i64,
+    /// The source of the DA commitment costs.
+    pub da_source: DaSourceConfig,
+    /// How often to poll the DA source for new costs.
+    /// The default interval of the DA source service is used if `None`.
+    pub da_poll_interval: Option<Duration>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DaSourceConfig {
+    /// Fetches the DA commitment costs from the HTTP API of the block committer.
+    BlockCommitter { url: String },
+    /// Doesn't fetch anything, so the algorithm relies only on the
+    /// projected cost of the DA commitments.
+    Dummy,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct VMConfig {
     pub backtrace: bool,
@@ -0,0 +1,483 @@
+//! The service that rebuilds the chain from the DA compressed blocks.
+//! The blocks are read one by one from a local directory or from the GraphQL API
+//! of another node, decompressed with the temporal registry stored in the
+//! [`DaDecompression`] database, executed, and committed by the block importer.
+//! It allows a node to reconstruct the chain without trusting P2P peers.
+//!
+//! The DA compressed blocks don't contain the signatures of the block producer,
+//! so the imported blocks are sealed with the [`DaImportConsensus`] that refers
+//! to the compressed block. Other nodes can't verify such blocks, so the P2P sync
+//! is disabled while the blocks are imported from the DA.
+
+use crate::{
+    combined_database::CombinedDatabaseConfig,
+    database::{
+        database_description::{
+            da_decompression::DaDecompression,
+            on_chain::OnChain,
+        },
+        Database,
+    },
+    graphql_api::da_compression::da_decompress_block,
+    service::adapters::{
+        BlockImporterAdapter,
+        ExecutorAdapter,
+        MaybeRelayerAdapter,
+        VerifierAdapter,
+    },
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_compression::VersionedCompressedBlock;
+use fuel_core_importer::ports::BlockVerifier;
+use fuel_core_poa::ports::RelayerPort;
+use fuel_core_producer::ports::BlockProducer;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_storage::{
+    codec::{
+        postcard::Postcard,
+        Decode,
+        Encode,
+    },
+    transactional::{
+        AtomicView,
+        HistoricalView,
+        WriteTransaction,
+    },
+};
+use fuel_core_types::{
+    blockchain::{
+        block::PartialFuelBlock,
+        consensus::{
+            Consensus,
+            DaImportConsensus,
+        },
+        primitives::DaBlockHeight,
+        SealedBlock,
+    },
+    fuel_crypto::Hasher,
+    fuel_tx::{
+        field::{
+            InputContract,
+            MintAmount,
+            MintAssetId,
+            MintGasPrice,
+        },
+        Transaction,
+    },
+    fuel_types::BlockHeight,
+    services::{
+        block_importer::ImportResult,
+        block_producer::Components,
+        executor::ExecutionResult,
+        Uncommitted,
+    },
+};
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::Duration,
+};
+
+/// The source of the DA compressed blocks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DaImportSource {
+    /// The directory with postcard encoded compressed blocks named `{height}.bin`.
+    Directory(PathBuf),
+    /// The URL of the GraphQL API of the node that serves the `daCompressedBlock` query.
+    Node(String),
+}
+
+/// The configuration of the DA import service.
+#[derive(Clone, Debug)]
+pub struct DaImportConfig {
+    /// The source of the DA compressed blocks.
+    pub source: DaImportSource,
+    /// The configuration used by the node that compressed the blocks.
+    pub compression: fuel_core_compression::Config,
+    /// How often the source is polled for the next block after all
+    /// available blocks are imported.
+    pub interval: Duration,
+}
+
+/// Returns the path of the compressed block with the `block_height` in the directory.
+pub fn compressed_block_path(path_to_directory: &Path, block_height: u32) -> PathBuf {
+    path_to_directory.join(format!("{}.bin", block_height))
+}
+
+/// The source of the DA compressed blocks used by the service.
+#[async_trait::async_trait]
+pub trait CompressedBlockSource: Send + Sync {
+    /// Returns the compressed block at the `height`,
+    /// or `None` if the block is not available yet.
+    async fn compressed_block(
+        &self,
+        height: &BlockHeight,
+    ) -> anyhow::Result<Option<VersionedCompressedBlock>>;
+}
+
+pub struct InDirectoryCompressedBlocks {
+    path_to_directory: PathBuf,
+}
+
+impl InDirectoryCompressedBlocks {
+    pub fn new(path_to_directory: PathBuf) -> Self {
+        Self { path_to_directory }
+    }
+}
+
+#[async_trait::async_trait]
+impl CompressedBlockSource for InDirectoryCompressedBlocks {
+    async fn compressed_block(
+        &self,
+        height: &BlockHeight,
+    ) -> anyhow::Result<Option<VersionedCompressedBlock>> {
+        let path = compressed_block_path(&self.path_to_directory, (*height).into());
+        if !path.exists() {
+            return Ok(None)
+        }
+
+        let bytes = tokio::fs::read(&path).await?;
+        let block = Postcard::decode(&bytes)
+            .with_context(|| format!("Failed to decode the compressed block {path:?}"))?;
+        Ok(Some(block))
+    }
+}
+
+pub struct NodeCompressedBlocks {
+    client: FuelClient,
+}
+
+impl NodeCompressedBlocks {
+    pub fn new(url: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            client: FuelClient::new(url)?,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl CompressedBlockSource for NodeCompressedBlocks {
+    async fn compressed_block(
+        &self,
+        height: &BlockHeight,
+    ) -> anyhow::Result<Option<VersionedCompressedBlock>> {
+        let Some(bytes) = self.client.da_compressed_block(*height).await? else {
+            return Ok(None)
+        };
+
+        let block = Postcard::decode(&bytes).with_context(|| {
+            format!("Failed to decode the compressed block at the height {height}")
+        })?;
+        Ok(Some(block))
+    }
+}
+
+/// Opens the database with the temporal registry next to the combined database.
+pub fn open_database(
+    config: &CombinedDatabaseConfig,
+) -> anyhow::Result<Database<DaDecompression>> {
+    let database = match config.database_type {
+        #[cfg(feature = "rocksdb")]
+        crate::service::DbType::RocksDb
+            if !config.database_path.as_os_str().is_empty() =>
+        {
+            Database::open_rocksdb(
+                &config.database_path,
+                config.max_database_cache_size,
+                config.state_rewind_policy,
+            )?
+        }
+        _ => Database::in_memory(),
+    };
+
+    Ok(database)
+}
+
+pub struct InitializeTask {
+    config: DaImportConfig,
+    source: Box<dyn CompressedBlockSource>,
+    on_chain: Database<OnChain>,
+    registry: Database<DaDecompression>,
+    executor: ExecutorAdapter,
+    importer: BlockImporterAdapter,
+    verifier: VerifierAdapter,
+    relayer: MaybeRelayerAdapter,
+}
+
+pub struct Task {
+    config: DaImportConfig,
+    source: Box<dyn CompressedBlockSource>,
+    on_chain: Database<OnChain>,
+    registry: Database<DaDecompression>,
+    executor: ExecutorAdapter,
+    importer: BlockImporterAdapter,
+    verifier: VerifierAdapter,
+    relayer: MaybeRelayerAdapter,
+    genesis_height: BlockHeight,
+    interval: tokio::time::Interval,
+    /// `true` if all available blocks are imported.
+    caught_up: bool,
+}
+
+impl Task {
+    /// Waits for the next tick of the interval if all available blocks are imported.
+    /// Otherwise, only yields to not block the runtime between blocks.
+    async fn wait_for_next_block(&mut self) {
+        if self.caught_up {
+            self.interval.tick().await;
+        } else {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    /// Imports the block that follows the latest block of the on-chain database.
+    /// Returns `true` if the next block is not available yet.
+    async fn import_next_block(&mut self) -> anyhow::Result<bool> {
+        let height = HistoricalView::latest_height(&self.on_chain).ok_or(anyhow!(
+            "The on-chain database doesn't contain the genesis block"
+        ))?;
+        let next_height = height
+            .succ()
+            .ok_or(anyhow!("Got overflow during increasing the height"))?;
+
+        // The temporal registry is committed before the block, so it can be one block
+        // ahead of the on-chain database if the node stopped between the commits.
+        // The decompression of the same block is idempotent, so it is repeated without
+        // committing the registry again.
+        let registry_height = HistoricalView::latest_height(&self.registry);
+        let registry_is_ahead = match registry_height {
+            None if height == self.genesis_height => false,
+            Some(registry_height) if registry_height == height => false,
+            Some(registry_height) if registry_height == next_height => true,
+            _ => {
+                anyhow::bail!(
+                    "The temporal registry at the height {registry_height:?} doesn't match \
+                    the on-chain database at the height {height}. The import from the DA \
+                    should start right after the genesis block."
+                )
+            }
+        };
+
+        let Some(compressed) = self.source.compressed_block(&next_height).await? else {
+            return Ok(true)
+        };
+
+        let compressed_block_hash = Hasher::hash(Postcard::encode(&compressed));
+        let mut registry_transaction = self.registry.write_transaction();
+        let PartialFuelBlock {
+            header,
+            mut transactions,
+        } = da_decompress_block(
+            self.config.compression,
+            compressed,
+            self.on_chain.latest_view()?,
+            &mut registry_transaction,
+        )?;
+        anyhow::ensure!(
+            header.consensus.height == next_height,
+            "The source returned the block at the height {} instead of {next_height}",
+            header.consensus.height
+        );
+
+        // The events from the DA layer must be available to execute the block.
+        self.relayer
+            .await_until_if_in_range(
+                &header.application.da_height,
+                &DaBlockHeight(u64::MAX),
+            )
+            .await?;
+
+        let mint = transactions
+            .pop()
+            .and_then(|tx| tx.as_mint().cloned())
+            .ok_or(anyhow!(
+                "The last transaction in the block should be a mint transaction"
+            ))?;
+        let component = Components {
+            header_to_produce: header,
+            transactions_source: transactions,
+            coinbase_recipient: mint.input_contract().contract_id,
+            gas_price: *mint.gas_price(),
+        };
+
+        let (
+            ExecutionResult {
+                block,
+                skipped_transactions,
+                tx_status,
+                events,
+            },
+            changes,
+        ) = self
+            .executor
+            .produce_without_commit(component)
+            .map_err(Into::<anyhow::Error>::into)
+            .with_context(|| {
+                format!("Failed to execute the block {next_height} from the DA")
+            })?
+            .into();
+
+        anyhow::ensure!(
+            skipped_transactions.is_empty(),
+            "The block {next_height} from the DA contains invalid transactions: \
+            {skipped_transactions:?}"
+        );
+        // The executor fills the fields of the mint transaction that are not compressed,
+        // so only the compressed fields are compared.
+        let matches_mint = match block.transactions().last() {
+            Some(Transaction::Mint(produced_mint)) => {
+                produced_mint.input_contract().contract_id
+                    == mint.input_contract().contract_id
+                    && produced_mint.mint_amount() == mint.mint_amount()
+                    && produced_mint.mint_asset_id() == mint.mint_asset_id()
+            }
+            _ => false,
+        };
+        anyhow::ensure!(
+            matches_mint,
+            "The mint transaction of the block {next_height} doesn't match the DA"
+        );
+
+        let sealed_block = SealedBlock {
+            entity: block,
+            consensus: Consensus::DaImport(DaImportConsensus {
+                compressed_block_hash,
+            }),
+        };
+        self.verifier
+            .verify_block_fields(&sealed_block.consensus, &sealed_block.entity)?;
+
+        if !registry_is_ahead {
+            registry_transaction.commit()?;
+        }
+        self.importer
+            .block_importer
+            .commit_result(Uncommitted::new(
+                ImportResult::new_from_network(sealed_block, tx_status, events),
+                changes,
+            ))
+            .await?;
+        tracing::debug!("Imported the block {} from the DA", next_height);
+
+        Ok(false)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            _ = self.wait_for_next_block() => {
+                match self.import_next_block().await {
+                    Ok(caught_up) => {
+                        self.caught_up = caught_up;
+                    }
+                    Err(err) => {
+                        tracing::error!("Failed to import the block from the DA: {:?}", err);
+                        self.caught_up = true;
+                    }
+                }
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Each block is committed atomically, so there is nothing to finish.
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for InitializeTask {
+    const NAME: &'static str = "DaImport";
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let InitializeTask {
+            config,
+            source,
+            on_chain,
+            registry,
+            executor,
+            importer,
+            verifier,
+            relayer,
+        } = self;
+
+        let genesis_height = on_chain.latest_view()?.genesis_height()?.ok_or(anyhow!(
+            "The on-chain database doesn't contain the genesis block"
+        ))?;
+        let mut interval = tokio::time::interval(config.interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        Ok(Task {
+            config,
+            source,
+            on_chain,
+            registry,
+            executor,
+            importer,
+            verifier,
+            relayer,
+            genesis_height,
+            interval,
+            caught_up: false,
+        })
+    }
+}
+
+pub fn new_service(
+    config: DaImportConfig,
+    on_chain: Database<OnChain>,
+    registry: Database<DaDecompression>,
+    executor: ExecutorAdapter,
+    importer: BlockImporterAdapter,
+    verifier: VerifierAdapter,
+    relayer: MaybeRelayerAdapter,
+) -> anyhow::Result<ServiceRunner<InitializeTask>> {
+    let source: Box<dyn CompressedBlockSource> = match &config.source {
+        DaImportSource::Directory(path) => {
+            Box::new(InDirectoryCompressedBlocks::new(path.clone()))
+        }
+        DaImportSource::Node(url) => Box::new(NodeCompressedBlocks::new(url)?),
+    };
+
+    Ok(ServiceRunner::new(InitializeTask {
+        config,
+        source,
+        on_chain,
+        registry,
+        executor,
+        importer,
+        verifier,
+        relayer,
+    }))
+}
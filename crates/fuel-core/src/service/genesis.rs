@@ -19,6 +19,7 @@ use fuel_core_storage::{
         UploadedBytecodes,
     },
     transactional::{
+        AtomicView,
         Changes,
         IntoTransaction,
         ReadTransaction,
@@ -56,9 +57,15 @@ use itertools::Itertools;
 mod exporter;
 mod importer;
 mod progress;
+mod state_roots;
 mod task_manager;
 
-pub use exporter::Exporter;
+pub use exporter::{
+    Exporter,
+    StateFilter,
+    StateTable,
+};
+pub use state_roots::StateRoots;
 pub use task_manager::NotifyCancel;
 
 use self::importer::SnapshotImporter;
@@ -150,6 +157,59 @@ pub async fn execute_genesis_block(
     Ok(result)
 }
 
+/// Imports the off-chain part of the genesis state from the snapshot into the off-chain
+/// database marked for reindexing. The import is resumable and tracks its progress
+/// the same way as the genesis import. The blocks after the genesis are processed
+/// by the off-chain worker on start.
+pub async fn import_off_chain_genesis_state(
+    watcher: StateWatcher,
+    config: &Config,
+    db: &CombinedDatabase,
+) -> anyhow::Result<()> {
+    let on_chain_view = db.on_chain().latest_view()?;
+    let genesis_height = on_chain_view
+        .genesis_height()?
+        .ok_or(anyhow::anyhow!("The genesis block height is not found"))?;
+    let genesis_block = on_chain_view
+        .get_sealed_block_by_height(&genesis_height)?
+        .ok_or(anyhow::anyhow!("The genesis block is not found"))?
+        .entity;
+
+    let snapshot_genesis_block = create_genesis_block(config);
+    if snapshot_genesis_block.id() != genesis_block.id() {
+        return Err(anyhow::anyhow!(
+            "The genesis block from the snapshot doesn't match the genesis block \
+            of the on-chain database. The off-chain database can be reindexed only \
+            with the snapshot used for the genesis."
+        ));
+    }
+    tracing::info!("Reindexing the off-chain genesis state at {genesis_height}");
+
+    let db = db.clone().off_chain_into_genesis();
+    SnapshotImporter::import_off_chain(
+        db.clone(),
+        genesis_block,
+        config.snapshot_reader.clone(),
+        watcher,
+    )
+    .await?;
+
+    // The progress of the import also contains the reindexing mark.
+    let genesis_progress_off_chain: Vec<String> = db
+        .off_chain()
+        .iter_all_keys::<GenesisMetadata<OffChain>>(None)
+        .try_collect()?;
+    let mut database_transaction_off_chain = db.off_chain().clone().into_transaction();
+    for key in genesis_progress_off_chain {
+        database_transaction_off_chain
+            .storage_as_mut::<GenesisMetadata<OffChain>>()
+            .remove(&key)?;
+    }
+    database_transaction_off_chain.commit()?;
+
+    Ok(())
+}
+
 #[cfg(feature = "test-helpers")]
 pub async fn execute_and_commit_genesis_block(
     config: &Config,
@@ -618,6 +678,51 @@ mod tests {
         assert!(init_result.is_err())
     }
 
+    #[tokio::test]
+    async fn import_off_chain_genesis_state__restores_owned_coins_of_reindexed_database()
+    {
+        let mut rng = StdRng::seed_from_u64(10);
+        let owner: Address = rng.gen();
+        let state = StateConfig {
+            coins: vec![CoinConfig {
+                owner,
+                amount: rng.gen(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let service_config = Config::local_node_with_state_config(state);
+        let db = CombinedDatabase::default();
+        let service =
+            FuelService::from_combined_database(db.clone(), service_config.clone())
+                .await
+                .unwrap();
+        service.send_stop_signal_and_await_shutdown().await.unwrap();
+
+        // Given
+        let off_chain = Database::<OffChain>::default();
+        off_chain.mark_for_reindex().unwrap();
+        let reindexed_db = CombinedDatabase::new(
+            db.on_chain().clone(),
+            off_chain,
+            db.relayer().clone(),
+            db.gas_price().clone(),
+        );
+
+        // When
+        FuelService::from_combined_database(reindexed_db.clone(), service_config)
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(get_coins(&reindexed_db, &owner).len(), 1);
+        assert!(!reindexed_db.off_chain().is_marked_for_reindex().unwrap());
+        assert_eq!(
+            reindexed_db.off_chain().latest_height(),
+            reindexed_db.on_chain().latest_height()
+        );
+    }
+
     fn get_coins(db: &CombinedDatabase, owner: &Address) -> Vec<Coin> {
         db.off_chain()
             .latest_view()
@@ -3,9 +3,11 @@ use crate::{
     database::{
         database_description::DatabaseDescription,
         Database,
+        OnChainIterableKeyValueView,
     },
     fuel_core_graphql_api::storage::{
         messages::SpentMessages,
+        receipts::ContractReceipts,
         transactions::{
             OwnedTransactions,
             TransactionStatuses,
@@ -30,10 +32,14 @@ use fuel_core_chain_config::{
 use fuel_core_poa::ports::Database as DatabaseTrait;
 use fuel_core_storage::{
     iter::{
+        BoxedIter,
+        IntoBoxedIter,
         IterDirection,
         IterableTable,
+        IteratorOverTable,
     },
     kv_store::StorageColumn,
+    not_found,
     structured_storage::TableWithBlueprint,
     tables::{
         merkle::{
@@ -52,9 +58,16 @@ use fuel_core_storage::{
         Transactions,
     },
     transactional::AtomicView,
+    Mappable,
+    Result as StorageResult,
+    StorageAsRef,
 };
 use fuel_core_types::{
-    fuel_types::ContractId,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        ContractId,
+    },
     fuel_vm::BlobData,
 };
 use itertools::Itertools;
@@ -65,6 +78,51 @@ use super::{
     NotifyCancel,
 };
 
+/// The part of the chain state that can be exported on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StateTable {
+    Coins,
+    Messages,
+    /// The code, the latest UTXO and the storage slots of contracts.
+    Contracts,
+    Blobs,
+    /// The balances of contracts.
+    Balances,
+}
+
+/// Restricts which part of the chain state is exported by the
+/// [`Exporter::write_state_snapshot`].
+#[derive(Debug, Clone, Default)]
+pub struct StateFilter {
+    /// The tables to export. All tables are exported if empty.
+    pub tables: Vec<StateTable>,
+    /// If not empty, only coins and messages owned by these addresses are exported.
+    pub owners: Vec<Address>,
+    /// If not empty, only these contracts and their balances are exported.
+    pub contracts: Vec<ContractId>,
+}
+
+impl StateFilter {
+    fn includes(&self, table: StateTable) -> bool {
+        self.tables.is_empty() || self.tables.contains(&table)
+    }
+
+    fn includes_owner(&self, owner: &Address) -> bool {
+        self.owners.is_empty() || self.owners.contains(owner)
+    }
+
+    fn contract_prefixes(&self) -> Vec<Option<Vec<u8>>> {
+        if self.contracts.is_empty() {
+            vec![None]
+        } else {
+            self.contracts
+                .iter()
+                .map(|contract_id| Some(contract_id.to_vec()))
+                .collect()
+        }
+    }
+}
+
 pub struct Exporter<Fun> {
     db: CombinedDatabase,
     prev_chain_config: ChainConfig,
@@ -125,6 +183,7 @@ where
             |ctx: &Self| ctx.db.off_chain(),
             TransactionStatuses,
             OwnedTransactions,
+            ContractReceipts,
             OldFuelBlocks,
             OldFuelBlockConsensus,
             OldTransactions,
@@ -157,14 +216,145 @@ where
         Ok(())
     }
 
+    /// Writes the chain state at the `height`(or at the latest height if `None`),
+    /// restricted by the `filter`. Exporting a height other than the latest one
+    /// requires the database to keep the history of changes.
+    pub async fn write_state_snapshot(
+        mut self,
+        height: Option<BlockHeight>,
+        filter: StateFilter,
+    ) -> Result<(), anyhow::Error> {
+        let view = match height {
+            Some(height) => self.db.on_chain().iterable_view_at(&height)?,
+            None => self.db.on_chain().latest_view()?,
+        };
+        let height = match height {
+            Some(height) => height,
+            None => view.latest_height()?,
+        };
+
+        if filter.includes(StateTable::Coins) {
+            let filter = filter.clone();
+            self.spawn_entries_task::<Coins, _>(view.clone(), move |view| {
+                table_entries::<Coins, _>(view, None)
+                    .filter_ok(move |entry| filter.includes_owner(entry.value.owner()))
+                    .into_boxed()
+            })?;
+        }
+
+        if filter.includes(StateTable::Messages) {
+            let filter = filter.clone();
+            self.spawn_entries_task::<Messages, _>(view.clone(), move |view| {
+                table_entries::<Messages, _>(view, None)
+                    .filter_ok(move |entry| {
+                        filter.includes_owner(entry.value.recipient())
+                    })
+                    .into_boxed()
+            })?;
+        }
+
+        if filter.includes(StateTable::Blobs) {
+            self.spawn_entries_task::<BlobData, _>(view.clone(), |view| {
+                table_entries::<BlobData, _>(view, None)
+            })?;
+        }
+
+        macro_rules! export_contracts {
+            ($($table: ty),*) => {
+                $({
+                    let prefixes = filter.contract_prefixes();
+                    self.spawn_entries_task::<$table, _>(view.clone(), move |view| {
+                        prefixes
+                            .into_iter()
+                            .flat_map(move |prefix| table_entries::<$table, _>(view, prefix))
+                            .into_boxed()
+                    })?;
+                })*
+            };
+        }
+
+        if filter.includes(StateTable::Contracts) {
+            export_contracts!(ContractsRawCode, ContractsLatestUtxo, ContractsState);
+        }
+
+        if filter.includes(StateTable::Balances) {
+            export_contracts!(ContractsAssets);
+        }
+
+        self.finalize_at(&view, height).await?;
+
+        Ok(())
+    }
+
+    /// Writes the blocks, their consensus data and transactions in the
+    /// `from..=to` range of heights. The latest block is used if `to` is `None`.
+    pub async fn write_blocks_snapshot(
+        mut self,
+        from: BlockHeight,
+        to: Option<BlockHeight>,
+    ) -> Result<(), anyhow::Error> {
+        let view = self.db.on_chain().latest_view()?;
+        let to = match to {
+            Some(to) => to,
+            None => view.latest_height()?,
+        };
+        anyhow::ensure!(
+            from <= to,
+            "The start of the range {from} is above the end of the range {to}"
+        );
+
+        self.spawn_entries_task::<FuelBlocks, _>(view.clone(), move |view| {
+            height_range_entries::<FuelBlocks, _>(view, from, to)
+        })?;
+        self.spawn_entries_task::<SealedBlockConsensus, _>(view.clone(), move |view| {
+            height_range_entries::<SealedBlockConsensus, _>(view, from, to)
+        })?;
+        self.spawn_entries_task::<Transactions, _>(view.clone(), move |view| {
+            height_range_entries::<FuelBlocks, _>(view, from, to)
+                .flat_map(move |block| match block {
+                    Ok(block) => block
+                        .value
+                        .transactions()
+                        .iter()
+                        .map(|tx_id| -> StorageResult<TableEntry<Transactions>> {
+                            let tx = view
+                                .storage::<Transactions>()
+                                .get(tx_id)?
+                                .ok_or(not_found!(Transactions))?;
+                            Ok(TableEntry {
+                                key: *tx_id,
+                                value: tx.into_owned(),
+                            })
+                        })
+                        .collect_vec(),
+                    Err(err) => vec![Err(err)],
+                })
+                .into_boxed()
+        })?;
+
+        self.finalize_at(&view, to).await?;
+
+        Ok(())
+    }
+
     async fn finalize(self) -> anyhow::Result<SnapshotMetadata> {
-        let writer = self.create_writer()?;
         let view = self.db.on_chain().latest_view()?;
-        let latest_block = view.latest_block()?;
-        let blocks_root =
-            view.block_header_merkle_root(latest_block.header().height())?;
-        let latest_block =
-            LastBlockConfig::from_header(latest_block.header(), blocks_root);
+        let height = view.latest_height()?;
+        self.finalize_at(&view, height).await
+    }
+
+    async fn finalize_at(
+        self,
+        view: &OnChainIterableKeyValueView,
+        height: BlockHeight,
+    ) -> anyhow::Result<SnapshotMetadata> {
+        let writer = self.create_writer()?;
+        let block = view
+            .storage::<FuelBlocks>()
+            .get(&height)?
+            .ok_or(not_found!(FuelBlocks))?;
+        let blocks_root = view.block_header_merkle_root(&height)?;
+        let latest_block = LastBlockConfig::from_header(block.header(), blocks_root);
 
         let writer_fragment = writer.partial_close()?;
         self.task_manager
@@ -192,19 +382,37 @@ where
         StateConfigBuilder: AddTable<T>,
         DbDesc: DatabaseDescription,
         Database<DbDesc>: IterableTable<T>,
+    {
+        let db = db_picker(self).clone();
+        let prefix = prefix.map(|p| p.to_vec());
+        self.spawn_entries_task::<T, _>(db, move |db| {
+            db.entries::<T>(prefix, IterDirection::Forward).into_boxed()
+        })
+    }
+
+    fn spawn_entries_task<T, S>(
+        &mut self,
+        source: S,
+        entries: impl for<'a> FnOnce(&'a S) -> BoxedIter<'a, StorageResult<TableEntry<T>>>
+            + Send
+            + 'static,
+    ) -> anyhow::Result<()>
+    where
+        T: TableWithBlueprint + 'static + Send + Sync,
+        TableEntry<T>: serde::Serialize,
+        StateConfigBuilder: AddTable<T>,
+        S: Send + 'static,
     {
         let mut writer = self.create_writer()?;
         let group_size = self.group_size;
 
-        let db = db_picker(self).clone();
-        let prefix = prefix.map(|p| p.to_vec());
         // TODO:
         // [1857](https://github.com/FuelLabs/fuel-core/issues/1857)
         // RocksDb can provide an estimate for the number of items.
         let progress_tracker =
             self.multi_progress.table_reporter(None, T::column().name());
         self.task_manager.spawn_blocking(move |cancel| {
-            db.entries::<T>(prefix, IterDirection::Forward)
+            entries(&source)
                 .chunks(group_size)
                 .into_iter()
                 .take_while(|_| !cancel.is_cancelled())
@@ -220,3 +428,33 @@ where
         Ok(())
     }
 }
+
+fn table_entries<'a, T, S>(
+    source: &'a S,
+    prefix: Option<Vec<u8>>,
+) -> BoxedIter<'a, StorageResult<TableEntry<T>>>
+where
+    T: Mappable + 'a,
+    S: IterableTable<T>,
+{
+    source
+        .iter_all_filtered::<T, _>(prefix, None, Some(IterDirection::Forward))
+        .map_ok(|(key, value)| TableEntry { key, value })
+        .into_boxed()
+}
+
+fn height_range_entries<'a, T, S>(
+    source: &'a S,
+    from: BlockHeight,
+    to: BlockHeight,
+) -> BoxedIter<'a, StorageResult<TableEntry<T>>>
+where
+    T: Mappable<Key = BlockHeight, OwnedKey = BlockHeight> + 'a,
+    S: IterableTable<T>,
+{
+    source
+        .iter_all_filtered::<T, Vec<u8>>(None, Some(&from), Some(IterDirection::Forward))
+        .take_while(move |entry| !matches!(entry, Ok((height, _)) if *height > to))
+        .map_ok(|(key, value)| TableEntry { key, value })
+        .into_boxed()
+}
@@ -19,6 +19,7 @@ use crate::{
             OldFuelBlocks,
             OldTransactions,
         },
+        receipts::ContractReceipts,
         transactions::{
             OwnedTransactions,
             TransactionStatuses,
@@ -109,8 +110,33 @@ impl SnapshotImporter {
             .await
     }
 
+    /// Imports only the off-chain part of the snapshot. It is used to rebuild
+    /// the off-chain database when the on-chain database is already initialized.
+    pub async fn import_off_chain(
+        db: CombinedGenesisDatabase,
+        genesis_block: Block,
+        snapshot_reader: SnapshotReader,
+        watcher: StateWatcher,
+    ) -> anyhow::Result<()> {
+        let mut importer = Self::new(db, genesis_block, snapshot_reader, watcher);
+        tracing::info!("Running off-chain imports");
+        importer.spawn_off_chain_workers()?;
+        importer.task_manager.wait().await?;
+
+        Ok(())
+    }
+
     async fn run_workers(mut self) -> anyhow::Result<()> {
         tracing::info!("Running imports");
+        self.spawn_on_chain_workers()?;
+        self.spawn_off_chain_workers()?;
+
+        self.task_manager.wait().await?;
+
+        Ok(())
+    }
+
+    fn spawn_on_chain_workers(&mut self) -> anyhow::Result<()> {
         self.spawn_worker_on_chain::<Coins>()?;
         self.spawn_worker_on_chain::<Messages>()?;
         self.spawn_worker_on_chain::<BlobData>()?;
@@ -122,8 +148,13 @@ impl SnapshotImporter {
         self.spawn_worker_on_chain::<FuelBlockMerkleData>()?;
         self.spawn_worker_on_chain::<FuelBlockMerkleMetadata>()?;
 
+        Ok(())
+    }
+
+    fn spawn_off_chain_workers(&mut self) -> anyhow::Result<()> {
         self.spawn_worker_off_chain::<TransactionStatuses, TransactionStatuses>()?;
         self.spawn_worker_off_chain::<OwnedTransactions, OwnedTransactions>()?;
+        self.spawn_worker_off_chain::<ContractReceipts, ContractReceipts>()?;
         self.spawn_worker_off_chain::<SpentMessages, SpentMessages>()?;
         self.spawn_worker_off_chain::<Messages, OwnedMessageIds>()?;
         self.spawn_worker_off_chain::<Coins, OwnedCoins>()?;
@@ -138,8 +169,6 @@ impl SnapshotImporter {
         self.spawn_worker_off_chain::<FuelBlocks, FuelBlockIdsToHeights>()?;
         self.spawn_worker_off_chain::<OldFuelBlocks, FuelBlockIdsToHeights>()?;
 
-        self.task_manager.wait().await?;
-
         Ok(())
     }
 
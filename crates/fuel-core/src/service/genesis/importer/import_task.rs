@@ -207,7 +207,6 @@ mod tests {
         state::{
             in_memory::memory_store::MemoryStore,
             IterableKeyValueView,
-            KeyValueView,
             TransactableStorage,
         },
     };
@@ -578,7 +577,7 @@ mod tests {
         fn view_at_height(
             &self,
             _: &BlockHeight,
-        ) -> StorageResult<KeyValueView<Self::Column>> {
+        ) -> StorageResult<IterableKeyValueView<Self::Column>> {
             Err(anyhow::anyhow!("I refuse to work!").into())
         }
 
@@ -589,6 +588,14 @@ mod tests {
         fn rollback_block_to(&self, _: &BlockHeight) -> StorageResult<()> {
             Err(anyhow::anyhow!("I refuse to work!").into())
         }
+
+        fn oldest_changes_height(&self) -> StorageResult<Option<u64>> {
+            Err(anyhow::anyhow!("I refuse to work!").into())
+        }
+
+        fn checkpoint(&self, _: &std::path::Path) -> StorageResult<()> {
+            Err(anyhow::anyhow!("I refuse to work!").into())
+        }
     }
 
     #[test]
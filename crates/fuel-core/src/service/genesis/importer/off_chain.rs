@@ -17,6 +17,7 @@ use crate::{
                 OldFuelBlocks,
                 OldTransactions,
             },
+            receipts::ContractReceipts,
             transactions::{
                 OwnedTransactions,
                 TransactionStatuses,
@@ -98,6 +99,24 @@ impl ImportTable for Handler<OwnedTransactions, OwnedTransactions> {
     }
 }
 
+impl ImportTable for Handler<ContractReceipts, ContractReceipts> {
+    type TableInSnapshot = ContractReceipts;
+    type TableBeingWritten = ContractReceipts;
+    type DbDesc = OffChain;
+
+    fn process(
+        &mut self,
+        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
+    ) -> anyhow::Result<()> {
+        for entry in group {
+            tx.storage::<ContractReceipts>()
+                .insert(&entry.key, &entry.value)?;
+        }
+        Ok(())
+    }
+}
+
 impl ImportTable for Handler<OwnedMessageIds, Messages> {
     type TableInSnapshot = Messages;
     type TableBeingWritten = OwnedMessageIds;
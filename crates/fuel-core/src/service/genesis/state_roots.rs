@@ -0,0 +1,288 @@
+use crate::database::OnChainIterableKeyValueView;
+use fuel_core_chain_config::{
+    AsTable,
+    GenesisCommitment,
+    SnapshotReader,
+    StateConfig,
+    TableEntry,
+};
+use fuel_core_storage::{
+    codec::{
+        postcard::Postcard,
+        Encode,
+        Encoder,
+    },
+    iter::{
+        IterableTable,
+        IteratorOverTable,
+    },
+    structured_storage::TableWithBlueprint,
+    tables::{
+        Coins,
+        ContractsAssets,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
+        Messages,
+    },
+    Mappable,
+    MerkleRoot,
+};
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_merkle::binary::root_calculator::MerkleRootCalculator,
+};
+use itertools::Itertools;
+
+/// The merkle roots of the chain state. They allow checking that the state
+/// stored in a snapshot is the same as the state stored in the database.
+///
+/// Each root is calculated over the entries in the order of their keys.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StateRoots {
+    pub coins: MerkleRoot,
+    pub messages: MerkleRoot,
+    /// The root of the code, the latest UTXO and the storage slots of contracts.
+    pub contracts: MerkleRoot,
+    /// The root of the balances of contracts.
+    pub balances: MerkleRoot,
+}
+
+impl StateRoots {
+    /// Calculates the roots of the state stored in the snapshot.
+    /// The snapshot entries are expected to be sorted by key,
+    /// as they are written by the [`Exporter`](super::Exporter).
+    pub fn from_snapshot(reader: &SnapshotReader) -> anyhow::Result<Self> {
+        let mut contracts = MerkleRootCalculator::new();
+        push_leaves(
+            snapshot_entries::<ContractsRawCode>(reader)?,
+            &mut contracts,
+        )?;
+        push_leaves(
+            snapshot_entries::<ContractsLatestUtxo>(reader)?,
+            &mut contracts,
+        )?;
+        push_leaves(snapshot_entries::<ContractsState>(reader)?, &mut contracts)?;
+
+        Ok(Self {
+            coins: root(snapshot_entries::<Coins>(reader)?)?,
+            messages: root(snapshot_entries::<Messages>(reader)?)?,
+            contracts: contracts.root(),
+            balances: root(snapshot_entries::<ContractsAssets>(reader)?)?,
+        })
+    }
+
+    /// Calculates the roots of the state visible in the `view` of the database.
+    pub fn from_view(view: &OnChainIterableKeyValueView) -> anyhow::Result<Self> {
+        let mut contracts = MerkleRootCalculator::new();
+        push_leaves(view_entries::<ContractsRawCode>(view), &mut contracts)?;
+        push_leaves(view_entries::<ContractsLatestUtxo>(view), &mut contracts)?;
+        push_leaves(view_entries::<ContractsState>(view), &mut contracts)?;
+
+        Ok(Self {
+            coins: root(view_entries::<Coins>(view))?,
+            messages: root(view_entries::<Messages>(view))?,
+            contracts: contracts.root(),
+            balances: root(view_entries::<ContractsAssets>(view))?,
+        })
+    }
+}
+
+/// The leaf of the merkle tree built over the entries of the table.
+trait StateLeaf {
+    fn leaf(&self) -> anyhow::Result<MerkleRoot>;
+}
+
+impl StateLeaf for TableEntry<Coins> {
+    fn leaf(&self) -> anyhow::Result<MerkleRoot> {
+        self.value.clone().uncompress(self.key).root()
+    }
+}
+
+impl StateLeaf for TableEntry<Messages> {
+    fn leaf(&self) -> anyhow::Result<MerkleRoot> {
+        self.value.root()
+    }
+}
+
+macro_rules! impl_state_leaf_for_encoded_entry {
+    ($($table: ty),*) => {
+        $(
+            impl StateLeaf for TableEntry<$table> {
+                fn leaf(&self) -> anyhow::Result<MerkleRoot> {
+                    let encoded = Postcard::encode(self);
+                    Ok(*Hasher::hash(encoded.as_bytes()))
+                }
+            }
+        )*
+    };
+}
+
+impl_state_leaf_for_encoded_entry!(
+    ContractsRawCode,
+    ContractsLatestUtxo,
+    ContractsState,
+    ContractsAssets
+);
+
+fn root<T>(
+    entries: impl Iterator<Item = anyhow::Result<TableEntry<T>>>,
+) -> anyhow::Result<MerkleRoot>
+where
+    T: Mappable,
+    TableEntry<T>: StateLeaf,
+{
+    let mut calculator = MerkleRootCalculator::new();
+    push_leaves(entries, &mut calculator)?;
+    Ok(calculator.root())
+}
+
+fn push_leaves<T>(
+    entries: impl Iterator<Item = anyhow::Result<TableEntry<T>>>,
+    calculator: &mut MerkleRootCalculator,
+) -> anyhow::Result<()>
+where
+    T: Mappable,
+    TableEntry<T>: StateLeaf,
+{
+    for entry in entries {
+        calculator.push(entry?.leaf()?.as_slice());
+    }
+    Ok(())
+}
+
+fn snapshot_entries<T>(
+    reader: &SnapshotReader,
+) -> anyhow::Result<impl Iterator<Item = anyhow::Result<TableEntry<T>>>>
+where
+    T: TableWithBlueprint,
+    StateConfig: AsTable<T>,
+    TableEntry<T>: serde::de::DeserializeOwned,
+{
+    Ok(reader.read::<T>()?.into_iter().flatten_ok())
+}
+
+fn view_entries<T>(
+    view: &OnChainIterableKeyValueView,
+) -> impl Iterator<Item = anyhow::Result<TableEntry<T>>> + '_
+where
+    T: Mappable,
+    T::OwnedKey: 'static,
+    T::OwnedValue: 'static,
+    OnChainIterableKeyValueView: IterableTable<T>,
+{
+    view.iter_all::<T>(None)
+        .map_ok(|(key, value)| TableEntry { key, value })
+        .map(|entry| entry.map_err(Into::into))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::combined_database::CombinedDatabase;
+    use fuel_core_chain_config::{
+        ChainConfig,
+        Randomize,
+    };
+    use fuel_core_storage::{
+        transactional::AtomicView,
+        StorageAsMut,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    fn given_state(rng: &mut StdRng) -> StateConfig {
+        StateConfig {
+            blobs: vec![],
+            last_block: None,
+            ..StateConfig::randomize(rng)
+        }
+        .sorted()
+    }
+
+    fn given_db_with(state: &StateConfig) -> CombinedDatabase {
+        let mut db = CombinedDatabase::default();
+        let on_chain = db.on_chain_mut();
+        for entry in AsTable::<Coins>::as_table(state) {
+            on_chain
+                .storage_as_mut::<Coins>()
+                .insert(&entry.key, &entry.value)
+                .unwrap();
+        }
+        for entry in AsTable::<Messages>::as_table(state) {
+            on_chain
+                .storage_as_mut::<Messages>()
+                .insert(&entry.key, &entry.value)
+                .unwrap();
+        }
+        for entry in AsTable::<ContractsRawCode>::as_table(state) {
+            on_chain
+                .storage_as_mut::<ContractsRawCode>()
+                .insert(&entry.key, entry.value.as_ref())
+                .unwrap();
+        }
+        for entry in AsTable::<ContractsLatestUtxo>::as_table(state) {
+            on_chain
+                .storage_as_mut::<ContractsLatestUtxo>()
+                .insert(&entry.key, &entry.value)
+                .unwrap();
+        }
+        for entry in AsTable::<ContractsState>::as_table(state) {
+            on_chain
+                .storage_as_mut::<ContractsState>()
+                .insert(&entry.key, Vec::<u8>::from(entry.value).as_slice())
+                .unwrap();
+        }
+        for entry in AsTable::<ContractsAssets>::as_table(state) {
+            on_chain
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&entry.key, &entry.value)
+                .unwrap();
+        }
+        db
+    }
+
+    #[test]
+    fn state_roots__snapshot_and_database_with_same_state_have_same_roots() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(2);
+        let state = given_state(&mut rng);
+        let db = given_db_with(&state);
+        let reader = SnapshotReader::new_in_memory(ChainConfig::local_testnet(), state);
+
+        // When
+        let snapshot_roots = StateRoots::from_snapshot(&reader).unwrap();
+        let db_roots =
+            StateRoots::from_view(&db.on_chain().latest_view().unwrap()).unwrap();
+
+        // Then
+        assert_eq!(snapshot_roots, db_roots);
+    }
+
+    #[test]
+    fn state_roots__different_coins_give_different_coins_root() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(2);
+        let state = given_state(&mut rng);
+        let db = given_db_with(&state);
+        let mut modified_state = state.clone();
+        modified_state.coins[0].amount = modified_state.coins[0].amount.wrapping_add(1);
+        let reader =
+            SnapshotReader::new_in_memory(ChainConfig::local_testnet(), modified_state);
+
+        // When
+        let snapshot_roots = StateRoots::from_snapshot(&reader).unwrap();
+        let db_roots =
+            StateRoots::from_view(&db.on_chain().latest_view().unwrap()).unwrap();
+
+        // Then
+        assert_ne!(snapshot_roots.coins, db_roots.coins);
+        assert_eq!(snapshot_roots.messages, db_roots.messages);
+        assert_eq!(snapshot_roots.contracts, db_roots.contracts);
+        assert_eq!(snapshot_roots.balances, db_roots.balances);
+    }
+}
@@ -0,0 +1,430 @@
+//! The service that prunes the historical data of old blocks.
+//! It removes transactions, receipts, owner indexes and tips of blocks
+//! outside of the retention window. The block headers, consensus data,
+//! and merkle data are never pruned, so proofs for old blocks still work.
+
+use crate::{
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+        },
+        Database,
+    },
+    fuel_core_graphql_api::{
+        storage::{
+            old::{
+                OldFuelBlocks,
+                OldTransactions,
+            },
+            pruning::{
+                PrunedBlocks,
+                PRUNED_BLOCKS,
+            },
+            receipts::{
+                ContractReceiptCursor,
+                ContractReceiptKey,
+                ContractReceipts,
+            },
+            statistic::StatisticTable,
+            tips::BlockTips,
+            transactions::{
+                OwnedTransactionIndexKey,
+                OwnedTransactions,
+                TransactionStatuses,
+            },
+        },
+        worker_service::tx_owners,
+    },
+};
+use fuel_core_metrics::pruning_metrics::pruning_metrics;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_storage::{
+    iter::{
+        IterDirection,
+        IteratorOverTable,
+    },
+    tables::{
+        FuelBlocks,
+        Transactions,
+    },
+    transactional::{
+        HistoricalView,
+        ReadTransaction,
+        StorageTransaction,
+    },
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        ContractId,
+        Receipt,
+    },
+    fuel_types::BlockHeight,
+    services::txpool::TransactionStatus,
+};
+use std::{
+    num::NonZeroU32,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The configuration of the pruning service.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PruningConfig {
+    /// The number of the latest blocks that are never pruned.
+    /// It shouldn't be less than the state rewind window, because the re-execution
+    /// of blocks requires their transactions, see [`Config::make_config_consistent`].
+    ///
+    /// [`Config::make_config_consistent`]: crate::service::Config::make_config_consistent
+    pub retained_blocks: NonZeroU32,
+    /// How often the service checks for new blocks to prune. Must be non-zero.
+    pub interval: Duration,
+    /// The maximum number of blocks pruned in one database commit.
+    pub batch_size: NonZeroU32,
+}
+
+pub struct InitializeTask {
+    on_chain: Database<OnChain>,
+    off_chain: Database<OffChain>,
+    config: PruningConfig,
+}
+
+pub struct Task {
+    on_chain: Database<OnChain>,
+    off_chain: Database<OffChain>,
+    config: PruningConfig,
+    interval: tokio::time::Interval,
+    /// `true` if all blocks outside of the retention window are pruned.
+    caught_up: bool,
+}
+
+impl Task {
+    /// Waits for the next tick of the interval if all blocks are already pruned.
+    /// Otherwise, only yields to not block the runtime between batches.
+    async fn wait_for_next_batch(&mut self) {
+        if self.caught_up {
+            self.interval.tick().await;
+        } else {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    /// Prunes the next batch of blocks.
+    /// Returns `true` if there are no more blocks to prune.
+    fn prune_next_batch(&mut self) -> anyhow::Result<bool> {
+        let Some(on_chain_height) = HistoricalView::latest_height(&self.on_chain) else {
+            return Ok(true)
+        };
+        // The off-chain worker indexes blocks using their transactions,
+        // so the blocks that are not processed yet, e.g. during the reindexing
+        // of the off-chain database, are not pruned.
+        let Some(off_chain_height) = HistoricalView::latest_height(&self.off_chain)
+        else {
+            return Ok(true)
+        };
+        let latest_height = on_chain_height.min(off_chain_height);
+        let Some(last_prunable) =
+            latest_height.checked_sub(self.config.retained_blocks.get())
+        else {
+            return Ok(true)
+        };
+        let last_prunable = BlockHeight::new(last_prunable);
+
+        let pruned_blocks = self
+            .off_chain
+            .storage_as_ref::<StatisticTable<PrunedBlocks>>()
+            .get(PRUNED_BLOCKS)?
+            .map(|pruned_blocks| pruned_blocks.into_owned());
+
+        let (first, next) = match pruned_blocks {
+            Some(pruned_blocks) => {
+                let Some(next) = pruned_blocks.last.succ() else {
+                    return Ok(true)
+                };
+                (pruned_blocks.first, next)
+            }
+            None => {
+                let Some(first) = self.first_block_height()? else {
+                    return Ok(true)
+                };
+                (first, first)
+            }
+        };
+
+        if next > last_prunable {
+            pruning_metrics().blocks_to_prune.set(0);
+            return Ok(true)
+        }
+
+        let batch_end = next
+            .saturating_add(self.config.batch_size.get().saturating_sub(1))
+            .min(*last_prunable);
+        let last = BlockHeight::new(batch_end);
+        let start = Instant::now();
+
+        let mut on_chain_transaction = self.on_chain.read_transaction();
+        let mut off_chain_transaction = self.off_chain.read_transaction();
+        for height in *next..=batch_end {
+            prune_block(
+                &BlockHeight::new(height),
+                &mut on_chain_transaction,
+                &mut off_chain_transaction,
+            )?;
+        }
+        off_chain_transaction
+            .storage_as_mut::<StatisticTable<PrunedBlocks>>()
+            .insert(PRUNED_BLOCKS, &PrunedBlocks { first, last })?;
+
+        // The off-chain database is committed first, so queries report the data
+        // as pruned instead of not found once it disappears from the on-chain database.
+        // If the node stops between commits, the transactions of the batch remain
+        // in the on-chain database, which doesn't affect the correctness of queries.
+        self.off_chain
+            .commit_changes_without_height(off_chain_transaction.into_changes())?;
+        self.on_chain
+            .commit_changes_without_height(on_chain_transaction.into_changes())?;
+
+        let metrics = pruning_metrics();
+        metrics.pruned_height.set(batch_end.into());
+        metrics
+            .pruned_blocks
+            .inc_by(batch_end.saturating_sub(*next).saturating_add(1).into());
+        metrics
+            .blocks_to_prune
+            .set(last_prunable.saturating_sub(batch_end).into());
+        metrics
+            .batch_duration
+            .observe(start.elapsed().as_secs_f64());
+        tracing::debug!("Pruned blocks from {} to {}", next, last);
+
+        Ok(batch_end == *last_prunable)
+    }
+
+    /// Returns the height of the first block known to the node.
+    /// The blocks from before the regenesis are stored in the off-chain database.
+    fn first_block_height(&self) -> StorageResult<Option<BlockHeight>> {
+        if let Some(height) = self
+            .off_chain
+            .iter_all_keys::<OldFuelBlocks>(Some(IterDirection::Forward))
+            .next()
+        {
+            return height.map(Some)
+        }
+
+        self.on_chain
+            .iter_all_keys::<FuelBlocks>(Some(IterDirection::Forward))
+            .next()
+            .transpose()
+    }
+}
+
+/// Prunes the transactions, receipts, owner indexes, and tips of the block.
+fn prune_block(
+    height: &BlockHeight,
+    on_chain: &mut StorageTransaction<&Database<OnChain>>,
+    off_chain: &mut StorageTransaction<&Database<OffChain>>,
+) -> anyhow::Result<()> {
+    let (tx_ids, is_old_block) = if let Some(block) =
+        on_chain.storage_as_ref::<FuelBlocks>().get(height)?
+    {
+        (block.transactions().to_vec(), false)
+    } else if let Some(block) = off_chain.storage_as_ref::<OldFuelBlocks>().get(height)? {
+        (block.transactions().to_vec(), true)
+    } else {
+        return Ok(())
+    };
+
+    for (tx_idx, tx_id) in tx_ids.iter().enumerate() {
+        let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+            anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
+        })?;
+
+        let tx = if is_old_block {
+            off_chain.storage_as_mut::<OldTransactions>().take(tx_id)?
+        } else {
+            on_chain.storage_as_mut::<Transactions>().take(tx_id)?
+        };
+        if let Some(tx) = tx {
+            for owner in tx_owners(&tx) {
+                off_chain
+                    .storage_as_mut::<OwnedTransactions>()
+                    .remove(&OwnedTransactionIndexKey::new(&owner, *height, tx_idx))?;
+            }
+        }
+
+        let status = off_chain
+            .storage_as_ref::<TransactionStatuses>()
+            .get(tx_id)?
+            .map(|status| status.into_owned());
+        if let Some(status) = status {
+            let (status, receipts) = take_receipts(status);
+            for (receipt_idx, receipt) in receipts.iter().enumerate() {
+                let Some(contract_id) = receipt.id() else {
+                    continue
+                };
+                if *contract_id == ContractId::zeroed() {
+                    continue
+                }
+                let receipt_idx = u16::try_from(receipt_idx).map_err(|e| {
+                    anyhow::anyhow!(
+                        "The transaction has more than `u16::MAX` receipts, {}",
+                        e
+                    )
+                })?;
+                let key = ContractReceiptKey::new(
+                    *contract_id,
+                    ContractReceiptCursor::new(*height, tx_idx, receipt_idx),
+                );
+                off_chain
+                    .storage_as_mut::<ContractReceipts>()
+                    .remove(&key)?;
+            }
+            off_chain
+                .storage_as_mut::<TransactionStatuses>()
+                .insert(tx_id, &status)?;
+        }
+    }
+
+    off_chain.storage_as_mut::<BlockTips>().remove(height)?;
+
+    Ok(())
+}
+
+/// Removes the receipts from the status of the transaction.
+/// The rest of the status is kept to allow querying the pruned transaction's status.
+fn take_receipts(status: TransactionStatus) -> (TransactionStatus, Vec<Receipt>) {
+    match status {
+        TransactionStatus::Success {
+            block_height,
+            time,
+            result,
+            receipts,
+            total_gas,
+            total_fee,
+        } => (
+            TransactionStatus::Success {
+                block_height,
+                time,
+                result,
+                receipts: vec![],
+                total_gas,
+                total_fee,
+            },
+            receipts,
+        ),
+        TransactionStatus::Failed {
+            block_height,
+            time,
+            result,
+            receipts,
+            total_gas,
+            total_fee,
+        } => (
+            TransactionStatus::Failed {
+                block_height,
+                time,
+                result,
+                receipts: vec![],
+                total_gas,
+                total_fee,
+            },
+            receipts,
+        ),
+        status => (status, vec![]),
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            _ = self.wait_for_next_batch() => {
+                match self.prune_next_batch() {
+                    Ok(caught_up) => {
+                        self.caught_up = caught_up;
+                    }
+                    Err(err) => {
+                        tracing::error!("Failed to prune old blocks: {:?}", err);
+                        self.caught_up = true;
+                    }
+                }
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Each batch is committed atomically, so there is nothing to finish.
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for InitializeTask {
+    const NAME: &'static str = "Pruning";
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let InitializeTask {
+            on_chain,
+            off_chain,
+            config,
+        } = self;
+
+        anyhow::ensure!(
+            !config.interval.is_zero(),
+            "The pruning interval must be non-zero"
+        );
+        let mut interval = tokio::time::interval(config.interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        Ok(Task {
+            on_chain,
+            off_chain,
+            config,
+            interval,
+            caught_up: false,
+        })
+    }
+}
+
+pub fn new_service(
+    on_chain: Database<OnChain>,
+    off_chain: Database<OffChain>,
+    config: PruningConfig,
+) -> ServiceRunner<InitializeTask> {
+    ServiceRunner::new(InitializeTask {
+        on_chain,
+        off_chain,
+        config,
+    })
+}
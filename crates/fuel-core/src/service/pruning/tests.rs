@@ -0,0 +1,299 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use crate::fuel_core_graphql_api::storage::{
+    blocks::FuelBlockIdsToHeights,
+    receipts::ContractReceipt,
+};
+use fuel_core_services::{
+    Service,
+    State,
+};
+use fuel_core_storage::transactional::WriteTransaction;
+use fuel_core_types::{
+    blockchain::{
+        block::CompressedBlock,
+        primitives::BlockId,
+    },
+    fuel_tx::{
+        Address,
+        AssetId,
+        Output,
+        TransactionBuilder,
+        TxId,
+        UniqueIdentifier,
+    },
+    fuel_types::ChainId,
+    tai64::Tai64,
+};
+
+const BLOCKS: u32 = 5;
+
+fn owner() -> Address {
+    Address::from([1; 32])
+}
+
+fn contract_id() -> ContractId {
+    ContractId::from([2; 32])
+}
+
+/// Creates `BLOCKS` blocks with one transaction each, and indexes them
+/// the same way as the off-chain worker does.
+fn setup() -> (Database<OnChain>, Database<OffChain>, Vec<TxId>) {
+    let mut on_chain = Database::<OnChain>::default();
+    let mut off_chain = Database::<OffChain>::default();
+    let mut tx_ids = vec![];
+
+    for height in 0..BLOCKS {
+        let height = BlockHeight::new(height);
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_output(Output::coin(owner(), u64::from(*height), AssetId::BASE))
+            .finalize_as_transaction();
+        let tx_id = tx.id(&ChainId::default());
+
+        let mut transaction = on_chain.write_transaction();
+        transaction
+            .storage_as_mut::<Transactions>()
+            .insert(&tx_id, &tx)
+            .unwrap();
+        transaction
+            .storage_as_mut::<FuelBlocks>()
+            .insert(
+                &height,
+                &CompressedBlock::test(Default::default(), vec![tx_id]),
+            )
+            .unwrap();
+        transaction.commit().unwrap();
+
+        let receipt = Receipt::log(contract_id(), 1, 2, 3, 4, 5, 6);
+        let mut transaction = off_chain.write_transaction();
+        transaction
+            .storage_as_mut::<FuelBlockIdsToHeights>()
+            .insert(
+                &BlockId::from([u8::try_from(*height).unwrap(); 32]),
+                &height,
+            )
+            .unwrap();
+        transaction
+            .storage_as_mut::<OwnedTransactions>()
+            .insert(&OwnedTransactionIndexKey::new(&owner(), height, 0), &tx_id)
+            .unwrap();
+        transaction
+            .storage_as_mut::<TransactionStatuses>()
+            .insert(
+                &tx_id,
+                &TransactionStatus::Success {
+                    block_height: height,
+                    time: Tai64::UNIX_EPOCH,
+                    result: None,
+                    receipts: vec![receipt.clone()],
+                    total_gas: 0,
+                    total_fee: 0,
+                },
+            )
+            .unwrap();
+        transaction
+            .storage_as_mut::<ContractReceipts>()
+            .insert(
+                &ContractReceiptKey::new(
+                    contract_id(),
+                    ContractReceiptCursor::new(height, 0, 0),
+                ),
+                &ContractReceipt { tx_id, receipt },
+            )
+            .unwrap();
+        transaction
+            .storage_as_mut::<BlockTips>()
+            .insert(&height, &vec![1])
+            .unwrap();
+        transaction.commit().unwrap();
+
+        tx_ids.push(tx_id);
+    }
+
+    (on_chain, off_chain, tx_ids)
+}
+
+fn task(
+    on_chain: Database<OnChain>,
+    off_chain: Database<OffChain>,
+    retained_blocks: u32,
+    batch_size: u32,
+) -> Task {
+    let config = PruningConfig {
+        retained_blocks: NonZeroU32::new(retained_blocks).unwrap(),
+        interval: Duration::from_secs(1),
+        batch_size: NonZeroU32::new(batch_size).unwrap(),
+    };
+    Task {
+        on_chain,
+        off_chain,
+        interval: tokio::time::interval(config.interval),
+        config,
+        caught_up: false,
+    }
+}
+
+fn pruned_blocks(off_chain: &Database<OffChain>) -> Option<PrunedBlocks> {
+    off_chain
+        .storage_as_ref::<StatisticTable<PrunedBlocks>>()
+        .get(PRUNED_BLOCKS)
+        .unwrap()
+        .map(|pruned_blocks| pruned_blocks.into_owned())
+}
+
+#[tokio::test]
+async fn prune_next_batch__prunes_blocks_outside_of_retention_window() {
+    // Given
+    let (on_chain, off_chain, tx_ids) = setup();
+    let mut task = task(on_chain.clone(), off_chain.clone(), 2, 100);
+
+    // When
+    let caught_up = task.prune_next_batch().unwrap();
+
+    // Then
+    assert!(caught_up);
+    assert_eq!(
+        pruned_blocks(&off_chain),
+        Some(PrunedBlocks {
+            first: 0.into(),
+            last: 2.into(),
+        })
+    );
+    for (height, tx_id) in tx_ids.iter().enumerate() {
+        let height = BlockHeight::new(u32::try_from(height).unwrap());
+        let pruned = *height <= 2;
+
+        let block = on_chain
+            .storage_as_ref::<FuelBlocks>()
+            .get(&height)
+            .unwrap();
+        assert!(block.is_some(), "The header should never be pruned");
+
+        let tx = on_chain
+            .storage_as_ref::<Transactions>()
+            .get(tx_id)
+            .unwrap();
+        assert_eq!(tx.is_none(), pruned);
+
+        let owned_tx = off_chain
+            .storage_as_ref::<OwnedTransactions>()
+            .get(&OwnedTransactionIndexKey::new(&owner(), height, 0))
+            .unwrap();
+        assert_eq!(owned_tx.is_none(), pruned);
+
+        let contract_receipt = off_chain
+            .storage_as_ref::<ContractReceipts>()
+            .get(&ContractReceiptKey::new(
+                contract_id(),
+                ContractReceiptCursor::new(height, 0, 0),
+            ))
+            .unwrap();
+        assert_eq!(contract_receipt.is_none(), pruned);
+
+        let tips = off_chain
+            .storage_as_ref::<BlockTips>()
+            .get(&height)
+            .unwrap();
+        assert_eq!(tips.is_none(), pruned);
+
+        let status = off_chain
+            .storage_as_ref::<TransactionStatuses>()
+            .get(tx_id)
+            .unwrap()
+            .expect("The status should never be pruned")
+            .into_owned();
+        let TransactionStatus::Success { receipts, .. } = status else {
+            panic!("Unexpected status {status:?}")
+        };
+        assert_eq!(receipts.is_empty(), pruned);
+    }
+}
+
+#[tokio::test]
+async fn prune_next_batch__prunes_at_most_batch_size_blocks_per_call() {
+    // Given
+    let (on_chain, off_chain, _) = setup();
+    let mut task = task(on_chain, off_chain.clone(), 2, 2);
+
+    // When
+    let first_caught_up = task.prune_next_batch().unwrap();
+    let first_pruned_blocks = pruned_blocks(&off_chain);
+    let second_caught_up = task.prune_next_batch().unwrap();
+    let second_pruned_blocks = pruned_blocks(&off_chain);
+
+    // Then
+    assert!(!first_caught_up);
+    assert_eq!(
+        first_pruned_blocks,
+        Some(PrunedBlocks {
+            first: 0.into(),
+            last: 1.into(),
+        })
+    );
+    assert!(second_caught_up);
+    assert_eq!(
+        second_pruned_blocks,
+        Some(PrunedBlocks {
+            first: 0.into(),
+            last: 2.into(),
+        })
+    );
+}
+
+#[tokio::test]
+async fn prune_next_batch__does_nothing_if_chain_is_shorter_than_retention_window() {
+    // Given
+    let (on_chain, off_chain, _) = setup();
+    let mut task = task(on_chain, off_chain.clone(), BLOCKS, 100);
+
+    // When
+    let caught_up = task.prune_next_batch().unwrap();
+
+    // Then
+    assert!(caught_up);
+    assert_eq!(pruned_blocks(&off_chain), None);
+}
+
+#[tokio::test]
+async fn prune_next_batch__does_nothing_if_blocks_are_not_indexed_off_chain() {
+    // Given
+    let (on_chain, _, tx_ids) = setup();
+    let reindexed_off_chain = Database::<OffChain>::default();
+    let mut task = task(on_chain.clone(), reindexed_off_chain.clone(), 2, 100);
+
+    // When
+    let caught_up = task.prune_next_batch().unwrap();
+
+    // Then
+    assert!(caught_up);
+    assert_eq!(pruned_blocks(&reindexed_off_chain), None);
+    for tx_id in tx_ids {
+        let tx = on_chain
+            .storage_as_ref::<Transactions>()
+            .get(&tx_id)
+            .unwrap();
+        assert!(tx.is_some());
+    }
+}
+
+#[tokio::test]
+async fn into_task__fails_with_zero_interval() {
+    // Given
+    let (on_chain, off_chain, _) = setup();
+    let service = new_service(
+        on_chain,
+        off_chain,
+        PruningConfig {
+            retained_blocks: NonZeroU32::new(2).unwrap(),
+            interval: Duration::ZERO,
+            batch_size: NonZeroU32::new(100).unwrap(),
+        },
+    );
+
+    // When
+    let state = service.start_and_await().await.unwrap();
+
+    // Then
+    assert!(matches!(state, State::StoppedWithError(_)));
+}
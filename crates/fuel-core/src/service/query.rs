@@ -7,7 +7,10 @@ use fuel_core_types::{
         UniqueIdentifier,
     },
     fuel_types::Bytes32,
-    services::txpool::InsertionResult,
+    services::txpool::{
+        InsertionResult,
+        TransactionStatus as TxPoolTransactionStatus,
+    },
 };
 use futures::{
     Stream,
@@ -30,8 +33,7 @@ impl FuelService {
             .insert(vec![Arc::new(tx)])
             .await
             .into_iter()
-            .collect::<Result<_, _>>()
-            .map_err(|e| anyhow::anyhow!(e))?;
+            .collect::<anyhow::Result<_>>()?;
         results
             .into_iter()
             .next()
@@ -89,7 +91,9 @@ impl FuelService {
         Ok(transaction_status_change(
             move |id| match db.get_tx_status(&id)? {
                 Some(status) => Ok(Some(status)),
-                None => Ok(txpool.find_one(id).map(Into::into)),
+                None => Ok(txpool
+                    .submission_time(id)
+                    .map(|time| TxPoolTransactionStatus::Submitted { time })),
             },
             rx,
             id,
@@ -11,7 +11,10 @@ use super::{
 use crate::relayer::Config as RelayerConfig;
 use crate::{
     combined_database::CombinedDatabase,
-    database::Database,
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
     fuel_core_graphql_api,
     fuel_core_graphql_api::Config as GraphQLConfig,
     schema::build_schema,
@@ -19,8 +22,19 @@ use crate::{
         adapters::{
             consensus_module::poa::InDirectoryPredefinedBlocks,
             consensus_parameters_provider,
-            fuel_gas_price_provider::FuelGasPriceProvider,
-            graphql_api::GraphQLBlockImporter,
+            fuel_gas_price_provider::{
+                FuelGasPriceProvider,
+                VersionedGasPriceProvider,
+            },
+            gas_price_adapters::{
+                da_block_costs_source,
+                v1_algorithm_config,
+            },
+            graphql_api::{
+                DatabaseCheckpointAdapter,
+                GraphQLBlockImporter,
+                NodeHealthAdapter,
+            },
             import_result_provider::ImportResultProvider,
             BlockImporterAdapter,
             BlockProducerAdapter,
@@ -31,16 +45,22 @@ use crate::{
             SharedMemoryPool,
             SystemTime,
             TxPoolAdapter,
+            TxPoolSharedState,
+            TxStatusSender,
             VerifierAdapter,
         },
         Config,
+        ServiceTrait,
         SharedState,
         SubServices,
     },
 };
-use fuel_core_gas_price_service::v0::uninitialized_task::{
-    new_gas_price_service_v0,
-    AlgorithmV0,
+use fuel_core_gas_price_service::{
+    v0::uninitialized_task::new_gas_price_service_v0,
+    v1::{
+        da_source_service,
+        uninitialized_task::new_gas_price_service_v1,
+    },
 };
 use fuel_core_poa::{
     signer::SignMode,
@@ -50,6 +70,7 @@ use fuel_core_storage::{
     self,
     transactional::AtomicView,
 };
+use fuel_core_txpool::service::TxStatusChange;
 #[cfg(feature = "relayer")]
 use fuel_core_types::blockchain::primitives::DaBlockHeight;
 use std::sync::Arc;
@@ -65,19 +86,29 @@ pub type PoAService = fuel_core_poa::Service<
 >;
 #[cfg(feature = "p2p")]
 pub type P2PService = fuel_core_p2p::service::Service<Database, TxPoolAdapter>;
-pub type TxPoolSharedState = fuel_core_txpool::service::SharedState<
+pub type TxPoolV1SharedState = fuel_core_txpool::service::SharedState<
     P2PAdapter,
     Database,
     ExecutorAdapter,
-    FuelGasPriceProvider<AlgorithmV0>,
+    VersionedGasPriceProvider,
     ConsensusParametersProvider,
     SharedMemoryPool,
 >;
+pub type TxPoolV2SharedState = fuel_core_txpool_v2::SharedState<
+    P2PAdapter,
+    Database,
+    ConsensusParametersProvider,
+    VersionedGasPriceProvider,
+    ExecutorAdapter,
+    SharedMemoryPool,
+    TxStatusSender,
+    Database<OffChain>,
+>;
 pub type BlockProducerService = fuel_core_producer::block_producer::Producer<
     Database,
     TxPoolAdapter,
     ExecutorAdapter,
-    FuelGasPriceProvider<AlgorithmV0>,
+    VersionedGasPriceProvider,
     ConsensusParametersProvider,
 >;
 
@@ -175,6 +206,8 @@ pub fn init_sub_services(
             bad_block_header: -100.,
             missing_transactions: -100.,
             invalid_transactions: -100.,
+            fast_blocks_download: 1.,
+            slow_blocks_download: -1.,
         };
         P2PAdapter::new(
             p2p_externals.as_ref().map(|ext| ext.0.clone()),
@@ -189,29 +222,82 @@ pub fn init_sub_services(
     let settings = consensus_parameters_provider.clone();
     let block_stream = importer_adapter.events_shared_result();
 
-    let gas_price_service_v0 = new_gas_price_service_v0(
-        config.clone().into(),
-        genesis_block_height,
-        settings,
-        block_stream,
-        database.gas_price().clone(),
-        database.on_chain().clone(),
-    )?;
-
-    let gas_price_provider =
-        FuelGasPriceProvider::new(gas_price_service_v0.shared.clone());
-    let txpool = fuel_core_txpool::new_service(
-        config.txpool.clone(),
-        database.on_chain().clone(),
-        importer_adapter.clone(),
-        p2p_adapter.clone(),
-        executor.clone(),
-        last_height,
-        gas_price_provider.clone(),
-        consensus_parameters_provider.clone(),
-        SharedMemoryPool::new(config.memory_pool_size),
-    );
-    let tx_pool_adapter = TxPoolAdapter::new(txpool.shared.clone());
+    let (gas_price_service, gas_price_provider): (
+        Box<dyn ServiceTrait + Send + Sync>,
+        _,
+    ) = if let Some(gas_price_v1_config) = &config.gas_price_v1 {
+        let da_source_service = da_source_service::service::new_service(
+            da_block_costs_source(&gas_price_v1_config.da_source),
+            gas_price_v1_config.da_poll_interval,
+        );
+        let gas_price_service_v1 = new_gas_price_service_v1(
+            v1_algorithm_config(config, gas_price_v1_config),
+            genesis_block_height,
+            settings,
+            block_stream,
+            database.gas_price().clone(),
+            database.on_chain().clone(),
+            da_source_service,
+        )?;
+        let gas_price_provider = VersionedGasPriceProvider::V1(
+            FuelGasPriceProvider::new(gas_price_service_v1.shared.clone()),
+        );
+        (Box::new(gas_price_service_v1), gas_price_provider)
+    } else {
+        let gas_price_service_v0 = new_gas_price_service_v0(
+            config.clone().into(),
+            genesis_block_height,
+            settings,
+            block_stream,
+            database.gas_price().clone(),
+            database.on_chain().clone(),
+        )?;
+        let gas_price_provider = VersionedGasPriceProvider::V0(
+            FuelGasPriceProvider::new(gas_price_service_v0.shared.clone()),
+        );
+        (Box::new(gas_price_service_v0), gas_price_provider)
+    };
+    let (txpool, txpool_shared_state): (Box<dyn ServiceTrait + Send + Sync>, _) =
+        if let Some(txpool_v2_config) = &config.txpool_v2 {
+            let tx_status_sender = TxStatusSender::new(TxStatusChange::new(
+                config.txpool.number_of_active_subscription,
+                // Subscribers should receive the `SqueezedOut` event produced by the TTL.
+                txpool_v2_config.max_txs_ttl.saturating_mul(2),
+            ));
+            let txpool = fuel_core_txpool_v2::new_service(
+                txpool_v2_config.clone(),
+                p2p_adapter.clone(),
+                importer_adapter.clone(),
+                database.on_chain().clone(),
+                consensus_parameters_provider.clone(),
+                last_height,
+                gas_price_provider.clone(),
+                executor.clone(),
+                SharedMemoryPool::new(config.memory_pool_size),
+                tx_status_sender.clone(),
+                database.off_chain().clone(),
+            );
+            let shared_state = TxPoolSharedState::V2 {
+                service: txpool.shared.clone(),
+                tx_status_sender,
+            };
+            (Box::new(txpool), shared_state)
+        } else {
+            let txpool = fuel_core_txpool::new_service(
+                config.txpool.clone(),
+                database.on_chain().clone(),
+                importer_adapter.clone(),
+                p2p_adapter.clone(),
+                executor.clone(),
+                last_height,
+                gas_price_provider.clone(),
+                consensus_parameters_provider.clone(),
+                SharedMemoryPool::new(config.memory_pool_size),
+            );
+            let shared_state = TxPoolSharedState::V1(txpool.shared.clone());
+            (Box::new(txpool), shared_state)
+        };
+    let tx_pool_adapter = TxPoolAdapter::new(txpool_shared_state.clone());
 
     #[cfg(feature = "p2p")]
     let mut network = config.p2p.clone().zip(p2p_externals).map(
@@ -248,6 +334,13 @@ pub fn init_sub_services(
         tracing::info!("Enabled manual block production because of `debug` flag");
     }
 
+    if production_enabled && config.da_import.is_some() {
+        production_enabled = false;
+        tracing::info!(
+            "Disabled block production because blocks are imported from the DA"
+        );
+    }
+
     let predefined_blocks =
         InDirectoryPredefinedBlocks::new(config.predefined_blocks_path.clone());
     let poa = (production_enabled).then(|| {
@@ -265,18 +358,25 @@ pub fn init_sub_services(
     });
     let poa_adapter = PoAAdapter::new(poa.as_ref().map(|service| service.shared.clone()));
 
+    // The blocks imported from the DA are the only source of the chain,
+    // so the blocks from the P2P peers are not synchronized.
     #[cfg(feature = "p2p")]
-    let sync = fuel_core_sync::service::new_service(
-        last_height,
-        p2p_adapter.clone(),
-        importer_adapter.clone(),
-        super::adapters::ConsensusAdapter::new(
-            verifier.clone(),
-            config.relayer_consensus_config.clone(),
-            relayer_adapter,
-        ),
-        config.sync,
-    )?;
+    let sync = if config.da_import.is_some() {
+        tracing::info!("Disabled P2P sync because blocks are imported from the DA");
+        None
+    } else {
+        Some(fuel_core_sync::service::new_service(
+            last_height,
+            p2p_adapter.clone(),
+            importer_adapter.clone(),
+            super::adapters::ConsensusAdapter::new(
+                verifier.clone(),
+                config.relayer_consensus_config.clone(),
+                relayer_adapter.clone(),
+            ),
+            config.sync,
+        )?)
+    };
 
     let schema = crate::schema::dap::init(build_schema(), config.debug)
         .data(database.on_chain().clone());
@@ -293,6 +393,67 @@ pub fn init_sub_services(
         config.continue_on_error,
     );
 
+    let da_import = config
+        .da_import
+        .clone()
+        .map(|da_import_config| {
+            super::da_import::new_service(
+                da_import_config,
+                database.on_chain().clone(),
+                super::da_import::open_database(&config.combined_db_config)?,
+                executor.clone(),
+                importer_adapter.clone(),
+                verifier.clone(),
+                relayer_adapter.clone(),
+            )
+        })
+        .transpose()?;
+
+    let pruning = config.pruning.clone().map(|pruning_config| {
+        super::pruning::new_service(
+            database.on_chain().clone(),
+            database.off_chain().clone(),
+            pruning_config,
+        )
+    });
+
+    #[allow(unused_mut)]
+    let mut health_services = vec![
+        ("GasPrice", gas_price_service.state_watcher()),
+        ("TxPool", txpool.state_watcher()),
+        (
+            "ConsensusParametersProvider",
+            consensus_parameters_provider_service.state_watcher(),
+        ),
+    ];
+    if let Some(poa) = &poa {
+        health_services.push(("PoA", poa.state_watcher()));
+    }
+    #[cfg(feature = "relayer")]
+    if let Some(relayer) = &relayer_service {
+        health_services.push(("Relayer", relayer.state_watcher()));
+    }
+    #[cfg(feature = "p2p")]
+    if let Some(network) = &network {
+        health_services.push(("P2P", network.state_watcher()));
+        if let Some(sync) = &sync {
+            health_services.push(("Sync", sync.state_watcher()));
+        }
+    }
+    health_services.push(("GraphQL_Off_Chain_Worker", graphql_worker.state_watcher()));
+    if let Some(da_import) = &da_import {
+        health_services.push(("DaImport", da_import.state_watcher()));
+    }
+    if let Some(pruning) = &pruning {
+        health_services.push(("Pruning", pruning.state_watcher()));
+    }
+    let health_adapter = NodeHealthAdapter::new(
+        health_services,
+        database.clone(),
+        p2p_adapter.clone(),
+        relayer_adapter,
+    );
+
     let graphql_config = GraphQLConfig {
         config: config.graphql_config.clone(),
         utxo_validation: config.utxo_validation,
@@ -316,11 +477,17 @@ pub fn init_sub_services(
         Box::new(gas_price_provider),
         Box::new(consensus_parameters_provider),
         SharedMemoryPool::new(config.memory_pool_size),
+        graphql_worker.shared.clone(),
+        Arc::new(health_adapter),
+        Box::new(DatabaseCheckpointAdapter::new(
+            database.clone(),
+            importer_adapter.clone(),
+        )),
     )?;
 
     let shared = SharedState {
         poa_adapter,
-        txpool_shared_state: txpool.shared.clone(),
+        txpool_shared_state,
         #[cfg(feature = "p2p")]
         network: network.as_ref().map(|n| n.shared.clone()),
         #[cfg(feature = "relayer")]
@@ -335,8 +502,8 @@ pub fn init_sub_services(
     #[allow(unused_mut)]
     // `FuelService` starts and shutdowns all sub-services in the `services` order
     let mut services: SubServices = vec![
-        Box::new(gas_price_service_v0),
-        Box::new(txpool),
+        gas_price_service,
+        txpool,
         Box::new(consensus_parameters_provider_service),
     ];
 
@@ -353,12 +520,22 @@ pub fn init_sub_services(
     {
         if let Some(network) = network.take() {
             services.push(Box::new(network));
-            services.push(Box::new(sync));
+            if let Some(sync) = sync {
+                services.push(Box::new(sync));
+            }
         }
     }
 
     services.push(Box::new(graph_ql));
     services.push(Box::new(graphql_worker));
 
+    if let Some(da_import) = da_import {
+        services.push(Box::new(da_import));
+    }
+
+    if let Some(pruning) = pruning {
+        services.push(Box::new(pruning));
+    }
+
     Ok((services, shared))
 }
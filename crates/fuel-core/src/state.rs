@@ -23,7 +23,10 @@ use fuel_core_storage::{
     transactional::Changes,
     Result as StorageResult,
 };
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    path::Path,
+};
 
 pub mod data_source;
 pub mod generic_database;
@@ -69,11 +72,18 @@ pub trait TransactableStorage<Height>: IterableStore + Debug + Send + Sync {
     fn view_at_height(
         &self,
         height: &Height,
-    ) -> StorageResult<KeyValueView<Self::Column>>;
+    ) -> StorageResult<IterableKeyValueView<Self::Column>>;
 
     fn latest_view(&self) -> StorageResult<IterableKeyValueView<Self::Column>>;
 
     fn rollback_block_to(&self, height: &Height) -> StorageResult<()>;
+
+    /// Returns the height of the oldest block whose changes are stored in the history.
+    /// The storage can be rolled back to the height before it.
+    fn oldest_changes_height(&self) -> StorageResult<Option<u64>>;
+
+    /// Creates a consistent on-disk checkpoint of the storage in the `path` directory.
+    fn checkpoint(&self, path: &Path) -> StorageResult<()>;
 }
 
 // It is used only to allow conversion of the `StorageTransaction` into the `DataSource`.
@@ -87,7 +97,10 @@ where
         unimplemented!()
     }
 
-    fn view_at_height(&self, _: &Height) -> StorageResult<KeyValueView<Self::Column>> {
+    fn view_at_height(
+        &self,
+        _: &Height,
+    ) -> StorageResult<IterableKeyValueView<Self::Column>> {
         unimplemented!()
     }
 
@@ -98,6 +111,14 @@ where
     fn rollback_block_to(&self, _: &Height) -> StorageResult<()> {
         unimplemented!()
     }
+
+    fn oldest_changes_height(&self) -> StorageResult<Option<u64>> {
+        unimplemented!()
+    }
+
+    fn checkpoint(&self, _: &Path) -> StorageResult<()> {
+        unimplemented!()
+    }
 }
 
 /// A type that allows to iterate over the `Changes`.
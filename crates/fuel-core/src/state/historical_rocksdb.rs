@@ -18,11 +18,9 @@ use crate::{
             view_at_height::ViewAtHeight,
         },
         iterable_key_value_view::IterableKeyValueViewWrapper,
-        key_value_view::KeyValueViewWrapper,
         rocks_db::RocksDb,
         ColumnType,
         IterableKeyValueView,
-        KeyValueView,
         TransactableStorage,
     },
 };
@@ -246,17 +244,6 @@ where
         Ok(())
     }
 
-    fn oldest_changes_height(&self) -> StorageResult<Option<u64>> {
-        let oldest_height = self
-            .db
-            .iter_all_keys::<ModificationsHistory<Description>>(Some(
-                IterDirection::Forward,
-            ))
-            .next()
-            .transpose()?;
-        Ok(oldest_height)
-    }
-
     #[cfg(test)]
     // TODO: This method doesn't work properly because of
     //  https://github.com/FuelLabs/fuel-core/issues/2095
@@ -453,9 +440,11 @@ where
     fn view_at_height(
         &self,
         height: &Description::Height,
-    ) -> StorageResult<KeyValueView<ColumnType<Description>>> {
+    ) -> StorageResult<IterableKeyValueView<ColumnType<Description>>> {
         let view = self.create_view_at(height)?;
-        Ok(KeyValueView::from_storage(KeyValueViewWrapper::new(view)))
+        Ok(IterableKeyValueView::from_storage(
+            IterableKeyValueViewWrapper::new(view),
+        ))
     }
 
     fn latest_view(
@@ -470,6 +459,22 @@ where
     fn rollback_block_to(&self, height: &Description::Height) -> StorageResult<()> {
         self.rollback_block_to(height.as_u64())
     }
+
+    fn oldest_changes_height(&self) -> StorageResult<Option<u64>> {
+        let oldest_height = self
+            .db
+            .iter_all_keys::<ModificationsHistory<Description>>(Some(
+                IterDirection::Forward,
+            ))
+            .next()
+            .transpose()?;
+        Ok(oldest_height)
+    }
+
+    fn checkpoint(&self, path: &Path) -> StorageResult<()> {
+        self.db.checkpoint(path)?;
+        Ok(())
+    }
 }
 
 pub fn height_key(key: &[u8], height: &u64) -> Vec<u8> {
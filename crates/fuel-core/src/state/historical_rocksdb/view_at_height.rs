@@ -16,23 +16,43 @@ use crate::{
     },
 };
 use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+        IterableStore,
+    },
     kv_store::{
+        KVItem,
+        Key,
+        KeyItem,
         KeyValueInspect,
         Value,
         WriteOperation,
     },
     Result as StorageResult,
 };
+use itertools::{
+    EitherOrBoth,
+    Itertools,
+};
 use rocksdb::{
     IteratorMode,
     ReadOptions,
 };
+use std::cmp::Ordering;
 
 pub struct ViewAtHeight<Description> {
     height: u64,
     read_db: RocksDb<Historical<Description>>,
 }
 
+/// Returns the original key of the key in the modifications history.
+fn original_key(height_key: &[u8]) -> &[u8] {
+    let key_len = height_key.len().saturating_sub(8);
+    &height_key[..key_len]
+}
+
 impl<Description> ViewAtHeight<Description>
 where
     Description: DatabaseDescription,
@@ -40,19 +60,18 @@ where
     pub fn new(height: u64, read_db: RocksDb<Historical<Description>>) -> Self {
         Self { height, read_db }
     }
-}
 
-impl<Description> KeyValueInspect for ViewAtHeight<Description>
-where
-    Description: DatabaseDescription,
-{
-    type Column = Description::Column;
-
-    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
-        let read_history = &self.read_db;
+    /// Returns the operation that restores the `key` of the `column` to its state
+    /// at the height of the view, or `None` if it wasn't modified after that height.
+    fn restoring_operation(
+        &self,
+        key: &[u8],
+        column: Description::Column,
+    ) -> StorageResult<Option<WriteOperation>> {
         let height_key = height_key(key, &self.height);
         let options = ReadOptions::default();
-        let nearest_modification = read_history
+        let nearest_modification = self
+            .read_db
             .iterator::<KeyAndValue>(
                 Column::HistoricalDuplicateColumn(column),
                 options,
@@ -62,19 +81,155 @@ where
 
         if let Some(upper_bound) = nearest_modification {
             let (found_height_key, value) = upper_bound?;
-            let found_key = found_height_key.as_slice();
 
-            if &found_key[..key.len()] == key {
-                let value = deserialize(&value)?;
+            if original_key(&found_height_key) == key {
+                return Ok(Some(deserialize(&value)?))
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the operations that restore the entries of the `column` modified after
+    /// the height of the view to their state at that height, sorted in the `direction`.
+    /// The modifications history is iterated lazily by seeking to the next modified key.
+    fn restoring_operations(
+        &self,
+        column: Description::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> impl Iterator<Item = StorageResult<(Key, WriteOperation)>> + '_ {
+        let prefix = prefix.map(<[u8]>::to_vec);
+        // The keys in the history are followed by the height,
+        // so the seek in reverse order starts after all modifications of the `start`.
+        let mut seek_from = start.map(|start| match direction {
+            IterDirection::Forward => start.to_vec(),
+            IterDirection::Reverse => height_key(start, &u64::MAX),
+        });
+        let mut previous_key: Option<Key> = None;
+        let mut finished = false;
+
+        core::iter::from_fn(move || {
+            while !finished {
+                let next_key = self
+                    .read_db
+                    .iter_store_keys(
+                        Column::HistoricalDuplicateColumn(column),
+                        prefix.as_deref(),
+                        seek_from.as_deref(),
+                        direction,
+                    )
+                    .find(|height_key| match (height_key, &previous_key) {
+                        (Ok(height_key), Some(previous_key)) => {
+                            original_key(height_key) != previous_key.as_slice()
+                        }
+                        _ => true,
+                    });
+
+                let key = match next_key {
+                    Some(Ok(height_key)) => original_key(&height_key).to_vec(),
+                    Some(Err(err)) => {
+                        finished = true;
+                        return Some(Err(err))
+                    }
+                    None => {
+                        finished = true;
+                        return None
+                    }
+                };
+
+                // Skips the remaining modifications of the `key` with the next seek.
+                seek_from = Some(match direction {
+                    IterDirection::Forward => height_key(&key, &u64::MAX),
+                    IterDirection::Reverse => height_key(&key, &0),
+                });
+                previous_key = Some(key.clone());
 
-                return match value {
-                    WriteOperation::Insert(value) => Ok(Some(value)),
-                    WriteOperation::Remove => Ok(None),
+                match self.restoring_operation(&key, column) {
+                    Ok(Some(operation)) => return Some(Ok((key, operation))),
+                    Ok(None) => continue,
+                    Err(err) => {
+                        finished = true;
+                        return Some(Err(err))
+                    }
                 }
             }
+            None
+        })
+    }
+}
+
+impl<Description> KeyValueInspect for ViewAtHeight<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Column = Description::Column;
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        match self.restoring_operation(key, column)? {
+            Some(WriteOperation::Insert(value)) => Ok(Some(value)),
+            Some(WriteOperation::Remove) => Ok(None),
+            None => self.read_db.get(key, Column::OriginalColumn(column)),
         }
+    }
+}
+
+impl<Description> IterableStore for ViewAtHeight<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_store(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        let latest = self.read_db.iter_store(
+            Column::OriginalColumn(column),
+            prefix,
+            start,
+            direction,
+        );
+        let restored = self.restoring_operations(column, prefix, start, direction);
+
+        // Both iterators are sorted in the `direction`, and the restored
+        // state of the key replaces its latest state.
+        latest
+            .merge_join_by(restored, move |latest, restored| match (latest, restored) {
+                (Ok((latest, _)), Ok((restored, _))) => match direction {
+                    IterDirection::Forward => latest.cmp(restored),
+                    IterDirection::Reverse => restored.cmp(latest),
+                },
+                (Err(_), _) => Ordering::Less,
+                (_, Err(_)) => Ordering::Greater,
+            })
+            .filter_map(|entry| match entry {
+                EitherOrBoth::Left(latest) => Some(latest),
+                EitherOrBoth::Right(restored) | EitherOrBoth::Both(_, restored) => {
+                    match restored {
+                        Ok((key, WriteOperation::Insert(value))) => {
+                            Some(Ok((key, value)))
+                        }
+                        Ok((_, WriteOperation::Remove)) => None,
+                        Err(err) => Some(Err(err)),
+                    }
+                }
+            })
+            .into_boxed()
+    }
 
-        self.read_db.get(key, Column::OriginalColumn(column))
+    fn iter_store_keys(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KeyItem> {
+        self.iter_store(column, prefix, start, direction)
+            .map(|entry| entry.map(|(key, _)| key))
+            .into_boxed()
     }
 }
 
@@ -92,6 +247,7 @@ mod tests {
         },
     };
     use fuel_core_storage::{
+        iter::IteratorOverTable,
         tables::ContractsAssets,
         transactional::{
             IntoTransaction,
@@ -261,4 +417,143 @@ mod tests {
         assert_eq!(balance_at_height_two, 321);
         assert_eq!(balance_at_height_three, 321);
     }
+
+    #[test]
+    fn historical_rocksdb_view_at_height_iterates_over_state_at_that_height() {
+        // Given
+        let rocks_db = RocksDb::<Historical<OnChain>>::default_open_temp(None).unwrap();
+        let historical_rocks_db =
+            HistoricalRocksDB::new(rocks_db, StateRewindPolicy::RewindFullRange).unwrap();
+        let first_key = ContractsAssetKey::new(&[1; 32].into(), &[1; 32].into());
+        let second_key = ContractsAssetKey::new(&[2; 32].into(), &[2; 32].into());
+        let third_key = ContractsAssetKey::new(&[3; 32].into(), &[3; 32].into());
+
+        // Insert the first and the second entries at height 1.
+        let mut transaction = historical_rocks_db.read_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&first_key, &1)
+            .unwrap();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&second_key, &2)
+            .unwrap();
+        historical_rocks_db
+            .commit_changes(Some(1u32.into()), transaction.into_changes())
+            .unwrap();
+
+        // Remove the first entry, modify the second and insert the third at height 2.
+        let mut transaction = historical_rocks_db.read_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .remove(&first_key)
+            .unwrap();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&second_key, &20)
+            .unwrap();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&third_key, &30)
+            .unwrap();
+        historical_rocks_db
+            .commit_changes(Some(2u32.into()), transaction.into_changes())
+            .unwrap();
+
+        // When
+        let view_at_height_one =
+            historical_rocks_db.create_view_at(&1u32.into()).unwrap();
+        let view_at_height_two =
+            historical_rocks_db.create_view_at(&2u32.into()).unwrap();
+        let entries_at_height_one: Vec<_> = view_at_height_one
+            .iter_all::<ContractsAssets>(None)
+            .try_collect()
+            .unwrap();
+        let reversed_entries_at_height_one: Vec<_> = view_at_height_one
+            .iter_all::<ContractsAssets>(Some(IterDirection::Reverse))
+            .try_collect()
+            .unwrap();
+        let entries_at_height_two: Vec<_> = view_at_height_two
+            .iter_all::<ContractsAssets>(None)
+            .try_collect()
+            .unwrap();
+
+        // Then
+        assert_eq!(entries_at_height_one, vec![(first_key, 1), (second_key, 2)]);
+        assert_eq!(
+            reversed_entries_at_height_one,
+            vec![(second_key, 2), (first_key, 1)]
+        );
+        assert_eq!(
+            entries_at_height_two,
+            vec![(second_key, 20), (third_key, 30)]
+        );
+    }
+
+    #[test]
+    fn historical_rocksdb_view_at_height_iterates_from_start_key() {
+        // Given
+        let rocks_db = RocksDb::<Historical<OnChain>>::default_open_temp(None).unwrap();
+        let historical_rocks_db =
+            HistoricalRocksDB::new(rocks_db, StateRewindPolicy::RewindFullRange).unwrap();
+        let first_key = ContractsAssetKey::new(&[1; 32].into(), &[1; 32].into());
+        let second_key = ContractsAssetKey::new(&[2; 32].into(), &[2; 32].into());
+        let third_key = ContractsAssetKey::new(&[3; 32].into(), &[3; 32].into());
+
+        // Insert all entries at height 1.
+        let mut transaction = historical_rocks_db.read_transaction();
+        for (key, value) in [(first_key, 1), (second_key, 2), (third_key, 3)] {
+            transaction
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&key, &value)
+                .unwrap();
+        }
+        historical_rocks_db
+            .commit_changes(Some(1u32.into()), transaction.into_changes())
+            .unwrap();
+
+        // Modify the first and the third entries and remove the second at heights 2 and 3.
+        for (height, first_value, third_value) in [(2u32, 10, 30), (3, 100, 300)] {
+            let mut transaction = historical_rocks_db.read_transaction();
+            transaction
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&first_key, &first_value)
+                .unwrap();
+            transaction
+                .storage_as_mut::<ContractsAssets>()
+                .remove(&second_key)
+                .unwrap();
+            transaction
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&third_key, &third_value)
+                .unwrap();
+            historical_rocks_db
+                .commit_changes(Some(height.into()), transaction.into_changes())
+                .unwrap();
+        }
+
+        // When
+        let view_at_height_one =
+            historical_rocks_db.create_view_at(&1u32.into()).unwrap();
+        let forward_from_second: Vec<_> = view_at_height_one
+            .iter_all_filtered::<ContractsAssets, [u8; 0]>(
+                None,
+                Some(&second_key),
+                Some(IterDirection::Forward),
+            )
+            .try_collect()
+            .unwrap();
+        let reverse_from_second: Vec<_> = view_at_height_one
+            .iter_all_filtered::<ContractsAssets, [u8; 0]>(
+                None,
+                Some(&second_key),
+                Some(IterDirection::Reverse),
+            )
+            .try_collect()
+            .unwrap();
+
+        // Then
+        assert_eq!(forward_from_second, vec![(second_key, 2), (third_key, 3)]);
+        assert_eq!(reverse_from_second, vec![(second_key, 2), (first_key, 1)]);
+    }
 }
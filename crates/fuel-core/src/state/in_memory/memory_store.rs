@@ -8,7 +8,6 @@ use crate::{
         iterable_key_value_view::IterableKeyValueViewWrapper,
         IterDirection,
         IterableKeyValueView,
-        KeyValueView,
         TransactableStorage,
     },
 };
@@ -38,6 +37,7 @@ use std::{
     collections::BTreeMap,
     fmt::Debug,
     ops::Deref,
+    path::Path,
     sync::Mutex,
 };
 
@@ -191,7 +191,7 @@ where
     fn view_at_height(
         &self,
         _: &Description::Height,
-    ) -> StorageResult<KeyValueView<Self::Column>> {
+    ) -> StorageResult<IterableKeyValueView<Self::Column>> {
         // TODO: https://github.com/FuelLabs/fuel-core/issues/1995
         Err(
             anyhow::anyhow!("The historical view is not implemented for `MemoryStore`")
@@ -213,6 +213,15 @@ where
                 .into(),
         )
     }
+
+    fn oldest_changes_height(&self) -> StorageResult<Option<u64>> {
+        // The `MemoryStore` doesn't store the history of changes.
+        Ok(None)
+    }
+
+    fn checkpoint(&self, _: &Path) -> StorageResult<()> {
+        Err(anyhow::anyhow!("The checkpoint is not supported by `MemoryStore`").into())
+    }
 }
 
 #[cfg(test)]
@@ -401,6 +401,21 @@ where
         }
     }
 
+    /// Creates a consistent checkpoint of the database in the `path` directory.
+    /// The checkpoint is stored in the sub-directory named after the database,
+    /// so the `path` can be opened the same way as the original database.
+    /// If the `path` is on the same filesystem, the data files are hard-linked
+    /// instead of copied.
+    pub fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        std::fs::create_dir_all(path).map_err(|e| DatabaseError::Other(e.into()))?;
+        let checkpoint = rocksdb::checkpoint::Checkpoint::new(&self.db)
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        checkpoint
+            .create_checkpoint(path.join(Description::name()))
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(())
+    }
+
     fn cf(&self, column: Description::Column) -> Arc<BoundColumnFamily> {
         self.cf_u32(column.id())
     }
@@ -24,6 +24,7 @@ pub mod future_tracker;
 pub mod graphql_metrics;
 pub mod importer;
 pub mod p2p_metrics;
+pub mod pruning_metrics;
 pub mod services;
 pub mod txpool_metrics;
 
@@ -0,0 +1,68 @@
+use crate::{
+    global_registry,
+    timing_buckets,
+};
+use prometheus_client::metrics::{
+    counter::Counter,
+    gauge::Gauge,
+    histogram::Histogram,
+};
+use std::sync::OnceLock;
+
+pub struct PruningMetrics {
+    /// The height of the last pruned block.
+    pub pruned_height: Gauge,
+    /// The number of blocks that are eligible for pruning but not pruned yet.
+    pub blocks_to_prune: Gauge,
+    /// The total number of pruned blocks since the start of the node.
+    pub pruned_blocks: Counter,
+    /// The time spent on pruning of one batch of blocks.
+    pub batch_duration: Histogram,
+}
+
+impl Default for PruningMetrics {
+    fn default() -> Self {
+        let pruned_height = Gauge::default();
+        let blocks_to_prune = Gauge::default();
+        let pruned_blocks = Counter::default();
+        let batch_duration = Histogram::new(timing_buckets().iter().cloned());
+
+        let mut registry = global_registry().registry.lock();
+        registry.register(
+            "pruning_pruned_height",
+            "The height of the last block pruned by the pruning service",
+            pruned_height.clone(),
+        );
+
+        registry.register(
+            "pruning_blocks_to_prune",
+            "The number of blocks outside of the retention window that are not pruned yet",
+            blocks_to_prune.clone(),
+        );
+
+        registry.register(
+            "pruning_pruned_blocks",
+            "The number of blocks pruned since the start of the node",
+            pruned_blocks.clone(),
+        );
+
+        registry.register(
+            "pruning_batch_duration_s",
+            "Records the duration time of pruning one batch of blocks",
+            batch_duration.clone(),
+        );
+
+        Self {
+            pruned_height,
+            blocks_to_prune,
+            pruned_blocks,
+            batch_duration,
+        }
+    }
+}
+
+static PRUNING_METRICS: OnceLock<PruningMetrics> = OnceLock::new();
+
+pub fn pruning_metrics() -> &'static PruningMetrics {
+    PRUNING_METRICS.get_or_init(PruningMetrics::default)
+}
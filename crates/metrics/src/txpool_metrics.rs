@@ -1,9 +1,14 @@
 use crate::global_registry;
-use prometheus_client::metrics::histogram::Histogram;
+use prometheus_client::metrics::{
+    counter::Counter,
+    histogram::Histogram,
+};
 use std::sync::OnceLock;
 
 pub struct TxPoolMetrics {
     pub tx_size_histogram: Histogram,
+    pub replaced_txs: Counter,
+    pub rejected_replacements: Counter,
 }
 
 impl Default for TxPoolMetrics {
@@ -11,8 +16,14 @@ impl Default for TxPoolMetrics {
         let tx_sizes = Vec::new();
 
         let tx_size_histogram = Histogram::new(tx_sizes.into_iter());
+        let replaced_txs = Counter::default();
+        let rejected_replacements = Counter::default();
 
-        let metrics = TxPoolMetrics { tx_size_histogram };
+        let metrics = TxPoolMetrics {
+            tx_size_histogram,
+            replaced_txs,
+            rejected_replacements,
+        };
 
         let mut registry = global_registry().registry.lock();
         registry.register(
@@ -21,6 +32,18 @@ impl Default for TxPoolMetrics {
             metrics.tx_size_histogram.clone(),
         );
 
+        registry.register(
+            "Replaced_Txs",
+            "A Counter which keeps track of the transactions replaced by transactions with a higher tip",
+            metrics.replaced_txs.clone(),
+        );
+
+        registry.register(
+            "Rejected_Replacements",
+            "A Counter which keeps track of the replacements rejected because of a too small tip bump",
+            metrics.rejected_replacements.clone(),
+        );
+
         metrics
     }
 }
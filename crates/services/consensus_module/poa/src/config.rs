@@ -1,3 +1,4 @@
+use fuel_core_chain_config::RoundRobinPoA;
 use fuel_core_types::fuel_types::ChainId;
 use tokio::time::Duration;
 
@@ -11,6 +12,9 @@ pub struct Config {
     pub min_connected_reserved_peers: usize,
     pub time_until_synced: Duration,
     pub chain_id: ChainId,
+    /// The schedule of the block producers taking turns. If set, the `Interval` trigger
+    /// produces blocks only in the slots of this node, using the slot duration of the schedule.
+    pub producer_schedule: Option<RoundRobinPoA>,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -23,6 +27,7 @@ impl Default for Config {
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
             chain_id: ChainId::default(),
+            producer_schedule: None,
         }
     }
 }
@@ -1,5 +1,6 @@
 use anyhow::{
     anyhow,
+    ensure,
     Context,
 };
 use std::{
@@ -33,9 +34,11 @@ use crate::{
         SyncState,
         SyncTask,
     },
+    verifier::MAX_BLOCK_TIME_DRIFT,
     Config,
     Trigger,
 };
+use fuel_core_chain_config::RoundRobinPoA;
 use fuel_core_services::{
     stream::{
         BoxFuture,
@@ -58,7 +61,10 @@ use fuel_core_types::{
         Transaction,
         TxId,
     },
-    fuel_types::BlockHeight,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
     services::{
         block_importer::ImportResult,
         executor::{
@@ -140,6 +146,9 @@ pub struct MainTask<T, B, I, S, PB, C> {
     last_block_created: Instant,
     predefined_blocks: PB,
     trigger: Trigger,
+    producer_schedule: Option<RoundRobinPoA>,
+    /// The address of this node in the `producer_schedule`
+    producer: Option<Address>,
     clock: C,
     /// Deadline clock, used by the triggers
     sync_task_handle: ServiceRunner<SyncTask>,
@@ -173,18 +182,33 @@ where
         let peer_connections_stream = p2p_port.reserved_peers_count();
 
         let Config {
+            signer: config_signer,
             min_connected_reserved_peers,
             time_until_synced,
             trigger,
+            producer_schedule,
             ..
         } = config;
 
+        let producer = producer_schedule.as_ref().and_then(|_| {
+            config_signer
+                .address()
+                .inspect_err(|err| {
+                    tracing::error!(
+                        "Unable to get the address of the block producer: {err}"
+                    )
+                })
+                .ok()
+                .flatten()
+        });
+
         let sync_task = SyncTask::new(
             peer_connections_stream,
             min_connected_reserved_peers,
             time_until_synced,
             block_stream,
             last_block,
+            producer_schedule.is_some(),
         );
 
         let sync_task_handle = ServiceRunner::new(sync_task);
@@ -202,6 +226,8 @@ where
             last_block_created,
             predefined_blocks,
             trigger,
+            producer_schedule,
+            producer,
             sync_task_handle,
             clock,
         }
@@ -227,6 +253,15 @@ where
             .expect("It should be impossible to produce more blocks than u32::MAX")
     }
 
+    /// Returns the time after the previous block when this node may produce the next
+    /// block according to the `schedule`, or `None` if it may not produce it.
+    fn scheduled_delay(&self, schedule: &RoundRobinPoA) -> Option<Duration> {
+        let producer = self.producer.as_ref()?;
+        schedule
+            .production_delay(self.next_height(), producer)
+            .map(Duration::from_secs)
+    }
+
     fn next_time(&self, request_type: RequestType) -> anyhow::Result<Tai64> {
         match request_type {
             RequestType::Manual => match self.trigger {
@@ -459,7 +494,31 @@ where
             }
             // In the Interval mode the timer expires only when a new block should be created.
             Trigger::Interval { .. } => {
-                self.produce_next_block().await?;
+                if let Some(schedule) = &self.producer_schedule {
+                    let delay = self.scheduled_delay(schedule).ok_or(anyhow!(
+                        "The node is not a scheduled producer of the next block"
+                    ))?;
+                    // The block can't be earlier than the slot of this node. The clock
+                    // of the previous producer may be ahead of the local clock, so
+                    // the block is stamped with the start of the slot if it is ahead
+                    // of the local time within the drift tolerated by other nodes.
+                    let earliest_time = increase_time(self.last_timestamp, delay)?;
+                    let now = self.clock.now();
+                    let block_time = core::cmp::max(now, earliest_time);
+                    ensure!(
+                        block_time.0 <= now.0.saturating_add(MAX_BLOCK_TIME_DRIFT),
+                        "The slot of this node for the height {} hasn't started yet",
+                        self.next_height()
+                    );
+                    self.produce_block(
+                        self.next_height(),
+                        block_time,
+                        TransactionsSource::TxPool,
+                    )
+                    .await?;
+                } else {
+                    self.produce_next_block().await?;
+                }
                 Ok(())
             }
         }
@@ -561,11 +620,21 @@ where
 
         let next_block_production: BoxFuture<()> = match self.trigger {
             Trigger::Never | Trigger::Instant => Box::pin(core::future::pending()),
-            Trigger::Interval { block_time } => Box::pin(sleep_until(
-                self.last_block_created
-                    .checked_add(block_time)
-                    .ok_or(anyhow!("Time exceeds system limits"))?,
-            )),
+            Trigger::Interval { block_time } => {
+                let delay = match &self.producer_schedule {
+                    Some(schedule) => self.scheduled_delay(schedule),
+                    None => Some(block_time),
+                };
+                match delay {
+                    Some(delay) => Box::pin(sleep_until(
+                        self.last_block_created
+                            .checked_add(delay)
+                            .ok_or(anyhow!("Time exceeds system limits"))?,
+                    )),
+                    // Other producers create blocks, while this node follows them
+                    None => Box::pin(core::future::pending()),
+                }
+            }
         };
 
         tokio::select! {
@@ -573,6 +642,11 @@ where
             _ = watcher.while_started() => {
                 should_continue = false;
             }
+            // The next block was imported from another producer of the schedule,
+            // so the time of the next slot has changed.
+            _ = sync_state.changed(), if self.producer_schedule.is_some() => {
+                should_continue = true;
+            }
             request = self.request_receiver.recv() => {
                 if let Some(request) = request {
                     match request {
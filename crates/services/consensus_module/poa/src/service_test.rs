@@ -21,7 +21,10 @@ use crate::{
     Trigger,
 };
 use async_trait::async_trait;
-use fuel_core_chain_config::default_consensus_dev_key;
+use fuel_core_chain_config::{
+    default_consensus_dev_key,
+    RoundRobinPoA,
+};
 use fuel_core_services::{
     stream::pending,
     Service as StorageTrait,
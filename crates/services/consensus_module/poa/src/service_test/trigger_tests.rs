@@ -1,8 +1,11 @@
+use fuel_core_services::stream::BoxStream;
+use fuel_core_types::services::block_importer::BlockImportInfo;
 use mockall::Sequence;
 use tokio::{
     sync::Notify,
     time::Instant,
 };
+use tokio_stream::wrappers::UnboundedReceiverStream;
 
 use super::*;
 
@@ -81,6 +84,13 @@ struct DefaultContext {
 
 impl DefaultContext {
     fn new(config: Config) -> Self {
+        Self::new_with_block_stream(config, Box::pin(tokio_stream::pending()))
+    }
+
+    fn new_with_block_stream(
+        config: Config,
+        block_stream: BoxStream<BlockImportInfo>,
+    ) -> Self {
         let mut rng = StdRng::seed_from_u64(1234u64);
         let mut ctx_builder = TestContextBuilder::new();
         ctx_builder.with_config(config);
@@ -101,15 +111,21 @@ impl DefaultContext {
             block_import_sender.send(sealed_block)?;
             Ok(())
         });
-        importer
-            .expect_block_stream()
-            .returning(|| Box::pin(tokio_stream::pending()));
+        let block_stream = StdMutex::new(Some(block_stream));
+        importer.expect_block_stream().returning(move || {
+            block_stream
+                .lock()
+                .unwrap()
+                .take()
+                .unwrap_or_else(|| Box::pin(tokio_stream::pending()))
+        });
 
         let mut block_producer = MockBlockProducer::default();
         block_producer
             .expect_produce_and_execute_block()
-            .returning(|_, time, _| {
+            .returning(|height, time, _| {
                 let mut block = Block::default();
+                block.header_mut().set_block_height(height);
                 block.header_mut().set_time(time);
                 block.header_mut().recalculate_metadata();
                 Ok(UncommittedResult::new(
@@ -234,6 +250,198 @@ async fn interval_trigger_produces_blocks_periodically() -> anyhow::Result<()> {
     Ok(())
 }
 
+fn producer_schedule(producers: Vec<Address>) -> RoundRobinPoA {
+    let slot_duration = 1;
+    let takeover_timeout = 1;
+    RoundRobinPoA::new(producers, slot_duration, takeover_timeout)
+}
+
+#[tokio::test]
+async fn interval_trigger__with_producer_schedule__takes_over_after_timeout(
+) -> anyhow::Result<()> {
+    // Given
+    let signer = SignMode::Key(test_signing_key());
+    let this_producer = signer.address()?.expect("The signer has the key");
+    // The other producer is scheduled for the next height 2
+    let other_producer = Address::from([1; 32]);
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Interval {
+            block_time: Duration::new(10, 0),
+        },
+        signer,
+        metrics: false,
+        producer_schedule: Some(producer_schedule(vec![other_producer, this_producer])),
+        ..Default::default()
+    });
+    let start_time = ctx.now();
+
+    // When
+    time::sleep(Duration::from_millis(1500)).await;
+
+    // Then
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // When
+    ctx.advance_time(Duration::new(2, 0));
+    time::sleep(Duration::from_millis(1000)).await;
+
+    // Then
+    let block = ctx.block_import.try_recv()?;
+    assert_eq!(block.entity.header().time(), Tai64(start_time.0 + 2));
+
+    // Stop
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn interval_trigger__with_producer_schedule__does_not_produce_if_not_a_producer(
+) -> anyhow::Result<()> {
+    // Given
+    let other_producer = Address::from([1; 32]);
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Interval {
+            block_time: Duration::new(1, 0),
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        producer_schedule: Some(producer_schedule(vec![other_producer])),
+        ..Default::default()
+    });
+
+    // When
+    time::sleep(Duration::new(3, 0)).await;
+
+    // Then
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // Stop
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn interval_trigger__with_producer_schedule__alternates_blocks_with_other_producer(
+) -> anyhow::Result<()> {
+    // Given
+    let signer = SignMode::Key(test_signing_key());
+    let this_producer = signer.address()?.expect("The signer has the key");
+    // The other producer is scheduled for even heights, this node for odd heights
+    let other_producer = Address::from([1; 32]);
+    let slot_duration = 1;
+    let takeover_timeout = 10;
+    let schedule = RoundRobinPoA::new(
+        vec![other_producer, this_producer],
+        slot_duration,
+        takeover_timeout,
+    );
+    let (network_blocks, block_stream) = tokio::sync::mpsc::unbounded_channel();
+    let mut ctx = DefaultContext::new_with_block_stream(
+        Config {
+            trigger: Trigger::Interval {
+                block_time: Duration::new(10, 0),
+            },
+            signer,
+            metrics: false,
+            producer_schedule: Some(schedule),
+            ..Default::default()
+        },
+        Box::pin(UnboundedReceiverStream::new(block_stream)),
+    );
+    let start_time = ctx.now();
+
+    for round in 0..2u32 {
+        // When
+        let other_height = 2 + 2 * round;
+        time::sleep(Duration::new(1, 0)).await;
+        ctx.advance_time_with_tokio();
+        let header = BlockHeader::new_block(other_height.into(), ctx.now());
+        network_blocks.send(BlockImportInfo::new_from_network(header))?;
+        time::sleep(Duration::new(1, 0)).await;
+        ctx.advance_time_with_tokio();
+        time::sleep(Duration::from_millis(1)).await;
+
+        // Then
+        let block = ctx.block_import.try_recv()?;
+        let this_height = other_height + 1;
+        assert_eq!(block.entity.header().height(), &this_height.into());
+        assert_eq!(
+            block.entity.header().time(),
+            Tai64(start_time.0 + u64::from(this_height - 1))
+        );
+        assert!(matches!(
+            ctx.block_import.try_recv(),
+            Err(broadcast::error::TryRecvError::Empty)
+        ));
+    }
+
+    // Stop
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn interval_trigger__with_producer_schedule__tolerates_clock_drift_of_other_producer(
+) -> anyhow::Result<()> {
+    // Given
+    let signer = SignMode::Key(test_signing_key());
+    let this_producer = signer.address()?.expect("The signer has the key");
+    let other_producer = Address::from([1; 32]);
+    let slot_duration = 1;
+    let takeover_timeout = 10;
+    let schedule = RoundRobinPoA::new(
+        vec![other_producer, this_producer],
+        slot_duration,
+        takeover_timeout,
+    );
+    let (network_blocks, block_stream) = tokio::sync::mpsc::unbounded_channel();
+    let mut ctx = DefaultContext::new_with_block_stream(
+        Config {
+            trigger: Trigger::Interval {
+                block_time: Duration::new(10, 0),
+            },
+            signer,
+            metrics: false,
+            producer_schedule: Some(schedule),
+            ..Default::default()
+        },
+        Box::pin(UnboundedReceiverStream::new(block_stream)),
+    );
+    time::sleep(Duration::new(1, 0)).await;
+    ctx.advance_time_with_tokio();
+    // The clock of the other producer is ahead of the local clock
+    let other_time = Tai64(ctx.now().0 + 1);
+    let header = BlockHeader::new_block(2u32.into(), other_time);
+    network_blocks.send(BlockImportInfo::new_from_network(header))?;
+
+    // When
+    time::sleep(Duration::new(1, 0)).await;
+    ctx.advance_time_with_tokio();
+    time::sleep(Duration::from_millis(1)).await;
+
+    // Then
+    let block = ctx.block_import.try_recv()?;
+    assert_eq!(block.entity.header().height(), &3u32.into());
+    assert_eq!(
+        block.entity.header().time(),
+        Tai64(other_time.0 + slot_duration)
+    );
+
+    // Stop
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn service__if_commit_result_fails_then_retry_commit_result_after_one_second(
 ) -> anyhow::Result<()> {
@@ -51,6 +51,10 @@ pub struct SyncTask {
     state_receiver: watch::Receiver<SyncState>,
     inner_state: InnerSyncState,
     timer: Option<tokio::time::Interval>,
+    /// The producers of the schedule take turns, so the blocks from the network
+    /// don't mean that the node is behind the chain. All imported blocks are
+    /// verified against the schedule, so they come from the authorized producers.
+    follow_producer_schedule: bool,
 }
 
 impl SyncTask {
@@ -60,6 +64,7 @@ impl SyncTask {
         time_until_synced: Duration,
         block_stream: BoxStream<BlockImportInfo>,
         block_header: &BlockHeader,
+        follow_producer_schedule: bool,
     ) -> Self {
         let inner_state = InnerSyncState::from_config(
             min_connected_reserved_peers,
@@ -91,6 +96,7 @@ impl SyncTask {
             state_receiver,
             inner_state,
             timer,
+            follow_producer_schedule,
         }
     }
 
@@ -185,7 +191,7 @@ impl RunnableTask for SyncTask {
                         self.restart_timer();
                     }
                     InnerSyncState::Synced { block_header, has_sufficient_peers } if new_block_height > block_header.height() => {
-                        if block_info.is_locally_produced() {
+                        if block_info.is_locally_produced() || self.follow_producer_schedule {
                             self.inner_state = InnerSyncState::Synced {
                                 block_header: block_info.block_header.clone(),
                                 has_sufficient_peers: *has_sufficient_peers
@@ -360,6 +366,7 @@ mod tests {
             time_until_synced,
             block_stream,
             &Default::default(),
+            false,
         );
 
         (sync_task, watcher, tx)
@@ -599,4 +606,45 @@ mod tests {
         ));
         matches!(*sync_task.state_receiver.borrow(), SyncState::Synced(_));
     }
+
+    // SyncTask follows the producer schedule and receives a block produced by another producer.
+    #[tokio::test]
+    async fn sync_task_with_producer_schedule_stays_synced_on_block_from_network() {
+        // given the SyncTask that is synced from the start and follows the schedule
+        let (mut sync_task, mut watcher, _tx) =
+            configure_sync_task(0, vec![], Duration::ZERO, 0);
+        sync_task.follow_producer_schedule = true;
+        assert!(matches!(
+            *sync_task.state_receiver.borrow(),
+            SyncState::Synced(_)
+        ));
+
+        // given that another producer of the schedule produced the next block
+        let latest_block_height = 1;
+        let new_block_stream = MockStream::new(vec![BlockHeader::new_block(
+            latest_block_height.into(),
+            Tai64::now(),
+        )])
+        .map(BlockImportInfo::new_from_network)
+        .into_boxed();
+        sync_task.block_stream = new_block_stream;
+
+        // when we run the task again
+        let _ = sync_task.run(&mut watcher).await;
+
+        // then the state should be still Synced with the block from the network
+        assert!(matches!(
+            sync_task.inner_state,
+            InnerSyncState::Synced { .. }
+        ));
+        assert_eq!(
+            sync_task.inner_state.block_height(),
+            &BlockHeight::from(latest_block_height)
+        );
+        let sync_state = sync_task.state_receiver.borrow().clone();
+        let SyncState::Synced(header) = sync_state else {
+            panic!("The node should stay synced")
+        };
+        assert_eq!(header.height(), &BlockHeight::from(latest_block_height));
+    }
 }
@@ -1,6 +1,12 @@
 use crate::ports::Database;
-use anyhow::ensure;
-use fuel_core_chain_config::ConsensusConfig;
+use anyhow::{
+    anyhow,
+    ensure,
+};
+use fuel_core_chain_config::{
+    ConsensusConfig,
+    RoundRobinPoA,
+};
 use fuel_core_types::{
     blockchain::{
         block::Block,
@@ -8,6 +14,7 @@ use fuel_core_types::{
         header::BlockHeader,
     },
     fuel_tx::Input,
+    tai64::Tai64,
 };
 
 #[cfg(test)]
@@ -37,9 +44,71 @@ pub fn verify_consensus(
                 .recover(m)
                 .map_or(false, |k| Input::owner(&k) == signing_key)
         }
+        ConsensusConfig::RoundRobinPoA(poa) => {
+            // Only the membership is verified here, because the producer allowed
+            // by the schedule depends on the previous block.
+            // See [`verify_producer_schedule`].
+            let id = header.id();
+            let m = id.as_message();
+            consensus
+                .signature
+                .recover(m)
+                .map_or(false, |k| poa.is_producer(&Input::owner(&k)))
+        }
     }
 }
 
+/// The number of seconds the time of the block produced according to the round-robin
+/// schedule may be ahead of the local clock, to tolerate the clock drift between nodes.
+pub const MAX_BLOCK_TIME_DRIFT: u64 = 2;
+
+/// Verifies that the block was produced by the producer allowed by the round-robin
+/// schedule: either the scheduled producer of the height after its slot,
+/// or the next producer in the rotation after its takeover timeout.
+///
+/// The producer chooses the time of the block, so the time is bounded by the local
+/// clock `now`. Otherwise, a producer could take over the slot of another producer
+/// by stamping the block with a time in the future.
+pub fn verify_producer_schedule<D: Database>(
+    database: &D,
+    schedule: &RoundRobinPoA,
+    header: &BlockHeader,
+    consensus: &PoAConsensus,
+    now: Tai64,
+) -> anyhow::Result<()> {
+    let height = *header.height();
+    let max_time = now.0.saturating_add(MAX_BLOCK_TIME_DRIFT);
+    ensure!(
+        header.time().0 <= max_time,
+        "The time of the block at the height {height} is ahead of the local time \
+        by more than {MAX_BLOCK_TIME_DRIFT} seconds"
+    );
+
+    let prev_height = height
+        .pred()
+        .ok_or(anyhow!("The PoA block can't have the zero height"))?;
+    let prev_header = database.block_header(&prev_height)?;
+
+    let id = header.id();
+    let producer = consensus
+        .signature
+        .recover(id.as_message())
+        .map(|k| Input::owner(&k))
+        .map_err(|_| anyhow!("Unable to recover the block producer"))?;
+
+    let delay = schedule
+        .production_delay(height, &producer)
+        .ok_or(anyhow!("The block producer {producer} is not authorized"))?;
+    let elapsed = header.time().0.saturating_sub(prev_header.time().0);
+    ensure!(
+        elapsed >= delay,
+        "The block producer {producer} is not allowed to produce the block at the height \
+        {height} {elapsed} seconds after the previous block, it should wait {delay} seconds"
+    );
+
+    Ok(())
+}
+
 pub fn verify_block_fields<D: Database>(
     database: &D,
     block: &Block,
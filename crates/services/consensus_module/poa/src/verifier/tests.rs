@@ -9,9 +9,15 @@ use fuel_core_types::{
         GeneratedConsensusFields,
         PartialBlockHeader,
     },
+    fuel_crypto::SecretKey,
     fuel_tx::Transaction,
+    fuel_vm::Signature,
     tai64::Tai64,
 };
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
 use test_case::test_case;
 
 struct Input {
@@ -118,3 +124,84 @@ fn test_verify_genesis_block_fields(input: Input) -> anyhow::Result<()> {
     *b.transactions_mut() = txs;
     verify_block_fields(&d, &b)
 }
+
+fn producer_keys() -> Vec<SecretKey> {
+    let mut rng = StdRng::seed_from_u64(2);
+    (0..4).map(|_| SecretKey::random(&mut rng)).collect()
+}
+
+fn round_robin(keys: &[SecretKey]) -> RoundRobinPoA {
+    // The last key is not an authorized producer
+    let producers = keys[..3]
+        .iter()
+        .map(|key| fuel_core_types::fuel_tx::Input::owner(&key.public_key()))
+        .collect();
+    RoundRobinPoA::new(producers, 2, 3)
+}
+
+fn signed_header(
+    height: u32,
+    time: Tai64,
+    key: &SecretKey,
+) -> (BlockHeader, PoAConsensus) {
+    let mut header = BlockHeader::default();
+    header.set_block_height(height.into());
+    header.set_time(time);
+    let signature = Signature::sign(key, &header.id().into_message());
+    (header, PoAConsensus::new(signature))
+}
+
+#[test_case(2, 2 => matches Ok(_) ; "scheduled producer after the slot")]
+#[test_case(2, 1 => matches Err(_) ; "scheduled producer before the end of the slot")]
+#[test_case(0, 5 => matches Ok(_) ; "next producer after the takeover timeout")]
+#[test_case(0, 4 => matches Err(_) ; "next producer before the takeover timeout")]
+#[test_case(1, 8 => matches Ok(_) ; "last producer after two takeover timeouts")]
+#[test_case(1, 7 => matches Err(_) ; "last producer before two takeover timeouts")]
+#[test_case(3, 100 => matches Err(_) ; "unauthorized producer")]
+fn test_verify_producer_schedule(producer: usize, elapsed: u64) -> anyhow::Result<()> {
+    let prev_time = Tai64(10);
+    let block_time = Tai64(prev_time.0.saturating_add(elapsed));
+    verify_schedule_at(producer, prev_time, block_time, block_time)
+}
+
+#[test_case(0 => matches Ok(_) ; "block time equal to the local time")]
+#[test_case(MAX_BLOCK_TIME_DRIFT => matches Ok(_) ; "block time within the drift")]
+#[test_case(MAX_BLOCK_TIME_DRIFT.saturating_add(1) => matches Err(_) ; "block time ahead of the drift")]
+fn test_verify_producer_schedule_with_block_time_ahead_of_local_time(
+    ahead: u64,
+) -> anyhow::Result<()> {
+    let prev_time = Tai64(10);
+    // The next producer is allowed to take over after 5 seconds
+    let block_time = Tai64(prev_time.0.saturating_add(5));
+    let now = Tai64(block_time.0.saturating_sub(ahead));
+    verify_schedule_at(0, prev_time, block_time, now)
+}
+
+fn verify_schedule_at(
+    producer: usize,
+    prev_time: Tai64,
+    block_time: Tai64,
+    now: Tai64,
+) -> anyhow::Result<()> {
+    let keys = producer_keys();
+    let schedule = round_robin(&keys);
+    // The producer with the index 2 is scheduled for the height 2
+    let (header, consensus) = signed_header(2, block_time, &keys[producer]);
+    let mut d = MockDatabase::default();
+    d.expect_block_header().returning(move |_| {
+        let mut h = BlockHeader::default();
+        h.set_time(prev_time);
+        Ok(h)
+    });
+    verify_producer_schedule(&d, &schedule, &header, &consensus, now)
+}
+
+#[test_case(0 => true ; "authorized producer")]
+#[test_case(2 => true ; "scheduled producer")]
+#[test_case(3 => false ; "unauthorized producer")]
+fn test_verify_round_robin_consensus(producer: usize) -> bool {
+    let keys = producer_keys();
+    let config = ConsensusConfig::RoundRobinPoA(round_robin(&keys));
+    let (header, consensus) = signed_header(2, Tai64(10), &keys[producer]);
+    verify_consensus(&config, &header, &consensus)
+}
@@ -3,6 +3,7 @@
 
 use crate::block_verifier::config::Config;
 use anyhow::ensure;
+use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_poa::ports::Database as PoAVerifierDatabase;
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
@@ -64,7 +65,21 @@ where
                     block.header(),
                 )
             }
-            Consensus::PoA(_) => {
+            Consensus::PoA(consensus) => {
+                let view = self.view_provider.latest_view()?;
+                fuel_core_poa::verifier::verify_block_fields(&view, block)?;
+                if let ConsensusConfig::RoundRobinPoA(schedule) = &self.config.consensus {
+                    fuel_core_poa::verifier::verify_producer_schedule(
+                        &view,
+                        schedule,
+                        block.header(),
+                        consensus,
+                        Tai64::now(),
+                    )?;
+                }
+                Ok(())
+            }
+            Consensus::DaImport(_) => {
                 let view = self.view_provider.latest_view()?;
                 fuel_core_poa::verifier::verify_block_fields(&view, block)
             }
@@ -85,6 +100,8 @@ where
                 header,
                 consensus,
             ),
+            // The block producer signature is unknown, so the block can't be verified.
+            Consensus::DaImport(_) => false,
             _ => false,
         }
     }
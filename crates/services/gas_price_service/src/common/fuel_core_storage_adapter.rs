@@ -38,6 +38,7 @@ use fuel_core_types::{
         },
         Transaction,
     },
+    fuel_types::canonical::Serialize,
 };
 use std::cmp::min;
 
@@ -101,10 +102,20 @@ pub fn get_block_info(
         height: (*block.header().height()).into(),
         gas_used: used_gas,
         block_gas_capacity: block_gas_limit,
+        block_bytes: block_bytes(block),
+        block_fees: fee,
     };
     Ok(info)
 }
 
+fn block_bytes(block: &Block<Transaction>) -> u64 {
+    block
+        .transactions()
+        .iter()
+        .map(|tx| u64::try_from(tx.size()).unwrap_or(u64::MAX))
+        .fold(0u64, |acc, size| acc.saturating_add(size))
+}
+
 fn mint_values(block: &Block<Transaction>) -> GasPriceResult<(u64, u64)> {
     let mint = block
         .transactions()
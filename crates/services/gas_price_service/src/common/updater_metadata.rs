@@ -1,16 +1,24 @@
-use crate::v0::metadata::V0Metadata;
+use crate::{
+    v0::metadata::V0Metadata,
+    v1::metadata::V1Metadata,
+};
 use fuel_core_types::fuel_types::BlockHeight;
-use fuel_gas_price_algorithm::v0::AlgorithmUpdaterV0;
+use fuel_gas_price_algorithm::{
+    v0::AlgorithmUpdaterV0,
+    v1::AlgorithmUpdaterV1,
+};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
 pub enum UpdaterMetadata {
     V0(V0Metadata),
+    V1(V1Metadata),
 }
 
 impl UpdaterMetadata {
     pub fn l2_block_height(&self) -> BlockHeight {
         match self {
-            UpdaterMetadata::V0(v1) => v1.l2_block_height.into(),
+            UpdaterMetadata::V0(v0) => v0.l2_block_height.into(),
+            UpdaterMetadata::V1(v1) => v1.l2_block_height.into(),
         }
     }
 }
@@ -20,3 +28,9 @@ impl From<AlgorithmUpdaterV0> for UpdaterMetadata {
         Self::V0(updater.into())
     }
 }
+
+impl From<AlgorithmUpdaterV1> for UpdaterMetadata {
+    fn from(updater: AlgorithmUpdaterV1) -> Self {
+        Self::V1(updater.into())
+    }
+}
@@ -16,6 +16,7 @@ use crate::{
         service::GasPriceServiceV0,
         uninitialized_task::initialize_algorithm,
     },
+    v1::metadata::V1Metadata,
 };
 use anyhow::anyhow;
 use fuel_core_services::{
@@ -24,6 +25,7 @@ use fuel_core_services::{
 };
 use fuel_core_types::fuel_types::BlockHeight;
 use std::{
+    num::NonZeroU64,
     sync::Arc,
     time::Duration,
 };
@@ -122,6 +124,8 @@ async fn next_gas_price__affected_by_new_l2_block() {
         height: 1,
         gas_used: 60,
         block_gas_capacity: 100,
+        block_bytes: 100,
+        block_fees: 100,
     };
     let (l2_block_sender, l2_block_receiver) = tokio::sync::mpsc::channel(1);
     let l2_block_source = FakeL2BlockSource {
@@ -160,6 +164,8 @@ async fn next__new_l2_block_saves_old_metadata() {
         height: 1,
         gas_used: 60,
         block_gas_capacity: 100,
+        block_bytes: 100,
+        block_fees: 100,
     };
     let (l2_block_sender, l2_block_receiver) = tokio::sync::mpsc::channel(1);
     let l2_block_source = FakeL2BlockSource {
@@ -244,3 +250,35 @@ async fn initialize_algorithm__should_fail_if_cannot_fetch_metadata() {
     // then
     assert!(matches!(res, Err(GasPriceError::CouldNotInitUpdater(_))));
 }
+
+#[tokio::test]
+async fn initialize_algorithm__converts_v1_metadata() {
+    // given
+    let v1_metadata = V1Metadata {
+        new_scaled_exec_price: 12_300,
+        l2_block_height: 42,
+        new_scaled_da_gas_price: 500,
+        gas_price_factor: NonZeroU64::new(100).unwrap(),
+        total_da_rewards_excess: 0,
+        da_recorded_block_height: 40,
+        latest_known_total_da_cost_excess: 0,
+        projected_total_da_cost: 0,
+        last_profit: 0,
+        second_to_last_profit: 0,
+        latest_da_cost_per_byte: 0,
+        unrecorded_blocks: vec![],
+    };
+    let metadata_storage = FakeMetadata {
+        inner: Arc::new(std::sync::Mutex::new(Some(UpdaterMetadata::V1(
+            v1_metadata,
+        )))),
+    };
+
+    // when
+    let metadata = arb_metadata();
+    let (algo_updater, _) = initialize_algorithm(metadata, &metadata_storage).unwrap();
+
+    // then
+    assert_eq!(algo_updater.new_exec_price, 123);
+    assert_eq!(algo_updater.l2_block_height, 42);
+}
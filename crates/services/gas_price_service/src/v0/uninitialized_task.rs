@@ -46,6 +46,7 @@ use fuel_core_types::{
     services::block_importer::SharedImportResult,
 };
 use fuel_gas_price_algorithm::v0::AlgorithmUpdaterV0;
+use std::ops::Div;
 
 pub use fuel_gas_price_algorithm::v0::AlgorithmV0;
 
@@ -239,6 +240,14 @@ where
                 old.l2_block_height,
                 l2_block_fullness_threshold_percent,
             ),
+            // Switching back from the V1 algorithm keeps only the execution gas price.
+            UpdaterMetadata::V1(old) => AlgorithmUpdaterV0::new(
+                old.new_scaled_exec_price.div(old.gas_price_factor),
+                min_exec_gas_price,
+                exec_gas_price_change_percent,
+                old.l2_block_height,
+                l2_block_fullness_threshold_percent,
+            ),
         };
     } else {
         algorithm_updater = AlgorithmUpdaterV0::new(
@@ -274,11 +283,16 @@ where
     GasPriceStore: GasPriceData + Modifiable + KeyValueInspect<Column = GasPriceColumn>,
     SettingsProvider: GasPriceSettingsProvider,
 {
-    let UpdaterMetadata::V0(metadata) = metadata_storage
+    let metadata = metadata_storage
         .get_metadata(&metadata_height.into())?
         .ok_or(anyhow::anyhow!(
             "Expected metadata to exist for height: {metadata_height}"
         ))?;
+    let UpdaterMetadata::V0(metadata) = metadata else {
+        return Err(anyhow::anyhow!(
+            "Expected V0 metadata for height: {metadata_height}"
+        ));
+    };
 
     let mut algo_updater = metadata.into();
 
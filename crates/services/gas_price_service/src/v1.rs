@@ -1,2 +1,5 @@
 pub mod algorithm;
 pub mod da_source_service;
+pub mod metadata;
+pub mod service;
+pub mod uninitialized_task;
@@ -66,6 +66,13 @@ pub trait DaBlockCostsSource: Send + Sync {
     async fn request_da_block_cost(&mut self) -> Result<DaBlockCosts>;
 }
 
+#[async_trait::async_trait]
+impl DaBlockCostsSource for Box<dyn DaBlockCostsSource> {
+    async fn request_da_block_cost(&mut self) -> Result<DaBlockCosts> {
+        self.as_mut().request_da_block_cost().await
+    }
+}
+
 #[async_trait::async_trait]
 impl<Source> RunnableService for DaSourceService<Source>
 where
@@ -0,0 +1,171 @@
+use crate::v0::metadata::V0Metadata;
+use fuel_gas_price_algorithm::v1::{
+    AlgorithmUpdaterV1,
+    ClampedPercentage,
+};
+use std::num::NonZeroU64;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct V1Metadata {
+    // Execution
+    /// The gas price (scaled by the `gas_price_factor`) to cover the execution of the next block
+    pub new_scaled_exec_price: u64,
+    /// The height of the next L2 block
+    pub l2_block_height: u32,
+    // DA
+    /// The gas price (scaled by the `gas_price_factor`) to cover the DA commitment of the next block
+    pub new_scaled_da_gas_price: u64,
+    /// Scale factor for the gas price.
+    pub gas_price_factor: NonZeroU64,
+    /// The cumulative reward from the DA portion of the gas price
+    pub total_da_rewards_excess: u128,
+    /// The height of the last L2 block recorded on the DA chain
+    pub da_recorded_block_height: u32,
+    /// The cumulative cost of recording L2 blocks on the DA chain as of the last recorded block
+    pub latest_known_total_da_cost_excess: u128,
+    /// The predicted cost of recording L2 blocks on the DA chain as of the last L2 block
+    /// (This value is added on top of the `latest_known_total_da_cost` if the L2 height is higher)
+    pub projected_total_da_cost: u128,
+    /// The last profit
+    pub last_profit: i128,
+    /// The profit before last
+    pub second_to_last_profit: i128,
+    /// The latest known cost per byte for recording blocks on the DA chain
+    pub latest_da_cost_per_byte: u128,
+    /// List of (height, size) for l2 blocks that have not been recorded on the DA chain (that we know),
+    /// but have been used to estimate the cost of recording blocks on the DA chain
+    pub unrecorded_blocks: Vec<(u32, u64)>,
+}
+
+impl V1Metadata {
+    /// Migrates the metadata of the V0 algorithm to the V1 algorithm.
+    /// All blocks before the migration are considered as already recorded on the DA chain.
+    pub fn construct_from_v0_metadata(
+        v0_metadata: V0Metadata,
+        config: &V1AlgorithmConfig,
+    ) -> anyhow::Result<Self> {
+        let gas_price_factor = config.gas_price_factor;
+        let new_scaled_exec_price = v0_metadata
+            .new_exec_price
+            .checked_mul(gas_price_factor.get())
+            .ok_or(anyhow::anyhow!("New exec price overflows"))?;
+        let new_scaled_da_gas_price = config
+            .min_da_gas_price
+            .checked_mul(gas_price_factor.get())
+            .ok_or(anyhow::anyhow!("New DA gas price overflows"))?;
+        let metadata = Self {
+            new_scaled_exec_price,
+            l2_block_height: v0_metadata.l2_block_height,
+            new_scaled_da_gas_price,
+            gas_price_factor,
+            total_da_rewards_excess: 0,
+            da_recorded_block_height: v0_metadata.l2_block_height,
+            latest_known_total_da_cost_excess: 0,
+            projected_total_da_cost: 0,
+            last_profit: 0,
+            second_to_last_profit: 0,
+            latest_da_cost_per_byte: 0,
+            unrecorded_blocks: vec![],
+        };
+        Ok(metadata)
+    }
+}
+
+/// The static parameters of the V1 algorithm. Unlike [`V1Metadata`], they are not
+/// stored in the database and can be changed between restarts of the node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct V1AlgorithmConfig {
+    pub new_exec_gas_price: u64,
+    pub min_exec_gas_price: u64,
+    pub exec_gas_price_change_percent: u16,
+    pub l2_block_fullness_threshold_percent: u8,
+    pub gas_price_factor: NonZeroU64,
+    pub min_da_gas_price: u64,
+    pub max_da_gas_price_change_percent: u16,
+    pub da_p_component: i64,
+    pub da_d_component: i64,
+}
+
+/// Creates the updater for the first run of the V1 algorithm.
+pub fn updater_from_config(
+    config: &V1AlgorithmConfig,
+    l2_block_height: u32,
+) -> AlgorithmUpdaterV1 {
+    AlgorithmUpdaterV1 {
+        new_scaled_exec_price: config
+            .new_exec_gas_price
+            .max(config.min_exec_gas_price)
+            .saturating_mul(config.gas_price_factor.get()),
+        min_exec_gas_price: config.min_exec_gas_price,
+        exec_gas_price_change_percent: config.exec_gas_price_change_percent,
+        l2_block_height,
+        l2_block_fullness_threshold_percent: ClampedPercentage::new(
+            config.l2_block_fullness_threshold_percent,
+        ),
+        new_scaled_da_gas_price: config
+            .min_da_gas_price
+            .saturating_mul(config.gas_price_factor.get()),
+        gas_price_factor: config.gas_price_factor,
+        min_da_gas_price: config.min_da_gas_price,
+        max_da_gas_price_change_percent: config.max_da_gas_price_change_percent,
+        total_da_rewards_excess: 0,
+        da_recorded_block_height: l2_block_height,
+        latest_known_total_da_cost_excess: 0,
+        projected_total_da_cost: 0,
+        da_p_component: config.da_p_component,
+        da_d_component: config.da_d_component,
+        last_profit: 0,
+        second_to_last_profit: 0,
+        latest_da_cost_per_byte: 0,
+        unrecorded_blocks: Default::default(),
+    }
+}
+
+/// Restores the updater from the stored metadata and the static parameters.
+pub fn v1_algorithm_from_metadata(
+    metadata: V1Metadata,
+    config: &V1AlgorithmConfig,
+) -> AlgorithmUpdaterV1 {
+    AlgorithmUpdaterV1 {
+        new_scaled_exec_price: metadata.new_scaled_exec_price,
+        min_exec_gas_price: config.min_exec_gas_price,
+        exec_gas_price_change_percent: config.exec_gas_price_change_percent,
+        l2_block_height: metadata.l2_block_height,
+        l2_block_fullness_threshold_percent: ClampedPercentage::new(
+            config.l2_block_fullness_threshold_percent,
+        ),
+        new_scaled_da_gas_price: metadata.new_scaled_da_gas_price,
+        gas_price_factor: metadata.gas_price_factor,
+        min_da_gas_price: config.min_da_gas_price,
+        max_da_gas_price_change_percent: config.max_da_gas_price_change_percent,
+        total_da_rewards_excess: metadata.total_da_rewards_excess,
+        da_recorded_block_height: metadata.da_recorded_block_height,
+        latest_known_total_da_cost_excess: metadata.latest_known_total_da_cost_excess,
+        projected_total_da_cost: metadata.projected_total_da_cost,
+        da_p_component: config.da_p_component,
+        da_d_component: config.da_d_component,
+        last_profit: metadata.last_profit,
+        second_to_last_profit: metadata.second_to_last_profit,
+        latest_da_cost_per_byte: metadata.latest_da_cost_per_byte,
+        unrecorded_blocks: metadata.unrecorded_blocks.into_iter().collect(),
+    }
+}
+
+impl From<AlgorithmUpdaterV1> for V1Metadata {
+    fn from(updater: AlgorithmUpdaterV1) -> Self {
+        Self {
+            new_scaled_exec_price: updater.new_scaled_exec_price,
+            l2_block_height: updater.l2_block_height,
+            new_scaled_da_gas_price: updater.new_scaled_da_gas_price,
+            gas_price_factor: updater.gas_price_factor,
+            total_da_rewards_excess: updater.total_da_rewards_excess,
+            da_recorded_block_height: updater.da_recorded_block_height,
+            latest_known_total_da_cost_excess: updater.latest_known_total_da_cost_excess,
+            projected_total_da_cost: updater.projected_total_da_cost,
+            last_profit: updater.last_profit,
+            second_to_last_profit: updater.second_to_last_profit,
+            latest_da_cost_per_byte: updater.latest_da_cost_per_byte,
+            unrecorded_blocks: updater.unrecorded_blocks.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,496 @@
+use crate::{
+    common::{
+        l2_block_source::L2BlockSource,
+        updater_metadata::UpdaterMetadata,
+        utils::BlockInfo,
+    },
+    ports::MetadataStorage,
+    v1::{
+        da_source_service::{
+            service::{
+                DaBlockCostsSource,
+                DaSourceService,
+            },
+            DaBlockCosts,
+        },
+        uninitialized_task::SharedV1Algorithm,
+    },
+};
+use anyhow::anyhow;
+use async_trait::async_trait;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    Service,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_gas_price_algorithm::v1::{
+    AlgorithmUpdaterV1,
+    AlgorithmV1,
+};
+use futures::FutureExt;
+use std::num::NonZeroU64;
+use tokio::sync::broadcast::{
+    error::RecvError,
+    Receiver,
+};
+
+/// The service that updates the gas price algorithm.
+pub struct GasPriceServiceV1<L2, Metadata, DA>
+where
+    DA: DaBlockCostsSource + 'static,
+{
+    /// The algorithm that can be used in the next block
+    shared_algo: SharedV1Algorithm,
+    /// The L2 block source
+    l2_block_source: L2,
+    /// The metadata storage
+    metadata_storage: Metadata,
+    /// The algorithm updater
+    algorithm_updater: AlgorithmUpdaterV1,
+    /// The DA source service, which fetches the costs of the DA commitments
+    da_source_adapter_handle: ServiceRunner<DaSourceService<DA>>,
+    /// The receiver of the DA block costs
+    da_source_channel: Receiver<DaBlockCosts>,
+}
+
+impl<L2, Metadata, DA> GasPriceServiceV1<L2, Metadata, DA>
+where
+    Metadata: MetadataStorage,
+    DA: DaBlockCostsSource + 'static,
+{
+    pub fn new(
+        l2_block_source: L2,
+        metadata_storage: Metadata,
+        shared_algo: SharedV1Algorithm,
+        algorithm_updater: AlgorithmUpdaterV1,
+        da_source_adapter_handle: ServiceRunner<DaSourceService<DA>>,
+    ) -> Self {
+        let da_source_channel = da_source_adapter_handle.shared.subscribe();
+        Self {
+            shared_algo,
+            l2_block_source,
+            metadata_storage,
+            algorithm_updater,
+            da_source_adapter_handle,
+            da_source_channel,
+        }
+    }
+
+    pub fn algorithm_updater(&self) -> &AlgorithmUpdaterV1 {
+        &self.algorithm_updater
+    }
+
+    pub fn next_block_algorithm(&self) -> SharedV1Algorithm {
+        self.shared_algo.clone()
+    }
+
+    async fn update(&mut self, new_algorithm: AlgorithmV1) {
+        self.shared_algo.update(new_algorithm).await;
+    }
+
+    fn validate_block_gas_capacity(
+        &self,
+        block_gas_capacity: u64,
+    ) -> anyhow::Result<NonZeroU64> {
+        NonZeroU64::new(block_gas_capacity)
+            .ok_or_else(|| anyhow!("Block gas capacity must be non-zero"))
+    }
+
+    async fn set_metadata(&mut self) -> anyhow::Result<()> {
+        let metadata: UpdaterMetadata = self.algorithm_updater.clone().into();
+        self.metadata_storage
+            .set_metadata(&metadata)
+            .map_err(|err| anyhow!(err))
+    }
+
+    async fn handle_normal_block(
+        &mut self,
+        height: u32,
+        gas_used: u64,
+        block_gas_capacity: u64,
+        block_bytes: u64,
+        block_fees: u64,
+    ) -> anyhow::Result<()> {
+        let capacity = self.validate_block_gas_capacity(block_gas_capacity)?;
+
+        self.algorithm_updater.update_l2_block_data(
+            height,
+            gas_used,
+            capacity,
+            block_bytes,
+            u128::from(block_fees),
+        )?;
+
+        self.set_metadata().await?;
+        Ok(())
+    }
+
+    async fn handle_da_block_costs(
+        &mut self,
+        da_block_costs: DaBlockCosts,
+    ) -> anyhow::Result<()> {
+        let DaBlockCosts {
+            l2_block_range,
+            blob_cost_wei,
+            ..
+        } = da_block_costs;
+        let start = u32::try_from(l2_block_range.start)?;
+        let end = u32::try_from(l2_block_range.end)?;
+
+        self.algorithm_updater
+            .update_da_record_data(start..end, blob_cost_wei)?;
+
+        self.set_metadata().await?;
+        Ok(())
+    }
+
+    async fn apply_block_info_to_gas_algorithm(
+        &mut self,
+        l2_block: BlockInfo,
+    ) -> anyhow::Result<()> {
+        match l2_block {
+            BlockInfo::GenesisBlock => {
+                self.set_metadata().await?;
+            }
+            BlockInfo::Block {
+                height,
+                gas_used,
+                block_gas_capacity,
+                block_bytes,
+                block_fees,
+            } => {
+                self.handle_normal_block(
+                    height,
+                    gas_used,
+                    block_gas_capacity,
+                    block_bytes,
+                    block_fees,
+                )
+                .await?;
+            }
+        }
+
+        self.update(self.algorithm_updater.algorithm()).await;
+        Ok(())
+    }
+
+    async fn apply_da_block_costs_to_gas_algorithm(
+        &mut self,
+        da_block_costs: DaBlockCosts,
+    ) -> anyhow::Result<()> {
+        self.handle_da_block_costs(da_block_costs).await?;
+        self.update(self.algorithm_updater.algorithm()).await;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<L2, Metadata, DA> RunnableService for GasPriceServiceV1<L2, Metadata, DA>
+where
+    L2: L2BlockSource,
+    Metadata: MetadataStorage,
+    DA: DaBlockCostsSource + 'static,
+{
+    const NAME: &'static str = "GasPriceServiceV1";
+    type SharedData = SharedV1Algorithm;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared_algo.clone()
+    }
+
+    async fn into_task(
+        mut self,
+        _state_watcher: &StateWatcher,
+        _params: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let algorithm = self.algorithm_updater.algorithm();
+        self.shared_algo.update(algorithm).await;
+        self.da_source_adapter_handle.start_and_await().await?;
+        Ok(self)
+    }
+}
+
+#[async_trait]
+impl<L2, Metadata, DA> RunnableTask for GasPriceServiceV1<L2, Metadata, DA>
+where
+    L2: L2BlockSource,
+    Metadata: MetadataStorage,
+    DA: DaBlockCostsSource + 'static,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => {
+                tracing::debug!("Stopping gas price service");
+                should_continue = false;
+            }
+            l2_block_res = self.l2_block_source.get_l2_block() => {
+                tracing::info!("Received L2 block result: {:?}", l2_block_res);
+                let block = l2_block_res?;
+
+                tracing::debug!("Updating gas price algorithm");
+                self.apply_block_info_to_gas_algorithm(block).await?;
+                should_continue = true;
+            }
+            da_block_costs = self.da_source_channel.recv() => {
+                match da_block_costs {
+                    Ok(da_block_costs) => {
+                        tracing::info!("Received DA block costs: {:?}", da_block_costs);
+                        self.apply_da_block_costs_to_gas_algorithm(da_block_costs).await?;
+                    }
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Skipped {skipped} DA block costs updates");
+                    }
+                    Err(RecvError::Closed) => {
+                        return Err(anyhow!("The DA source service is stopped"));
+                    }
+                }
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(mut self) -> anyhow::Result<()> {
+        while let Some(Ok(block)) = self.l2_block_source.get_l2_block().now_or_never() {
+            tracing::debug!("Updating gas price algorithm");
+            self.apply_block_info_to_gas_algorithm(block).await?;
+        }
+        self.da_source_adapter_handle.stop_and_await().await?;
+        Ok(())
+    }
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        common::{
+            l2_block_source::L2BlockSource,
+            updater_metadata::UpdaterMetadata,
+            utils::{
+                BlockInfo,
+                Result as GasPriceResult,
+            },
+        },
+        ports::MetadataStorage,
+        v0::metadata::V0Metadata,
+        v1::{
+            da_source_service::{
+                dummy_costs::DummyDaBlockCosts,
+                service::DaSourceService,
+                DaBlockCosts,
+            },
+            metadata::{
+                updater_from_config,
+                V1AlgorithmConfig,
+            },
+            service::GasPriceServiceV1,
+            uninitialized_task::{
+                initialize_algorithm,
+                SharedV1Algorithm,
+            },
+        },
+    };
+    use fuel_core_services::{
+        Service,
+        ServiceRunner,
+    };
+    use fuel_core_types::fuel_types::BlockHeight;
+    use std::{
+        num::NonZeroU64,
+        sync::Arc,
+        time::Duration,
+    };
+    use tokio::sync::mpsc;
+
+    struct FakeL2BlockSource {
+        l2_block: mpsc::Receiver<BlockInfo>,
+    }
+
+    #[async_trait::async_trait]
+    impl L2BlockSource for FakeL2BlockSource {
+        async fn get_l2_block(&mut self) -> GasPriceResult<BlockInfo> {
+            let block = self.l2_block.recv().await.unwrap();
+            Ok(block)
+        }
+    }
+
+    struct FakeMetadata {
+        inner: Arc<std::sync::Mutex<Option<UpdaterMetadata>>>,
+    }
+
+    impl FakeMetadata {
+        fn empty() -> Self {
+            Self {
+                inner: Arc::new(std::sync::Mutex::new(None)),
+            }
+        }
+    }
+
+    impl MetadataStorage for FakeMetadata {
+        fn get_metadata(
+            &self,
+            _: &BlockHeight,
+        ) -> GasPriceResult<Option<UpdaterMetadata>> {
+            let metadata = self.inner.lock().unwrap().clone();
+            Ok(metadata)
+        }
+
+        fn set_metadata(&mut self, metadata: &UpdaterMetadata) -> GasPriceResult<()> {
+            *self.inner.lock().unwrap() = Some(metadata.clone());
+            Ok(())
+        }
+    }
+
+    fn arb_config() -> V1AlgorithmConfig {
+        V1AlgorithmConfig {
+            new_exec_gas_price: 100,
+            min_exec_gas_price: 50,
+            exec_gas_price_change_percent: 20,
+            l2_block_fullness_threshold_percent: 20,
+            gas_price_factor: NonZeroU64::new(10).unwrap(),
+            min_da_gas_price: 10,
+            max_da_gas_price_change_percent: 20,
+            da_p_component: 4,
+            da_d_component: 2,
+        }
+    }
+
+    #[tokio::test]
+    async fn run__updates_gas_price_with_l2_block_source() {
+        // given
+        let block_height = 1;
+        let l2_block = BlockInfo::Block {
+            height: block_height,
+            gas_used: 60,
+            block_gas_capacity: 100,
+            block_bytes: 100,
+            block_fees: 100,
+        };
+        let (l2_block_sender, l2_block_receiver) = mpsc::channel(1);
+        let l2_block_source = FakeL2BlockSource {
+            l2_block: l2_block_receiver,
+        };
+        let metadata_storage = FakeMetadata::empty();
+        let algorithm_updater = updater_from_config(&arb_config(), 0);
+        let shared_algo =
+            SharedV1Algorithm::new_with_algorithm(algorithm_updater.algorithm());
+        let da_source = DummyDaBlockCosts::new(Err(anyhow::anyhow!("unused")));
+        let da_source_service = ServiceRunner::new(DaSourceService::new(da_source, None));
+
+        let service = GasPriceServiceV1::new(
+            l2_block_source,
+            metadata_storage,
+            shared_algo,
+            algorithm_updater,
+            da_source_service,
+        );
+        let read_algo = service.next_block_algorithm();
+        let service = ServiceRunner::new(service);
+        let prev = read_algo.next_gas_price().await;
+
+        // when
+        service.start_and_await().await.unwrap();
+        l2_block_sender.send(l2_block).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // then
+        let actual_price = read_algo.next_gas_price().await;
+        assert_ne!(prev, actual_price);
+        service.stop_and_await().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn run__updates_metadata_with_da_block_costs() {
+        // given
+        let (l2_block_sender, l2_block_receiver) = mpsc::channel(1);
+        let l2_block_source = FakeL2BlockSource {
+            l2_block: l2_block_receiver,
+        };
+        let metadata_inner = Arc::new(std::sync::Mutex::new(None));
+        let metadata_storage = FakeMetadata {
+            inner: metadata_inner.clone(),
+        };
+        let algorithm_updater = updater_from_config(&arb_config(), 0);
+        let shared_algo =
+            SharedV1Algorithm::new_with_algorithm(algorithm_updater.algorithm());
+        let da_source = DummyDaBlockCosts::new(Ok(DaBlockCosts {
+            l2_block_range: 1..2,
+            blob_size_bytes: 100,
+            blob_cost_wei: 9000,
+        }));
+        let da_source_service = ServiceRunner::new(DaSourceService::new(
+            da_source,
+            Some(Duration::from_millis(20)),
+        ));
+
+        let service = GasPriceServiceV1::new(
+            l2_block_source,
+            metadata_storage,
+            shared_algo,
+            algorithm_updater,
+            da_source_service,
+        );
+        let service = ServiceRunner::new(service);
+
+        // when
+        service.start_and_await().await.unwrap();
+        l2_block_sender
+            .send(BlockInfo::Block {
+                height: 1,
+                gas_used: 60,
+                block_gas_capacity: 100,
+                block_bytes: 100,
+                block_fees: 100,
+            })
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // then
+        let metadata = metadata_inner.lock().unwrap().clone();
+        let Some(UpdaterMetadata::V1(metadata)) = metadata else {
+            panic!("Expected V1 metadata");
+        };
+        assert_eq!(metadata.da_recorded_block_height, 1);
+        assert_eq!(metadata.latest_known_total_da_cost_excess, 9000);
+        service.stop_and_await().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn initialize_algorithm__migrates_v0_metadata() {
+        // given
+        let v0_metadata = V0Metadata {
+            new_exec_price: 100,
+            min_exec_gas_price: 50,
+            exec_gas_price_change_percent: 10,
+            l2_block_height: 123,
+            l2_block_fullness_threshold_percent: 50,
+        };
+        let metadata_storage = FakeMetadata {
+            inner: Arc::new(std::sync::Mutex::new(Some(UpdaterMetadata::V0(
+                v0_metadata,
+            )))),
+        };
+        let config = arb_config();
+
+        // when
+        let (algorithm_updater, _) =
+            initialize_algorithm(&config, 123, &metadata_storage).unwrap();
+
+        // then
+        assert_eq!(algorithm_updater.l2_block_height, 123);
+        assert_eq!(algorithm_updater.da_recorded_block_height, 123);
+        assert_eq!(
+            algorithm_updater.new_scaled_exec_price,
+            100 * config.gas_price_factor.get()
+        );
+    }
+}
@@ -0,0 +1,383 @@
+use crate::{
+    common::{
+        fuel_core_storage_adapter::{
+            get_block_info,
+            storage::GasPriceColumn,
+            GasPriceSettings,
+            GasPriceSettingsProvider,
+        },
+        gas_price_algorithm::SharedGasPriceAlgo,
+        l2_block_source::FuelL2BlockSource,
+        updater_metadata::UpdaterMetadata,
+        utils::{
+            BlockInfo,
+            Error as GasPriceError,
+            Result as GasPriceResult,
+        },
+    },
+    ports::{
+        GasPriceData,
+        L2Data,
+        MetadataStorage,
+    },
+    v1::{
+        da_source_service::service::{
+            DaBlockCostsSource,
+            DaSourceService,
+        },
+        metadata::{
+            updater_from_config,
+            v1_algorithm_from_metadata,
+            V1AlgorithmConfig,
+            V1Metadata,
+        },
+        service::GasPriceServiceV1,
+    },
+};
+use fuel_core_services::{
+    stream::BoxStream,
+    RunnableService,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_storage::{
+    kv_store::KeyValueInspect,
+    not_found,
+    structured_storage::StructuredStorage,
+    transactional::{
+        AtomicView,
+        Modifiable,
+    },
+};
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::block_importer::SharedImportResult,
+};
+use fuel_gas_price_algorithm::v1::AlgorithmUpdaterV1;
+
+pub use fuel_gas_price_algorithm::v1::AlgorithmV1;
+
+pub type SharedV1Algorithm = SharedGasPriceAlgo<AlgorithmV1>;
+
+pub struct UninitializedTask<L2DataStoreView, GasPriceStore, DA, SettingsProvider>
+where
+    DA: DaBlockCostsSource + 'static,
+{
+    pub config: V1AlgorithmConfig,
+    pub genesis_block_height: BlockHeight,
+    pub settings: SettingsProvider,
+    pub gas_price_db: GasPriceStore,
+    pub on_chain_db: L2DataStoreView,
+    pub block_stream: BoxStream<SharedImportResult>,
+    da_source_service: ServiceRunner<DaSourceService<DA>>,
+    shared_algo: SharedV1Algorithm,
+    algo_updater: AlgorithmUpdaterV1,
+    metadata_storage: StructuredStorage<GasPriceStore>,
+}
+
+impl<L2DataStore, L2DataStoreView, GasPriceStore, DA, SettingsProvider>
+    UninitializedTask<L2DataStoreView, GasPriceStore, DA, SettingsProvider>
+where
+    L2DataStore: L2Data,
+    L2DataStoreView: AtomicView<LatestView = L2DataStore>,
+    GasPriceStore:
+        GasPriceData + Modifiable + KeyValueInspect<Column = GasPriceColumn> + Clone,
+    DA: DaBlockCostsSource + 'static,
+    SettingsProvider: GasPriceSettingsProvider,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        config: V1AlgorithmConfig,
+        genesis_block_height: BlockHeight,
+        settings: SettingsProvider,
+        block_stream: BoxStream<SharedImportResult>,
+        gas_price_db: GasPriceStore,
+        on_chain_db: L2DataStoreView,
+        da_source_service: ServiceRunner<DaSourceService<DA>>,
+    ) -> anyhow::Result<Self> {
+        let latest_block_height: u32 = on_chain_db
+            .latest_view()?
+            .latest_height()
+            .unwrap_or(genesis_block_height)
+            .into();
+
+        let metadata_storage = StructuredStorage::new(gas_price_db.clone());
+        let (algo_updater, shared_algo) =
+            initialize_algorithm(&config, latest_block_height, &metadata_storage)?;
+
+        let task = Self {
+            config,
+            genesis_block_height,
+            settings,
+            gas_price_db,
+            on_chain_db,
+            block_stream,
+            da_source_service,
+            algo_updater,
+            shared_algo,
+            metadata_storage,
+        };
+        Ok(task)
+    }
+
+    pub fn init(
+        mut self,
+    ) -> anyhow::Result<
+        GasPriceServiceV1<
+            FuelL2BlockSource<SettingsProvider>,
+            StructuredStorage<GasPriceStore>,
+            DA,
+        >,
+    > {
+        let mut first_run = false;
+        let latest_block_height: u32 = self
+            .on_chain_db
+            .latest_view()?
+            .latest_height()
+            .unwrap_or(self.genesis_block_height)
+            .into();
+
+        let maybe_metadata_height = self.gas_price_db.latest_height();
+        let metadata_height = if let Some(metadata_height) = maybe_metadata_height {
+            metadata_height.into()
+        } else {
+            first_run = true;
+            latest_block_height
+        };
+
+        let l2_block_source = FuelL2BlockSource::new(
+            self.genesis_block_height,
+            self.settings.clone(),
+            self.block_stream,
+        );
+
+        if BlockHeight::from(latest_block_height) != self.genesis_block_height
+            && !first_run
+            && latest_block_height > metadata_height
+        {
+            sync_gas_price_db_with_on_chain_storage(
+                &self.settings,
+                &self.config,
+                &mut self.metadata_storage,
+                &self.on_chain_db,
+                metadata_height,
+                latest_block_height,
+            )?;
+        }
+
+        let service = GasPriceServiceV1::new(
+            l2_block_source,
+            self.metadata_storage,
+            self.shared_algo,
+            self.algo_updater,
+            self.da_source_service,
+        );
+        Ok(service)
+    }
+}
+
+#[async_trait::async_trait]
+impl<L2DataStore, L2DataStoreView, GasPriceStore, DA, SettingsProvider> RunnableService
+    for UninitializedTask<L2DataStoreView, GasPriceStore, DA, SettingsProvider>
+where
+    L2DataStore: L2Data,
+    L2DataStoreView: AtomicView<LatestView = L2DataStore>,
+    GasPriceStore:
+        GasPriceData + Modifiable + KeyValueInspect<Column = GasPriceColumn> + Clone,
+    DA: DaBlockCostsSource + 'static,
+    SettingsProvider: GasPriceSettingsProvider,
+{
+    const NAME: &'static str = "UninitializedGasPriceServiceV1";
+    type SharedData = SharedV1Algorithm;
+    type Task = GasPriceServiceV1<
+        FuelL2BlockSource<SettingsProvider>,
+        StructuredStorage<GasPriceStore>,
+        DA,
+    >;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared_algo.clone()
+    }
+
+    async fn into_task(
+        self,
+        _state_watcher: &StateWatcher,
+        _params: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        UninitializedTask::init(self)
+    }
+}
+
+/// Restores the V1 algorithm from the metadata stored at the `latest_block_height`.
+/// If the stored metadata belongs to the V0 algorithm, it is migrated to the V1 metadata.
+/// If there is no metadata, the algorithm starts from the values of the `config`.
+pub fn initialize_algorithm<Metadata>(
+    config: &V1AlgorithmConfig,
+    latest_block_height: u32,
+    metadata_storage: &Metadata,
+) -> GasPriceResult<(AlgorithmUpdaterV1, SharedV1Algorithm)>
+where
+    Metadata: MetadataStorage,
+{
+    let algorithm_updater = if let Some(old_metadata) = metadata_storage
+        .get_metadata(&latest_block_height.into())
+        .map_err(|err| GasPriceError::CouldNotInitUpdater(anyhow::anyhow!(err)))?
+    {
+        let metadata = v1_metadata(old_metadata, config)
+            .map_err(GasPriceError::CouldNotInitUpdater)?;
+        v1_algorithm_from_metadata(metadata, config)
+    } else {
+        updater_from_config(config, latest_block_height)
+    };
+
+    let shared_algo =
+        SharedGasPriceAlgo::new_with_algorithm(algorithm_updater.algorithm());
+
+    Ok((algorithm_updater, shared_algo))
+}
+
+fn v1_metadata(
+    metadata: UpdaterMetadata,
+    config: &V1AlgorithmConfig,
+) -> anyhow::Result<V1Metadata> {
+    match metadata {
+        UpdaterMetadata::V0(v0) => V1Metadata::construct_from_v0_metadata(v0, config),
+        UpdaterMetadata::V1(v1) => Ok(v1),
+    }
+}
+
+fn sync_gas_price_db_with_on_chain_storage<
+    L2DataStore,
+    L2DataStoreView,
+    GasPriceStore,
+    SettingsProvider,
+>(
+    settings: &SettingsProvider,
+    config: &V1AlgorithmConfig,
+    metadata_storage: &mut StructuredStorage<GasPriceStore>,
+    on_chain_db: &L2DataStoreView,
+    metadata_height: u32,
+    latest_block_height: u32,
+) -> anyhow::Result<()>
+where
+    L2DataStore: L2Data,
+    L2DataStoreView: AtomicView<LatestView = L2DataStore>,
+    GasPriceStore: GasPriceData + Modifiable + KeyValueInspect<Column = GasPriceColumn>,
+    SettingsProvider: GasPriceSettingsProvider,
+{
+    let metadata = metadata_storage
+        .get_metadata(&metadata_height.into())?
+        .ok_or(anyhow::anyhow!(
+            "Expected metadata to exist for height: {metadata_height}"
+        ))?;
+    let metadata = v1_metadata(metadata, config)?;
+
+    let mut algo_updater = v1_algorithm_from_metadata(metadata, config);
+
+    sync_v1_metadata(
+        settings,
+        on_chain_db,
+        metadata_height,
+        latest_block_height,
+        &mut algo_updater,
+        metadata_storage,
+    )?;
+
+    Ok(())
+}
+
+fn sync_v1_metadata<L2DataStore, L2DataStoreView, GasPriceStore, SettingsProvider>(
+    settings: &SettingsProvider,
+    on_chain_db: &L2DataStoreView,
+    metadata_height: u32,
+    latest_block_height: u32,
+    updater: &mut AlgorithmUpdaterV1,
+    metadata_storage: &mut StructuredStorage<GasPriceStore>,
+) -> anyhow::Result<()>
+where
+    L2DataStore: L2Data,
+    L2DataStoreView: AtomicView<LatestView = L2DataStore>,
+    GasPriceStore: GasPriceData + Modifiable + KeyValueInspect<Column = GasPriceColumn>,
+    SettingsProvider: GasPriceSettingsProvider,
+{
+    let first = metadata_height.saturating_add(1);
+    let view = on_chain_db.latest_view()?;
+    for height in first..=latest_block_height {
+        let block = view
+            .get_block(&height.into())?
+            .ok_or(not_found!("FullBlock"))?;
+        let param_version = block.header().consensus_parameters_version;
+
+        let GasPriceSettings {
+            gas_price_factor,
+            block_gas_limit,
+        } = settings.settings(&param_version)?;
+        let block_gas_capacity = block_gas_limit.try_into()?;
+
+        let (block_gas_used, block_bytes, block_fees) =
+            match get_block_info(&block, gas_price_factor, block_gas_limit)? {
+                BlockInfo::GenesisBlock => {
+                    Err(anyhow::anyhow!("should not be genesis block"))?
+                }
+                BlockInfo::Block {
+                    gas_used,
+                    block_bytes,
+                    block_fees,
+                    ..
+                } => (gas_used, block_bytes, block_fees),
+            };
+
+        updater.update_l2_block_data(
+            height,
+            block_gas_used,
+            block_gas_capacity,
+            block_bytes,
+            u128::from(block_fees),
+        )?;
+        let metadata: UpdaterMetadata = updater.clone().into();
+        metadata_storage.set_metadata(&metadata)?;
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn new_gas_price_service_v1<
+    L2DataStore,
+    L2DataStoreView,
+    GasPriceStore,
+    DA,
+    SettingsProvider,
+>(
+    config: V1AlgorithmConfig,
+    genesis_block_height: BlockHeight,
+    settings: SettingsProvider,
+    block_stream: BoxStream<SharedImportResult>,
+    gas_price_db: GasPriceStore,
+    on_chain_db: L2DataStoreView,
+    da_source_service: ServiceRunner<DaSourceService<DA>>,
+) -> anyhow::Result<
+    ServiceRunner<
+        UninitializedTask<L2DataStoreView, GasPriceStore, DA, SettingsProvider>,
+    >,
+>
+where
+    L2DataStore: L2Data,
+    L2DataStoreView: AtomicView<LatestView = L2DataStore>,
+    GasPriceStore:
+        GasPriceData + Modifiable + KeyValueInspect<Column = GasPriceColumn> + Clone,
+    DA: DaBlockCostsSource + 'static,
+    SettingsProvider: GasPriceSettingsProvider,
+{
+    let gas_price_init = UninitializedTask::new(
+        config,
+        genesis_block_height,
+        settings,
+        block_stream,
+        gas_price_db,
+        on_chain_db,
+        da_source_service,
+    )?;
+    Ok(ServiceRunner::new(gas_price_init))
+}
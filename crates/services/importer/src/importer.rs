@@ -54,6 +54,7 @@ use std::{
 };
 use tokio::sync::{
     broadcast,
+    MutexGuard,
     OwnedSemaphorePermit,
     Semaphore,
     TryAcquireError,
@@ -125,6 +126,9 @@ pub struct Importer<D, E, V> {
     /// the block importer stops committing new blocks and waits for
     /// the resolution of the previous one.
     active_import_results: Arc<Semaphore>,
+    /// The lock is held during the commit of the block into the database.
+    /// It allows pausing commits of new blocks from outside of the importer.
+    commit_lock: tokio::sync::Mutex<()>,
     process_thread: rayon::ThreadPool,
 }
 
@@ -154,6 +158,7 @@ impl<D, E, V> Importer<D, E, V> {
             broadcast,
             active_import_results: Arc::new(Semaphore::new(max_block_notify_buffer)),
             guard: Semaphore::new(1),
+            commit_lock: tokio::sync::Mutex::new(()),
             process_thread,
         }
     }
@@ -173,6 +178,12 @@ impl<D, E, V> Importer<D, E, V> {
         self.broadcast.subscribe()
     }
 
+    /// Pauses commits of new blocks into the database until the returned guard
+    /// is dropped. Waits for the commit in progress, if any, to finish.
+    pub async fn pause_commits(&self) -> MutexGuard<'_, ()> {
+        self.commit_lock.lock().await
+    }
+
     pub(crate) fn lock(&self) -> Result<tokio::sync::SemaphorePermit, Error> {
         let guard = self.guard.try_acquire();
         match guard {
@@ -244,6 +255,7 @@ where
             return Err(Error::PreviousBlockProcessingNotFinished)
         };
         let permit = permit.map_err(Error::ActiveBlockResultsSemaphoreClosed)?;
+        let _commit_guard = self.commit_lock.lock().await;
 
         self.async_run(move || {
             let mut guard = self
@@ -292,7 +304,7 @@ where
                 }
                 actual_next_height
             }
-            Consensus::PoA(_) => {
+            Consensus::PoA(_) | Consensus::DaImport(_) => {
                 if actual_next_height == BlockHeight::from(0u32) {
                     return Err(Error::ZeroNonGenericHeight)
                 }
@@ -511,6 +523,7 @@ where
             return Err(Error::PreviousBlockProcessingNotFinished)
         };
         let permit = permit.map_err(Error::ActiveBlockResultsSemaphoreClosed)?;
+        let _commit_guard = self.commit_lock.lock().await;
 
         let commit_result = self
             .async_run(move || {
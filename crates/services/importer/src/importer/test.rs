@@ -414,6 +414,34 @@ async fn execute_and_commit_fail_when_locked() {
     );
 }
 
+#[tokio::test]
+async fn commit_result_waits_while_commits_are_paused() {
+    // Given
+    let mut underlying_db = underlying_db(ok(Some(112)))();
+    let transaction = db_transaction(ok(Some(112)), ok(true), 1)();
+    underlying_db
+        .expect_storage_transaction()
+        .return_once(|_| transaction);
+    let importer = Importer::default_config(underlying_db, (), ());
+    let uncommitted_result = UncommittedResult::new(
+        ImportResult::new_from_local(poa_block(113), vec![], vec![]),
+        Default::default(),
+    );
+    let _imported_blocks = importer.subscribe();
+    let pause = importer.pause_commits().await;
+
+    // When
+    let commit = importer.commit_result(uncommitted_result);
+    tokio::pin!(commit);
+    let paused_result =
+        tokio::time::timeout(std::time::Duration::from_millis(100), &mut commit).await;
+    drop(pause);
+
+    // Then
+    assert!(paused_result.is_err());
+    assert_eq!(commit.await, Ok(()));
+}
+
 #[test]
 fn one_lock_at_the_same_time() {
     let importer = Importer::default_config(
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use fuel_core_types::fuel_tx::Transaction;
+use fuel_core_types::{
+    blockchain::SealedBlock,
+    fuel_tx::Transaction,
+};
 
 use serde::{
     Deserialize,
@@ -12,6 +15,7 @@ use serde::{
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum GossipTopicTag {
     NewTx,
+    NewBlock,
 }
 
 /// Takes `Arc<T>` and wraps it in a matching GossipsubBroadcastRequest
@@ -20,9 +24,11 @@ pub enum GossipTopicTag {
 #[derive(Debug, Clone)]
 pub enum GossipsubBroadcastRequest {
     NewTx(Arc<Transaction>),
+    NewBlock(Arc<SealedBlock>),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum GossipsubMessage {
     NewTx(Transaction),
+    NewBlock(SealedBlock),
 }
@@ -11,6 +11,7 @@ use super::messages::{
 
 pub type GossipTopic = Sha256Topic;
 pub const NEW_TX_GOSSIP_TOPIC: &str = "new_tx";
+pub const NEW_BLOCK_GOSSIP_TOPIC: &str = "new_block";
 
 /// Holds used Gossipsub Topics
 /// Each field contains TopicHash and GossipTopic itself
@@ -18,14 +19,18 @@ pub const NEW_TX_GOSSIP_TOPIC: &str = "new_tx";
 #[derive(Debug)]
 pub struct GossipsubTopics {
     new_tx_topic: (TopicHash, GossipTopic),
+    new_block_topic: (TopicHash, GossipTopic),
 }
 
 impl GossipsubTopics {
     pub fn new(network_name: &str) -> Self {
         let new_tx_topic = Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
+        let new_block_topic =
+            Topic::new(format!("{NEW_BLOCK_GOSSIP_TOPIC}/{network_name}"));
 
         Self {
             new_tx_topic: (new_tx_topic.hash(), new_tx_topic),
+            new_block_topic: (new_block_topic.hash(), new_block_topic),
         }
     }
 
@@ -34,10 +39,14 @@ impl GossipsubTopics {
         &self,
         incoming_topic: &TopicHash,
     ) -> Option<GossipTopicTag> {
-        let GossipsubTopics { new_tx_topic } = &self;
+        let GossipsubTopics {
+            new_tx_topic,
+            new_block_topic,
+        } = &self;
 
         match incoming_topic {
             hash if hash == &new_tx_topic.0 => Some(GossipTopicTag::NewTx),
+            hash if hash == &new_block_topic.0 => Some(GossipTopicTag::NewBlock),
             _ => None,
         }
     }
@@ -50,6 +59,7 @@ impl GossipsubTopics {
     ) -> GossipTopic {
         match outgoing_request {
             GossipsubBroadcastRequest::NewTx(_) => self.new_tx_topic.1.clone(),
+            GossipsubBroadcastRequest::NewBlock(_) => self.new_block_topic.1.clone(),
         }
     }
 }
@@ -57,7 +67,10 @@ impl GossipsubTopics {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use fuel_core_types::fuel_tx::Transaction;
+    use fuel_core_types::{
+        blockchain::SealedBlock,
+        fuel_tx::Transaction,
+    };
     use libp2p::gossipsub::Topic;
     use std::sync::Arc;
 
@@ -66,17 +79,24 @@ mod tests {
         let network_name = "fuel_test_network";
         let new_tx_topic: GossipTopic =
             Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
+        let new_block_topic: GossipTopic =
+            Topic::new(format!("{NEW_BLOCK_GOSSIP_TOPIC}/{network_name}"));
 
         let gossipsub_topics = GossipsubTopics::new(network_name);
 
         // Test matching Topic Hashes
         assert_eq!(gossipsub_topics.new_tx_topic.0, new_tx_topic.hash());
+        assert_eq!(gossipsub_topics.new_block_topic.0, new_block_topic.hash());
 
         // Test given a TopicHash that `get_gossipsub_tag()` returns matching `GossipTopicTag`
         assert_eq!(
             gossipsub_topics.get_gossipsub_tag(&new_tx_topic.hash()),
             Some(GossipTopicTag::NewTx)
         );
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_tag(&new_block_topic.hash()),
+            Some(GossipTopicTag::NewBlock)
+        );
 
         // Test given a `GossipsubBroadcastRequest` that `get_gossipsub_topic()` returns matching `Topic`
         let broadcast_req =
@@ -85,5 +105,11 @@ mod tests {
             gossipsub_topics.get_gossipsub_topic(&broadcast_req).hash(),
             new_tx_topic.hash()
         );
+        let broadcast_req =
+            GossipsubBroadcastRequest::NewBlock(Arc::new(SealedBlock::default()));
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_topic(&broadcast_req).hash(),
+            new_block_topic.hash()
+        );
     }
 }
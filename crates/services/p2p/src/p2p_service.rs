@@ -779,11 +779,13 @@ mod tests {
         config::Config,
         gossipsub::{
             messages::{
+                GossipTopicTag,
                 GossipsubBroadcastRequest,
                 GossipsubMessage,
             },
             topics::{
                 GossipTopic,
+                NEW_BLOCK_GOSSIP_TOPIC,
                 NEW_TX_GOSSIP_TOPIC,
             },
         },
@@ -804,6 +806,7 @@ mod tests {
                 Consensus,
             },
             header::BlockHeader,
+            SealedBlock,
             SealedBlockHeader,
         },
         fuel_tx::{
@@ -1344,6 +1347,38 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn gossipsub_broadcast_block_with_accept() {
+        for _ in 0..100 {
+            tokio::time::timeout(
+                Duration::from_secs(5),
+                gossipsub_broadcast(
+                    GossipsubBroadcastRequest::NewBlock(Arc::new(SealedBlock::default())),
+                    GossipsubMessageAcceptance::Accept,
+                ),
+            )
+            .await
+            .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn gossipsub_broadcast_block_with_reject() {
+        for _ in 0..100 {
+            tokio::time::timeout(
+                Duration::from_secs(5),
+                gossipsub_broadcast(
+                    GossipsubBroadcastRequest::NewBlock(Arc::new(SealedBlock::default())),
+                    GossipsubMessageAcceptance::Reject,
+                ),
+            )
+            .await
+            .unwrap();
+        }
+    }
+
     #[tokio::test]
     #[instrument]
     #[ignore]
@@ -1471,12 +1506,19 @@ mod tests {
     ) {
         let mut p2p_config = Config::default_initialized("gossipsub_exchanges_messages");
 
-        let selected_topic: GossipTopic = {
-            let topic = match broadcast_request {
-                GossipsubBroadcastRequest::NewTx(_) => NEW_TX_GOSSIP_TOPIC,
+        let (selected_tag, selected_topic) = {
+            let (tag, topic) = match broadcast_request {
+                GossipsubBroadcastRequest::NewTx(_) => {
+                    (GossipTopicTag::NewTx, NEW_TX_GOSSIP_TOPIC)
+                }
+                GossipsubBroadcastRequest::NewBlock(_) => {
+                    (GossipTopicTag::NewBlock, NEW_BLOCK_GOSSIP_TOPIC)
+                }
             };
 
-            Topic::new(format!("{}/{}", topic, p2p_config.network_name))
+            let topic: GossipTopic =
+                Topic::new(format!("{}/{}", topic, p2p_config.network_name));
+            (tag, topic)
         };
 
         let mut message_sent = false;
@@ -1511,16 +1553,16 @@ mod tests {
 
             tokio::select! {
                 node_a_event = node_a.next_event() => {
-                    if let Some(FuelP2PEvent::NewSubscription { peer_id, .. }) = &node_a_event {
-                        if peer_id == &node_b.local_peer_id {
+                    if let Some(FuelP2PEvent::NewSubscription { peer_id, tag }) = &node_a_event {
+                        if peer_id == &node_b.local_peer_id && tag == &selected_tag {
                             a_connected_to_b = true;
                         }
                     }
                     tracing::info!("Node A Event: {:?}", node_a_event);
                 },
                 node_b_event = node_b.next_event() => {
-                    if let Some(FuelP2PEvent::NewSubscription { peer_id, .. }) = &node_b_event {
-                        if peer_id == &node_c.local_peer_id {
+                    if let Some(FuelP2PEvent::NewSubscription { peer_id, tag }) = &node_b_event {
+                        if peer_id == &node_c.local_peer_id && tag == &selected_tag {
                             b_connected_to_c = true;
                         }
                     }
@@ -1544,6 +1586,12 @@ mod tests {
                                     panic!("Wrong GossipsubMessage")
                                 }
                             }
+                            GossipsubMessage::NewBlock(block) => {
+                                if block != &SealedBlock::default() {
+                                    tracing::error!("Wrong p2p message {:?}", message);
+                                    panic!("Wrong GossipsubMessage")
+                                }
+                            }
                         }
 
                         // Node B received the correct message
@@ -225,19 +225,31 @@ impl PeerManager {
     }
 
     /// Find a peer that is holding the given block height.
-    pub fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId> {
+    /// Selects a random peer that has the block at the `height`.
+    /// The `excluded_peers` are selected only if no other peer has the block.
+    pub fn get_peer_id_with_height(
+        &self,
+        height: &BlockHeight,
+        excluded_peers: &[PeerId],
+    ) -> Option<PeerId> {
         let mut range = rand::thread_rng();
         // TODO: Optimize the selection of the peer.
         //  We can store pair `(peer id, height)` for all nodes(reserved and not) in the
         //  https://docs.rs/sorted-vec/latest/sorted_vec/struct.SortedVec.html
-        self.non_reserved_connected_peers
+        let (excluded, preferred): (Vec<_>, Vec<_>) = self
+            .non_reserved_connected_peers
             .iter()
             .chain(self.reserved_connected_peers.iter())
             .filter(|(_, peer_info)| {
                 peer_info.heartbeat_data.block_height >= Some(*height)
             })
             .map(|(peer_id, _)| *peer_id)
+            .partition(|peer_id| excluded_peers.contains(peer_id));
+
+        preferred
+            .into_iter()
             .choose(&mut range)
+            .or_else(|| excluded.into_iter().choose(&mut range))
     }
 
     /// Handles the first connection established with a Peer
@@ -388,6 +400,7 @@ pub trait Punisher {
 }
 
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
     use super::*;
 
@@ -486,4 +499,45 @@ mod tests {
             reserved_peers.len() + max_non_reserved_peers
         );
     }
+
+    #[test]
+    fn get_peer_id_with_height__prefers_not_excluded_peers() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let peers = get_random_peers(2);
+        for peer_id in &peers {
+            peer_manager.handle_initial_connection(peer_id);
+            peer_manager.handle_peer_info_updated(peer_id, 10u32.into());
+        }
+
+        // Given
+        let excluded_peers = vec![peers[0]];
+
+        // When
+        let selected =
+            peer_manager.get_peer_id_with_height(&10u32.into(), &excluded_peers);
+
+        // Then
+        assert_eq!(selected, Some(peers[1]));
+    }
+
+    #[test]
+    fn get_peer_id_with_height__selects_excluded_peer_if_no_other_has_the_height() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let peers = get_random_peers(2);
+        for peer_id in &peers {
+            peer_manager.handle_initial_connection(peer_id);
+        }
+        peer_manager.handle_peer_info_updated(&peers[0], 10u32.into());
+        peer_manager.handle_peer_info_updated(&peers[1], 5u32.into());
+
+        // Given
+        let excluded_peers = vec![peers[0]];
+
+        // When
+        let selected =
+            peer_manager.get_peer_id_with_height(&10u32.into(), &excluded_peers);
+
+        // Then
+        assert_eq!(selected, Some(peers[0]));
+    }
 }
@@ -3,6 +3,7 @@ use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
     blockchain::{
         consensus::Genesis,
+        SealedBlock,
         SealedBlockHeader,
     },
     fuel_tx::TxId,
@@ -12,7 +13,10 @@ use fuel_core_types::{
         Transactions,
     },
 };
-use std::ops::Range;
+use std::{
+    ops::Range,
+    sync::Arc,
+};
 
 pub trait P2pDb: Send + Sync {
     fn get_sealed_headers(
@@ -31,6 +35,9 @@ pub trait P2pDb: Send + Sync {
 pub trait BlockHeightImporter: Send + Sync {
     /// Creates a stream of next block heights
     fn next_block_height(&self) -> BoxStream<BlockHeight>;
+
+    /// Creates a stream of blocks produced by this node
+    fn next_produced_block(&self) -> BoxStream<Arc<SealedBlock>>;
 }
 
 pub trait TxPool: Send + Sync + Clone {
@@ -39,7 +39,10 @@ use fuel_core_services::{
 };
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
-    blockchain::SealedBlockHeader,
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+    },
     fuel_tx::{
         Transaction,
         TxId,
@@ -54,6 +57,7 @@ use fuel_core_types::{
             AppScore,
             PeerReport,
         },
+        BlockGossipData,
         BlockHeightHeartbeatData,
         GossipData,
         GossipsubMessageAcceptance,
@@ -95,6 +99,9 @@ use tokio::{
 use tracing::warn;
 
 const CHANNEL_SIZE: usize = 1024 * 10;
+/// Blocks are much bigger than other messages, so the channel of gossiped blocks
+/// is smaller to limit the memory used by slow subscribers.
+const BLOCK_CHANNEL_SIZE: usize = 64;
 
 pub type Service<V, T> = ServiceRunner<UninitializedTask<V, SharedState, T>>;
 
@@ -107,6 +114,7 @@ pub enum TaskRequest {
     },
     GetSealedHeaders {
         block_height_range: Range<u32>,
+        excluded_peers: Vec<PeerId>,
         channel: OnResponse<Option<Vec<SealedBlockHeader>>>,
     },
     GetTransactions {
@@ -199,7 +207,11 @@ pub enum HeartBeatPeerReportReason {
 
 pub trait TaskP2PService: Send {
     fn get_all_peer_info(&self) -> Vec<(&PeerId, &PeerInfo)>;
-    fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId>;
+    fn get_peer_id_with_height(
+        &self,
+        height: &BlockHeight,
+        excluded_peers: &[PeerId],
+    ) -> Option<PeerId>;
 
     fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>>;
 
@@ -253,8 +265,13 @@ impl TaskP2PService for FuelP2PService {
         self.peer_manager().get_all_peers().collect()
     }
 
-    fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId> {
-        self.peer_manager().get_peer_id_with_height(height)
+    fn get_peer_id_with_height(
+        &self,
+        height: &BlockHeight,
+        excluded_peers: &[PeerId],
+    ) -> Option<PeerId> {
+        self.peer_manager()
+            .get_peer_id_with_height(height, excluded_peers)
     }
 
     fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>> {
@@ -328,6 +345,8 @@ pub trait Broadcast: Send {
 
     fn tx_broadcast(&self, transaction: TransactionGossipData) -> anyhow::Result<()>;
 
+    fn block_broadcast(&self, block: BlockGossipData) -> anyhow::Result<()>;
+
     fn new_tx_subscription_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()>;
 }
 
@@ -354,6 +373,11 @@ impl Broadcast for SharedState {
         Ok(())
     }
 
+    fn block_broadcast(&self, block: BlockGossipData) -> anyhow::Result<()> {
+        self.block_broadcast.send(block)?;
+        Ok(())
+    }
+
     fn new_tx_subscription_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()> {
         self.new_tx_subscription_broadcast.send(peer_id)?;
         Ok(())
@@ -365,6 +389,7 @@ pub struct UninitializedTask<V, B, T> {
     chain_id: ChainId,
     view_provider: V,
     next_block_height: BoxStream<BlockHeight>,
+    next_produced_block: BoxStream<Arc<SealedBlock>>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
     broadcast: B,
@@ -380,6 +405,8 @@ pub struct Task<P, V, B, T> {
     p2p_service: P,
     view_provider: V,
     next_block_height: BoxStream<BlockHeight>,
+    /// Blocks produced by this node that are gossiped to the network
+    next_produced_block: BoxStream<Arc<SealedBlock>>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
     request_sender: mpsc::Sender<TaskRequest>,
@@ -413,12 +440,14 @@ impl<V, T> UninitializedTask<V, SharedState, T> {
         tx_pool: T,
     ) -> Self {
         let next_block_height = block_importer.next_block_height();
+        let next_produced_block = block_importer.next_produced_block();
 
         Self {
             chain_id,
             view_provider,
             tx_pool,
             next_block_height,
+            next_produced_block,
             request_receiver,
             broadcast: shared_state,
             config,
@@ -713,6 +742,7 @@ where
             chain_id,
             view_provider,
             next_block_height,
+            next_produced_block,
             request_receiver,
             broadcast,
             tx_pool,
@@ -764,6 +794,7 @@ where
             request_receiver,
             request_sender,
             next_block_height,
+            next_produced_block,
             broadcast,
             tx_pool,
             heavy_task_processor,
@@ -807,6 +838,19 @@ where
                     should_continue = false;
                 }
             },
+            produced_block = self.next_produced_block.next() => {
+                if let Some(block) = produced_block {
+                    let height = *block.entity.header().height();
+                    let broadcast = GossipsubBroadcastRequest::NewBlock(block);
+                    let result = self.p2p_service.publish_message(broadcast);
+                    if let Err(e) = result {
+                        tracing::error!("Got an error during block {} broadcasting {}", height, e);
+                    }
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            },
             next_service_request = self.request_receiver.recv() => {
                 should_continue = true;
                 match next_service_request {
@@ -818,14 +862,14 @@ where
                             tracing::error!("Got an error during transaction {} broadcasting {}", tx_id, e);
                         }
                     }
-                    Some(TaskRequest::GetSealedHeaders { block_height_range, channel}) => {
+                    Some(TaskRequest::GetSealedHeaders { block_height_range, excluded_peers, channel}) => {
                         let channel = ResponseSender::SealedHeaders(channel);
                         let request_msg = RequestMessage::SealedHeaders(block_height_range.clone());
 
                         // Note: this range has already been checked for
                         // validity in `SharedState::get_sealed_block_headers`.
                         let height = BlockHeight::from(block_height_range.end.saturating_sub(1));
-                        let peer = self.p2p_service.get_peer_id_with_height(&height);
+                        let peer = self.p2p_service.get_peer_id_with_height(&height, &excluded_peers);
                         if self.p2p_service.send_request_msg(peer, request_msg, channel).is_err() {
                             tracing::warn!("No peers found for block at height {:?}", height);
                         }
@@ -897,6 +941,10 @@ where
                                 let next_transaction = GossipData::new(transaction, peer_id, message_id);
                                 let _ = self.broadcast.tx_broadcast(next_transaction);
                             },
+                            GossipsubMessage::NewBlock(block) => {
+                                let next_block = GossipData::new(block, peer_id, message_id);
+                                let _ = self.broadcast.block_broadcast(next_block);
+                            },
                         }
                     },
                     Some(FuelP2PEvent::InboundRequestMessage { request_message, request_id }) => {
@@ -945,6 +993,8 @@ pub struct SharedState {
     new_tx_subscription_broadcast: broadcast::Sender<FuelPeerId>,
     /// Sender of p2p transaction used for subscribing.
     tx_broadcast: broadcast::Sender<TransactionGossipData>,
+    /// Sender of p2p blocks used for subscribing.
+    block_broadcast: broadcast::Sender<BlockGossipData>,
     /// Sender of reserved peers connection updates.
     reserved_peers_broadcast: broadcast::Sender<usize>,
     /// Used for communicating with the `Task`.
@@ -969,9 +1019,33 @@ impl SharedState {
         Ok(())
     }
 
+    /// Reports the validity of the gossiped block to the network.
+    /// The rejected block penalizes the peer that propagated it.
+    pub fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        acceptance: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        if acceptance == GossipsubMessageAcceptance::Reject {
+            tracing::debug!(
+                "Rejecting the block gossiped by the peer {}",
+                message_info.peer_id
+            );
+        }
+        self.request_sender
+            .try_send(TaskRequest::RespondWithGossipsubMessageReport((
+                message_info,
+                acceptance,
+            )))?;
+        Ok(())
+    }
+
+    /// Requests the headers from a peer that has them.
+    /// The `excluded_peers` are asked only if no other peer has the headers.
     pub async fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
+        excluded_peers: Vec<FuelPeerId>,
     ) -> anyhow::Result<(Vec<u8>, Option<Vec<SealedBlockHeader>>)> {
         let (sender, receiver) = oneshot::channel();
 
@@ -981,9 +1055,15 @@ impl SharedState {
             ));
         }
 
+        let excluded_peers = excluded_peers
+            .iter()
+            .map(|peer_id| PeerId::from_bytes(peer_id.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+
         self.request_sender
             .send(TaskRequest::GetSealedHeaders {
                 block_height_range,
+                excluded_peers,
                 channel: sender,
             })
             .await?;
@@ -1118,6 +1198,10 @@ impl SharedState {
         self.tx_broadcast.subscribe()
     }
 
+    pub fn subscribe_block(&self) -> broadcast::Receiver<BlockGossipData> {
+        self.block_broadcast.subscribe()
+    }
+
     pub fn subscribe_block_height(
         &self,
     ) -> broadcast::Receiver<BlockHeightHeartbeatData> {
@@ -1160,6 +1244,7 @@ pub fn build_shared_state(
 ) -> (SharedState, Receiver<TaskRequest>) {
     let (request_sender, request_receiver) = mpsc::channel(CHANNEL_SIZE);
     let (tx_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
+    let (block_broadcast, _) = broadcast::channel(BLOCK_CHANNEL_SIZE);
     let (new_tx_subscription_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
     let (block_height_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
 
@@ -1176,6 +1261,7 @@ pub fn build_shared_state(
             request_sender,
             new_tx_subscription_broadcast,
             tx_broadcast,
+            block_broadcast,
             reserved_peers_broadcast,
             block_height_broadcast,
             max_txs_per_request: config.max_txs_per_request,
@@ -1303,6 +1389,10 @@ pub mod tests {
         fn next_block_height(&self) -> BoxStream<BlockHeight> {
             Box::pin(fuel_core_services::stream::pending())
         }
+
+        fn next_produced_block(&self) -> BoxStream<Arc<SealedBlock>> {
+            Box::pin(fuel_core_services::stream::pending())
+        }
     }
 
     #[derive(Clone, Debug)]
@@ -1358,7 +1448,11 @@ pub mod tests {
             self.peer_info.iter().map(|tup| (&tup.0, &tup.1)).collect()
         }
 
-        fn get_peer_id_with_height(&self, _height: &BlockHeight) -> Option<PeerId> {
+        fn get_peer_id_with_height(
+            &self,
+            _height: &BlockHeight,
+            _excluded_peers: &[PeerId],
+        ) -> Option<PeerId> {
             todo!()
         }
 
@@ -1476,6 +1570,10 @@ pub mod tests {
             todo!()
         }
 
+        fn block_broadcast(&self, _block: BlockGossipData) -> anyhow::Result<()> {
+            Ok(())
+        }
+
         fn new_tx_subscription_broadcast(
             &self,
             _peer_id: FuelPeerId,
@@ -1535,6 +1633,7 @@ pub mod tests {
             p2p_service,
             view_provider: FakeDB,
             next_block_height: FakeBlockImporter.next_block_height(),
+            next_produced_block: FakeBlockImporter.next_produced_block(),
             tx_pool: FakeTxPool,
             request_receiver,
             request_sender,
@@ -1625,6 +1724,7 @@ pub mod tests {
             view_provider: FakeDB,
             tx_pool: FakeTxPool,
             next_block_height: FakeBlockImporter.next_block_height(),
+            next_produced_block: FakeBlockImporter.next_produced_block(),
             request_receiver,
             request_sender,
             heavy_task_processor: HeavyTaskProcessor::new(1, 1).unwrap(),
@@ -1686,6 +1786,7 @@ pub mod tests {
             tx_pool: FakeTxPool,
             view_provider: FakeDB,
             next_block_height,
+            next_produced_block: FakeBlockImporter.next_produced_block(),
             request_receiver,
             request_sender,
             heavy_task_processor: HeavyTaskProcessor::new(1, 1).unwrap(),
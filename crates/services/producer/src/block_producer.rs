@@ -294,12 +294,18 @@ where
         });
 
         let simulated_time = time.unwrap_or_else(|| {
-            view.get_block(&latest_height)
+            simulated_height
+                .pred()
+                .and_then(|previous_height| view.get_block(&previous_height).ok())
                 .map(|block| block.header().time())
                 .unwrap_or(Tai64::UNIX_EPOCH)
         });
 
-        let header = self.new_header(simulated_height, simulated_time)?;
+        let header = if simulated_height > latest_height {
+            self.new_header(simulated_height, simulated_time)?
+        } else {
+            Self::historical_header(simulated_height, simulated_time, &view)?
+        };
 
         let gas_price = if let Some(inner) = gas_price {
             inner
@@ -460,6 +466,41 @@ where
         })
     }
 
+    /// Creates the header for the block in the past to simulate it on top of the
+    /// previous block. The versions are taken from the previous block, because
+    /// the latest versions may be unknown to the state at that height.
+    fn historical_header(
+        height: BlockHeight,
+        block_time: Tai64,
+        view: &ViewProvider::LatestView,
+    ) -> anyhow::Result<PartialBlockHeader> {
+        let prev_height = height
+            .pred()
+            .ok_or_else(|| anyhow!("The genesis block can't be simulated"))?;
+        let previous_block = view
+            .get_block(&prev_height)
+            .map_err(|_| Error::MissingBlock(prev_height))?;
+        let prev_root = view.block_header_merkle_root(&prev_height)?;
+        let previous_application = previous_block.header().application();
+
+        Ok(PartialBlockHeader {
+            application: ApplicationHeader {
+                da_height: previous_application.da_height,
+                consensus_parameters_version: previous_application
+                    .consensus_parameters_version,
+                state_transition_bytecode_version: previous_application
+                    .state_transition_bytecode_version,
+                generated: Default::default(),
+            },
+            consensus: ConsensusHeader {
+                prev_root,
+                height,
+                time: block_time,
+                generated: Default::default(),
+            },
+        })
+    }
+
     fn previous_block_info(
         &self,
         height: BlockHeight,
@@ -669,7 +669,47 @@ mod dry_run {
         assert!(executor.has_no_captured_block_timestamp());
     }
 
+    #[tokio::test]
+    async fn dry_run__executes_on_top_of_the_past_block() {
+        // Given
+        let past_block_height = BlockHeight::new(42);
+        let past_block_time = Tai64::from_unix(1337);
+
+        let executor = MockExecutorWithCapture::default();
+        let ctx = TestContextBuilder::new()
+            .with_prev_height(past_block_height)
+            .with_prev_time(past_block_time)
+            .build_with_executor(executor.clone());
+        let simulated_height = past_block_height.succ().unwrap();
+        ctx.db
+            .blocks
+            .lock()
+            .unwrap()
+            .insert(simulated_height, CompressedBlock::default());
+
+        // When
+        let _ = ctx
+            .producer()
+            .dry_run(vec![], Some(simulated_height), None, None, None)
+            .await;
+
+        // Then
+        assert_eq!(executor.captured_block_height(), simulated_height);
+        assert_eq!(executor.captured_block_timestamp(), past_block_time);
+    }
+
     impl MockExecutorWithCapture<Transaction> {
+        fn captured_block_height(&self) -> BlockHeight {
+            *self
+                .captured
+                .lock()
+                .unwrap()
+                .as_ref()
+                .expect("should have captured a block")
+                .header_to_produce
+                .height()
+        }
+
         fn captured_block_timestamp(&self) -> Tai64 {
             *self
                 .captured
@@ -55,6 +55,8 @@ mod test;
 
 type Synced = watch::Receiver<Option<DaBlockHeight>>;
 type NotifySynced = watch::Sender<Option<DaBlockHeight>>;
+type RemoteFinalized = watch::Receiver<Option<DaBlockHeight>>;
+type NotifyRemoteFinalized = watch::Sender<Option<DaBlockHeight>>;
 
 /// The alias of runnable relayer service.
 pub type Service<D> = CustomizableService<Provider<QuorumProvider<Http>>, D>;
@@ -65,6 +67,8 @@ type CustomizableService<P, D> = ServiceRunner<NotInitializedTask<P, D>>;
 pub struct SharedState<D> {
     /// Receives signals when the relayer reaches consistency with the DA layer.
     synced: Synced,
+    /// Receives the finalized height of the DA layer observed by the relayer.
+    remote_finalized: RemoteFinalized,
     start_da_block_height: DaBlockHeight,
     database: D,
 }
@@ -73,6 +77,8 @@ pub struct SharedState<D> {
 pub struct NotInitializedTask<P, D> {
     /// Sends signals when the relayer reaches consistency with the DA layer.
     synced: NotifySynced,
+    /// Sends the finalized height of the DA layer observed by the relayer.
+    remote_finalized: NotifyRemoteFinalized,
     /// The node that communicates with Ethereum.
     eth_node: P,
     /// The fuel database.
@@ -87,6 +93,8 @@ pub struct NotInitializedTask<P, D> {
 pub struct Task<P, D> {
     /// Sends signals when the relayer reaches consistency with the DA layer.
     synced: NotifySynced,
+    /// Sends the finalized height of the DA layer observed by the relayer.
+    remote_finalized: NotifyRemoteFinalized,
     /// The node that communicates with Ethereum.
     eth_node: P,
     /// The fuel database.
@@ -104,8 +112,10 @@ impl<P, D> NotInitializedTask<P, D> {
     /// Create a new relayer task.
     fn new(eth_node: P, database: D, config: Config, retry_on_error: bool) -> Self {
         let (synced, _) = watch::channel(None);
+        let (remote_finalized, _) = watch::channel(None);
         Self {
             synced,
+            remote_finalized,
             eth_node,
             database,
             config,
@@ -178,9 +188,11 @@ where
 
     fn shared_data(&self) -> Self::SharedData {
         let synced = self.synced.subscribe();
+        let remote_finalized = self.remote_finalized.subscribe();
 
         SharedState {
             synced,
+            remote_finalized,
             start_da_block_height: self.config.da_deploy_height,
             database: self.database.clone(),
         }
@@ -194,6 +206,7 @@ where
         let shutdown = watcher.clone();
         let NotInitializedTask {
             synced,
+            remote_finalized,
             eth_node,
             database,
             config,
@@ -201,6 +214,7 @@ where
         } = self;
         let task = Task {
             synced,
+            remote_finalized,
             eth_node,
             database,
             config,
@@ -299,6 +313,13 @@ impl<D> SharedState<D> {
             .unwrap_or(self.start_da_block_height)
     }
 
+    /// Get the finalized height of the DA layer observed by the relayer
+    /// during the last synchronization. Returns `None` if the relayer
+    /// didn't reach the DA node yet.
+    pub fn get_remote_finalized_da_height(&self) -> Option<DaBlockHeight> {
+        *self.remote_finalized.borrow()
+    }
+
     /// Getter for database field
     pub fn database(&self) -> &D {
         &self.database
@@ -323,6 +344,7 @@ where
                     .and_then(|block| block.number)
                     .ok_or(anyhow::anyhow!("Block pending"))?
                     .as_u64();
+                self.remote_finalized.send_replace(Some(block_number.into()));
                 Ok(block_number)
             }
         }
@@ -0,0 +1,162 @@
+//! # Gossip task
+//! Imports blocks gossiped by peers right after their production.
+//! It allows the node to follow the tip of the chain without
+//! requesting headers and transactions from peers.
+
+use std::sync::Arc;
+
+use fuel_core_services::{
+    stream::BoxStream,
+    SharedMutex,
+};
+use fuel_core_types::{
+    blockchain::SealedBlockHeader,
+    services::p2p::{
+        BlockGossipData,
+        GossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
+    },
+};
+use futures::StreamExt;
+
+use crate::{
+    import::{
+        execute_and_commit,
+        report_peer,
+    },
+    ports::{
+        BlockImporterPort,
+        ConsensusPort,
+        PeerReportReason,
+        PeerToPeerPort,
+    },
+    state::State,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Imports blocks gossiped by peers.
+pub(crate) struct GossipedBlocks<P, E, C> {
+    block_stream: BoxStream<BlockGossipData>,
+    state: SharedMutex<State>,
+    p2p: Arc<P>,
+    executor: Arc<E>,
+    consensus: Arc<C>,
+}
+
+impl<P, E, C> GossipedBlocks<P, E, C>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    E: BlockImporterPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    pub(crate) fn new(
+        block_stream: BoxStream<BlockGossipData>,
+        state: SharedMutex<State>,
+        p2p: Arc<P>,
+        executor: Arc<E>,
+        consensus: Arc<C>,
+    ) -> Self {
+        Self {
+            block_stream,
+            state,
+            p2p,
+            executor,
+            consensus,
+        }
+    }
+
+    /// Waits for the next gossiped block.
+    /// Returns `None` if the stream of gossiped blocks is closed.
+    pub(crate) async fn next(&mut self) -> Option<BlockGossipData> {
+        self.block_stream.next().await
+    }
+
+    #[tracing::instrument(skip_all)]
+    /// Verifies the gossiped block and reports the result to the network.
+    /// The valid block is imported if it is the next block after the committed one.
+    /// Other blocks are synced by the import task.
+    pub(crate) async fn import(&self, message: BlockGossipData) {
+        let GossipData {
+            data,
+            peer_id,
+            message_id,
+        } = message;
+        let Some(block) = data else { return };
+
+        let header = SealedBlockHeader {
+            entity: block.entity.header().clone(),
+            consensus: block.consensus.clone(),
+        };
+        // The header is signed by the producer, but the body is not, so the
+        // transactions are checked against the header before propagating the block.
+        let acceptance = match self.consensus.check_sealed_header(&header) {
+            Ok(true)
+                if header
+                    .entity
+                    .validate_transactions(block.entity.transactions()) =>
+            {
+                GossipsubMessageAcceptance::Accept
+            }
+            Ok(true) => {
+                report_peer(
+                    &self.p2p,
+                    Some(peer_id.clone()),
+                    PeerReportReason::InvalidTransactions,
+                );
+                GossipsubMessageAcceptance::Reject
+            }
+            Ok(false) => {
+                report_peer(
+                    &self.p2p,
+                    Some(peer_id.clone()),
+                    PeerReportReason::BadBlockHeader,
+                );
+                GossipsubMessageAcceptance::Reject
+            }
+            Err(err) => {
+                tracing::error!("Failed to check the gossiped block: {:?}", err);
+                GossipsubMessageAcceptance::Ignore
+            }
+        };
+        let message_info = GossipsubMessageInfo {
+            message_id,
+            peer_id: peer_id.clone(),
+        };
+        let _ = self
+            .p2p
+            .notify_gossip_block_validity(message_info, acceptance);
+
+        if acceptance != GossipsubMessageAcceptance::Accept {
+            return
+        }
+
+        let height = *header.entity.height();
+        if !self.state.apply(|s| s.is_next_to_commit(*height)) {
+            tracing::debug!("Skipping the gossiped block at height {}", *height);
+            return
+        }
+
+        if let Err(err) = self
+            .consensus
+            .await_da_height(&header.entity.da_height)
+            .await
+        {
+            tracing::error!("Failed to await DA height {:?}", err);
+            return
+        }
+
+        if execute_and_commit(self.executor.as_ref(), &self.state, block)
+            .await
+            .is_ok()
+        {
+            report_peer(
+                &self.p2p,
+                Some(peer_id),
+                PeerReportReason::SuccessfulBlockImport,
+            );
+        }
+    }
+}
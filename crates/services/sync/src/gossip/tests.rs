@@ -0,0 +1,172 @@
+#![allow(non_snake_case)]
+
+use fuel_core_services::stream::IntoBoxStream;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        SealedBlock,
+    },
+    fuel_tx::Transaction,
+    services::p2p::PeerId,
+};
+
+use crate::{
+    import::test_helpers::empty_header,
+    ports::{
+        MockBlockImporterPort,
+        MockConsensusPort,
+        MockPeerToPeerPort,
+    },
+};
+
+use super::*;
+
+fn gossiped_block(height: u32) -> BlockGossipData {
+    let header = empty_header(height);
+    let block = Block::try_from_executed(header.entity, vec![])
+        .expect("The transaction root of the header is valid");
+    let block = SealedBlock {
+        entity: block,
+        consensus: header.consensus,
+    };
+    let peer_id: PeerId = vec![1, 2, 3].into();
+    GossipData::new(block, peer_id, vec![4, 5, 6])
+}
+
+fn gossiped_blocks(
+    state: State,
+    p2p: MockPeerToPeerPort,
+    executor: MockBlockImporterPort,
+    consensus: MockConsensusPort,
+) -> GossipedBlocks<MockPeerToPeerPort, MockBlockImporterPort, MockConsensusPort> {
+    GossipedBlocks::new(
+        futures::stream::pending().into_boxed(),
+        SharedMutex::new(state),
+        Arc::new(p2p),
+        Arc::new(executor),
+        Arc::new(consensus),
+    )
+}
+
+#[tokio::test]
+async fn import__valid_next_block_is_accepted_and_committed() {
+    // given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_notify_gossip_block_validity()
+        .times(1)
+        .withf(|_, validity| *validity == GossipsubMessageAcceptance::Accept)
+        .returning(|_, _| Ok(()));
+    p2p.expect_report_peer()
+        .times(1)
+        .withf(|_, report| *report == PeerReportReason::SuccessfulBlockImport)
+        .returning(|_, _| Ok(()));
+    let mut executor = MockBlockImporterPort::default();
+    executor
+        .expect_execute_and_commit()
+        .times(1)
+        .returning(|_| Ok(()));
+    let mut consensus = MockConsensusPort::default();
+    consensus
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    consensus.expect_await_da_height().returning(|_| Ok(()));
+    let gossiped_blocks = gossiped_blocks(State::new(4, None), p2p, executor, consensus);
+
+    // when
+    gossiped_blocks.import(gossiped_block(5)).await;
+
+    // then
+    assert_eq!(
+        gossiped_blocks.state.apply(|s| s.clone()),
+        State::new(5, None)
+    );
+}
+
+#[tokio::test]
+async fn import__block_with_invalid_header_is_rejected() {
+    // given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_notify_gossip_block_validity()
+        .times(1)
+        .withf(|_, validity| *validity == GossipsubMessageAcceptance::Reject)
+        .returning(|_, _| Ok(()));
+    p2p.expect_report_peer()
+        .times(1)
+        .withf(|_, report| *report == PeerReportReason::BadBlockHeader)
+        .returning(|_, _| Ok(()));
+    let mut executor = MockBlockImporterPort::default();
+    executor.expect_execute_and_commit().times(0);
+    let mut consensus = MockConsensusPort::default();
+    consensus
+        .expect_check_sealed_header()
+        .returning(|_| Ok(false));
+    let gossiped_blocks = gossiped_blocks(State::new(4, None), p2p, executor, consensus);
+
+    // when
+    gossiped_blocks.import(gossiped_block(5)).await;
+
+    // then
+    assert_eq!(
+        gossiped_blocks.state.apply(|s| s.clone()),
+        State::new(4, None)
+    );
+}
+
+#[tokio::test]
+async fn import__block_with_transactions_not_matching_header_is_rejected() {
+    // given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_notify_gossip_block_validity()
+        .times(1)
+        .withf(|_, validity| *validity == GossipsubMessageAcceptance::Reject)
+        .returning(|_, _| Ok(()));
+    p2p.expect_report_peer()
+        .times(1)
+        .withf(|_, report| *report == PeerReportReason::InvalidTransactions)
+        .returning(|_, _| Ok(()));
+    let mut executor = MockBlockImporterPort::default();
+    executor.expect_execute_and_commit().times(0);
+    let mut consensus = MockConsensusPort::default();
+    consensus
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    let gossiped_blocks = gossiped_blocks(State::new(4, None), p2p, executor, consensus);
+    let mut message = gossiped_block(5);
+    let block = message.data.as_mut().expect("The block is set above");
+    block
+        .entity
+        .transactions_mut()
+        .push(Transaction::default());
+
+    // when
+    gossiped_blocks.import(message).await;
+
+    // then
+    assert_eq!(
+        gossiped_blocks.state.apply(|s| s.clone()),
+        State::new(4, None)
+    );
+}
+
+#[tokio::test]
+async fn import__valid_block_is_not_committed_while_the_range_is_processed() {
+    // given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_notify_gossip_block_validity()
+        .times(1)
+        .withf(|_, validity| *validity == GossipsubMessageAcceptance::Accept)
+        .returning(|_, _| Ok(()));
+    let mut executor = MockBlockImporterPort::default();
+    executor.expect_execute_and_commit().times(0);
+    let mut consensus = MockConsensusPort::default();
+    consensus
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    let gossiped_blocks = gossiped_blocks(State::new(4, 6), p2p, executor, consensus);
+
+    // when
+    gossiped_blocks.import(gossiped_block(5)).await;
+
+    // then
+    assert_eq!(gossiped_blocks.state.apply(|s| s.clone()), State::new(4, 6));
+}
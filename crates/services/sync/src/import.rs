@@ -27,12 +27,17 @@ use futures::{
     Stream,
 };
 use std::{
+    collections::HashMap,
     future::Future,
     ops::{
         Range,
         RangeInclusive,
     },
     sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 use tokio::{
     pin,
@@ -72,6 +77,9 @@ pub struct Config {
     pub block_stream_buffer_size: usize,
     /// The maximum number of headers to request in a single batch.
     pub header_batch_size: usize,
+    /// The maximum number of times the part of the batch that the peer failed
+    /// to provide is re-requested from other peers.
+    pub max_batch_retries: usize,
 }
 
 impl Default for Config {
@@ -79,6 +87,7 @@ impl Default for Config {
         Self {
             block_stream_buffer_size: 10,
             header_batch_size: 100,
+            max_batch_retries: 3,
         }
     }
 }
@@ -98,6 +107,8 @@ pub struct Import<P, E, C> {
     executor: Arc<E>,
     /// Consensus port.
     consensus: Arc<C>,
+    /// The download throughput of peers.
+    throughput: SharedMutex<PeersThroughput>,
 }
 
 impl<P, E, C> Import<P, E, C> {
@@ -118,6 +129,7 @@ impl<P, E, C> Import<P, E, C> {
             p2p,
             executor,
             consensus,
+            throughput: Default::default(),
         }
     }
 
@@ -151,6 +163,60 @@ impl<T> Batch<T> {
 type SealedHeaderBatch = Batch<SealedBlockHeader>;
 type SealedBlockBatch = Batch<SealedBlock>;
 
+/// The weight of the latest measurement in the average throughput of the peer.
+const THROUGHPUT_SMOOTHING_FACTOR: f64 = 0.3;
+/// The peer is considered fast if its throughput is this many times higher
+/// than the average throughput of other peers, and slow if it is this many times lower.
+const THROUGHPUT_DEVIATION_FACTOR: f64 = 2.0;
+
+/// Tracks the download throughput of peers to reward fast peers and penalize slow ones.
+#[derive(Debug, Default)]
+pub(crate) struct PeersThroughput {
+    blocks_per_second: HashMap<PeerId, f64>,
+}
+
+impl PeersThroughput {
+    /// Records that the `peer` provided `blocks` within `elapsed` time.
+    /// Returns the report for the peer if its throughput deviates significantly
+    /// from the throughput of other peers.
+    pub(crate) fn record(
+        &mut self,
+        peer: &PeerId,
+        blocks: u32,
+        elapsed: Duration,
+    ) -> Option<PeerReportReason> {
+        let measured = f64::from(blocks) / elapsed.as_secs_f64().max(f64::EPSILON);
+        let throughput = self
+            .blocks_per_second
+            .entry(peer.clone())
+            .and_modify(|average| {
+                *average = THROUGHPUT_SMOOTHING_FACTOR * measured
+                    + (1.0 - THROUGHPUT_SMOOTHING_FACTOR) * *average
+            })
+            .or_insert(measured);
+        let throughput = *throughput;
+
+        let others = self
+            .blocks_per_second
+            .iter()
+            .filter(|(id, _)| *id != peer)
+            .map(|(_, throughput)| *throughput)
+            .collect::<Vec<_>>();
+        if others.is_empty() {
+            return None
+        }
+        let others_average = others.iter().sum::<f64>() / others.len() as f64;
+
+        if throughput > others_average * THROUGHPUT_DEVIATION_FACTOR {
+            Some(PeerReportReason::FastBlocksDownload)
+        } else if throughput * THROUGHPUT_DEVIATION_FACTOR < others_average {
+            Some(PeerReportReason::SlowBlocksDownload)
+        } else {
+            None
+        }
+    }
+}
+
 impl<P, E, C> Import<P, E, C>
 where
     P: PeerToPeerPort + Send + Sync + 'static,
@@ -199,6 +265,7 @@ where
             params,
             p2p,
             consensus,
+            throughput,
             ..
         } = &self;
 
@@ -208,11 +275,12 @@ where
             let params = *params;
             let p2p = p2p.clone();
             let consensus = consensus.clone();
+            let throughput = throughput.clone();
             let block_stream_buffer_size = params.block_stream_buffer_size;
             let mut shutdown_signal = shutdown.clone();
             async move {
                 let block_stream =
-                    get_block_stream(range.clone(), params, p2p, consensus);
+                    get_block_stream(range.clone(), params, p2p, consensus, throughput);
 
                 let shutdown_future = {
                     let mut s = shutdown_signal.clone();
@@ -247,6 +315,8 @@ where
                     .take_until(shutdown_future)
                     .into_scan_none()
                     .scan_none()
+                    // Batches re-requested from other peers follow the original batch.
+                    .flat_map(futures::stream::iter)
                     .into_scan_err()
                     .scan_err();
 
@@ -364,78 +434,148 @@ fn get_block_stream<
     params: Config,
     p2p: Arc<P>,
     consensus: Arc<C>,
-) -> impl Stream<Item = impl Future<Output = SealedBlockBatch>> {
-    let header_stream = get_header_batch_stream(range.clone(), params, p2p.clone());
-    header_stream
-        .map({
-            let p2p = p2p.clone();
-            let consensus = consensus.clone();
-            move |header_batch| {
-                let p2p = p2p.clone();
-                let consensus = consensus.clone();
-                async move {
-                    let Batch {
-                        peer,
-                        range,
-                        results,
-                    } = header_batch.await;
-                    let checked_headers = results
-                        .into_iter()
-                        .take_while(|header| {
-                            check_sealed_header(header, peer.clone(), &p2p, &consensus)
-                        })
-                        .collect::<Vec<_>>();
-                    Batch::new(peer, range, checked_headers)
-                }
-            }
-        })
-        .map({
-            let p2p = p2p.clone();
-            let consensus = consensus.clone();
-            move |headers| {
-                let p2p = p2p.clone();
-                let consensus = consensus.clone();
-                async move {
-                    let Batch {
-                        peer,
-                        range,
-                        results,
-                    } = headers.await;
-                    if results.is_empty() {
-                        SealedBlockBatch::new(peer, range, vec![])
-                    } else {
-                        await_da_height(
-                            results
-                                .last()
-                                .expect("We checked headers are not empty above"),
-                            &consensus,
-                        )
-                        .await;
-                        let headers = SealedHeaderBatch::new(peer, range, results);
-                        get_blocks(&p2p, headers).await
-                    }
-                }
-                .instrument(tracing::debug_span!("consensus_and_transactions"))
-                .in_current_span()
-            }
-        })
-}
-
-fn get_header_batch_stream<P: PeerToPeerPort + Send + Sync + 'static>(
-    range: RangeInclusive<u32>,
-    params: Config,
-    p2p: Arc<P>,
-) -> impl Stream<Item = impl Future<Output = SealedHeaderBatch>> {
+    throughput: SharedMutex<PeersThroughput>,
+) -> impl Stream<Item = impl Future<Output = Vec<SealedBlockBatch>>> {
     let Config {
-        header_batch_size, ..
+        header_batch_size,
+        max_batch_retries,
+        ..
     } = params;
     let ranges = range_chunks(range, header_batch_size);
     futures::stream::iter(ranges).map(move |range| {
         let p2p = p2p.clone();
-        async move { get_headers_batch(range, &p2p).await }
+        let consensus = consensus.clone();
+        let throughput = throughput.clone();
+        async move {
+            get_blocks_with_retries(
+                range,
+                max_batch_retries,
+                &p2p,
+                &consensus,
+                &throughput,
+            )
+            .await
+        }
+        .instrument(tracing::debug_span!("consensus_and_transactions"))
+        .in_current_span()
     })
 }
 
+/// Downloads blocks of the `range`. If the peer fails to provide the whole range,
+/// the rest of the range is re-requested from other peers up to `max_retries` times.
+///
+/// Returns batches of blocks from each peer ordered by height.
+/// Only the last batch can be incomplete.
+async fn get_blocks_with_retries<P, C>(
+    range: Range<u32>,
+    max_retries: usize,
+    p2p: &Arc<P>,
+    consensus: &Arc<C>,
+    throughput: &SharedMutex<PeersThroughput>,
+) -> Vec<SealedBlockBatch>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    let mut batches = vec![];
+    let mut excluded_peers = vec![];
+    let mut remaining = range;
+    let mut retries_left = max_retries;
+
+    loop {
+        let (batch, download_time) =
+            get_sealed_blocks(remaining.clone(), excluded_peers.clone(), p2p, consensus)
+                .await;
+        let received = u32::try_from(batch.results.len())
+            .expect("The number of blocks can't exceed the size of the range");
+
+        if let Some(peer) = &batch.peer {
+            if received > 0 {
+                let report = throughput.apply(|throughput| {
+                    throughput.record(peer, received, download_time)
+                });
+                if let Some(report) = report {
+                    report_peer(p2p, Some(peer.clone()), report);
+                }
+            }
+        }
+
+        if !batch.is_err() || retries_left == 0 {
+            batches.push(batch);
+            break
+        }
+        retries_left = retries_left.saturating_sub(1);
+
+        let Batch {
+            peer,
+            range,
+            results,
+        } = batch;
+        let received_end = range.start.saturating_add(received);
+        if !results.is_empty() {
+            batches.push(Batch::new(peer.clone(), range.start..received_end, results));
+        }
+        if let Some(peer) = peer {
+            excluded_peers.push(peer);
+        }
+        remaining = received_end..range.end;
+        tracing::debug!(
+            "Re-requesting the range from {} to {} from other peers",
+            remaining.start,
+            remaining.end
+        );
+    }
+
+    batches
+}
+
+/// Downloads headers and transactions of the `range` from one peer.
+///
+/// Returns the batch along with the time spent on downloading it from the peer.
+/// The time spent waiting for the local DA height is not included.
+async fn get_sealed_blocks<P, C>(
+    range: Range<u32>,
+    excluded_peers: Vec<PeerId>,
+    p2p: &Arc<P>,
+    consensus: &Arc<C>,
+) -> (SealedBlockBatch, Duration)
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    let started_at = Instant::now();
+    let Batch {
+        peer,
+        range,
+        results,
+    } = get_headers_batch(range, excluded_peers, p2p).await;
+    let headers_download_time = started_at.elapsed();
+    let checked_headers = results
+        .into_iter()
+        .take_while(|header| check_sealed_header(header, peer.clone(), p2p, consensus))
+        .collect::<Vec<_>>();
+
+    if checked_headers.is_empty() {
+        return (
+            SealedBlockBatch::new(peer, range, vec![]),
+            headers_download_time,
+        )
+    }
+
+    await_da_height(
+        checked_headers
+            .last()
+            .expect("We checked headers are not empty above"),
+        consensus,
+    )
+    .await;
+    let headers = SealedHeaderBatch::new(peer, range, checked_headers);
+    let started_at = Instant::now();
+    let blocks = get_blocks(p2p, headers).await;
+    let download_time = headers_download_time.saturating_add(started_at.elapsed());
+    (blocks, download_time)
+}
+
 fn range_chunks(
     range: RangeInclusive<u32>,
     chunk_size: usize,
@@ -498,6 +638,7 @@ async fn wait_for_notify_or_shutdown(
 
 async fn get_sealed_block_headers<P>(
     range: Range<u32>,
+    excluded_peers: Vec<PeerId>,
     p2p: &Arc<P>,
 ) -> Option<SourcePeer<Vec<SealedBlockHeader>>>
 where
@@ -508,7 +649,7 @@ where
         range.start,
         range.end
     );
-    p2p.get_sealed_block_headers(range)
+    p2p.get_sealed_block_headers(range, excluded_peers)
         .await
         .trace_err("Failed to get headers")
         .ok()
@@ -541,7 +682,11 @@ where
     }
 }
 
-async fn get_headers_batch<P>(range: Range<u32>, p2p: &Arc<P>) -> SealedHeaderBatch
+async fn get_headers_batch<P>(
+    range: Range<u32>,
+    excluded_peers: Vec<PeerId>,
+    p2p: &Arc<P>,
+) -> SealedHeaderBatch
 where
     P: PeerToPeerPort + Send + Sync + 'static,
 {
@@ -550,7 +695,9 @@ where
         range.start,
         range.end
     );
-    let Some(sourced_headers) = get_sealed_block_headers(range.clone(), p2p).await else {
+    let Some(sourced_headers) =
+        get_sealed_block_headers(range.clone(), excluded_peers, p2p).await
+    else {
         return Batch::new(None, range, vec![])
     };
     let SourcePeer {
@@ -577,8 +724,11 @@ where
     Batch::new(Some(peer_id), range, headers)
 }
 
-fn report_peer<P>(p2p: &Arc<P>, peer_id: Option<PeerId>, reason: PeerReportReason)
-where
+pub(crate) fn report_peer<P>(
+    p2p: &Arc<P>,
+    peer_id: Option<PeerId>,
+    reason: PeerReportReason,
+) where
     P: PeerToPeerPort + Send + Sync + 'static,
 {
     if let Some(peer_id) = peer_id {
@@ -646,7 +796,7 @@ where
     ),
     err
 )]
-async fn execute_and_commit<E>(
+pub(crate) async fn execute_and_commit<E>(
     executor: &E,
     state: &SharedMutex<State>,
     block: SealedBlock,
@@ -10,11 +10,17 @@ use crate::{
         PeerToPeerPort,
     },
 };
-use fuel_core_services::stream::BoxStream;
+use fuel_core_services::stream::{
+    BoxStream,
+    IntoBoxStream,
+};
 use fuel_core_types::{
     blockchain::SealedBlockHeader,
     fuel_types::BlockHeight,
     services::p2p::{
+        BlockGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
         PeerId,
         SourcePeer,
         Transactions,
@@ -40,11 +46,14 @@ impl PeerToPeerPort for PressurePeerToPeer {
     async fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
+        excluded_peers: Vec<PeerId>,
     ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlockHeader>>>> {
         self.counts.apply(|c| c.inc_headers());
         tokio::time::sleep(self.durations[0]).await;
         self.counts.apply(|c| c.dec_headers());
-        self.p2p.get_sealed_block_headers(block_height_range).await
+        self.p2p
+            .get_sealed_block_headers(block_height_range, excluded_peers)
+            .await
     }
 
     async fn get_transactions(
@@ -67,21 +76,34 @@ impl PeerToPeerPort for PressurePeerToPeer {
     ) -> anyhow::Result<()> {
         Ok(())
     }
+
+    fn gossiped_block_stream(&self) -> BoxStream<BlockGossipData> {
+        futures::stream::pending().into_boxed()
+    }
+
+    fn notify_gossip_block_validity(
+        &self,
+        _message_info: GossipsubMessageInfo,
+        _validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
 
 impl PressurePeerToPeer {
     pub fn new(counts: SharedCounts, delays: [Duration; 2]) -> Self {
         let mut mock = MockPeerToPeerPort::default();
-        mock.expect_get_sealed_block_headers().returning(|range| {
-            let peer = random_peer();
-            let headers = range
-                .clone()
-                .map(BlockHeight::from)
-                .map(empty_header)
-                .collect();
-            let headers = peer.bind(Some(headers));
-            Ok(headers)
-        });
+        mock.expect_get_sealed_block_headers()
+            .returning(|range, _| {
+                let peer = random_peer();
+                let headers = range
+                    .clone()
+                    .map(BlockHeight::from)
+                    .map(empty_header)
+                    .collect();
+                let headers = peer.bind(Some(headers));
+                Ok(headers)
+            });
         mock.expect_get_transactions().returning(|block_ids| {
             let data = block_ids.data;
             let v = data.into_iter().map(|_| Transactions::default()).collect();
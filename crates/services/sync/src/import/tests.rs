@@ -5,6 +5,9 @@ use crate::{
     import::test_helpers::{
         empty_header,
         random_peer,
+        PressureConsensus,
+        PressurePeerToPeer,
+        SharedCounts,
     },
     ports::{
         MockBlockImporterPort,
@@ -37,7 +40,7 @@ async fn test_import_0_to_5() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -54,6 +57,7 @@ async fn test_import_0_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
     let mocks = Mocks {
         consensus_port,
@@ -83,7 +87,7 @@ async fn test_import_3_to_5() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -100,6 +104,7 @@ async fn test_import_3_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
     let mocks = Mocks {
         consensus_port,
@@ -146,7 +151,7 @@ async fn test_import_0_to_499() {
     let times = div_ceil(n, header_batch_size);
     p2p.expect_get_sealed_block_headers()
         .times(times)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -166,6 +171,7 @@ async fn test_import_0_to_499() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size,
+        max_batch_retries: 0,
     };
     let mocks = Mocks {
         consensus_port,
@@ -195,7 +201,7 @@ async fn import__signature_fails_on_header_5_only() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -218,6 +224,7 @@ async fn import__signature_fails_on_header_5_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -243,7 +250,7 @@ async fn import__signature_fails_on_header_4_only() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -266,6 +273,7 @@ async fn import__signature_fails_on_header_4_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -281,7 +289,7 @@ async fn import__header_not_found() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| {
+        .returning(|_, _| {
             let peer = random_peer();
             let headers = Some(Vec::new());
             let headers = peer.bind(headers);
@@ -297,6 +305,7 @@ async fn import__header_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -312,7 +321,7 @@ async fn import__header_response_incomplete() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| {
+        .returning(|_, _| {
             let peer = random_peer();
             let headers = None;
             let headers = peer.bind(headers);
@@ -328,6 +337,7 @@ async fn import__header_response_incomplete() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -343,7 +353,7 @@ async fn import__header_5_not_found() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| {
+        .returning(|_, _| {
             let peer = random_peer();
             let headers = Some(vec![empty_header(4)]);
             let headers = peer.bind(headers);
@@ -367,6 +377,7 @@ async fn import__header_5_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -382,7 +393,7 @@ async fn import__header_4_not_found() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| {
+        .returning(|_, _| {
             let peer = random_peer();
             let headers = Some(vec![empty_header(5)]);
             let headers = peer.bind(headers);
@@ -399,6 +410,7 @@ async fn import__header_4_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -424,7 +436,7 @@ async fn import__transactions_not_found() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -443,6 +455,7 @@ async fn import__transactions_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -468,7 +481,7 @@ async fn import__transactions_not_found_for_header_4() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -497,6 +510,7 @@ async fn import__transactions_not_found_for_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -522,7 +536,7 @@ async fn import__transactions_not_found_for_header_5() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -542,6 +556,7 @@ async fn import__transactions_not_found_for_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -557,7 +572,7 @@ async fn import__p2p_error() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| Err(anyhow::anyhow!("Some network error")));
+        .returning(|_, _| Err(anyhow::anyhow!("Some network error")));
     p2p.expect_get_transactions().times(0);
 
     let state = State::new(3, 5).into();
@@ -569,6 +584,7 @@ async fn import__p2p_error() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -594,7 +610,7 @@ async fn import__p2p_error_on_4_transactions() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -613,6 +629,7 @@ async fn import__p2p_error_on_4_transactions() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -644,7 +661,7 @@ async fn import__consensus_error_on_4() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -661,6 +678,7 @@ async fn import__consensus_error_on_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -692,7 +710,7 @@ async fn import__consensus_error_on_5() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -715,6 +733,7 @@ async fn import__consensus_error_on_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -740,7 +759,7 @@ async fn import__execution_error_on_header_4() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -775,6 +794,7 @@ async fn import__execution_error_on_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -800,7 +820,7 @@ async fn import__execution_error_on_header_5() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -835,6 +855,7 @@ async fn import__execution_error_on_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -863,6 +884,7 @@ async fn signature_always_fails() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -891,7 +913,7 @@ async fn import__can_work_in_two_loops() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(2)
-        .returning(move |range| {
+        .returning(move |range, _| {
             state.apply(|s| s.observe(6));
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
@@ -915,6 +937,7 @@ async fn import__can_work_in_two_loops() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -924,6 +947,110 @@ async fn import__can_work_in_two_loops() {
     assert_eq!((State::new(6, None), true), res);
 }
 
+#[tokio::test]
+async fn import__re_requests_the_rest_of_incomplete_batch_from_other_peer() {
+    // given
+    let first_peer: PeerId = vec![1].into();
+    let second_peer: PeerId = vec![2].into();
+
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(6)
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .times(2)
+        .returning(|_| Ok(()));
+
+    let mut p2p = MockPeerToPeerPort::default();
+    let peer = first_peer.clone();
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .withf(|range, excluded_peers| *range == (0..6) && excluded_peers.is_empty())
+        .returning(move |_, _| {
+            let headers = Some((0..3).map(empty_header).collect());
+            Ok(peer.clone().bind(headers))
+        });
+    let excluded_peer = first_peer.clone();
+    let peer = second_peer.clone();
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .withf(move |range, excluded_peers| {
+            *range == (3..6) && excluded_peers == &vec![excluded_peer.clone()]
+        })
+        .returning(move |range, _| {
+            let headers = Some(range.map(empty_header).collect());
+            Ok(peer.clone().bind(headers))
+        });
+    p2p.expect_get_transactions()
+        .times(2)
+        .returning(|block_ids| {
+            let data = block_ids.data;
+            let v = data.into_iter().map(|_| Transactions::default()).collect();
+            Ok(Some(v))
+        });
+
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor: DefaultMocks::times([6]),
+    };
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries: 1,
+    };
+    let state = SharedMutex::new(State::new(None, 5));
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(5, None), true), res);
+}
+
+#[test]
+fn peers_throughput__reports_peers_that_deviate_from_others() {
+    // given
+    let mut throughput = PeersThroughput::default();
+    let slow_peer: PeerId = vec![1].into();
+    let average_peer: PeerId = vec![2].into();
+    let fast_peer: PeerId = vec![3].into();
+    let second = Duration::from_secs(1);
+
+    // when
+    let first_report = throughput.record(&average_peer, 10, second);
+    let slow_report = throughput.record(&slow_peer, 1, second);
+    let fast_report = throughput.record(&fast_peer, 100, second);
+
+    // then
+    assert_eq!(first_report, None);
+    assert_eq!(slow_report, Some(PeerReportReason::SlowBlocksDownload));
+    assert_eq!(fast_report, Some(PeerReportReason::FastBlocksDownload));
+}
+
+#[tokio::test]
+async fn get_sealed_blocks__download_time_excludes_waiting_for_da_height() {
+    // given
+    let counts = SharedCounts::new(Default::default());
+    let download_delay = Duration::from_millis(10);
+    let da_delay = Duration::from_millis(500);
+    let p2p = Arc::new(PressurePeerToPeer::new(
+        counts.clone(),
+        [download_delay, download_delay],
+    ));
+    let consensus = Arc::new(PressureConsensus::new(counts, da_delay));
+
+    // when
+    let (batch, download_time) = get_sealed_blocks(0..5, vec![], &p2p, &consensus).await;
+
+    // then
+    assert_eq!(batch.results.len(), 5);
+    assert!(download_time >= download_delay);
+    assert!(download_time < da_delay);
+}
+
 async fn test_import_inner(
     state: SharedMutex<State>,
     mocks: Mocks,
@@ -949,6 +1076,7 @@ async fn test_import_inner(
         p2p,
         executor,
         consensus,
+        throughput: Default::default(),
     };
     let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
     let mut watcher = shutdown.into();
@@ -1039,7 +1167,7 @@ async fn import__execution_error_on_header_4_when_awaits_for_1000000_blocks() {
         .returning(|_| Ok(()));
 
     let mut p2p = MockPeerToPeerPort::default();
-    p2p.expect_get_sealed_block_headers().returning(|range| {
+    p2p.expect_get_sealed_block_headers().returning(|range, _| {
         let peer = random_peer();
         let headers = Some(range.map(empty_header).collect());
         let headers = peer.bind(headers);
@@ -1072,6 +1200,7 @@ async fn import__execution_error_on_header_4_when_awaits_for_1000000_blocks() {
     let params = Config {
         block_stream_buffer_size: 1,
         header_batch_size: 1,
+        max_batch_retries: 0,
     };
 
     // when
@@ -1161,6 +1290,7 @@ impl PeerReportTestBuilder {
         let params = Config {
             block_stream_buffer_size: 10,
             header_batch_size: 10,
+            max_batch_retries: 0,
         };
 
         let import = Import {
@@ -1170,6 +1300,7 @@ impl PeerReportTestBuilder {
             p2p,
             executor,
             consensus,
+            throughput: Default::default(),
         };
         let (_tx, shutdown) =
             tokio::sync::watch::channel(fuel_core_services::State::Started);
@@ -1187,14 +1318,15 @@ impl PeerReportTestBuilder {
 
         let peer_id = self.shared_peer_id.clone();
         if let Some(get_headers) = self.get_sealed_headers.clone() {
-            p2p.expect_get_sealed_block_headers().returning(move |_| {
-                let peer: PeerId = peer_id.clone().into();
-                let headers = peer.bind(get_headers.clone());
-                Ok(headers)
-            });
+            p2p.expect_get_sealed_block_headers()
+                .returning(move |_, _| {
+                    let peer: PeerId = peer_id.clone().into();
+                    let headers = peer.bind(get_headers.clone());
+                    Ok(headers)
+                });
         } else {
             p2p.expect_get_sealed_block_headers()
-                .returning(move |range| {
+                .returning(move |range, _| {
                     let peer: PeerId = peer_id.clone().into();
                     let headers = Some(range.map(empty_header).collect());
                     let headers = peer.bind(headers);
@@ -1325,7 +1457,7 @@ impl DefaultMocks for MockPeerToPeerPort {
 
         p2p.expect_get_sealed_block_headers()
             .times(1)
-            .returning(|range| {
+            .returning(|range, _| {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
                 let headers = peer.bind(headers);
@@ -9,6 +9,9 @@ use fuel_core_types::{
     },
     fuel_types::BlockHeight,
     services::p2p::{
+        BlockGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
         PeerId,
         SourcePeer,
         Transactions,
@@ -32,6 +35,12 @@ pub enum PeerReportReason {
     MissingTransactions,
     /// Received invalid transactions
     InvalidTransactions,
+
+    // Throughput
+    /// The peer provides blocks noticeably faster than other peers
+    FastBlocksDownload,
+    /// The peer provides blocks noticeably slower than other peers
+    SlowBlocksDownload,
 }
 
 #[cfg_attr(any(test, feature = "benchmarking"), mockall::automock)]
@@ -42,9 +51,11 @@ pub trait PeerToPeerPort {
     fn height_stream(&self) -> BoxStream<BlockHeight>;
 
     /// Request a range of sealed block headers from the network.
+    /// The `excluded_peers` are asked only if no other peer has the headers.
     async fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
+        excluded_peers: Vec<PeerId>,
     ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlockHeader>>>>;
 
     /// Request transactions from the network for the given block
@@ -56,6 +67,17 @@ pub trait PeerToPeerPort {
 
     /// Report a peer for some reason to modify their reputation.
     fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()>;
+
+    /// Stream of new blocks gossiped by peers.
+    fn gossiped_block_stream(&self) -> BoxStream<BlockGossipData>;
+
+    /// Report the validity of the gossiped block to the network.
+    /// Only accepted blocks are propagated further.
+    fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()>;
 }
 
 #[cfg_attr(any(test, feature = "benchmarking"), mockall::automock)]
@@ -1,8 +1,21 @@
+#![allow(non_snake_case)]
+
 use fuel_core_services::{
     stream::IntoBoxStream,
     Service,
 };
-use fuel_core_types::services::p2p::Transactions;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        primitives::DaBlockHeight,
+        SealedBlock,
+        SealedBlockHeader,
+    },
+    services::p2p::{
+        GossipData,
+        Transactions,
+    },
+};
 use futures::{
     stream,
     StreamExt,
@@ -38,7 +51,9 @@ async fn test_new_service() {
         })
         .into_boxed()
     });
-    p2p.expect_get_sealed_block_headers().returning(|range| {
+    p2p.expect_gossiped_block_stream()
+        .returning(|| futures::stream::pending().into_boxed());
+    p2p.expect_get_sealed_block_headers().returning(|range, _| {
         let peer = random_peer();
         let headers = Some(range.map(empty_header).collect::<Vec<_>>());
         let headers = peer.bind(headers);
@@ -66,6 +81,7 @@ async fn test_new_service() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
     let s = new_service(4u32.into(), p2p, importer, consensus, params).unwrap();
 
@@ -87,3 +103,92 @@ async fn test_new_service() {
         fuel_core_services::State::Stopped
     );
 }
+
+/// Never reaches the DA height of the blocks with DA messages.
+struct LaggingRelayerConsensus;
+
+#[async_trait::async_trait]
+impl ConsensusPort for LaggingRelayerConsensus {
+    fn check_sealed_header(&self, _: &SealedBlockHeader) -> anyhow::Result<bool> {
+        Ok(true)
+    }
+
+    async fn await_da_height(&self, da_height: &DaBlockHeight) -> anyhow::Result<()> {
+        if **da_height > 0 {
+            futures::future::pending::<()>().await;
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn sync_heights__is_not_stalled_by_gossiped_block_awaiting_da_height() {
+    // given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_report_peer().returning(|_, _| Ok(()));
+    p2p.expect_notify_gossip_block_validity()
+        .returning(|_, _| Ok(()));
+    p2p.expect_height_stream().returning(|| {
+        stream::iter([BlockHeight::from(10u32)])
+            .chain(stream::pending())
+            .into_boxed()
+    });
+    p2p.expect_gossiped_block_stream().returning(|| {
+        let mut header = empty_header(5);
+        header.entity.set_da_height(100u64.into());
+        let block = Block::try_from_executed(header.entity, vec![])
+            .expect("The transaction root of the header is valid");
+        let block = SealedBlock {
+            entity: block,
+            consensus: header.consensus,
+        };
+        let message = GossipData::new(block, vec![1, 2, 3], vec![4, 5, 6]);
+        stream::iter([message])
+            .chain(stream::pending())
+            .into_boxed()
+    });
+    p2p.expect_get_sealed_block_headers().returning(|range, _| {
+        let peer = random_peer();
+        let headers = Some(range.map(empty_header).collect::<Vec<_>>());
+        let headers = peer.bind(headers);
+        Ok(headers)
+    });
+    p2p.expect_get_transactions().returning(|block_ids| {
+        let data = block_ids.data;
+        let v = data.into_iter().map(|_| Transactions::default()).collect();
+        Ok(Some(v))
+    });
+    let mut importer = MockBlockImporterPort::default();
+    importer
+        .expect_committed_height_stream()
+        .returning(|| futures::stream::pending::<BlockHeight>().into_boxed());
+    let (tx, mut rx) = tokio::sync::mpsc::channel(100);
+    importer.expect_execute_and_commit().returning(move |h| {
+        tx.try_send(**h.entity.header().height()).unwrap();
+        Ok(())
+    });
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries: 0,
+    };
+    let s =
+        new_service(4u32.into(), p2p, importer, LaggingRelayerConsensus, params).unwrap();
+
+    // when
+    s.start_and_await().await.unwrap();
+    let mut last_value = 0;
+    while let Some(h) = rx.recv().await {
+        last_value = h;
+        if h == 10 {
+            break
+        }
+    }
+
+    // then
+    assert_eq!(last_value, 10);
+    assert_eq!(
+        s.stop_and_await().await.unwrap(),
+        fuel_core_services::State::Stopped
+    );
+}
@@ -198,6 +198,15 @@ impl State {
         self.apply_status(status);
     }
 
+    /// Returns `true` if there is nothing to process and
+    /// the `height` is the next one after the committed height.
+    pub fn is_next_to_commit(&self, height: u32) -> bool {
+        match &self.status {
+            Status::Committed(committed) => committed.checked_add(1) == Some(height),
+            Status::Uninitialized | Status::Processing(_) => false,
+        }
+    }
+
     fn apply_status(&mut self, status: Option<Status>) {
         match status {
             Some(s) => {
@@ -65,6 +65,16 @@ fn test_observe(mut state: State, height: u32) -> Status {
     state.status
 }
 
+#[test_case(State::new(None, None), 0 => false)]
+#[test_case(State::new(0, None), 0 => false)]
+#[test_case(State::new(0, None), 1 => true)]
+#[test_case(State::new(0, None), 2 => false)]
+#[test_case(State::new(0, 1), 1 => false)]
+#[test_case(State::new(None, 1), 0 => false)]
+fn test_is_next_to_commit(state: State, height: u32) -> bool {
+    state.is_next_to_commit(height)
+}
+
 #[test_case(State::new(None, None), 0..=0 => Status::Uninitialized)]
 #[test_case(State::new(None, None), 0..=100 => Status::Uninitialized)]
 #[test_case(State::new(0, None), 0..=0 => Status::Committed(0))]
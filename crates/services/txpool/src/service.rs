@@ -113,7 +113,10 @@ impl TxStatusChange {
         ));
     }
 
-    pub fn send_squeezed_out(&self, id: Bytes32, reason: TxPoolError) {
+    pub fn send_squeezed_out<E>(&self, id: Bytes32, reason: E)
+    where
+        E: core::fmt::Display,
+    {
         tracing::info!("Transaction {id} squeezed out because {reason}");
         self.update_sender.send(TxUpdate::new(
             id,
@@ -122,6 +125,16 @@ impl TxStatusChange {
             }),
         ));
     }
+
+    pub fn new_tx_notification_subscribe(&self) -> broadcast::Receiver<TxId> {
+        self.new_tx_notification_sender.subscribe()
+    }
+
+    pub fn tx_update_subscribe(&self, tx_id: Bytes32) -> anyhow::Result<TxStatusStream> {
+        self.update_sender
+            .try_subscribe::<MpscChannel>(tx_id)
+            .ok_or(anyhow!("Maximum number of subscriptions reached"))
+    }
 }
 
 pub struct SharedState<
@@ -382,6 +395,12 @@ impl<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, MP>
         self.txpool.lock().consumable_gas()
     }
 
+    /// Returns the minimal tip that a transaction with the `max_gas` needs
+    /// to be included within the `available_gas` ahead of the pooled transactions.
+    pub fn tip_to_outbid(&self, available_gas: u64, max_gas: u64) -> u64 {
+        self.txpool.lock().tip_to_outbid(available_gas, max_gas)
+    }
+
     pub fn remove_txs(&self, ids: Vec<(TxId, String)>) -> Vec<ArcPoolTx> {
         self.txpool.lock().remove(&self.tx_status_sender, ids)
     }
@@ -418,14 +437,11 @@ impl<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, MP>
     }
 
     pub fn new_tx_notification_subscribe(&self) -> broadcast::Receiver<TxId> {
-        self.tx_status_sender.new_tx_notification_sender.subscribe()
+        self.tx_status_sender.new_tx_notification_subscribe()
     }
 
     pub fn tx_update_subscribe(&self, tx_id: Bytes32) -> anyhow::Result<TxStatusStream> {
-        self.tx_status_sender
-            .update_sender
-            .try_subscribe::<MpscChannel>(tx_id)
-            .ok_or(anyhow!("Maximum number of subscriptions reached"))
+        self.tx_status_sender.tx_update_subscribe(tx_id)
     }
 
     pub fn send_complete(
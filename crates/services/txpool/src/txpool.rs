@@ -1,6 +1,7 @@
 use crate::{
     containers::{
         dependency::Dependency,
+        sort::SortableKey,
         time_sort::TimeSort,
         tip_per_gas_sort::RatioGasTipSort,
     },
@@ -137,6 +138,25 @@ impl<ViewProvider, WasmChecker> TxPool<ViewProvider, WasmChecker> {
             .map(|(_, tx)| tx.clone())
     }
 
+    /// Returns the minimal tip that a transaction with the `max_gas` needs
+    /// to be included within the `available_gas` ahead of the includable transactions.
+    pub fn tip_to_outbid(&self, available_gas: Word, max_gas: Word) -> Word {
+        let mut used_gas: Word = 0;
+        for (key, tx) in self.by_ratio_gas_tip.sort.iter().rev() {
+            used_gas = used_gas.saturating_add(tx.max_gas());
+            if used_gas.saturating_add(max_gas) > available_gas {
+                let tip_per_gas = key.value();
+                let tip = u128::from(*tip_per_gas.numer())
+                    .saturating_mul(u128::from(max_gas))
+                    .checked_div(u128::from(*tip_per_gas.denom()))
+                    .unwrap_or_default()
+                    .saturating_add(1);
+                return Word::try_from(tip).unwrap_or(Word::MAX)
+            }
+        }
+        0
+    }
+
     pub fn remove_inner(&mut self, tx: &ArcPoolTx) -> Vec<ArcPoolTx> {
         self.remove_by_tx_id(&tx.id())
     }
@@ -853,6 +853,50 @@ async fn sorted_out_tx1_2_3() {
     assert_eq!(txs[2].id(), tx2_id, "Third should be tx2");
 }
 
+#[tokio::test]
+async fn tip_to_outbid__scales_ratio_of_first_tx_that_does_not_fit_by_max_gas() {
+    let mut context = TextContext::default();
+
+    // Given
+    let (_, gas_coin) = context.setup_coin();
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .tip(30)
+        .max_fee_limit(30)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let (_, gas_coin) = context.setup_coin();
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .tip(10)
+        .max_fee_limit(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+
+    txpool
+        .insert_single(tx1)
+        .expect("Tx1 should be Ok, got Err");
+    txpool
+        .insert_single(tx2)
+        .expect("Tx2 should be Ok, got Err");
+    let max_gas = txpool.sorted_includable().next().unwrap().max_gas();
+
+    // When
+    let tip_to_fit_all = txpool.tip_to_outbid(3 * max_gas, max_gas);
+    let tip_to_outbid_tx2 = txpool.tip_to_outbid(2 * max_gas, max_gas);
+    let tip_to_outbid_tx1 = txpool.tip_to_outbid(2 * max_gas, 2 * max_gas);
+
+    // Then
+    assert_eq!(tip_to_fit_all, 0);
+    assert_eq!(tip_to_outbid_tx2, 11);
+    assert_eq!(tip_to_outbid_tx1, 61);
+}
+
 #[tokio::test]
 async fn sorted_out_tx_same_tips() {
     let mut context = TextContext::default();
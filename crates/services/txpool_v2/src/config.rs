@@ -28,6 +28,19 @@ use fuel_core_types::{
 
 use crate::error::Error;
 
+/// The entry of the [`BlackList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlackListEntry {
+    /// Blacklisted address.
+    Owner(Address),
+    /// Blacklisted UTXO id.
+    Coin(UtxoId),
+    /// Blacklisted message by `Nonce`.
+    Message(Nonce),
+    /// Blacklisted contract.
+    Contract(ContractId),
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct BlackList {
     /// Blacklisted addresses.
@@ -56,6 +69,39 @@ impl BlackList {
         }
     }
 
+    /// Adds the entry to the blacklist.
+    /// Returns `false` if the entry was already blacklisted.
+    pub fn insert(&mut self, entry: BlackListEntry) -> bool {
+        match entry {
+            BlackListEntry::Owner(owner) => self.owners.insert(owner),
+            BlackListEntry::Coin(utxo_id) => self.coins.insert(utxo_id),
+            BlackListEntry::Message(nonce) => self.messages.insert(nonce),
+            BlackListEntry::Contract(contract_id) => self.contracts.insert(contract_id),
+        }
+    }
+
+    /// Removes the entry from the blacklist.
+    /// Returns `false` if the entry was not blacklisted.
+    pub fn remove(&mut self, entry: &BlackListEntry) -> bool {
+        match entry {
+            BlackListEntry::Owner(owner) => self.owners.remove(owner),
+            BlackListEntry::Coin(utxo_id) => self.coins.remove(utxo_id),
+            BlackListEntry::Message(nonce) => self.messages.remove(nonce),
+            BlackListEntry::Contract(contract_id) => self.contracts.remove(contract_id),
+        }
+    }
+
+    /// Returns all entries of the blacklist.
+    pub fn entries(&self) -> impl Iterator<Item = BlackListEntry> + '_ {
+        self.owners
+            .iter()
+            .copied()
+            .map(BlackListEntry::Owner)
+            .chain(self.coins.iter().copied().map(BlackListEntry::Coin))
+            .chain(self.messages.iter().copied().map(BlackListEntry::Message))
+            .chain(self.contracts.iter().copied().map(BlackListEntry::Contract))
+    }
+
     /// Check if the transaction has blacklisted inputs.
     pub fn check_blacklisting(&self, tx: &PoolTransaction) -> Result<(), Error> {
         for input in tx.inputs() {
@@ -115,7 +161,7 @@ impl BlackList {
     }
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct Config {
     /// Enable UTXO validation (will check if UTXO exists in the database and has correct data).
     pub utxo_validation: bool,
@@ -133,9 +179,22 @@ pub struct Config {
     pub heavy_work: HeavyWorkConfig,
     /// Blacklist. Transactions with blacklisted inputs will not be accepted.
     pub black_list: BlackList,
+    /// Configuration of the selection of transactions for the block.
+    pub selection: SelectionConfig,
+    /// The minimum increase of the tip per unit of gas, in percent, required
+    /// to replace a transaction that uses the same coins, messages, contract or blob.
+    pub min_tip_bump_percent: u16,
+    /// Enables prometheus metrics for the pool.
+    pub metrics: bool,
+    /// Records accepted transactions in the journal and re-inserts them
+    /// into the pool after the restart of the node.
+    pub persist_transactions: bool,
+    /// Records changes of the blacklist made at runtime and re-applies them
+    /// after the restart of the node.
+    pub persist_black_list: bool,
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct PoolLimits {
     /// Maximum number of transactions in the pool.
     pub max_txs: usize,
@@ -145,7 +204,31 @@ pub struct PoolLimits {
     pub max_bytes_size: usize,
 }
 
-#[derive(Clone)]
+/// The order in which executable transactions are selected for the block.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionStrategy {
+    /// Transactions with the highest tip per unit of max gas go first.
+    #[default]
+    RatioTipGas,
+    /// Transactions with the highest tip per byte go first.
+    RatioTipBytes,
+    /// Transactions go in the order of their insertion into the pool.
+    /// The selection stops at the first transaction that doesn't fit into the block,
+    /// so newer transactions never overtake older ones.
+    Fifo,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct SelectionConfig {
+    /// The order in which transactions are selected.
+    pub strategy: SelectionStrategy,
+    /// Maximum number of transactions of the same owner in one block.
+    /// The owner is the owner of any coin or the recipient of any message in the inputs.
+    /// If not set, the number of transactions per owner is not limited.
+    pub max_txs_per_owner: Option<u16>,
+}
+
+#[derive(Debug, Clone)]
 pub struct HeavyWorkConfig {
     /// Maximum of threads for managing verifications/insertions.
     pub number_threads_to_verify_transactions: usize,
@@ -153,7 +236,6 @@ pub struct HeavyWorkConfig {
     pub size_of_verification_queue: usize,
 }
 
-#[cfg(test)]
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -168,6 +250,11 @@ impl Default for Config {
             },
             max_txs_ttl: Duration::from_secs(60 * 10),
             black_list: BlackList::default(),
+            selection: SelectionConfig::default(),
+            min_tip_bump_percent: 0,
+            metrics: false,
+            persist_transactions: false,
+            persist_black_list: false,
             heavy_work: HeavyWorkConfig {
                 number_threads_to_verify_transactions: 4,
                 size_of_verification_queue: 100,
@@ -6,7 +6,10 @@ use fuel_core_types::{
         TxId,
         UtxoId,
     },
-    fuel_types::Nonce,
+    fuel_types::{
+        Nonce,
+        Word,
+    },
     fuel_vm::checked_transaction::CheckError,
 };
 
@@ -32,6 +35,11 @@ pub enum Error {
     NotInsertedChainDependencyTooBig,
     #[display(fmt = "Transaction collided: {_0}")]
     Collided(CollisionReason),
+    #[display(
+        fmt = "Transaction is not inserted. The replacement of the transaction {tx_id} \
+        requires a tip of at least {required_tip}"
+    )]
+    NotInsertedReplacementTipTooLow { tx_id: TxId, required_tip: Word },
     #[display(fmt = "Transaction is not inserted. Collision is also a dependency")]
     NotInsertedCollisionIsDependency,
     #[display(fmt = "The dependent transaction creates a diamond problem, \
@@ -97,6 +105,26 @@ pub enum Error {
     ZeroMaxGas,
     #[display(fmt = "Transaction with the same tx_id(_0) already exists in the pool")]
     DuplicateTxId(TxId),
+    #[display(fmt = "Too much transactions are in queue to be inserted. Can't add more")]
+    TooManyQueuedTransactions,
+    #[display(fmt = "The verification of the transaction was interrupted")]
+    VerificationInterrupted,
+    #[display(
+        fmt = "Transaction expired because it exceeded the configured time to live `tx-pool-ttl`."
+    )]
+    TTLReason,
+    #[display(fmt = "Transaction was removed to free space for the transaction {_0}")]
+    RemovedForMoreProfitable(TxId),
+    #[display(
+        fmt = "Transaction was replaced by the transaction {by} with a higher tip"
+    )]
+    Replaced { by: TxId },
+    #[display(fmt = "Transaction squeezed out because {_0}")]
+    SqueezedOut(String),
+    #[display(
+        fmt = "Parent transaction with {parent}, was removed because of the {reason}"
+    )]
+    DependencyRemoved { parent: TxId, reason: String },
 }
 
 #[derive(Debug, Clone, derive_more::Display)]
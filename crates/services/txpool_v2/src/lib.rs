@@ -6,11 +6,11 @@
 #![allow(unused)]
 
 mod collision_manager;
-mod config;
-mod error;
+pub mod config;
+pub mod error;
 mod heavy_async_processing;
 mod pool;
-mod ports;
+pub mod ports;
 mod selection_algorithms;
 mod service;
 mod storage;
@@ -23,9 +23,13 @@ mod tests;
 #[cfg(test)]
 fuel_core_trace::enable_tracing!();
 
+pub use config::Config;
+pub use error::Error;
 use fuel_core_types::fuel_asm::Word;
 pub use service::{
     new_service,
     Service,
     SharedState,
+    TxDependencies,
+    TxInfo,
 };
@@ -11,7 +11,10 @@ use fuel_core_types::{
         field::BlobId,
         TxId,
     },
-    services::txpool::PoolTransaction,
+    services::txpool::{
+        ArcPoolTx,
+        PoolTransaction,
+    },
 };
 use num_rational::Ratio;
 use tracing::instrument;
@@ -21,7 +24,10 @@ use crate::{
         CollisionManager,
         Collisions,
     },
-    config::Config,
+    config::{
+        BlackList,
+        Config,
+    },
     error::Error,
     pool::collisions::CollisionsExt,
     ports::{
@@ -80,6 +86,18 @@ impl<PSProvider, S, SI, CM, SA> Pool<PSProvider, S, SI, CM, SA> {
             current_bytes_size: 0,
         }
     }
+
+    /// Returns the blacklist of the pool.
+    pub fn black_list(&self) -> &BlackList {
+        &self.config.black_list
+    }
+
+    /// Returns the mutable blacklist of the pool.
+    /// Changes don't affect transactions that are already in the pool,
+    /// see [`Pool::find_blacklisted_transactions`].
+    pub fn black_list_mut(&mut self) -> &mut BlackList {
+        &mut self.config.black_list
+    }
 }
 
 impl<PS, View, S: Storage, CM, SA> Pool<PS, S, S::StorageIndex, CM, SA>
@@ -91,11 +109,20 @@ where
     SA: SelectionAlgorithm<Storage = S, StorageIndex = S::StorageIndex>,
 {
     /// Insert transactions into the pool.
-    /// Returns a list of results for each transaction.
-    /// Each result is a list of transactions that were removed from the pool
+    /// Returns the transactions that were removed from the pool
     /// because of the insertion of the new transaction.
     #[instrument(skip(self))]
-    pub fn insert(&mut self, tx: PoolTransaction) -> Result<Vec<PoolTransaction>, Error> {
+    pub fn insert(&mut self, tx: PoolTransaction) -> Result<RemovedOnInsertion, Error> {
+        self.insert_created_at(tx, Instant::now())
+    }
+
+    /// Insert the transaction into the pool as if it was inserted at the `creation_instant`.
+    /// It is used to restore transactions without resetting their time to live.
+    pub fn insert_created_at(
+        &mut self,
+        tx: PoolTransaction,
+        creation_instant: Instant,
+    ) -> Result<RemovedOnInsertion, Error> {
         let CanStoreTransaction {
             checked_transaction,
             transactions_to_remove,
@@ -105,26 +132,26 @@ where
 
         let has_dependencies = !checked_transaction.all_dependencies().is_empty();
 
-        let mut removed_transactions = vec![];
+        let mut evicted_transactions = vec![];
         for tx in transactions_to_remove {
             let removed = self.storage.remove_transaction_and_dependents_subtree(tx);
             self.update_components_and_caches_on_removal(removed.iter());
-            removed_transactions.extend(removed);
+            evicted_transactions.extend(removed);
         }
 
+        let mut replaced_transactions = vec![];
         for collided_tx in collisions.keys() {
             let removed = self
                 .storage
                 .remove_transaction_and_dependents_subtree(*collided_tx);
             self.update_components_and_caches_on_removal(removed.iter());
 
-            removed_transactions.extend(removed);
+            replaced_transactions.extend(removed);
         }
 
         let tx = checked_transaction.tx();
         let tx_id = tx.id();
         let gas = tx.max_gas();
-        let creation_instant = Instant::now();
         let bytes_size = tx.metered_bytes_size();
 
         let storage_id = self
@@ -145,12 +172,18 @@ where
                 .new_executable_transaction(storage_id, tx);
         }
 
-        let removed_transactions = removed_transactions
-            .into_iter()
-            .map(|data| data.transaction)
-            .collect::<Vec<_>>();
+        let removed = RemovedOnInsertion {
+            evicted: evicted_transactions
+                .into_iter()
+                .map(|data| data.transaction)
+                .collect(),
+            replaced: replaced_transactions
+                .into_iter()
+                .map(|data| data.transaction)
+                .collect(),
+        };
 
-        Ok(removed_transactions)
+        Ok(removed)
     }
 
     /// Check if a transaction can be inserted into the pool.
@@ -193,13 +226,12 @@ where
 
         let has_dependencies = !checked_transaction.all_dependencies().is_empty();
 
-        collisions
-            .check_collision_requirements(
-                checked_transaction.tx(),
-                has_dependencies,
-                &self.storage,
-            )
-            .map_err(Error::Collided)?;
+        collisions.check_collision_requirements(
+            checked_transaction.tx(),
+            has_dependencies,
+            self.config.min_tip_bump_percent,
+            &self.storage,
+        )?;
 
         let can_fit_into_pool = self.can_fit_into_pool(&checked_transaction)?;
 
@@ -218,21 +250,27 @@ where
         Ok(can_store_transaction)
     }
 
-    // TODO: Use block space also (https://github.com/FuelLabs/fuel-core/issues/2133)
     /// Extract transactions for a block.
     /// Returns a list of transactions that were selected for the block
     /// based on the constraints given in the configuration and the selection algorithm used.
-    pub fn extract_transactions_for_block(
+    pub fn extract_transactions_for_block(&mut self) -> Result<Vec<ArcPoolTx>, Error> {
+        let block_size = u32::try_from(self.config.max_block_size).unwrap_or(u32::MAX);
+        self.extract_transactions_for_block_with_constraints(Constraints {
+            max_gas: self.config.max_block_gas,
+            maximum_txs: u16::MAX,
+            maximum_block_size: block_size,
+        })
+    }
+
+    /// Extract transactions for a block respecting the given `constraints`.
+    /// Returns a list of transactions that were selected for the block.
+    pub fn extract_transactions_for_block_with_constraints(
         &mut self,
-    ) -> Result<Vec<PoolTransaction>, Error> {
+        constraints: Constraints,
+    ) -> Result<Vec<ArcPoolTx>, Error> {
         let extracted_transactions = self
             .selection_algorithm
-            .gather_best_txs(
-                Constraints {
-                    max_gas: self.config.max_block_gas,
-                },
-                &mut self.storage,
-            )?
+            .gather_best_txs(constraints, &mut self.storage)?
             .into_iter()
             .map(|storage_entry| {
                 self.update_components_and_caches_on_removal(iter::once(&storage_entry));
@@ -244,10 +282,193 @@ where
         Ok(extracted_transactions)
     }
 
-    #[allow(dead_code)]
-    pub fn find_one(&self, tx_id: &TxId) -> Option<&PoolTransaction> {
+    /// Returns the minimal tip that a transaction with the `max_gas` and
+    /// the `metered_bytes_size` needs to be selected within the `available_gas`
+    /// ahead of the transactions from the pool.
+    /// Returns `None` if the tip can't be estimated without the `metered_bytes_size`.
+    pub fn tip_to_outbid(
+        &self,
+        available_gas: u64,
+        max_gas: u64,
+        metered_bytes_size: Option<u64>,
+    ) -> Option<u64> {
+        self.selection_algorithm.tip_to_outbid(
+            available_gas,
+            max_gas,
+            metered_bytes_size,
+            &self.storage,
+        )
+    }
+
+    pub fn find_one(&self, tx_id: &TxId) -> Option<&StorageData> {
         Storage::get(&self.storage, self.tx_id_to_storage_id.get(tx_id)?)
-            .map(|data| &data.transaction)
+    }
+
+    /// Returns `true` if the pool contains the transaction.
+    pub fn contains(&self, tx_id: &TxId) -> bool {
+        self.tx_id_to_storage_id.contains_key(tx_id)
+    }
+
+    /// Returns the number of transactions in the pool.
+    pub fn tx_count(&self) -> usize {
+        self.tx_id_to_storage_id.len()
+    }
+
+    /// Returns the cumulative max gas of all transactions in the pool.
+    pub fn current_gas(&self) -> u64 {
+        self.current_gas
+    }
+
+    /// Returns the cumulative size in bytes of all transactions in the pool.
+    pub fn current_bytes_size(&self) -> usize {
+        self.current_bytes_size
+    }
+
+    /// Returns an iterator over ids of all transactions in the pool.
+    pub fn iter_tx_ids(&self) -> impl Iterator<Item = &TxId> {
+        self.tx_id_to_storage_id.keys()
+    }
+
+    /// Returns an iterator over all transactions in the pool.
+    pub fn iter_transactions(&self) -> impl Iterator<Item = &StorageData> {
+        self.tx_id_to_storage_id
+            .values()
+            .filter_map(|storage_id| self.storage.get(storage_id))
+    }
+
+    /// Returns ids of transactions that the transaction directly depends on
+    /// and ids of transactions that directly depend on it.
+    pub fn find_direct_dependencies(
+        &self,
+        tx_id: &TxId,
+    ) -> Option<(Vec<TxId>, Vec<TxId>)> {
+        let storage_id = *self.tx_id_to_storage_id.get(tx_id)?;
+        let dependencies = self
+            .storage
+            .get_direct_dependencies(storage_id)
+            .filter_map(|storage_id| self.storage.get(&storage_id))
+            .map(|data| data.transaction.id())
+            .collect();
+        let dependents = self
+            .storage
+            .get_direct_dependents(storage_id)
+            .filter_map(|storage_id| self.storage.get(&storage_id))
+            .map(|data| data.transaction.id())
+            .collect();
+        Some((dependencies, dependents))
+    }
+
+    /// Remove transactions included in a block from the pool.
+    ///
+    /// Unlike other removals, dependents of the committed transactions stay in
+    /// the pool. Their inputs are now in the database, so once the last dependency
+    /// is committed, they become executable.
+    pub fn remove_committed_transactions(&mut self, tx_ids: impl Iterator<Item = TxId>) {
+        for tx_id in tx_ids {
+            let Some(storage_id) = self.tx_id_to_storage_id.get(&tx_id).copied() else {
+                continue
+            };
+
+            let dependents: Vec<_> =
+                self.storage.get_direct_dependents(storage_id).collect();
+
+            // Transactions in the block are ordered, so dependencies of the transaction
+            // were already committed and removed before we reach it.
+            let removed = match self
+                .storage
+                .remove_transaction_without_dependencies(storage_id)
+            {
+                Ok(removed) => removed,
+                Err(err) => {
+                    debug_assert!(false, "Failed to remove committed transaction: {err}");
+                    tracing::warn!(
+                        "Failed to remove committed transaction {tx_id}: {err}"
+                    );
+                    continue
+                }
+            };
+            self.update_components_and_caches_on_removal(iter::once(&removed));
+
+            for dependent in dependents {
+                if self.storage.has_dependencies(&dependent) {
+                    continue
+                }
+                let Some(storage_data) = self.storage.get(&dependent) else {
+                    debug_assert!(
+                        false,
+                        "Dependent of the committed transaction not found"
+                    );
+                    tracing::warn!(
+                        "Dependent of the committed transaction {tx_id} not found"
+                    );
+                    continue
+                };
+                self.selection_algorithm
+                    .new_executable_transaction(dependent, storage_data);
+            }
+        }
+    }
+
+    /// Remove transactions and all of their dependents from the pool.
+    /// Returns the list of removed transactions.
+    pub fn remove_transactions(
+        &mut self,
+        tx_ids: impl Iterator<Item = TxId>,
+    ) -> Vec<ArcPoolTx> {
+        let mut removed_transactions = vec![];
+        for tx_id in tx_ids {
+            let Some(storage_id) = self.tx_id_to_storage_id.get(&tx_id).copied() else {
+                continue
+            };
+            let removed = self
+                .storage
+                .remove_transaction_and_dependents_subtree(storage_id);
+            self.update_components_and_caches_on_removal(removed.iter());
+            removed_transactions.extend(removed.into_iter().map(|data| data.transaction));
+        }
+        removed_transactions
+    }
+
+    /// Returns ids of transactions in the pool that use blacklisted inputs
+    /// along with the reason why they are blacklisted.
+    pub fn find_blacklisted_transactions(&self) -> Vec<(TxId, Error)> {
+        self.tx_id_to_storage_id
+            .iter()
+            .filter_map(|(tx_id, storage_id)| {
+                let data = self.storage.get(storage_id)?;
+                self.config
+                    .black_list
+                    .check_blacklisting(&data.transaction)
+                    .err()
+                    .map(|err| (*tx_id, err))
+            })
+            .collect()
+    }
+
+    /// Remove transactions that are in the pool longer than `max_txs_ttl`,
+    /// along with their dependents.
+    /// Returns the list of removed transactions.
+    pub fn prune_old_transactions(&mut self) -> Vec<ArcPoolTx> {
+        let Some(deadline) = Instant::now().checked_sub(self.config.max_txs_ttl) else {
+            // TTL is so big that we don't need to prune any transactions
+            return vec![]
+        };
+
+        let expired_tx_ids = self
+            .tx_id_to_storage_id
+            .iter()
+            .filter(|(_, storage_id)| {
+                self.storage
+                    .get(storage_id)
+                    .map(|data| data.creation_instant <= deadline)
+                    .unwrap_or(false)
+            })
+            .map(|(tx_id, _)| *tx_id)
+            .collect::<Vec<_>>();
+
+        // The transaction can be already removed as a dependent of the previous one,
+        // `remove_transactions` skips unknown transactions.
+        self.remove_transactions(expired_tx_ids.into_iter())
     }
 
     /// Check if the pool has enough space to store a transaction.
@@ -417,6 +638,25 @@ where
     }
 }
 
+/// Transactions removed from the pool because of the insertion of a new transaction.
+#[derive(Debug, Default)]
+pub struct RemovedOnInsertion {
+    /// Less profitable transactions removed to free space for the new transaction.
+    pub evicted: Vec<ArcPoolTx>,
+    /// Transactions that collided with the new transaction and were replaced by it,
+    /// along with their dependents.
+    pub replaced: Vec<ArcPoolTx>,
+}
+
+impl RemovedOnInsertion {
+    /// Returns all removed transactions.
+    pub fn into_all(self) -> Vec<ArcPoolTx> {
+        let mut all = self.evicted;
+        all.extend(self.replaced);
+        all
+    }
+}
+
 pub struct NotEnoughSpace {
     gas_left: u64,
     bytes_left: usize,
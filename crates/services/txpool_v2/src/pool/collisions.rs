@@ -1,9 +1,16 @@
 use crate::{
     collision_manager::Collisions,
-    error::CollisionReason,
+    error::{
+        CollisionReason,
+        Error,
+    },
     storage::Storage,
 };
-use fuel_core_types::services::txpool::PoolTransaction;
+use fuel_core_types::{
+    fuel_tx::TxId,
+    fuel_types::Word,
+    services::txpool::PoolTransaction,
+};
 use num_rational::Ratio;
 
 /// Trait that extends the `Collision` type functionality used by the pool.
@@ -14,8 +21,9 @@ pub trait CollisionsExt<S> {
         &self,
         tx: &PoolTransaction,
         has_dependencies: bool,
+        min_tip_bump_percent: u16,
         storage: &S,
-    ) -> Result<(), CollisionReason>;
+    ) -> Result<(), Error>;
 }
 
 impl<S> CollisionsExt<S> for Collisions<S::StorageIndex>
@@ -32,24 +40,36 @@ where
     /// - A transaction doesn't have dependencies:
     ///     - A new transaction can be accepted if its profitability is higher
     ///         than the collided subtrees'.
+    /// - Replace-by-fee: the tip per unit of gas of a new transaction must be
+    ///     at least `min_tip_bump_percent` higher than the one of each collided subtree.
     fn check_collision_requirements(
         &self,
         tx: &PoolTransaction,
         has_dependencies: bool,
+        min_tip_bump_percent: u16,
         storage: &S,
-    ) -> Result<(), CollisionReason> {
+    ) -> Result<(), Error> {
         if has_dependencies && self.len() > 1 {
-            return Err(CollisionReason::MultipleCollisions);
+            return Err(Error::Collided(CollisionReason::MultipleCollisions));
         }
 
         for (collision, reason) in self.iter() {
             if !is_better_than_collision(tx, collision, storage)? {
                 if let Some(reason) = reason.first() {
-                    return Err(reason.clone());
+                    return Err(Error::Collided(reason.clone()));
                 } else {
-                    return Err(CollisionReason::Unknown);
+                    return Err(Error::Collided(CollisionReason::Unknown));
                 }
             }
+
+            let (tx_id, required_tip) =
+                required_replacement_tip(tx, collision, min_tip_bump_percent, storage)?;
+            if tx.tip() < required_tip {
+                return Err(Error::NotInsertedReplacementTipTooLow {
+                    tx_id,
+                    required_tip,
+                });
+            }
         }
 
         Ok(())
@@ -60,15 +80,46 @@ fn is_better_than_collision<S>(
     tx: &PoolTransaction,
     collision: &S::StorageIndex,
     storage: &S,
-) -> Result<bool, CollisionReason>
+) -> Result<bool, Error>
 where
     S: Storage,
 {
     let new_tx_ratio = Ratio::new(tx.tip(), tx.max_gas());
-    let colliding_tx = storage.get(collision).ok_or(CollisionReason::Unknown)?;
+    let colliding_tx = storage
+        .get(collision)
+        .ok_or(Error::Collided(CollisionReason::Unknown))?;
     let colliding_tx_ratio = Ratio::new(
         colliding_tx.dependents_cumulative_tip,
         colliding_tx.dependents_cumulative_gas,
     );
     Ok(new_tx_ratio > colliding_tx_ratio)
 }
+
+/// Returns the id of the collided transaction and the minimal tip that
+/// the new transaction must pay to replace the collided subtree.
+fn required_replacement_tip<S>(
+    tx: &PoolTransaction,
+    collision: &S::StorageIndex,
+    min_tip_bump_percent: u16,
+    storage: &S,
+) -> Result<(TxId, Word), Error>
+where
+    S: Storage,
+{
+    let colliding_tx = storage
+        .get(collision)
+        .ok_or(Error::Collided(CollisionReason::Unknown))?;
+    let collided_tip = u128::from(colliding_tx.dependents_cumulative_tip);
+    let collided_gas = u128::from(colliding_tx.dependents_cumulative_gas);
+    let new_gas = u128::from(tx.max_gas());
+
+    // The tip of the new transaction with the same tip per unit of gas
+    // as the collided subtree, increased by the `min_tip_bump_percent`.
+    let bumped_tip = collided_tip
+        .saturating_mul(new_gas)
+        .saturating_mul(100u128.saturating_add(min_tip_bump_percent.into()))
+        .div_ceil(collided_gas.saturating_mul(100).max(1));
+    let required_tip = u64::try_from(bumped_tip).unwrap_or(u64::MAX);
+
+    Ok((colliding_tx.transaction.id(), required_tip))
+}
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
     PredicateStorageRequirements,
     Result as StorageResult,
@@ -15,19 +16,107 @@ use fuel_core_types::{
         Bytes32,
         ConsensusParameters,
         ContractId,
+        Transaction,
+        TxId,
         UtxoId,
     },
     fuel_types::Nonce,
     fuel_vm::interpreter::Memory,
+    services::{
+        block_importer::SharedImportResult,
+        p2p::{
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+            NetworkData,
+            PeerId,
+        },
+    },
+    tai64::Tai64,
 };
 
 use crate::{
+    config::BlackListEntry,
     error::Error,
     GasPrice,
 };
 
 pub use fuel_core_storage::transactional::AtomicView;
 
+pub trait BlockImporter: Send + Sync {
+    /// Wait until the next block is available
+    fn block_events(&self) -> BoxStream<SharedImportResult>;
+}
+
+#[async_trait::async_trait]
+pub trait P2P: Send + Sync {
+    type GossipedTransaction: NetworkData<Transaction>;
+
+    /// Gossip broadcast a transaction inserted via API.
+    fn broadcast_transaction(&self, transaction: Arc<Transaction>) -> anyhow::Result<()>;
+
+    /// Creates a stream that is filled with the peer_id when they subscribe to
+    /// our transactions gossip.
+    fn subscribe_new_peers(&self) -> BoxStream<PeerId>;
+
+    /// Creates a stream of next transactions gossiped from the network.
+    fn gossiped_transaction_events(&self) -> BoxStream<Self::GossipedTransaction>;
+
+    /// Report the validity of a transaction received from the network.
+    fn notify_gossip_transaction_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()>;
+
+    /// Asks the network to gather all tx ids of a specific peer
+    async fn request_tx_ids(&self, peer_id: PeerId) -> anyhow::Result<Vec<TxId>>;
+
+    /// Asks the network to gather specific transactions from a specific peer
+    async fn request_txs(
+        &self,
+        peer_id: PeerId,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Vec<Option<Transaction>>>;
+}
+
+/// Trait for notifying subscribers about the status of transactions in the pool.
+pub trait TxStatusNotifier: Send + Sync {
+    /// The transaction was accepted by the pool.
+    fn send_submitted(&self, tx_id: TxId, time: Tai64);
+
+    /// The transaction was removed from the pool without being included into a block.
+    fn send_squeezed_out(&self, tx_id: TxId, reason: Error);
+}
+
+/// Trait for the journal that persists transactions of the pool and runtime
+/// changes of the blacklist to restore them after the restart of the node.
+pub trait TxPoolJournal: Send + Sync {
+    /// Records the transaction accepted by the pool at the `submitted_time`.
+    fn record_inserted(
+        &self,
+        tx_id: TxId,
+        transaction: &Transaction,
+        submitted_time: Tai64,
+    ) -> StorageResult<()>;
+
+    /// Removes transactions that left the pool from the journal.
+    fn record_removed(&self, tx_ids: &[TxId]) -> StorageResult<()>;
+
+    /// Returns all transactions from the journal along with their submission time.
+    fn recorded_transactions(&self) -> StorageResult<Vec<(TxId, Transaction, Tai64)>>;
+
+    /// Records that entries were added to (`blacklisted` is `true`)
+    /// or removed from (`blacklisted` is `false`) the blacklist.
+    fn record_black_list_change(
+        &self,
+        entries: &[BlackListEntry],
+        blacklisted: bool,
+    ) -> StorageResult<()>;
+
+    /// Returns all recorded changes of the blacklist.
+    fn recorded_black_list_changes(&self) -> StorageResult<Vec<(BlackListEntry, bool)>>;
+}
+
 /// Trait for getting the latest consensus parameters.
 #[cfg_attr(feature = "test-helpers", mockall::automock)]
 pub trait ConsensusParametersProvider {
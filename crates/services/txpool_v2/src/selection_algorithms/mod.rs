@@ -10,7 +10,12 @@ pub mod ratio_tip_gas;
 
 /// Constraints that the selection algorithm has to respect.
 pub struct Constraints {
+    /// Maximum cumulative gas of the selected transactions.
     pub max_gas: u64,
+    /// Maximum number of the selected transactions.
+    pub maximum_txs: u16,
+    /// Maximum cumulative size in bytes of the selected transactions.
+    pub maximum_block_size: u32,
 }
 
 /// The selection algorithm is responsible for selecting the best transactions to include in a block.
@@ -36,6 +41,19 @@ pub trait SelectionAlgorithm {
     /// Get less worth transactions iterator
     fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex>;
 
+    /// Returns the minimal tip that a transaction with the `max_gas` and
+    /// the `metered_bytes_size` needs to be selected within the `available_gas`
+    /// ahead of the transactions from the `storage`.
+    /// Returns `None` if the algorithm orders transactions by their size,
+    /// but the `metered_bytes_size` is not provided.
+    fn tip_to_outbid(
+        &self,
+        available_gas: u64,
+        max_gas: u64,
+        metered_bytes_size: Option<u64>,
+        storage: &Self::Storage,
+    ) -> Option<u64>;
+
     /// Inform the selection algorithm that a transaction was removed from the pool.
     fn on_removed_transaction(&mut self, storage_entry: &StorageData);
 }
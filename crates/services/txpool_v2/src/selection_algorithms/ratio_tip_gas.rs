@@ -3,15 +3,28 @@ use std::{
         Ordering,
         Reverse,
     },
-    collections::BTreeMap,
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
     fmt::Debug,
     time::Instant,
 };
 
-use fuel_core_types::fuel_tx::TxId;
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        TxId,
+    },
+    services::txpool::PoolTransaction,
+};
 use num_rational::Ratio;
 
 use crate::{
+    config::{
+        SelectionConfig,
+        SelectionStrategy,
+    },
     error::Error,
     storage::{
         RemovedTransactions,
@@ -39,20 +52,23 @@ pub trait RatioTipGasSelectionAlgorithmStorage {
     fn remove(&mut self, index: &Self::StorageIndex) -> Option<StorageData>;
 }
 
-pub type RatioTipGas = Ratio<u64>;
+pub type Priority = Ratio<u64>;
 
-/// Key used to sort transactions by tip/gas ratio.
-/// It first compares the tip/gas ratio, then the creation instant and finally the transaction id.
+/// The priority of the transactions with the default [`SelectionStrategy::RatioTipGas`].
+pub type RatioTipGas = Priority;
+
+/// Key used to sort transactions by their priority.
+/// It first compares the priority, then the creation instant and finally the transaction id.
 #[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub struct Key {
-    ratio: RatioTipGas,
+    priority: Priority,
     creation_instant: Instant,
     tx_id: TxId,
 }
 
 impl Ord for Key {
     fn cmp(&self, other: &Self) -> Ordering {
-        let cmp = self.ratio.cmp(&other.ratio);
+        let cmp = self.priority.cmp(&other.priority);
         if cmp == Ordering::Equal {
             let instant_cmp = other.creation_instant.cmp(&self.creation_instant);
             if instant_cmp == Ordering::Equal {
@@ -72,36 +88,77 @@ impl PartialOrd for Key {
     }
 }
 
-/// The selection algorithm that selects transactions based on the tip/gas ratio.
+/// The selection algorithm that selects transactions in the order defined by
+/// the [`SelectionStrategy`], by default based on the tip/gas ratio,
+/// and optionally limits the number of transactions per owner.
 pub struct RatioTipGasSelection<S: RatioTipGasSelectionAlgorithmStorage> {
-    executable_transactions_sorted_tip_gas_ratio: BTreeMap<Reverse<Key>, S::StorageIndex>,
+    config: SelectionConfig,
+    executable_transactions_sorted: BTreeMap<Reverse<Key>, S::StorageIndex>,
 }
 
 impl<S: RatioTipGasSelectionAlgorithmStorage> RatioTipGasSelection<S> {
     pub fn new() -> Self {
+        Self::new_with_config(SelectionConfig::default())
+    }
+
+    pub fn new_with_config(config: SelectionConfig) -> Self {
         Self {
-            executable_transactions_sorted_tip_gas_ratio: BTreeMap::new(),
+            config,
+            executable_transactions_sorted: BTreeMap::new(),
         }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.executable_transactions_sorted_tip_gas_ratio.is_empty()
+        self.executable_transactions_sorted.is_empty()
     }
 
-    fn key(store_entry: &StorageData) -> Key {
+    fn key(&self, store_entry: &StorageData) -> Key {
         let transaction = &store_entry.transaction;
-        let tip_gas_ratio = RatioTipGas::new(transaction.tip(), transaction.max_gas());
+        let priority = match self.config.strategy {
+            SelectionStrategy::RatioTipGas => {
+                Priority::new(transaction.tip(), transaction.max_gas())
+            }
+            SelectionStrategy::RatioTipBytes => Priority::new(
+                transaction.tip(),
+                u64::try_from(transaction.metered_bytes_size().max(1))
+                    .unwrap_or(u64::MAX),
+            ),
+            // All transactions have the same priority, so they are sorted
+            // by the creation instant: the oldest transaction is selected first
+            // and the newest transaction is the less worth one.
+            SelectionStrategy::Fifo => Priority::default(),
+        };
 
         Key {
-            ratio: tip_gas_ratio,
+            priority,
             creation_instant: store_entry.creation_instant,
             tx_id: transaction.id(),
         }
     }
 
+    /// Returns the minimal tip that gives the transaction a higher priority than the `priority`.
+    fn tip_above(
+        &self,
+        priority: Priority,
+        max_gas: u64,
+        metered_bytes_size: Option<u64>,
+    ) -> Option<u64> {
+        let units = match self.config.strategy {
+            SelectionStrategy::RatioTipGas => max_gas,
+            SelectionStrategy::RatioTipBytes => metered_bytes_size?.max(1),
+            // The tip doesn't change the position of the transaction.
+            SelectionStrategy::Fifo => return Some(0),
+        };
+
+        let tip = u128::from(*priority.numer())
+            .saturating_mul(u128::from(units))
+            .checked_div(u128::from(*priority.denom()))?
+            .saturating_add(1);
+        Some(u64::try_from(tip).unwrap_or(u64::MAX))
+    }
+
     fn on_removed_transaction_inner(&mut self, key: Key) {
-        self.executable_transactions_sorted_tip_gas_ratio
-            .remove(&Reverse(key));
+        self.executable_transactions_sorted.remove(&Reverse(key));
     }
 }
 
@@ -111,6 +168,19 @@ impl<S: RatioTipGasSelectionAlgorithmStorage> Default for RatioTipGasSelection<S
     }
 }
 
+/// Returns the unique owners of the coins and recipients of the messages
+/// used by the transaction.
+fn owners(transaction: &PoolTransaction) -> Vec<Address> {
+    let mut owners = transaction
+        .inputs()
+        .iter()
+        .filter_map(|input| input.input_owner().copied())
+        .collect::<Vec<_>>();
+    owners.sort();
+    owners.dedup();
+    owners
+}
+
 impl<S: RatioTipGasSelectionAlgorithmStorage> SelectionAlgorithm
     for RatioTipGasSelection<S>
 {
@@ -123,9 +193,13 @@ impl<S: RatioTipGasSelectionAlgorithmStorage> SelectionAlgorithm
         storage: &mut S,
     ) -> Result<RemovedTransactions, Error> {
         let mut gas_left = constraints.max_gas;
+        let mut space_left = constraints.maximum_block_size as usize;
+        let mut nb_left = constraints.maximum_txs;
+        let mut txs_per_owner = HashMap::<Address, u16>::new();
+        let mut stop_selection = false;
         let mut result = Vec::new();
 
-        // Take iterate over all transactions with the highest tip/gas ratio. If transaction
+        // Take iterate over all transactions with the highest priority. If transaction
         // fits in the gas limit select it and mark all its dependents to be promoted.
         // Do that until end of the list or gas limit is reached. If gas limit is not
         // reached, but we have promoted transactions we can start again from the beginning.
@@ -133,13 +207,16 @@ impl<S: RatioTipGasSelectionAlgorithmStorage> SelectionAlgorithm
         // It is done in this way to minimize number of iteration of the list of executable
         // transactions.
         while gas_left > 0
-            && !self.executable_transactions_sorted_tip_gas_ratio.is_empty()
+            && nb_left > 0
+            && space_left > 0
+            && !stop_selection
+            && !self.executable_transactions_sorted.is_empty()
         {
             let mut clean_up_list = Vec::new();
             let mut transactions_to_remove = Vec::new();
             let mut transactions_to_promote = Vec::new();
 
-            for (key, storage_id) in &self.executable_transactions_sorted_tip_gas_ratio {
+            for (key, storage_id) in &self.executable_transactions_sorted {
                 let Some(stored_transaction) = storage.get(storage_id) else {
                     debug_assert!(
                         false,
@@ -152,12 +229,47 @@ impl<S: RatioTipGasSelectionAlgorithmStorage> SelectionAlgorithm
                     continue
                 };
 
-                if stored_transaction.transaction.max_gas() > gas_left {
+                if nb_left == 0 {
+                    break;
+                }
+
+                let less_gas = stored_transaction.transaction.max_gas() <= gas_left;
+                let less_space =
+                    stored_transaction.transaction.metered_bytes_size() <= space_left;
+                if !less_gas || !less_space {
+                    if self.config.strategy == SelectionStrategy::Fifo {
+                        // Newer transactions are not allowed to overtake the older one.
+                        stop_selection = true;
+                        break;
+                    }
                     continue;
                 }
 
+                let owners = owners(&stored_transaction.transaction);
+                if let Some(max_txs_per_owner) = self.config.max_txs_per_owner {
+                    let owner_limit_reached = owners.iter().any(|owner| {
+                        txs_per_owner.get(owner).copied().unwrap_or_default()
+                            >= max_txs_per_owner
+                    });
+                    if owner_limit_reached {
+                        if self.config.strategy == SelectionStrategy::Fifo {
+                            // Newer transactions are not allowed to overtake the older one.
+                            stop_selection = true;
+                            break;
+                        }
+                        continue;
+                    }
+                }
+                for owner in owners {
+                    let count = txs_per_owner.entry(owner).or_default();
+                    *count = count.saturating_add(1);
+                }
+
                 gas_left =
                     gas_left.saturating_sub(stored_transaction.transaction.max_gas());
+                space_left = space_left
+                    .saturating_sub(stored_transaction.transaction.metered_bytes_size());
+                nb_left = nb_left.saturating_sub(1);
 
                 let dependents = storage.get_dependents(storage_id).collect::<Vec<_>>();
                 debug_assert!(!storage.has_dependencies(storage_id));
@@ -207,19 +319,39 @@ impl<S: RatioTipGasSelectionAlgorithmStorage> SelectionAlgorithm
         storage_id: Self::StorageIndex,
         store_entry: &StorageData,
     ) {
-        let key = Self::key(store_entry);
-        self.executable_transactions_sorted_tip_gas_ratio
+        let key = self.key(store_entry);
+        self.executable_transactions_sorted
             .insert(Reverse(key), storage_id);
     }
 
+    /// Returns the transactions in the reverse order of the selection.
+    /// With the [`SelectionStrategy::Fifo`] the newest transactions are returned first.
     fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex> {
-        self.executable_transactions_sorted_tip_gas_ratio
-            .values()
-            .rev()
+        self.executable_transactions_sorted.values().rev()
+    }
+
+    fn tip_to_outbid(
+        &self,
+        available_gas: u64,
+        max_gas: u64,
+        metered_bytes_size: Option<u64>,
+        storage: &S,
+    ) -> Option<u64> {
+        let mut used_gas: u64 = 0;
+        for (Reverse(key), storage_id) in &self.executable_transactions_sorted {
+            let Some(stored_transaction) = storage.get(storage_id) else {
+                continue
+            };
+            used_gas = used_gas.saturating_add(stored_transaction.transaction.max_gas());
+            if used_gas.saturating_add(max_gas) > available_gas {
+                return self.tip_above(key.priority, max_gas, metered_bytes_size)
+            }
+        }
+        Some(0)
     }
 
     fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
-        let key = Self::key(storage_entry);
+        let key = self.key(storage_entry);
         self.on_removed_transaction_inner(key)
     }
 }
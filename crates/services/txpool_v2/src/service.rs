@@ -1,33 +1,76 @@
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
 
+use fuel_core_metrics::txpool_metrics::txpool_metrics;
 use fuel_core_services::{
+    stream::BoxStream,
     RunnableService,
     RunnableTask,
     ServiceRunner,
     StateWatcher,
 };
 use fuel_core_types::{
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
-    services::txpool::PoolTransaction,
+    services::{
+        block_importer::SharedImportResult,
+        p2p::{
+            GossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+            PeerId,
+            TransactionGossipData,
+        },
+        txpool::{
+            ArcPoolTx,
+            InsertionResult as PoolInsertionResult,
+        },
+    },
+    tai64::Tai64,
 };
+use futures::StreamExt;
 use parking_lot::RwLock;
+use tokio::{
+    sync::oneshot,
+    time::MissedTickBehavior,
+};
 
 use crate::{
     collision_manager::basic::BasicCollisionManager,
-    config::Config,
+    config::{
+        BlackList,
+        BlackListEntry,
+        Config,
+    },
     error::Error,
     heavy_async_processing::HeavyAsyncProcessor,
     pool::Pool,
     ports::{
         AtomicView,
+        BlockImporter as BlockImporterTrait,
         ConsensusParametersProvider,
         GasPriceProvider as GasPriceProviderTrait,
         MemoryPool as MemoryPoolTrait,
+        TxPoolJournal as TxPoolJournalTrait,
         TxPoolPersistentStorage,
+        TxStatusNotifier as TxStatusNotifierTrait,
         WasmChecker as WasmCheckerTrait,
+        P2P as P2PTrait,
+    },
+    selection_algorithms::{
+        ratio_tip_gas::RatioTipGasSelection,
+        Constraints,
     },
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelection,
     storage::{
         graph::{
             GraphConfig,
@@ -38,7 +81,7 @@ use crate::{
     verifications::perform_all_verifications,
 };
 
-pub type RemovedTransactions = Vec<PoolTransaction>;
+pub type RemovedTransactions = Vec<ArcPoolTx>;
 pub type InsertionResult = Result<RemovedTransactions, Error>;
 
 pub type TxPool<PSProvider> = Arc<
@@ -53,72 +96,459 @@ pub type TxPool<PSProvider> = Arc<
     >,
 >;
 
+/// Information of a transaction fetched from the txpool.
+#[derive(Debug, Clone)]
+pub struct TxInfo {
+    tx: ArcPoolTx,
+    creation_instant: Instant,
+}
+
+impl TxInfo {
+    pub fn tx(&self) -> &ArcPoolTx {
+        &self.tx
+    }
+
+    pub fn created(&self) -> Instant {
+        self.creation_instant
+    }
+
+    /// The time when the transaction was inserted into the pool.
+    pub fn submitted_time(&self) -> Tai64 {
+        let since_epoch = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Now is bellow of the `UNIX_EPOCH`")
+            .saturating_sub(self.creation_instant.elapsed());
+        Tai64::from_unix(i64::try_from(since_epoch.as_secs()).unwrap_or(i64::MAX))
+    }
+}
+
+/// Transactions in the pool that are directly connected to a transaction.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TxDependencies {
+    /// Transactions whose outputs are spent by the transaction.
+    pub dependencies: Vec<TxId>,
+    /// Transactions that spend outputs of the transaction.
+    pub dependents: Vec<TxId>,
+}
+
 pub struct SharedState<
+    P2P,
     PSProvider,
     ConsensusParamsProvider,
     GasPriceProvider,
     WasmChecker,
     MemoryPool,
+    TxStatusNotifier,
+    Journal,
 > {
     pool: TxPool<PSProvider>,
+    p2p: Arc<P2P>,
     persistent_storage_provider: Arc<PSProvider>,
     current_height: Arc<RwLock<BlockHeight>>,
     consensus_parameters_provider: Arc<ConsensusParamsProvider>,
     gas_price_provider: Arc<GasPriceProvider>,
     wasm_checker: Arc<WasmChecker>,
     memory: Arc<MemoryPool>,
+    tx_status_sender: Arc<TxStatusNotifier>,
+    journal: Arc<Journal>,
     heavy_async_processor: Arc<HeavyAsyncProcessor>,
     utxo_validation: bool,
+    metrics: bool,
+    persist_transactions: bool,
+    persist_black_list: bool,
+    max_txs_ttl: Duration,
 }
 
-impl<PSProvider, ConsensusParamsProvider, GasPriceProvider, WasmChecker, MemoryPool> Clone
+impl<
+        P2P,
+        PSProvider,
+        ConsensusParamsProvider,
+        GasPriceProvider,
+        WasmChecker,
+        MemoryPool,
+        TxStatusNotifier,
+        Journal,
+    > Clone
     for SharedState<
+        P2P,
         PSProvider,
         ConsensusParamsProvider,
         GasPriceProvider,
         WasmChecker,
         MemoryPool,
+        TxStatusNotifier,
+        Journal,
     >
 {
     fn clone(&self) -> Self {
         SharedState {
             pool: self.pool.clone(),
+            p2p: self.p2p.clone(),
             persistent_storage_provider: self.persistent_storage_provider.clone(),
             current_height: self.current_height.clone(),
             consensus_parameters_provider: self.consensus_parameters_provider.clone(),
             gas_price_provider: self.gas_price_provider.clone(),
             wasm_checker: self.wasm_checker.clone(),
             memory: self.memory.clone(),
+            tx_status_sender: self.tx_status_sender.clone(),
+            journal: self.journal.clone(),
             heavy_async_processor: self.heavy_async_processor.clone(),
             utxo_validation: self.utxo_validation,
+            metrics: self.metrics,
+            persist_transactions: self.persist_transactions,
+            persist_black_list: self.persist_black_list,
+            max_txs_ttl: self.max_txs_ttl,
         }
     }
 }
 
 impl<
+        P2P,
         PSProvider,
         PSView,
         ConsensusParamsProvider,
         GasPriceProvider,
         WasmChecker,
         MemoryPool,
+        TxStatusNotifier,
+        Journal,
     >
     SharedState<
+        P2P,
         PSProvider,
         ConsensusParamsProvider,
         GasPriceProvider,
         WasmChecker,
         MemoryPool,
+        TxStatusNotifier,
+        Journal,
     >
 where
+    PSProvider: AtomicView<LatestView = PSView>,
+    PSView: TxPoolPersistentStorage,
+    TxStatusNotifier: TxStatusNotifierTrait,
+    Journal: TxPoolJournalTrait,
+{
+    /// Returns the number of transactions in the pool.
+    pub fn pending_number(&self) -> usize {
+        self.pool.read().tx_count()
+    }
+
+    /// Returns the cumulative max gas of all transactions in the pool.
+    pub fn total_consumable_gas(&self) -> u64 {
+        self.pool.read().current_gas()
+    }
+
+    /// Returns the cumulative size in bytes of all transactions in the pool.
+    pub fn total_bytes_size(&self) -> usize {
+        self.pool.read().current_bytes_size()
+    }
+
+    /// Returns the minimal tip that a transaction with the `max_gas` and
+    /// the `metered_bytes_size` needs to be selected within the `available_gas`
+    /// ahead of the transactions from the pool.
+    /// Returns `None` if the tip can't be estimated without the `metered_bytes_size`.
+    pub fn tip_to_outbid(
+        &self,
+        available_gas: u64,
+        max_gas: u64,
+        metered_bytes_size: Option<u64>,
+    ) -> Option<u64> {
+        self.pool
+            .read()
+            .tip_to_outbid(available_gas, max_gas, metered_bytes_size)
+    }
+
+    /// Returns all transactions from the pool.
+    pub fn get_all_transactions(&self) -> Vec<TxInfo> {
+        self.pool
+            .read()
+            .iter_transactions()
+            .map(|data| TxInfo {
+                tx: data.transaction.clone(),
+                creation_instant: data.creation_instant,
+            })
+            .collect()
+    }
+
+    /// Returns transactions from the pool that are directly connected to the transaction.
+    /// Returns `None` if the transaction is not in the pool.
+    pub fn get_dependencies(&self, tx_id: TxId) -> Option<TxDependencies> {
+        self.pool.read().find_direct_dependencies(&tx_id).map(
+            |(dependencies, dependents)| TxDependencies {
+                dependencies,
+                dependents,
+            },
+        )
+    }
+
+    pub fn find(&self, tx_ids: Vec<TxId>) -> Vec<Option<TxInfo>> {
+        let pool = self.pool.read();
+        tx_ids
+            .iter()
+            .map(|tx_id| {
+                pool.find_one(tx_id).map(|data| TxInfo {
+                    tx: data.transaction.clone(),
+                    creation_instant: data.creation_instant,
+                })
+            })
+            .collect()
+    }
+
+    pub fn find_one(&self, tx_id: TxId) -> Option<TxInfo> {
+        self.find(vec![tx_id]).pop().flatten()
+    }
+
+    /// Returns up to `max_txs` ids of transactions from the pool.
+    pub fn get_tx_ids(&self, max_txs: usize) -> Vec<TxId> {
+        self.pool
+            .read()
+            .iter_tx_ids()
+            .take(max_txs)
+            .copied()
+            .collect()
+    }
+
+    /// Extract the best transactions from the pool for the next block.
+    /// Extracted transactions are removed from the pool.
+    pub fn extract_transactions_for_block(
+        &self,
+        max_gas: u64,
+        maximum_txs: u16,
+        maximum_block_size: u32,
+    ) -> Result<Vec<ArcPoolTx>, Error> {
+        self.pool
+            .write()
+            .extract_transactions_for_block_with_constraints(Constraints {
+                max_gas,
+                maximum_txs,
+                maximum_block_size,
+            })
+    }
+
+    /// Remove transactions with their dependents from the pool
+    /// and notify subscribers about the `reason`.
+    pub fn remove_txs(&self, tx_ids: Vec<(TxId, String)>) -> Vec<ArcPoolTx> {
+        self.remove_txs_with_errors(
+            tx_ids
+                .into_iter()
+                .map(|(tx_id, reason)| (tx_id, Error::SqueezedOut(reason)))
+                .collect(),
+        )
+    }
+
+    /// Remove transactions with their dependents from the pool
+    /// and notify subscribers about the `error` that caused the removal.
+    fn remove_txs_with_errors(&self, tx_ids: Vec<(TxId, Error)>) -> Vec<ArcPoolTx> {
+        let mut removed: Vec<ArcPoolTx> = vec![];
+        let mut pool = self.pool.write();
+        for (tx_id, error) in tx_ids {
+            let removed_txs = pool.remove_transactions(std::iter::once(tx_id));
+            for removed_tx in removed_txs.iter() {
+                let removed_tx_id = removed_tx.id();
+                if removed_tx_id != tx_id {
+                    self.tx_status_sender.send_squeezed_out(
+                        removed_tx_id,
+                        Error::DependencyRemoved {
+                            parent: tx_id,
+                            reason: error.to_string(),
+                        },
+                    );
+                }
+            }
+            if removed_txs.iter().any(|tx| tx.id() == tx_id) {
+                self.tx_status_sender.send_squeezed_out(tx_id, error);
+            }
+            removed.extend(removed_txs);
+        }
+        drop(pool);
+        self.record_removed(removed.iter().map(|tx| tx.id()));
+        removed
+    }
+
+    /// Returns the current blacklist of the pool.
+    pub fn black_list(&self) -> BlackList {
+        self.pool.read().black_list().clone()
+    }
+
+    /// Adds entries to the blacklist and removes transactions that use them
+    /// from the pool along with their dependents.
+    /// Returns the removed transactions.
+    pub fn add_to_black_list(&self, entries: Vec<BlackListEntry>) -> Vec<ArcPoolTx> {
+        let blacklisted = {
+            let mut pool = self.pool.write();
+            let black_list = pool.black_list_mut();
+            for entry in entries.iter() {
+                black_list.insert(*entry);
+            }
+            pool.find_blacklisted_transactions()
+        };
+        self.record_black_list_change(&entries, true);
+        self.remove_txs_with_errors(blacklisted)
+    }
+
+    /// Removes entries from the blacklist.
+    pub fn remove_from_black_list(&self, entries: Vec<BlackListEntry>) {
+        {
+            let mut pool = self.pool.write();
+            let black_list = pool.black_list_mut();
+            for entry in entries.iter() {
+                black_list.remove(entry);
+            }
+        }
+        self.record_black_list_change(&entries, false);
+    }
+
+    fn prune_old_transactions(&self) {
+        let removed = self.pool.write().prune_old_transactions();
+        for tx in removed.iter() {
+            self.tx_status_sender
+                .send_squeezed_out(tx.id(), Error::TTLReason);
+        }
+        self.record_removed(removed.iter().map(|tx| tx.id()));
+    }
+
+    fn process_block(&self, result: SharedImportResult) {
+        let new_height = *result.sealed_block.entity.header().height();
+        {
+            let mut pool = self.pool.write();
+            pool.remove_committed_transactions(
+                result.tx_status.iter().map(|status| status.id),
+            );
+            *self.current_height.write() = new_height;
+        }
+        self.record_removed(result.tx_status.iter().map(|status| status.id));
+    }
+
+    /// Removes transactions that left the pool from the journal.
+    fn record_removed(&self, tx_ids: impl Iterator<Item = TxId>) {
+        if !self.persist_transactions {
+            return
+        }
+        let tx_ids: Vec<_> = tx_ids.collect();
+        if tx_ids.is_empty() {
+            return
+        }
+        if let Err(err) = self.journal.record_removed(&tx_ids) {
+            tracing::error!("Unable to remove transactions from the journal: {err}");
+        }
+    }
+
+    /// Records changes of the blacklist in the journal.
+    fn record_black_list_change(&self, entries: &[BlackListEntry], blacklisted: bool) {
+        if !self.persist_black_list || entries.is_empty() {
+            return
+        }
+        if let Err(err) = self.journal.record_black_list_change(entries, blacklisted) {
+            tracing::error!("Unable to record changes of the blacklist: {err}");
+        }
+    }
+
+    /// Re-applies changes of the blacklist recorded before the restart of the node.
+    fn restore_black_list(&self) {
+        if !self.persist_black_list {
+            return
+        }
+        let changes = match self.journal.recorded_black_list_changes() {
+            Ok(changes) => changes,
+            Err(err) => {
+                tracing::error!("Unable to read changes of the blacklist: {err}");
+                return
+            }
+        };
+
+        let mut pool = self.pool.write();
+        let black_list = pool.black_list_mut();
+        for (entry, blacklisted) in changes {
+            if blacklisted {
+                black_list.insert(entry);
+            } else {
+                black_list.remove(&entry);
+            }
+        }
+    }
+}
+
+impl<
+        P2P,
+        PSProvider,
+        PSView,
+        ConsensusParamsProvider,
+        GasPriceProvider,
+        WasmChecker,
+        MemoryPool,
+        TxStatusNotifier,
+        Journal,
+    >
+    SharedState<
+        P2P,
+        PSProvider,
+        ConsensusParamsProvider,
+        GasPriceProvider,
+        WasmChecker,
+        MemoryPool,
+        TxStatusNotifier,
+        Journal,
+    >
+where
+    P2P: P2PTrait<GossipedTransaction = TransactionGossipData> + 'static,
     PSProvider: AtomicView<LatestView = PSView> + Send + Sync + 'static,
     PSView: TxPoolPersistentStorage,
-    ConsensusParamsProvider: ConsensusParametersProvider + 'static,
+    ConsensusParamsProvider: ConsensusParametersProvider + Send + Sync + 'static,
     GasPriceProvider: GasPriceProviderTrait + Send + Sync + 'static,
     WasmChecker: WasmCheckerTrait + Send + Sync + 'static,
     MemoryPool: MemoryPoolTrait + Send + Sync + 'static,
+    TxStatusNotifier: TxStatusNotifierTrait + 'static,
+    Journal: TxPoolJournalTrait + 'static,
 {
-    async fn insert(&self, transactions: Vec<Transaction>) -> Result<(), Error> {
+    /// Verify and insert transactions into the pool.
+    /// Successfully inserted transactions are broadcasted to the network.
+    pub async fn insert(
+        &self,
+        transactions: Vec<Arc<Transaction>>,
+    ) -> Vec<Result<PoolInsertionResult, Error>> {
+        let mut receivers = Vec::with_capacity(transactions.len());
+        for transaction in transactions {
+            let (sender, receiver) = oneshot::channel();
+            let shared_state = self.clone();
+            let spawned = self.heavy_async_processor.spawn(async move {
+                let result = shared_state.insert_transaction(transaction.clone()).await;
+                if result.is_ok() {
+                    shared_state.broadcast_transaction(transaction);
+                }
+                let _ = sender.send(result);
+            });
+            receivers.push(spawned.map(|_| receiver));
+        }
+
+        let mut results = Vec::with_capacity(receivers.len());
+        for receiver in receivers {
+            let result = match receiver {
+                Ok(receiver) => receiver
+                    .await
+                    .unwrap_or(Err(Error::VerificationInterrupted)),
+                Err(_) => Err(Error::TooManyQueuedTransactions),
+            };
+            results.push(result);
+        }
+        results
+    }
+
+    async fn insert_transaction(
+        &self,
+        transaction: Arc<Transaction>,
+    ) -> Result<PoolInsertionResult, Error> {
+        self.insert_transaction_at(transaction, SystemTime::now())
+            .await
+    }
+
+    /// Verify and insert the transaction into the pool as if
+    /// it was submitted at the `submitted_at` time.
+    async fn insert_transaction_at(
+        &self,
+        transaction: Arc<Transaction>,
+        submitted_at: SystemTime,
+    ) -> Result<PoolInsertionResult, Error> {
         let current_height = *self.current_height.read();
         let (version, params) = self
             .consensus_parameters_provider
@@ -129,105 +559,311 @@ where
             .latest_view()
             .map_err(|e| Error::Database(e.to_string()))?;
 
-        for transaction in transactions {
-            self.heavy_async_processor.spawn({
-                let shared_state = self.clone();
-                let params = params.clone();
-                let view = view.clone();
-                async move {
-                    // TODO: Return the error in the status update channel (see: https://github.com/FuelLabs/fuel-core/issues/2185)
-                    let checked_tx = perform_all_verifications(
-                        transaction,
-                        shared_state.pool.clone(),
-                        current_height,
-                        &params,
-                        version,
-                        shared_state.gas_price_provider.as_ref(),
-                        shared_state.wasm_checker.as_ref(),
-                        shared_state.memory.get_memory().await,
-                        view,
-                    )
-                    .await
-                    .unwrap();
-                    #[allow(unused_variables)]
-                    let result = {
-                        let mut pool = shared_state.pool.write();
-                        // TODO: Return the result of the insertion (see: https://github.com/FuelLabs/fuel-core/issues/2185)
-                        pool.insert(checked_tx)
-                    };
+        let checked_tx = perform_all_verifications(
+            transaction.as_ref().clone(),
+            self.pool.clone(),
+            current_height,
+            &params,
+            version,
+            self.gas_price_provider.as_ref(),
+            self.wasm_checker.as_ref(),
+            self.memory.get_memory().await,
+            view,
+        )
+        .await
+        .inspect_err(|err| self.record_rejected_replacement(err))?;
+        let tx_id = checked_tx.id();
+
+        let (inserted, removed) = {
+            let mut pool = self.pool.write();
+            let age = SystemTime::now()
+                .duration_since(submitted_at)
+                .unwrap_or_default();
+            let creation_instant =
+                Instant::now().checked_sub(age).unwrap_or_else(Instant::now);
+            let removed = pool
+                .insert_created_at(checked_tx, creation_instant)
+                .inspect_err(|err| self.record_rejected_replacement(err))?;
+            let inserted = pool
+                .find_one(&tx_id)
+                .map(|data| data.transaction.clone())
+                .expect("The transaction was inserted above");
+            (inserted, removed)
+        };
+
+        let submitted_time = submitted_at
+            .duration_since(UNIX_EPOCH)
+            .expect("Now is bellow of the `UNIX_EPOCH`");
+        let submitted_tai64 =
+            Tai64::from_unix(i64::try_from(submitted_time.as_secs()).unwrap_or(i64::MAX));
+        self.tx_status_sender.send_submitted(tx_id, submitted_tai64);
+        if self.persist_transactions {
+            if let Err(err) =
+                self.journal
+                    .record_inserted(tx_id, transaction.as_ref(), submitted_tai64)
+            {
+                tracing::error!(
+                    "Unable to record transaction {tx_id} in the journal: {err}"
+                );
+            }
+        }
+        for removed_tx in removed.evicted.iter() {
+            self.tx_status_sender.send_squeezed_out(
+                removed_tx.id(),
+                Error::RemovedForMoreProfitable(tx_id),
+            );
+        }
+        for replaced_tx in removed.replaced.iter() {
+            self.tx_status_sender
+                .send_squeezed_out(replaced_tx.id(), Error::Replaced { by: tx_id });
+        }
+        if self.metrics && !removed.replaced.is_empty() {
+            txpool_metrics()
+                .replaced_txs
+                .inc_by(removed.replaced.len() as u64);
+        }
+        self.record_removed(
+            removed
+                .evicted
+                .iter()
+                .chain(removed.replaced.iter())
+                .map(|tx| tx.id()),
+        );
+
+        Ok(PoolInsertionResult {
+            inserted,
+            submitted_time,
+            removed: removed.into_all(),
+        })
+    }
+
+    /// Re-inserts transactions recorded in the journal before the restart of the node.
+    /// Transactions that outlived `max_txs_ttl` or don't pass the verification
+    /// anymore are removed from the journal.
+    async fn restore_transactions(&self) {
+        if !self.persist_transactions {
+            return
+        }
+        let recorded = match self.journal.recorded_transactions() {
+            Ok(recorded) => recorded,
+            Err(err) => {
+                tracing::error!("Unable to read transactions from the journal: {err}");
+                return
+            }
+        };
+
+        let now = SystemTime::now();
+        let mut restored: usize = 0;
+        let mut outdated = vec![];
+        for (tx_id, transaction, submitted_time) in recorded {
+            let submitted_at = u64::try_from(submitted_time.to_unix())
+                .ok()
+                .and_then(|secs| UNIX_EPOCH.checked_add(Duration::from_secs(secs)))
+                .unwrap_or(UNIX_EPOCH);
+            let age = now.duration_since(submitted_at).unwrap_or_default();
+            if age >= self.max_txs_ttl {
+                outdated.push(tx_id);
+                continue
+            }
+
+            match self
+                .insert_transaction_at(Arc::new(transaction), submitted_at)
+                .await
+            {
+                Ok(_) => restored = restored.saturating_add(1),
+                Err(err) => {
+                    tracing::debug!("Failed to restore transaction {tx_id}: {err}");
+                    outdated.push(tx_id);
                 }
-            });
+            }
+        }
+        self.record_removed(outdated.into_iter());
+        tracing::info!("Restored {restored} transactions from the journal");
+    }
+
+    fn record_rejected_replacement(&self, err: &Error) {
+        if self.metrics && matches!(err, Error::NotInsertedReplacementTipTooLow { .. }) {
+            txpool_metrics().rejected_replacements.inc();
+        }
+    }
+
+    fn broadcast_transaction(&self, transaction: Arc<Transaction>) {
+        if let Err(e) = self.p2p.broadcast_transaction(transaction) {
+            // It can be only in the case of p2p being down or requests overloading it.
+            tracing::error!("Unable to broadcast transaction, got an {} error", e);
+        }
+    }
+
+    async fn insert_gossiped_transaction(&self, data: TransactionGossipData) {
+        let GossipData {
+            data: Some(transaction),
+            message_id,
+            peer_id,
+        } = data
+        else {
+            return
+        };
+
+        let result = self.insert_transaction(Arc::new(transaction)).await;
+        let acceptance = match result {
+            Ok(_) => GossipsubMessageAcceptance::Accept,
+            // Use similar p2p punishment rules as bitcoin
+            // https://github.com/bitcoin/bitcoin/blob/6ff0aa089c01ff3e610ecb47814ed739d685a14c/src/net_processing.cpp#L1856
+            Err(Error::ConsensusValidity(_))
+            | Err(Error::MintIsDisallowed)
+            | Err(Error::NotSupportedTransactionType) => {
+                GossipsubMessageAcceptance::Reject
+            }
+            Err(_) => GossipsubMessageAcceptance::Ignore,
+        };
+
+        let message_info = GossipsubMessageInfo {
+            message_id,
+            peer_id,
+        };
+        let _ = self
+            .p2p
+            .notify_gossip_transaction_validity(message_info, acceptance);
+    }
+
+    async fn new_peer_subscribed(&self, peer_id: PeerId) {
+        let peer_tx_ids = self
+            .p2p
+            .request_tx_ids(peer_id.clone())
+            .await
+            .inspect_err(|e| {
+                tracing::error!("Failed to gather tx ids from peer {}: {}", &peer_id, e);
+            })
+            .unwrap_or_default();
+        let tx_ids_to_ask: Vec<_> = {
+            let pool = self.pool.read();
+            peer_tx_ids
+                .into_iter()
+                .filter(|tx_id| !pool.contains(tx_id))
+                .collect()
+        };
+        if tx_ids_to_ask.is_empty() {
+            return;
+        }
+        let transactions = self
+            .p2p
+            .request_txs(peer_id.clone(), tx_ids_to_ask)
+            .await
+            .inspect_err(|e| {
+                tracing::error!("Failed to gather txs from peer {}: {}", &peer_id, e);
+            })
+            .unwrap_or_default();
+        for transaction in transactions.into_iter().flatten() {
+            if let Err(e) = self.insert_transaction(Arc::new(transaction)).await {
+                tracing::debug!("Failed to insert tx from peer {}: {}", &peer_id, e);
+            }
         }
-        Ok(())
     }
 }
 
 pub type Service<
+    P2P,
     PSProvider,
     ConsensusParamsProvider,
     GasPriceProvider,
     WasmChecker,
     MemoryPool,
+    TxStatusNotifier,
+    Journal,
 > = ServiceRunner<
-    Task<PSProvider, ConsensusParamsProvider, GasPriceProvider, WasmChecker, MemoryPool>,
+    Task<
+        P2P,
+        PSProvider,
+        ConsensusParamsProvider,
+        GasPriceProvider,
+        WasmChecker,
+        MemoryPool,
+        TxStatusNotifier,
+        Journal,
+    >,
 >;
 
 pub struct Task<
+    P2P,
     PSProvider,
     ConsensusParamsProvider,
     GasPriceProvider,
     WasmChecker,
     MemoryPool,
+    TxStatusNotifier,
+    Journal,
 > {
+    committed_block_stream: BoxStream<SharedImportResult>,
+    gossiped_tx_stream: BoxStream<TransactionGossipData>,
+    new_tx_gossip_subscription: BoxStream<PeerId>,
+    ttl_timer: tokio::time::Interval,
     shared_state: SharedState<
+        P2P,
         PSProvider,
         ConsensusParamsProvider,
         GasPriceProvider,
         WasmChecker,
         MemoryPool,
+        TxStatusNotifier,
+        Journal,
     >,
 }
 
 #[async_trait::async_trait]
 impl<
+        P2P,
         PSProvider,
         PSView,
         ConsensusParamsProvider,
         GasPriceProvider,
         WasmChecker,
         MemoryPool,
+        TxStatusNotifier,
+        Journal,
     > RunnableService
     for Task<
+        P2P,
         PSProvider,
         ConsensusParamsProvider,
         GasPriceProvider,
         WasmChecker,
         MemoryPool,
+        TxStatusNotifier,
+        Journal,
     >
 where
-    PSProvider: AtomicView<LatestView = PSView>,
+    P2P: P2PTrait<GossipedTransaction = TransactionGossipData> + 'static,
+    PSProvider: AtomicView<LatestView = PSView> + Send + Sync + 'static,
     PSView: TxPoolPersistentStorage,
-    ConsensusParamsProvider: ConsensusParametersProvider + Send + Sync,
-    GasPriceProvider: GasPriceProviderTrait + Send + Sync,
-    WasmChecker: WasmCheckerTrait + Send + Sync,
-    MemoryPool: MemoryPoolTrait + Send + Sync,
+    ConsensusParamsProvider: ConsensusParametersProvider + Send + Sync + 'static,
+    GasPriceProvider: GasPriceProviderTrait + Send + Sync + 'static,
+    WasmChecker: WasmCheckerTrait + Send + Sync + 'static,
+    MemoryPool: MemoryPoolTrait + Send + Sync + 'static,
+    TxStatusNotifier: TxStatusNotifierTrait + 'static,
+    Journal: TxPoolJournalTrait + 'static,
 {
     const NAME: &'static str = "TxPoolv2";
 
     type SharedData = SharedState<
+        P2P,
         PSProvider,
         ConsensusParamsProvider,
         GasPriceProvider,
         WasmChecker,
         MemoryPool,
+        TxStatusNotifier,
+        Journal,
     >;
 
     type Task = Task<
+        P2P,
         PSProvider,
         ConsensusParamsProvider,
         GasPriceProvider,
         WasmChecker,
         MemoryPool,
+        TxStatusNotifier,
+        Journal,
     >;
 
     type TaskParams = ();
@@ -241,40 +877,92 @@ where
         _: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
+        self.shared_state.restore_black_list();
+        self.shared_state.restore_transactions().await;
+        self.ttl_timer.reset();
         Ok(self)
     }
 }
 
 #[async_trait::async_trait]
 impl<
+        P2P,
         PSProvider,
         PSView,
         ConsensusParamsProvider,
         GasPriceProvider,
         WasmChecker,
         MemoryPool,
+        TxStatusNotifier,
+        Journal,
     > RunnableTask
     for Task<
+        P2P,
         PSProvider,
         ConsensusParamsProvider,
         GasPriceProvider,
         WasmChecker,
         MemoryPool,
+        TxStatusNotifier,
+        Journal,
     >
 where
-    PSProvider: AtomicView<LatestView = PSView>,
+    P2P: P2PTrait<GossipedTransaction = TransactionGossipData> + 'static,
+    PSProvider: AtomicView<LatestView = PSView> + Send + Sync + 'static,
     PSView: TxPoolPersistentStorage,
-    ConsensusParamsProvider: ConsensusParametersProvider + Send + Sync,
-    GasPriceProvider: GasPriceProviderTrait + Send + Sync,
-    WasmChecker: WasmCheckerTrait + Send + Sync,
-    MemoryPool: MemoryPoolTrait + Send + Sync,
+    ConsensusParamsProvider: ConsensusParametersProvider + Send + Sync + 'static,
+    GasPriceProvider: GasPriceProviderTrait + Send + Sync + 'static,
+    WasmChecker: WasmCheckerTrait + Send + Sync + 'static,
+    MemoryPool: MemoryPoolTrait + Send + Sync + 'static,
+    TxStatusNotifier: TxStatusNotifierTrait + 'static,
+    Journal: TxPoolJournalTrait + 'static,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
         let should_continue;
         tokio::select! {
+            biased;
+
             _ = watcher.while_started() => {
                 should_continue = false;
             }
+
+            block_result = self.committed_block_stream.next() => {
+                if let Some(result) = block_result {
+                    self.shared_state.process_block(result);
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+
+            _ = self.ttl_timer.tick() => {
+                self.shared_state.prune_old_transactions();
+                should_continue = true;
+            }
+
+            new_transaction = self.gossiped_tx_stream.next() => {
+                if let Some(data) = new_transaction {
+                    // If we are out of capacity, we will skip this transaction.
+                    let shared_state = self.shared_state.clone();
+                    let _ = self.shared_state.heavy_async_processor.spawn(async move {
+                        shared_state.insert_gossiped_transaction(data).await;
+                    });
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+
+            new_peer_subscribed = self.new_tx_gossip_subscription.next() => {
+                // If we are out of capacity, we will skip this event.
+                if let Some(peer_id) = new_peer_subscribed {
+                    let shared_state = self.shared_state.clone();
+                    let _ = self.shared_state.heavy_async_processor.spawn(async move {
+                        shared_state.new_peer_subscribed(peer_id).await;
+                    });
+                }
+                should_continue = true;
+            }
         }
         Ok(should_continue)
     }
@@ -284,39 +972,78 @@ where
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn new_service<
+    P2P,
+    BlockImporter,
     PSProvider,
     PSView,
     ConsensusParamsProvider,
     GasPriceProvider,
     WasmChecker,
     MemoryPool,
+    TxStatusNotifier,
+    Journal,
 >(
     config: Config,
+    p2p: P2P,
+    block_importer: BlockImporter,
     ps_provider: PSProvider,
     consensus_parameters_provider: ConsensusParamsProvider,
     current_height: BlockHeight,
     gas_price_provider: GasPriceProvider,
     wasm_checker: WasmChecker,
     memory_pool: MemoryPool,
-) -> Service<PSProvider, ConsensusParamsProvider, GasPriceProvider, WasmChecker, MemoryPool>
+    tx_status_sender: TxStatusNotifier,
+    journal: Journal,
+) -> Service<
+    P2P,
+    PSProvider,
+    ConsensusParamsProvider,
+    GasPriceProvider,
+    WasmChecker,
+    MemoryPool,
+    TxStatusNotifier,
+    Journal,
+>
 where
-    PSProvider: AtomicView<LatestView = PSView> + Clone + Send + Sync,
+    P2P: P2PTrait<GossipedTransaction = TransactionGossipData> + 'static,
+    BlockImporter: BlockImporterTrait,
+    PSProvider: AtomicView<LatestView = PSView> + Clone + Send + Sync + 'static,
     PSView: TxPoolPersistentStorage,
-    ConsensusParamsProvider: ConsensusParametersProvider + Send + Sync,
-    GasPriceProvider: GasPriceProviderTrait + Send + Sync,
-    WasmChecker: WasmCheckerTrait + Send + Sync,
-    MemoryPool: MemoryPoolTrait + Send + Sync,
+    ConsensusParamsProvider: ConsensusParametersProvider + Send + Sync + 'static,
+    GasPriceProvider: GasPriceProviderTrait + Send + Sync + 'static,
+    WasmChecker: WasmCheckerTrait + Send + Sync + 'static,
+    MemoryPool: MemoryPoolTrait + Send + Sync + 'static,
+    TxStatusNotifier: TxStatusNotifierTrait + 'static,
+    Journal: TxPoolJournalTrait + 'static,
 {
+    let committed_block_stream = block_importer.block_events();
+    let gossiped_tx_stream = p2p.gossiped_transaction_events();
+    let new_tx_gossip_subscription = p2p.subscribe_new_peers();
+    let mut ttl_timer = tokio::time::interval(config.max_txs_ttl);
+    ttl_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
     Service::new(Task {
+        committed_block_stream,
+        gossiped_tx_stream,
+        new_tx_gossip_subscription,
+        ttl_timer,
         shared_state: SharedState {
+            p2p: Arc::new(p2p),
             persistent_storage_provider: Arc::new(ps_provider.clone()),
             consensus_parameters_provider: Arc::new(consensus_parameters_provider),
             gas_price_provider: Arc::new(gas_price_provider),
             wasm_checker: Arc::new(wasm_checker),
             memory: Arc::new(memory_pool),
+            tx_status_sender: Arc::new(tx_status_sender),
+            journal: Arc::new(journal),
             current_height: Arc::new(RwLock::new(current_height)),
             utxo_validation: config.utxo_validation,
+            metrics: config.metrics,
+            persist_transactions: config.persist_transactions,
+            persist_black_list: config.persist_black_list,
+            max_txs_ttl: config.max_txs_ttl,
             heavy_async_processor: Arc::new(
                 HeavyAsyncProcessor::new(
                     config.heavy_work.number_threads_to_verify_transactions,
@@ -330,7 +1057,7 @@ where
                     max_txs_chain_count: config.max_txs_chain_count,
                 }),
                 BasicCollisionManager::new(),
-                RatioTipGasSelection::new(),
+                RatioTipGasSelection::new_with_config(config.selection.clone()),
                 config,
             ))),
         },
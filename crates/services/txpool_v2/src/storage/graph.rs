@@ -4,6 +4,7 @@ use std::{
         HashSet,
         VecDeque,
     },
+    sync::Arc,
     time::Instant,
 };
 
@@ -390,7 +391,7 @@ impl Storage for GraphStorage {
             dependents_cumulative_tip: tip,
             dependents_cumulative_gas: gas,
             dependents_cumulative_bytes_size: size,
-            transaction,
+            transaction: Arc::new(transaction),
             creation_instant,
             number_dependents_in_chain: 1,
         };
@@ -485,6 +486,20 @@ impl Storage for GraphStorage {
         self.get_direct_dependencies(*index).next().is_some()
     }
 
+    fn get_direct_dependents(
+        &self,
+        index: Self::StorageIndex,
+    ) -> impl Iterator<Item = Self::StorageIndex> {
+        GraphStorage::get_direct_dependents(self, index)
+    }
+
+    fn get_direct_dependencies(
+        &self,
+        index: Self::StorageIndex,
+    ) -> impl Iterator<Item = Self::StorageIndex> {
+        GraphStorage::get_direct_dependencies(self, index)
+    }
+
     fn validate_inputs(
         &self,
         transaction: &PoolTransaction,
@@ -564,6 +579,26 @@ impl Storage for GraphStorage {
     ) -> RemovedTransactions {
         self.remove_node_and_dependent_sub_graph(index)
     }
+
+    fn remove_transaction_without_dependencies(
+        &mut self,
+        index: Self::StorageIndex,
+    ) -> Result<StorageData, Error> {
+        if Storage::has_dependencies(self, &index) {
+            return Err(Error::Storage(format!(
+                "Node with id {:?} has dependencies and can't be removed alone",
+                index
+            )));
+        }
+
+        // The dependents of the removed transaction don't contribute to the cumulative
+        // values of other transactions via this node, so no other node should be updated.
+        let storage_entry = self.graph.remove_node(index).ok_or_else(|| {
+            Error::Storage(format!("Node with id {:?} not found", index))
+        })?;
+        self.clear_cache(&storage_entry);
+        Ok(storage_entry)
+    }
 }
 
 impl RatioTipGasSelectionAlgorithmStorage for GraphStorage {
@@ -9,7 +9,10 @@ use crate::{
     error::Error,
     ports::TxPoolPersistentStorage,
 };
-use fuel_core_types::services::txpool::PoolTransaction;
+use fuel_core_types::services::txpool::{
+    ArcPoolTx,
+    PoolTransaction,
+};
 
 pub mod checked_collision;
 pub mod graph;
@@ -17,7 +20,7 @@ pub mod graph;
 #[derive(Debug)]
 pub struct StorageData {
     /// The transaction.
-    pub transaction: PoolTransaction,
+    pub transaction: ArcPoolTx,
     /// The cumulative tip of a transaction and all of its children.
     pub dependents_cumulative_tip: u64,
     /// The cumulative gas of a transaction and all of its children.
@@ -76,6 +79,18 @@ pub trait Storage {
     /// Returns `true` if the transaction has dependencies.
     fn has_dependencies(&self, index: &Self::StorageIndex) -> bool;
 
+    /// Returns the transactions that directly depend on the transaction.
+    fn get_direct_dependents(
+        &self,
+        index: Self::StorageIndex,
+    ) -> impl Iterator<Item = Self::StorageIndex>;
+
+    /// Returns the transactions that the transaction directly depends on.
+    fn get_direct_dependencies(
+        &self,
+        index: Self::StorageIndex,
+    ) -> impl Iterator<Item = Self::StorageIndex>;
+
     /// Validate inputs of a transaction.
     fn validate_inputs(
         &self,
@@ -89,4 +104,11 @@ pub trait Storage {
         &mut self,
         index: Self::StorageIndex,
     ) -> RemovedTransactions;
+
+    /// Remove a transaction without touching its dependents.
+    /// The transaction can't have dependencies, otherwise an error is returned.
+    fn remove_transaction_without_dependencies(
+        &mut self,
+        index: Self::StorageIndex,
+    ) -> Result<StorageData, Error>;
 }
@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Instant,
+};
 
 use fuel_core_types::{
     entities::{
@@ -53,7 +56,10 @@ use crate::{
     collision_manager::basic::BasicCollisionManager,
     config::Config,
     error::Error,
-    pool::Pool,
+    pool::{
+        Pool,
+        RemovedOnInsertion,
+    },
     selection_algorithms::ratio_tip_gas::RatioTipGasSelection,
     service::{
         RemovedTransactions,
@@ -118,7 +124,7 @@ impl TestPoolUniverse {
                 max_txs_chain_count: self.config.max_txs_chain_count,
             }),
             BasicCollisionManager::new(),
-            RatioTipGasSelection::new(),
+            RatioTipGasSelection::new_with_config(self.config.selection.clone()),
             self.config.clone(),
         )));
         self.pool = Some(pool.clone());
@@ -165,7 +171,33 @@ impl TestPoolUniverse {
                 MockDb::default(),
             )
             .await?;
-            pool.write().insert(tx)
+            pool.write().insert(tx).map(RemovedOnInsertion::into_all)
+        } else {
+            panic!("Pool needs to be built first");
+        }
+    }
+
+    pub async fn verify_and_insert_created_at(
+        &mut self,
+        tx: Transaction,
+        creation_instant: Instant,
+    ) -> Result<RemovedTransactions, Error> {
+        if let Some(pool) = &self.pool {
+            let tx = perform_all_verifications(
+                tx,
+                pool.clone(),
+                Default::default(),
+                &ConsensusParameters::default(),
+                0,
+                &MockTxPoolGasPrice::new(0),
+                &MockWasmChecker::new(Ok(())),
+                MemoryInstance::new(),
+                MockDb::default(),
+            )
+            .await?;
+            pool.write()
+                .insert_created_at(tx, creation_instant)
+                .map(RemovedOnInsertion::into_all)
         } else {
             panic!("Pool needs to be built first");
         }
@@ -189,7 +221,7 @@ impl TestPoolUniverse {
                 MockDb::default(),
             )
             .await?;
-            pool.write().insert(tx)
+            pool.write().insert(tx).map(RemovedOnInsertion::into_all)
         } else {
             panic!("Pool needs to be built first");
         }
@@ -214,7 +246,7 @@ impl TestPoolUniverse {
                 MockDb::default(),
             )
             .await?;
-            pool.write().insert(tx)
+            pool.write().insert(tx).map(RemovedOnInsertion::into_all)
         } else {
             panic!("Pool needs to be built first");
         }
@@ -2,14 +2,18 @@
 
 use crate::{
     config::{
+        BlackListEntry,
         Config,
         PoolLimits,
+        SelectionConfig,
+        SelectionStrategy,
     },
     error::{
         CollisionReason,
         Error,
     },
     ports::WasmValidityError,
+    selection_algorithms::Constraints,
     tests::{
         context::{
             create_contract_input,
@@ -60,7 +64,13 @@ use fuel_core_types::{
         PredicateVerificationFailed,
     },
 };
-use std::vec;
+use std::{
+    time::{
+        Duration,
+        Instant,
+    },
+    vec,
+};
 
 #[tokio::test]
 async fn insert_one_tx_succeeds() {
@@ -160,6 +170,56 @@ async fn insert__tx_with_blacklisted_message() {
     assert!(matches!(err, Error::BlacklistedMessage(id) if id == nonce));
 }
 
+#[tokio::test]
+async fn find_blacklisted_transactions__returns_pooled_tx_with_newly_blacklisted_owner() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let coin = universe.setup_coin().1;
+    let owner_addr = *coin.input_owner().unwrap();
+    let tx = universe.build_script_transaction(Some(vec![coin]), None, 0);
+    let tx_id = tx.id(&ChainId::default());
+    universe.verify_and_insert(tx).await.unwrap();
+
+    // When
+    let pool = universe.get_pool();
+    let blacklisted = {
+        let mut pool = pool.write();
+        pool.black_list_mut()
+            .insert(BlackListEntry::Owner(owner_addr));
+        pool.find_blacklisted_transactions()
+    };
+
+    // Then
+    assert_eq!(blacklisted.len(), 1);
+    assert_eq!(blacklisted[0].0, tx_id);
+    assert!(matches!(blacklisted[0].1, Error::BlacklistedOwner(id) if id == owner_addr));
+}
+
+#[tokio::test]
+async fn insert__tx_succeeds_after_removal_from_blacklist() {
+    let mut universe = TestPoolUniverse::default();
+
+    // Given
+    let coin = universe.setup_coin().1;
+    let utxo_id = *coin.utxo_id().unwrap();
+    universe.config.black_list.coins.insert(utxo_id);
+    universe.build_pool();
+    let tx = universe.build_script_transaction(Some(vec![coin]), None, 0);
+    universe
+        .get_pool()
+        .write()
+        .black_list_mut()
+        .remove(&BlackListEntry::Coin(utxo_id));
+
+    // When
+    let result = universe.verify_and_insert(tx).await;
+
+    // Then
+    assert!(result.is_ok());
+}
+
 #[tokio::test]
 async fn insert__tx2_succeeds_after_dependent_tx1() {
     let mut universe = TestPoolUniverse::default();
@@ -181,6 +241,32 @@ async fn insert__tx2_succeeds_after_dependent_tx1() {
     assert!(result2.is_ok());
 }
 
+#[tokio::test]
+async fn find_direct_dependencies__returns_parent_and_child_of_dependent_txs() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 0);
+    let tx1_id = tx1.id(&ChainId::default());
+    let input = unset_input.into_input(UtxoId::new(tx1_id, 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 0);
+    let tx2_id = tx2.id(&ChainId::default());
+    universe.verify_and_insert(tx1).await.unwrap();
+    universe.verify_and_insert(tx2).await.unwrap();
+
+    // When
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    let tx1_dependencies = pool.find_direct_dependencies(&tx1_id);
+    let tx2_dependencies = pool.find_direct_dependencies(&tx2_id);
+
+    // Then
+    assert_eq!(tx1_dependencies, Some((vec![], vec![tx2_id])));
+    assert_eq!(tx2_dependencies, Some((vec![tx1_id], vec![])));
+}
+
 #[tokio::test]
 async fn insert__tx2_collided_on_contract_id() {
     let mut universe = TestPoolUniverse::default();
@@ -321,6 +407,57 @@ async fn insert__higher_priced_tx_removes_lower_priced_tx() {
     assert_eq!(result[0].id(), tx_id);
 }
 
+#[tokio::test]
+async fn insert__replacement_with_too_small_tip_bump_returns_error() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        min_tip_bump_percent: 10,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let tx1 =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 100);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2 = universe.build_script_transaction(Some(vec![common_coin]), None, 105);
+    universe.verify_and_insert(tx1).await.unwrap();
+
+    // When
+    let err = universe.verify_and_insert(tx2).await.unwrap_err();
+
+    // Then
+    assert!(matches!(
+        err,
+        Error::NotInsertedReplacementTipTooLow { tx_id, required_tip }
+            if tx_id == tx1_id && required_tip == 110
+    ));
+}
+
+#[tokio::test]
+async fn insert__replacement_with_enough_tip_bump_replaces_tx() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        min_tip_bump_percent: 10,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let tx1 =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 100);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2 = universe.build_script_transaction(Some(vec![common_coin]), None, 110);
+    universe.verify_and_insert(tx1).await.unwrap();
+
+    // When
+    let removed = universe.verify_and_insert(tx2).await.unwrap();
+
+    // Then
+    assert_eq!(removed.len(), 1);
+    assert_eq!(removed[0].id(), tx1_id);
+}
+
 #[tokio::test]
 async fn insert__colliding_dependent_and_underpriced_returns_error() {
     let mut universe = TestPoolUniverse::default();
@@ -752,6 +889,281 @@ async fn get_sorted_out_tx_by_creation_instant() {
     assert_eq!(txs[3].id(), tx4_id, "Fourth should be tx4");
 }
 
+#[tokio::test]
+async fn get_sorted_out_tx__fifo_strategy_ignores_tips() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        selection: SelectionConfig {
+            strategy: SelectionStrategy::Fifo,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 1);
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    let tx3 = universe.build_script_transaction(None, None, 10);
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+    let tx3_id = tx3.id(&ChainId::default());
+
+    universe.verify_and_insert(tx1).await.unwrap();
+    universe.verify_and_insert(tx2).await.unwrap();
+    universe.verify_and_insert(tx3).await.unwrap();
+
+    // When
+    let txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block()
+        .unwrap();
+
+    // Then
+    assert_eq!(txs.len(), 3, "Should have 3 txs");
+    assert_eq!(txs[0].id(), tx1_id, "First should be tx1");
+    assert_eq!(txs[1].id(), tx2_id, "Second should be tx2");
+    assert_eq!(txs[2].id(), tx3_id, "Third should be tx3");
+}
+
+#[tokio::test]
+async fn get_sorted_out_tx__fifo_strategy_stops_at_tx_that_does_not_fit() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        selection: SelectionConfig {
+            strategy: SelectionStrategy::Fifo,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let gas_coin = universe.setup_coin().1;
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .tip(10)
+        .max_fee_limit(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let (_, gas_coin) = universe.setup_coin();
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .tip(10)
+        .max_fee_limit(10)
+        .script_gas_limit(GAS_LIMIT / 10)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    universe.verify_and_insert(tx1).await.unwrap();
+    universe.verify_and_insert(tx2).await.unwrap();
+
+    // When
+    let txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block_with_constraints(Constraints {
+            max_gas: GAS_LIMIT / 2,
+            maximum_txs: u16::MAX,
+            maximum_block_size: u32::MAX,
+        })
+        .unwrap();
+
+    // Then
+    assert!(txs.is_empty(), "tx2 should not overtake tx1");
+}
+
+#[tokio::test]
+async fn get_sorted_out_tx__fifo_strategy_stops_at_tx_of_owner_with_reached_limit() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        selection: SelectionConfig {
+            strategy: SelectionStrategy::Fifo,
+            max_txs_per_owner: Some(1),
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    // All messages have the same recipient.
+    let mut same_owner_txs = vec![];
+    for nonce in 0..2 {
+        let (message, input) = create_message_predicate_from_message(5000, nonce);
+        universe.database_mut().insert_message(message);
+        let tx = universe.build_script_transaction(Some(vec![input]), None, 0);
+        same_owner_txs.push(tx);
+    }
+    let other_owner_tx = universe.build_script_transaction(None, None, 0);
+
+    let tx1_id = same_owner_txs[0].id(&ChainId::default());
+    let tx2_id = same_owner_txs[1].id(&ChainId::default());
+    let other_owner_tx_id = other_owner_tx.id(&ChainId::default());
+
+    for tx in same_owner_txs {
+        universe.verify_and_insert(tx).await.unwrap();
+    }
+    universe.verify_and_insert(other_owner_tx).await.unwrap();
+
+    // When
+    let first_block_txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block()
+        .unwrap();
+    let second_block_txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block()
+        .unwrap();
+
+    // Then
+    assert_eq!(first_block_txs.len(), 1, "Should have 1 tx");
+    assert_eq!(first_block_txs[0].id(), tx1_id, "First should be tx1");
+    assert_eq!(second_block_txs.len(), 2, "Should have 2 txs");
+    assert_eq!(second_block_txs[0].id(), tx2_id, "First should be tx2");
+    assert_eq!(
+        second_block_txs[1].id(),
+        other_owner_tx_id,
+        "Second should be the tx of another owner"
+    );
+}
+
+#[tokio::test]
+async fn insert__fifo_strategy_evicts_newest_tx_when_limit_hit() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        pool_limits: PoolLimits {
+            max_txs: 2,
+            max_bytes_size: 1000000000,
+            max_gas: 100_000_000_000,
+        },
+        selection: SelectionConfig {
+            strategy: SelectionStrategy::Fifo,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 5);
+    let tx2 = universe.build_script_transaction(None, None, 10);
+    let tx3 = universe.build_script_transaction(None, None, 20);
+    let tx2_id = tx2.id(&ChainId::default());
+
+    universe.verify_and_insert(tx1).await.unwrap();
+    universe.verify_and_insert(tx2).await.unwrap();
+
+    // When
+    let removed_txs = universe.verify_and_insert(tx3).await.unwrap();
+
+    // Then
+    assert_eq!(removed_txs.len(), 1);
+    assert_eq!(
+        removed_txs[0].id(),
+        tx2_id,
+        "The newest tx2 should be evicted"
+    );
+}
+
+#[tokio::test]
+async fn get_sorted_out_tx__ratio_tip_bytes_strategy_prefers_smaller_txs() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        selection: SelectionConfig {
+            strategy: SelectionStrategy::RatioTipBytes,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let gas_coin = universe.setup_coin().1;
+    let tx1 = TransactionBuilder::script(vec![], vec![0; 1000])
+        .tip(10)
+        .max_fee_limit(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let tx2 = universe.build_script_transaction(None, None, 9);
+    let tx3 = universe.build_script_transaction(None, None, 20);
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+    let tx3_id = tx3.id(&ChainId::default());
+
+    universe.verify_and_insert(tx1).await.unwrap();
+    universe.verify_and_insert(tx2).await.unwrap();
+    universe.verify_and_insert(tx3).await.unwrap();
+
+    // When
+    let txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block()
+        .unwrap();
+
+    // Then
+    assert_eq!(txs.len(), 3, "Should have 3 txs");
+    assert_eq!(txs[0].id(), tx3_id, "First should be tx3");
+    assert_eq!(txs[1].id(), tx2_id, "Second should be tx2");
+    assert_eq!(txs[2].id(), tx1_id, "Third should be tx1");
+}
+
+#[tokio::test]
+async fn get_sorted_out_tx__respects_max_txs_per_owner() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        selection: SelectionConfig {
+            max_txs_per_owner: Some(1),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    // All messages have the same recipient.
+    let mut same_owner_txs = vec![];
+    for (nonce, tip) in [(0, 1), (1, 3), (2, 2)] {
+        let (message, input) = create_message_predicate_from_message(5000, nonce);
+        universe.database_mut().insert_message(message);
+        let tx = universe.build_script_transaction(Some(vec![input]), None, tip);
+        same_owner_txs.push(tx);
+    }
+    let other_owner_tx = universe.build_script_transaction(None, None, 0);
+
+    let tx2_id = same_owner_txs[1].id(&ChainId::default());
+    let tx3_id = same_owner_txs[2].id(&ChainId::default());
+    let other_owner_tx_id = other_owner_tx.id(&ChainId::default());
+
+    for tx in same_owner_txs {
+        universe.verify_and_insert(tx).await.unwrap();
+    }
+    universe.verify_and_insert(other_owner_tx).await.unwrap();
+
+    // When
+    let first_block_txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block()
+        .unwrap();
+    let second_block_txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block()
+        .unwrap();
+
+    // Then
+    assert_eq!(first_block_txs.len(), 2, "Should have 2 txs");
+    assert_eq!(first_block_txs[0].id(), tx2_id, "First should be tx2");
+    assert_eq!(
+        first_block_txs[1].id(),
+        other_owner_tx_id,
+        "Second should be the tx of another owner"
+    );
+    assert_eq!(second_block_txs.len(), 1, "Should have 1 tx");
+    assert_eq!(second_block_txs[0].id(), tx3_id, "First should be tx3");
+}
+
 #[tokio::test]
 async fn insert_tx_at_least_min_gas_price() {
     // Given
@@ -1233,3 +1645,87 @@ async fn insert__tx_upgrade_with_invalid_wasm() {
         Error::WasmValidity(WasmValidityError::Validity)
     ));
 }
+
+#[tokio::test]
+async fn prune_old_transactions__takes_into_account_the_creation_instant() {
+    let max_txs_ttl = Duration::from_secs(60);
+    let mut universe = TestPoolUniverse::default().config(Config {
+        max_txs_ttl,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let outdated_tx = universe.build_script_transaction(None, None, 0);
+    let outdated_tx_id = outdated_tx.id(&ChainId::default());
+    let fresh_tx = universe.build_script_transaction(None, None, 0);
+    let fresh_tx_id = fresh_tx.id(&ChainId::default());
+    let creation_instant = Instant::now()
+        .checked_sub(max_txs_ttl)
+        .expect("The instant is not too old");
+    universe
+        .verify_and_insert_created_at(outdated_tx, creation_instant)
+        .await
+        .unwrap();
+    universe.verify_and_insert(fresh_tx).await.unwrap();
+
+    // When
+    let pruned = universe.get_pool().write().prune_old_transactions();
+
+    // Then
+    assert_eq!(pruned.len(), 1);
+    assert_eq!(pruned[0].id(), outdated_tx_id);
+    assert!(universe.get_pool().read().contains(&fresh_tx_id));
+}
+
+#[tokio::test]
+async fn tip_to_outbid__scales_ratio_of_first_tx_that_does_not_fit_by_max_gas() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 30);
+    let tx2 = universe.build_script_transaction(None, None, 10);
+    let tx1_id = tx1.id(&ChainId::default());
+    universe.verify_and_insert(tx1).await.unwrap();
+    universe.verify_and_insert(tx2).await.unwrap();
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    let max_gas = pool.find_one(&tx1_id).unwrap().transaction.max_gas();
+
+    // When
+    let tip_to_fit_all = pool.tip_to_outbid(3 * max_gas, max_gas, None);
+    let tip_to_outbid_tx2 = pool.tip_to_outbid(2 * max_gas, max_gas, None);
+    let tip_to_outbid_tx1 = pool.tip_to_outbid(2 * max_gas, 2 * max_gas, None);
+
+    // Then
+    assert_eq!(tip_to_fit_all, Some(0));
+    assert_eq!(tip_to_outbid_tx2, Some(11));
+    assert_eq!(tip_to_outbid_tx1, Some(61));
+}
+
+#[tokio::test]
+async fn tip_to_outbid__requires_size_with_ratio_tip_bytes_strategy() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        selection: SelectionConfig {
+            strategy: SelectionStrategy::RatioTipBytes,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let tx = universe.build_script_transaction(None, None, 10);
+    universe.verify_and_insert(tx).await.unwrap();
+    let pool = universe.get_pool();
+    let pool = pool.read();
+
+    // When
+    let tip_without_size = pool.tip_to_outbid(0, 1, None);
+    let tip_with_size = pool.tip_to_outbid(0, 1, Some(100));
+
+    // Then
+    assert_eq!(tip_without_size, None);
+    assert!(tip_with_size.is_some());
+}
@@ -29,6 +29,7 @@ use fuel_core_types::{
     blockchain::{
         block::Block,
         header::{
+            PartialBlockHeader,
             StateTransitionBytecodeVersion,
             LATEST_STATE_TRANSITION_VERSION,
         },
@@ -508,12 +509,8 @@ where
             gas_price,
         };
 
-        let previous_block_height = if !dry_run {
-            block.header_to_produce.height().pred()
-        } else {
-            // TODO: https://github.com/FuelLabs/fuel-core/issues/2062
-            None
-        };
+        let previous_block_height =
+            self.previous_block_height(&block.header_to_produce, dry_run);
 
         let instance_without_input =
             crate::instance::Instance::new(&self.engine).add_source(source)?;
@@ -605,12 +602,8 @@ where
     where
         TxSource: TransactionsSource + Send + Sync + 'static,
     {
-        let previous_block_height = if !dry_run {
-            block.header_to_produce.height().pred()
-        } else {
-            // TODO: https://github.com/FuelLabs/fuel-core/issues/2062
-            None
-        };
+        let previous_block_height =
+            self.previous_block_height(&block.header_to_produce, dry_run);
         let relayer = self.relayer_view_provider.latest_view()?;
 
         if let Some(previous_block_height) = previous_block_height {
@@ -624,6 +617,25 @@ where
         }
     }
 
+    /// Returns the height of the block on top of which the new block is produced.
+    /// `None` means that the latest state should be used.
+    ///
+    /// The dry run uses the latest state unless it simulates the block in the past.
+    fn previous_block_height(
+        &self,
+        header: &PartialBlockHeader,
+        dry_run: bool,
+    ) -> Option<BlockHeight> {
+        let previous_block_height = header.height().pred();
+
+        if dry_run {
+            let latest_height = self.storage_view_provider.latest_height();
+            previous_block_height.filter(|height| Some(*height) < latest_height)
+        } else {
+            previous_block_height
+        }
+    }
+
     fn native_validate_inner(
         &self,
         block: &Block,
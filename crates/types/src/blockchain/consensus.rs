@@ -24,6 +24,8 @@ pub enum Consensus {
     Genesis(Genesis),
     /// Proof of authority consensus
     PoA(PoAConsensus),
+    /// The block is rebuilt from the DA compressed block
+    DaImport(DaImportConsensus),
 }
 
 impl Consensus {
@@ -39,6 +41,9 @@ impl Consensus {
                 let address = Input::owner(&public_key);
                 Ok(address)
             }
+            Consensus::DaImport(_) => Err(anyhow::anyhow!(
+                "The block imported from the DA doesn't have the block producer signature"
+            )),
         }
     }
 }
@@ -49,6 +54,16 @@ impl Default for Consensus {
     }
 }
 
+/// The seal of the block rebuilt from the DA compressed block. The DA compressed
+/// blocks don't contain the signature of the block producer, so the block can't
+/// be verified by other nodes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DaImportConsensus {
+    /// The hash of the DA compressed block the block was rebuilt from.
+    pub compressed_block_hash: Bytes32,
+}
+
 /// Consensus type that a block is using
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConsensusType {
@@ -7,6 +7,7 @@ use serde::{
 };
 
 use crate::{
+    blockchain::SealedBlock,
     fuel_tx::Transaction,
     fuel_types::BlockHeight,
 };
@@ -74,6 +75,9 @@ pub struct GossipData<T> {
 /// Transactions gossiped by peers for inclusion into a block
 pub type TransactionGossipData = GossipData<Transaction>;
 
+/// Blocks gossiped by peers right after their production
+pub type BlockGossipData = GossipData<SealedBlock>;
+
 #[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// The source of some network data.
 pub struct SourcePeer<T> {
@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 use fuel_core::{
     chain_config::{
         LastBlockConfig,
@@ -348,6 +350,78 @@ async fn missing_first_and_last_parameters_returns_an_error() {
     assert!(result.contains("The queries for the whole range is not supported"));
 }
 
+#[tokio::test]
+async fn subscribe_blocks__replays_old_blocks_and_continues_with_new_ones() {
+    use futures::StreamExt;
+
+    let node = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(node.bound_address);
+
+    // given
+    client.produce_blocks(3, None).await.unwrap();
+
+    // when
+    let mut stream = client.subscribe_blocks(Some(2.into())).await.unwrap();
+    let mut heights = vec![];
+    for _ in 0..2 {
+        let block = stream.next().await.unwrap().unwrap();
+        heights.push(block.header.height);
+    }
+    client.produce_blocks(2, None).await.unwrap();
+    for _ in 0..2 {
+        let block = stream.next().await.unwrap().unwrap();
+        heights.push(block.header.height);
+    }
+
+    // then
+    assert_eq!(heights, vec![2, 3, 4, 5]);
+}
+
+#[tokio::test]
+async fn subscribe_blocks__replays_old_blocks_in_several_batches() {
+    use futures::StreamExt;
+
+    let node = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(node.bound_address);
+
+    // given
+    client.produce_blocks(150, None).await.unwrap();
+
+    // when
+    let stream = client.subscribe_blocks(Some(1.into())).await.unwrap();
+    let heights = stream
+        .take(150)
+        .map(|block| block.unwrap().header.height)
+        .collect::<Vec<_>>()
+        .await;
+
+    // then
+    assert_eq!(heights, (1..=150).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn subscribe_block_headers__without_height_returns_only_new_blocks() {
+    use futures::StreamExt;
+
+    let node = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(node.bound_address);
+    client.produce_blocks(3, None).await.unwrap();
+
+    // given
+    let mut stream = client.subscribe_block_headers(None).await.unwrap();
+
+    // when
+    let (header, _) = tokio::join!(stream.next(), async {
+        // The subscription is established on the first poll of the stream.
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        client.produce_blocks(1, None).await.unwrap();
+    });
+
+    // then
+    let header = header.unwrap().unwrap();
+    assert_eq!(header.height, 4);
+}
+
 mod full_block {
     use super::*;
     use cynic::QueryBuilder;
@@ -0,0 +1,87 @@
+#![allow(non_snake_case)]
+
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    service::{
+        Config,
+        FuelService,
+    },
+    types::fuel_tx::Transaction,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::fuel_tx::UniqueIdentifier;
+
+const ADMIN_TOKEN: &str = "admin-token";
+const CAPACITY: usize = 1024 * 1024;
+
+fn config() -> Config {
+    let mut config = Config::local_node();
+    config.graphql_config.admin_token = Some(ADMIN_TOKEN.to_string());
+    config
+}
+
+#[tokio::test]
+async fn create_database_checkpoint__node_starts_from_checkpoint() {
+    // Given
+    let db_dir = tempfile::TempDir::new().unwrap();
+    let checkpoint_dir = tempfile::TempDir::new().unwrap();
+    let checkpoint_path = checkpoint_dir.path().join("checkpoint");
+    let database =
+        CombinedDatabase::open(db_dir.path(), CAPACITY, Default::default()).unwrap();
+    let service = FuelService::from_combined_database(database, config())
+        .await
+        .unwrap();
+    let client = FuelClient::from(service.bound_address).with_admin_token(ADMIN_TOKEN);
+    let tx = Transaction::default_test_tx();
+    let chain_id = client
+        .chain_info()
+        .await
+        .unwrap()
+        .consensus_parameters
+        .chain_id();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    // When
+    let height = client
+        .create_database_checkpoint(checkpoint_path.to_str().unwrap())
+        .await
+        .unwrap()
+        .expect("The checkpoint should have a height");
+
+    // Then
+    let database =
+        CombinedDatabase::open(&checkpoint_path, CAPACITY, Default::default()).unwrap();
+    let heights = database.heights().unwrap();
+    assert_eq!(heights.on_chain, Some((*height).into()));
+    assert!(heights.off_chain <= Some((*height).into()));
+    let restored = FuelService::from_combined_database(database, config())
+        .await
+        .unwrap();
+    let restored_client = FuelClient::from(restored.bound_address);
+    let chain_info = restored_client.chain_info().await.unwrap();
+    assert!(chain_info.latest_block.header.height >= *height);
+    let restored_tx = restored_client
+        .transaction(&tx.id(&chain_id))
+        .await
+        .unwrap();
+    assert!(restored_tx.is_some());
+}
+
+#[tokio::test]
+async fn create_database_checkpoint__requires_admin_token() {
+    // Given
+    let database = CombinedDatabase::default();
+    let service = FuelService::from_combined_database(database, config())
+        .await
+        .unwrap();
+    let client = FuelClient::from(service.bound_address);
+    let checkpoint_dir = tempfile::TempDir::new().unwrap();
+
+    // When
+    let result = client
+        .create_database_checkpoint(checkpoint_dir.path().to_str().unwrap())
+        .await;
+
+    // Then
+    assert!(result.is_err());
+}
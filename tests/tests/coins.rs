@@ -12,7 +12,10 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::{
-    types::CoinType,
+    types::{
+        CoinSelectionStrategy,
+        CoinType,
+    },
     FuelClient,
 };
 use fuel_core_types::fuel_tx::*;
@@ -22,6 +25,8 @@ use rand::{
 };
 
 mod coin {
+    #![allow(non_snake_case)]
+
     use super::*;
     use fuel_core::chain_config::CoinConfigGenerator;
     use fuel_core_client::client::types::CoinType;
@@ -144,6 +149,66 @@ mod coin {
         assert!(remaining_coins_b.is_err())
     }
 
+    #[tokio::test]
+    async fn coins_to_spend_with_change__smallest_first_returns_change() {
+        // Given
+        let owner = Address::from([5; 32]);
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let context = setup(owner, asset_id_a, asset_id_b).await;
+
+        // When
+        let selections = context
+            .client
+            .coins_to_spend_with_change(
+                &owner,
+                vec![(asset_id_a, 120, None)],
+                None,
+                CoinSelectionStrategy::SmallestFirst,
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(selections.len(), 1);
+        let selection = &selections[0];
+        assert_eq!(selection.asset_id, asset_id_a);
+        let amounts: Vec<_> = selection.coins.iter().map(|c| c.amount()).collect();
+        assert_eq!(amounts, vec![50, 100]);
+        assert_eq!(selection.amount, 150);
+        assert_eq!(selection.change, 30);
+    }
+
+    #[tokio::test]
+    async fn coins_to_spend_with_strategy__minimize_inputs_pays_for_inputs() {
+        // Given
+        let owner = Address::from([5; 32]);
+        // The cost per input is paid only by the base asset.
+        let asset_id_a = AssetId::BASE;
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let context = setup(owner, asset_id_a, asset_id_b).await;
+
+        // When
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_with_strategy(
+                &owner,
+                vec![(asset_id_a, 140, None)],
+                None,
+                CoinSelectionStrategy::MinimizeInputs,
+                Some(20),
+            )
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(coins_per_asset.len(), 1);
+        // The largest coin of 150 doesn't cover 140 + 20 for its own input.
+        assert_eq!(coins_per_asset[0].len(), 2);
+        assert_eq!(coins_per_asset[0].amount(), 250);
+    }
+
     async fn query_target_1(owner: Address, asset_id_a: AssetId, asset_id_b: AssetId) {
         let context = setup(owner, asset_id_a, asset_id_b).await;
 
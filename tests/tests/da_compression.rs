@@ -4,6 +4,11 @@ use fuel_core::{
     fuel_core_graphql_api::worker_service::DaCompressionConfig,
     p2p_test_helpers::*,
     service::{
+        da_import::{
+            compressed_block_path,
+            DaImportConfig,
+            DaImportSource,
+        },
         Config,
         FuelService,
     },
@@ -25,6 +30,7 @@ use fuel_core_types::{
         Input,
         TransactionBuilder,
     },
+    fuel_types::BlockHeight,
     secrecy::Secret,
 };
 use rand::{
@@ -116,3 +122,96 @@ async fn da_compressed_blocks_are_available_from_non_block_producing_nodes() {
         .expect("Compressed block not available from validator");
     let _: VersionedCompressedBlock = postcard::from_bytes(&block).unwrap();
 }
+
+const IMPORTED_BLOCKS: u32 = 5;
+
+fn compression_config() -> fuel_core_compression::Config {
+    fuel_core_compression::Config {
+        temporal_registry_retention: Duration::from_secs(3600),
+    }
+}
+
+async fn producer_with_compressed_blocks() -> (FuelService, FuelClient) {
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Enabled(compression_config());
+    let producer = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(producer.bound_address);
+    client.produce_blocks(IMPORTED_BLOCKS, None).await.unwrap();
+    (producer, client)
+}
+
+async fn importer(source: DaImportSource) -> (FuelService, FuelClient) {
+    let mut config = Config::local_node();
+    config.da_import = Some(DaImportConfig {
+        source,
+        compression: compression_config(),
+        interval: Duration::from_millis(10),
+    });
+    let importer = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(importer.bound_address);
+    (importer, client)
+}
+
+async fn wait_for_height(client: &FuelClient, height: u32) {
+    tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let chain_info = client.chain_info().await.unwrap();
+            if chain_info.latest_block.header.height >= height {
+                break
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("The blocks were not imported in time");
+}
+
+async fn assert_same_blocks(expected: &FuelClient, actual: &FuelClient) {
+    for height in 0..=IMPORTED_BLOCKS {
+        let height = BlockHeight::new(height);
+        let expected_block = expected.block_by_height(height).await.unwrap().unwrap();
+        let actual_block = actual.block_by_height(height).await.unwrap().unwrap();
+        assert_eq!(expected_block.id, actual_block.id);
+        if *height > 0 {
+            // The imported blocks don't have the signature of the block producer.
+            assert_eq!(actual_block.block_producer(), None);
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn da_import__rebuilds_chain_from_compressed_blocks_of_another_node() {
+    // Given
+    let (producer, producer_client) = producer_with_compressed_blocks().await;
+
+    // When
+    let url = format!("http://{}", producer.bound_address);
+    let (_importer, importer_client) = importer(DaImportSource::Node(url)).await;
+
+    // Then
+    wait_for_height(&importer_client, IMPORTED_BLOCKS).await;
+    assert_same_blocks(&producer_client, &importer_client).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn da_import__rebuilds_chain_from_compressed_blocks_in_directory() {
+    // Given
+    let (_producer, producer_client) = producer_with_compressed_blocks().await;
+    let blocks_dir = tempfile::TempDir::new().unwrap();
+    for height in 1..=IMPORTED_BLOCKS {
+        let block = producer_client
+            .da_compressed_block(BlockHeight::new(height))
+            .await
+            .unwrap()
+            .expect("Unable to get compressed block");
+        std::fs::write(compressed_block_path(blocks_dir.path(), height), block).unwrap();
+    }
+
+    // When
+    let source = DaImportSource::Directory(blocks_dir.path().to_path_buf());
+    let (_importer, importer_client) = importer(source).await;
+
+    // Then
+    wait_for_height(&importer_client, IMPORTED_BLOCKS).await;
+    assert_same_blocks(&producer_client, &importer_client).await;
+}
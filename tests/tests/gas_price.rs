@@ -25,6 +25,7 @@ use fuel_core_gas_price_service::{
         updater_metadata::UpdaterMetadata,
     },
     v0::metadata::V0Metadata,
+    v1::metadata::V1Metadata,
 };
 use fuel_core_poa::Trigger;
 use fuel_core_storage::{
@@ -263,6 +264,91 @@ async fn estimate_gas_price__returns_min_gas_price_if_starting_gas_price_is_zero
     assert_eq!(MIN_GAS_PRICE, actual)
 }
 
+fn tx_with_tip(tip: Word, script_gas_limit: Word) -> Transaction {
+    TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(script_gas_limit)
+        .tip(tip)
+        .max_fee_limit(tip)
+        .add_fee_input()
+        .finalize_as_transaction()
+}
+
+#[tokio::test]
+async fn estimate_tip__returns_percentile_of_tips_in_recent_blocks() {
+    // Given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    for tip in [10, 20, 30] {
+        let tx = tx_with_tip(tip, 10_000);
+        client.submit_and_await_commit(&tx).await.unwrap();
+    }
+
+    // When
+    let lowest = client.estimate_tip(1, Some(0), 10_000, None).await.unwrap();
+    let median = client.estimate_tip(1, None, 10_000, None).await.unwrap();
+    let highest = client
+        .estimate_tip(1, Some(100), 10_000, None)
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(lowest.recent_tip, 10);
+    assert_eq!(median.recent_tip, 20);
+    assert_eq!(highest.recent_tip, 30);
+    assert_eq!(highest.pool_tip, 0);
+    assert_eq!(highest.tip, 30);
+}
+
+#[tokio::test]
+async fn estimate_tip__outbids_pool_transactions_that_do_not_fit_into_target_blocks() {
+    // Given
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let consensus_parameters = client.chain_info().await.unwrap().consensus_parameters;
+    // Two transactions don't fit into one block.
+    let script_gas_limit = consensus_parameters.block_gas_limit() / 3 * 2;
+    client
+        .submit(&tx_with_tip(100, script_gas_limit))
+        .await
+        .unwrap();
+    client
+        .submit(&tx_with_tip(200, script_gas_limit))
+        .await
+        .unwrap();
+
+    let max_gas = consensus_parameters.block_gas_limit() / 10;
+
+    // When
+    let one_block = client.estimate_tip(1, None, max_gas, None).await.unwrap();
+    let double_gas = client
+        .estimate_tip(1, None, 2 * max_gas, None)
+        .await
+        .unwrap();
+    let two_blocks = client.estimate_tip(2, None, max_gas, None).await.unwrap();
+
+    // Then
+    // The transaction with the tip 100 doesn't fit into one block. The required tip
+    // is proportional to the max gas of the estimated transaction.
+    assert!(one_block.pool_tip > 0 && one_block.pool_tip < 100);
+    assert_eq!(one_block.tip, one_block.pool_tip);
+    assert!(double_gas.pool_tip >= 2 * one_block.pool_tip - 1);
+    assert_eq!(two_blocks.pool_tip, 0);
+}
+
+#[tokio::test]
+async fn estimate_tip__fails_for_invalid_percentile() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client.estimate_tip(1, Some(101), 10_000, None).await;
+
+    // Then
+    assert!(result.is_err());
+}
+
 #[tokio::test]
 async fn latest_gas_price__if_node_restarts_gets_latest_value() {
     // given
@@ -418,9 +504,61 @@ async fn startup__can_override_gas_price_values_by_changing_config() {
         l2_block_height,
         l2_block_fullness_threshold_percent,
         ..
-    }) = new_metadata;
+    }) = new_metadata
+    else {
+        panic!("Expected V0 metadata");
+    };
     assert_eq!(exec_gas_price_change_percent, 11);
     assert_eq!(l2_block_fullness_threshold_percent, 22);
     assert_eq!(min_exec_gas_price, 33);
     assert_eq!(l2_block_height, new_height);
 }
+
+#[tokio::test]
+async fn startup__migrates_v0_metadata_to_v1_algorithm() {
+    // given
+    let args = vec!["--debug", "--poa-instant", "true"];
+    let driver = FuelCoreDriver::spawn(&args).await.unwrap();
+    driver.client.produce_blocks(1, None).await.unwrap();
+    let temp_dir = driver.kill().await;
+
+    // when
+    let new_args = vec![
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--gas-price-algorithm",
+        "v1",
+    ];
+    let recovered_driver = FuelCoreDriver::spawn_with_directory(temp_dir, &new_args)
+        .await
+        .unwrap();
+    recovered_driver
+        .client
+        .produce_blocks(1, None)
+        .await
+        .unwrap();
+
+    // then
+    let new_height = 2;
+    let recovered_database = &recovered_driver.node.shared.database;
+    let recovered_view = recovered_database.gas_price().latest_view().unwrap();
+    let new_metadata = recovered_view
+        .storage::<GasPriceMetadata>()
+        .get(&new_height.into())
+        .unwrap()
+        .unwrap()
+        .deref()
+        .clone();
+
+    let UpdaterMetadata::V1(V1Metadata {
+        l2_block_height,
+        da_recorded_block_height,
+        ..
+    }) = new_metadata
+    else {
+        panic!("Expected V1 metadata");
+    };
+    assert_eq!(l2_block_height, new_height);
+    assert_eq!(da_recorded_block_height, 1);
+}
@@ -7,7 +7,10 @@ use fuel_core::{
     },
     types::fuel_tx::Transaction,
 };
-use fuel_core_client::client::FuelClient;
+use fuel_core_client::client::{
+    types::ServiceState,
+    FuelClient,
+};
 
 #[tokio::test]
 async fn health() {
@@ -20,6 +23,54 @@ async fn health() {
     assert!(health);
 }
 
+#[tokio::test]
+async fn node_health() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client
+        .submit_and_await_commit(&Transaction::default_test_tx())
+        .await
+        .unwrap();
+
+    let health = client.node_health().await.unwrap();
+    assert!(health.live);
+    assert!(health.ready);
+    assert!(health
+        .services
+        .iter()
+        .all(|service| service.state == ServiceState::Started));
+    assert!(health
+        .services
+        .iter()
+        .any(|service| service.name == "GraphQL_Off_Chain_Worker"));
+    assert_eq!(health.on_chain_height, Some(1));
+    assert_eq!(health.sync_lag, None);
+    assert_eq!(health.off_chain_lag, Some(0));
+}
+
+#[tokio::test]
+async fn liveness_and_readiness_endpoints() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+
+    let live = reqwest::get(format!("http://{}/health/live", srv.bound_address))
+        .await
+        .unwrap();
+    assert_eq!(live.status(), reqwest::StatusCode::OK);
+
+    let ready = reqwest::get(format!("http://{}/health/ready", srv.bound_address))
+        .await
+        .unwrap();
+    assert_eq!(ready.status(), reqwest::StatusCode::OK);
+    let body: serde_json::Value =
+        serde_json::from_str(&ready.text().await.unwrap()).unwrap();
+    assert_eq!(body["ready"], true);
+    assert_eq!(body["services"]["TxPool"], "Started");
+}
+
 #[cfg(feature = "default")]
 #[tokio::test]
 async fn can_restart_node() {
@@ -5,6 +5,7 @@ mod balances;
 mod blob;
 mod blocks;
 mod chain;
+mod checkpoint;
 mod coin;
 mod coins;
 mod contract;
@@ -21,6 +22,8 @@ mod messages;
 mod metrics;
 mod node_info;
 mod poa;
+mod pruning;
+mod receipts;
 mod recovery;
 mod regenesis;
 #[cfg(feature = "relayer")]
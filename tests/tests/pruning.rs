@@ -0,0 +1,145 @@
+#![allow(non_snake_case)]
+
+use fuel_core::service::{
+    pruning::PruningConfig,
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    types::PrunedBlocks,
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_crypto::SecretKey,
+    fuel_tx::{
+        AssetId,
+        Output,
+        TransactionBuilder,
+        TxId,
+        UniqueIdentifier,
+    },
+    fuel_types::BlockHeight,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::{
+    num::NonZeroU32,
+    time::Duration,
+};
+
+const RETAINED_BLOCKS: u32 = 2;
+
+async fn setup() -> (FuelService, FuelClient) {
+    let mut config = Config::local_node();
+    config.pruning = Some(PruningConfig {
+        retained_blocks: NonZeroU32::new(RETAINED_BLOCKS).unwrap(),
+        interval: Duration::from_millis(10),
+        batch_size: NonZeroU32::new(100).unwrap(),
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    (srv, client)
+}
+
+/// Submits `count` transactions, each of them is included into its own block.
+async fn submit_transactions(client: &FuelClient, count: usize) -> Vec<TxId> {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let chain_id = client
+        .chain_info()
+        .await
+        .unwrap()
+        .consensus_parameters
+        .chain_id();
+    let mut tx_ids = vec![];
+    for _ in 0..count {
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_unsigned_coin_input(
+                SecretKey::random(&mut rng),
+                rng.gen(),
+                1000,
+                AssetId::BASE,
+                Default::default(),
+            )
+            .add_output(Output::change(rng.gen(), 0, AssetId::BASE))
+            .finalize_as_transaction();
+        client.submit_and_await_commit(&tx).await.unwrap();
+        tx_ids.push(tx.id(&chain_id));
+    }
+    tx_ids
+}
+
+async fn wait_for_pruning(client: &FuelClient, last: BlockHeight) -> PrunedBlocks {
+    loop {
+        if let Some(pruned_blocks) = client.pruned_blocks().await.unwrap() {
+            if pruned_blocks.last >= last {
+                return pruned_blocks
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+#[tokio::test]
+async fn pruning__reports_pruned_range_and_keeps_headers() {
+    // Given
+    let (_srv, client) = setup().await;
+    let tx_ids = submit_transactions(&client, 5).await;
+    let latest_height = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+    let last_pruned = BlockHeight::new(latest_height - RETAINED_BLOCKS);
+
+    // When
+    let pruned_blocks = tokio::time::timeout(
+        Duration::from_secs(10),
+        wait_for_pruning(&client, last_pruned),
+    )
+    .await
+    .expect("The pruning service should prune old blocks");
+
+    // Then
+    assert_eq!(pruned_blocks.first, BlockHeight::new(0));
+    assert_eq!(pruned_blocks.last, last_pruned);
+    let header = client.block_by_height(last_pruned).await.unwrap();
+    assert!(
+        header.is_some(),
+        "The header of the pruned block should be available"
+    );
+    let latest_tx = client.transaction(tx_ids.last().unwrap()).await.unwrap();
+    assert!(latest_tx.is_some());
+}
+
+#[tokio::test]
+async fn pruning__querying_pruned_transaction_returns_pruned_error() {
+    // Given
+    let (_srv, client) = setup().await;
+    let tx_ids = submit_transactions(&client, 5).await;
+    let latest_height = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+    let last_pruned = BlockHeight::new(latest_height - RETAINED_BLOCKS);
+    tokio::time::timeout(
+        Duration::from_secs(10),
+        wait_for_pruning(&client, last_pruned),
+    )
+    .await
+    .expect("The pruning service should prune old blocks");
+
+    // When
+    let result = client.transaction(&tx_ids[0]).await;
+
+    // Then
+    let err = result.expect_err("The transaction should be pruned");
+    assert!(err.to_string().contains("pruned"), "{err}");
+}
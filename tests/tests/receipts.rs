@@ -0,0 +1,261 @@
+#![allow(non_snake_case)]
+
+use crate::helpers::{
+    TestContext,
+    TestSetupBuilder,
+};
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::{
+        contract::ReceiptType,
+        ContractReceiptFilter,
+        TransactionStatus,
+    },
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        GTFArgs,
+        RegId,
+    },
+    fuel_tx::{
+        ContractId,
+        Finalizable,
+        Input,
+        Output,
+        Receipt,
+        TransactionBuilder,
+    },
+    fuel_types::canonical::Serialize,
+    fuel_vm::{
+        Call,
+        CallFrame,
+    },
+};
+use std::time::Duration;
+
+/// The contract emits two `Log` receipts and one `LogData` receipt.
+/// The `ra` of all receipts is the first parameter of the call.
+fn logging_contract() -> Vec<u8> {
+    [
+        // Load the first parameter of the call.
+        op::addi(0x10, RegId::FP, CallFrame::a_offset().try_into().unwrap()),
+        op::lw(0x10, 0x10, 0),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::log(0x10, RegId::ONE, RegId::ZERO, RegId::ZERO),
+        op::logd(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect()
+}
+
+async fn call_contract(client: &FuelClient, contract_id: ContractId, param: u64) {
+    let script = [
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let script_data = Call::new(contract_id, param, 0).to_bytes();
+
+    let tx = TransactionBuilder::script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
+
+async fn setup() -> (TestContext, ContractId) {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    let (_, contract_id) = test_builder.setup_contract(logging_contract(), vec![], None);
+    let context = test_builder.finalize().await;
+    (context, contract_id)
+}
+
+fn forward(results: i32) -> PaginationRequest<String> {
+    PaginationRequest {
+        cursor: None,
+        results,
+        direction: PageDirection::Forward,
+    }
+}
+
+#[tokio::test]
+async fn contract_receipts__returns_receipts_of_the_contract_in_order() {
+    let (context, contract_id) = setup().await;
+    let client = &context.client;
+
+    // given
+    call_contract(client, contract_id, 1).await;
+    call_contract(client, contract_id, 2).await;
+
+    // when
+    let filter = ContractReceiptFilter {
+        contract_id,
+        receipt_type: Some(ReceiptType::Log),
+        rb: Some(1),
+        ..Default::default()
+    };
+    let receipts = client
+        .contract_receipts(filter, forward(10))
+        .await
+        .unwrap()
+        .results;
+
+    // then
+    let params: Vec<_> = receipts.iter().map(|r| r.receipt.ra().unwrap()).collect();
+    assert_eq!(params, vec![1, 2]);
+    assert!(receipts[0].block_height < receipts[1].block_height);
+    assert!(receipts
+        .iter()
+        .all(|r| r.receipt.id() == Some(&contract_id)));
+}
+
+#[tokio::test]
+async fn contract_receipts__paginates_over_receipts() {
+    let (context, contract_id) = setup().await;
+    let client = &context.client;
+
+    // given
+    call_contract(client, contract_id, 1).await;
+    let filter = ContractReceiptFilter {
+        contract_id,
+        receipt_type: Some(ReceiptType::Log),
+        ..Default::default()
+    };
+
+    // when
+    let first_page = client
+        .contract_receipts(filter.clone(), forward(1))
+        .await
+        .unwrap();
+    let second_page = client
+        .contract_receipts(
+            filter,
+            PaginationRequest {
+                cursor: first_page.cursor.clone(),
+                results: 1,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // then
+    assert!(first_page.has_next_page);
+    assert_eq!(first_page.results.len(), 1);
+    assert_eq!(second_page.results.len(), 1);
+    assert_eq!(first_page.results[0].receipt.rb(), Some(0));
+    assert_eq!(second_page.results[0].receipt.rb(), Some(1));
+}
+
+#[tokio::test]
+async fn contract_receipts__respects_height_range() {
+    let (context, contract_id) = setup().await;
+    let client = &context.client;
+
+    // given
+    call_contract(client, contract_id, 1).await;
+    call_contract(client, contract_id, 2).await;
+    let all = client
+        .contract_receipts(
+            ContractReceiptFilter {
+                contract_id,
+                receipt_type: Some(ReceiptType::LogData),
+                ..Default::default()
+            },
+            forward(10),
+        )
+        .await
+        .unwrap()
+        .results;
+    let second_block = all[1].block_height;
+
+    // when
+    let receipts = client
+        .contract_receipts(
+            ContractReceiptFilter {
+                contract_id,
+                receipt_type: Some(ReceiptType::LogData),
+                from_height: Some(second_block),
+                to_height: Some(second_block),
+                ..Default::default()
+            },
+            forward(10),
+        )
+        .await
+        .unwrap()
+        .results;
+
+    // then
+    assert_eq!(receipts, vec![all[1].clone()]);
+    assert!(matches!(
+        receipts[0].receipt,
+        Receipt::LogData { ra: 2, .. }
+    ));
+}
+
+#[tokio::test]
+async fn contract_receipts__unknown_contract_has_no_receipts() {
+    let (context, contract_id) = setup().await;
+    let client = &context.client;
+    call_contract(client, contract_id, 1).await;
+
+    // when
+    let filter = ContractReceiptFilter {
+        contract_id: ContractId::from([1; 32]),
+        ..Default::default()
+    };
+    let receipts = client.contract_receipts(filter, forward(10)).await.unwrap();
+
+    // then
+    assert!(receipts.results.is_empty());
+}
+
+#[tokio::test]
+async fn subscribe_contract_receipts__replays_old_receipts_and_continues_with_new_ones() {
+    use futures::StreamExt;
+
+    let (context, contract_id) = setup().await;
+    let client = &context.client;
+
+    // given
+    call_contract(client, contract_id, 1).await;
+    let filter = ContractReceiptFilter {
+        contract_id,
+        receipt_type: Some(ReceiptType::Log),
+        rb: Some(1),
+        from_height: Some(0.into()),
+        ..Default::default()
+    };
+
+    // when
+    let mut stream = client.subscribe_contract_receipts(filter).await.unwrap();
+    let old = stream.next().await.unwrap().unwrap();
+    let (new, _) = tokio::join!(stream.next(), async {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        call_contract(client, contract_id, 2).await;
+    });
+
+    // then
+    let new = new.unwrap().unwrap();
+    assert_eq!(old.receipt.ra(), Some(1));
+    assert_eq!(new.receipt.ra(), Some(2));
+    assert!(old.block_height < new.block_height);
+}
@@ -1,7 +1,21 @@
 #![allow(non_snake_case)]
 
+use fuel_core_client::client::types::{
+    TransactionStatus,
+    TxPoolBlackList,
+};
 use fuel_core_storage::transactional::HistoricalView;
-use fuel_core_types::fuel_types::BlockHeight;
+use fuel_core_types::{
+    fuel_tx::{
+        Finalizable,
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
+};
 use proptest::{
     prelude::{
         Just,
@@ -64,6 +78,44 @@ async fn off_chain_worker_can_recover_on_start_up_when_is_behind() -> anyhow::Re
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn off_chain_worker_can_reindex_off_chain_database_on_start_up(
+) -> anyhow::Result<()> {
+    let args = [
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "7d",
+    ];
+    let driver = FuelCoreDriver::spawn_feeless(&args).await?;
+
+    // Given
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10_000)
+        .add_fee_input()
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+    driver.client.submit_and_await_commit(&tx).await?;
+    driver.client.produce_blocks(10, None).await?;
+    let height = driver.node.shared.database.on_chain().latest_height();
+    let temp_dir = driver.kill().await;
+
+    // When
+    let reindex_args = [&args[..], &["--reindex-offchain"]].concat();
+    let recovered_driver =
+        FuelCoreDriver::spawn_feeless_with_directory(temp_dir, &reindex_args).await?;
+
+    // Then
+    let recovered_database = &recovered_driver.node.shared.database;
+    assert_eq!(recovered_database.off_chain().latest_height(), height);
+    assert!(!recovered_database.off_chain().is_marked_for_reindex()?);
+    let status = recovered_driver.client.transaction_status(&tx_id).await?;
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+
+    Ok(())
+}
+
 prop_compose! {
     fn height_and_lower_height()(height in 2..100u32)(height in Just(height), lower_height in 1..height) -> (u32, u32) {
         (height, lower_height)
@@ -266,6 +318,115 @@ async fn gas_price_updater__if_no_metadata_history_start_from_current_block(
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn txpool_v2__restores_transactions_after_restart() -> anyhow::Result<()> {
+    let args = [
+        "--debug",
+        "--poa-instant",
+        "false",
+        "--tx-pool-v2",
+        "--tx-pool-persist",
+    ];
+    let driver = FuelCoreDriver::spawn_feeless(&args).await?;
+
+    // Given
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10_000)
+        .add_fee_input()
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+    driver.client.submit(&tx).await?;
+    let temp_dir = driver.kill().await;
+
+    // When
+    let recovered_driver =
+        FuelCoreDriver::spawn_feeless_with_directory(temp_dir, &args).await?;
+
+    // Then
+    let status = recovered_driver.client.transaction_status(&tx_id).await?;
+    assert!(matches!(status, TransactionStatus::Submitted { .. }));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn txpool_v2__restores_transactions_after_reindexing_off_chain_database(
+) -> anyhow::Result<()> {
+    let args = [
+        "--debug",
+        "--poa-instant",
+        "false",
+        "--tx-pool-v2",
+        "--tx-pool-persist",
+        "--state-rewind-duration",
+        "7d",
+    ];
+    let driver = FuelCoreDriver::spawn_feeless(&args).await?;
+
+    // Given
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10_000)
+        .add_fee_input()
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+    driver.client.submit(&tx).await?;
+    let temp_dir = driver.kill().await;
+
+    // When
+    let reindex_args = [&args[..], &["--reindex-offchain"]].concat();
+    let recovered_driver =
+        FuelCoreDriver::spawn_feeless_with_directory(temp_dir, &reindex_args).await?;
+
+    // Then
+    let status = recovered_driver.client.transaction_status(&tx_id).await?;
+    assert!(matches!(status, TransactionStatus::Submitted { .. }));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn txpool_v2__restores_black_list_after_restart() -> anyhow::Result<()> {
+    let args = [
+        "--debug",
+        "--poa-instant",
+        "false",
+        "--tx-pool-v2",
+        "--tx-blacklist-persist",
+        "--graphql-admin-token",
+        "admin-token",
+    ];
+    let driver = FuelCoreDriver::spawn_feeless(&args).await?;
+
+    // Given
+    let black_list = TxPoolBlackList {
+        owners: vec![[1; 32].into()],
+        contracts: vec![[2; 32].into()],
+        ..Default::default()
+    };
+    driver
+        .client
+        .clone()
+        .with_admin_token("admin-token")
+        .add_to_txpool_black_list(black_list.clone())
+        .await?;
+    let temp_dir = driver.kill().await;
+
+    // When
+    let recovered_driver =
+        FuelCoreDriver::spawn_feeless_with_directory(temp_dir, &args).await?;
+
+    // Then
+    let recovered_black_list = recovered_driver
+        .client
+        .clone()
+        .with_admin_token("admin-token")
+        .txpool_black_list()
+        .await?;
+    assert_eq!(recovered_black_list, black_list);
+
+    Ok(())
+}
+
 fn multithreaded_runtime() -> tokio::runtime::Runtime {
     tokio::runtime::Builder::new_multi_thread()
         .enable_all()
@@ -8,6 +8,7 @@ use fuel_core::{
         FuelService,
     },
 };
+use fuel_core_client::client::types::TransactionStatus as ClientTransactionStatus;
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_txpool::types::TxId;
 use fuel_core_types::{
@@ -18,6 +19,7 @@ use fuel_core_types::{
         Transaction,
         TransactionBuilder,
         UniqueIdentifier,
+        UtxoId,
     },
     fuel_types::BlockHeight,
 };
@@ -290,3 +292,87 @@ async fn rollback_to__should_work_with_empty_gas_price_database() -> anyhow::Res
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn coin_at_height__returns_coin_only_after_its_creation() -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "7d",
+    ])
+    .await?;
+    let client = &driver.client;
+
+    // Given
+    let tx = transfer_transaction(123456, &mut rng);
+    let status = client.submit_and_await_commit(&tx).await?;
+    let ClientTransactionStatus::Success { block_height, .. } = status else {
+        panic!("Transaction should succeed: {status:?}");
+    };
+    produce_block_with_tx(&mut rng, client).await;
+    let utxo_id = UtxoId::new(tx.id(&Default::default()), 0);
+    let previous_height = block_height.pred().expect("Not a genesis block");
+
+    // When
+    let coin_before = client.coin_at_height(&utxo_id, previous_height).await?;
+    let coin_after = client.coin_at_height(&utxo_id, block_height).await?;
+
+    // Then
+    assert!(coin_before.is_none());
+    assert_eq!(coin_after.map(|coin| coin.utxo_id), Some(utxo_id));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn coin_at_height__fails_for_height_without_history() -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "0s",
+    ])
+    .await?;
+    let client = &driver.client;
+
+    // Given
+    produce_block_with_tx(&mut rng, client).await;
+    produce_block_with_tx(&mut rng, client).await;
+    let utxo_id = UtxoId::new(Default::default(), 0);
+
+    // When
+    let result = client.coin_at_height(&utxo_id, 1u32.into()).await;
+
+    // Then
+    let err = result.expect_err("The history is not available");
+    assert!(err.to_string().contains("history"), "{err}");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn coin_at_height__fails_for_future_height() -> anyhow::Result<()> {
+    let driver =
+        FuelCoreDriver::spawn_feeless(&["--debug", "--poa-instant", "true"]).await?;
+    let client = &driver.client;
+
+    // Given
+    let latest_height = client.chain_info().await?.latest_block.header.height;
+    let utxo_id = UtxoId::new(Default::default(), 0);
+
+    // When
+    let result = client
+        .coin_at_height(&utxo_id, (latest_height + 10).into())
+        .await;
+
+    // Then
+    let err = result.expect_err("The height is in the future");
+    assert!(err.to_string().contains("higher than the latest"), "{err}");
+
+    Ok(())
+}
@@ -54,6 +54,8 @@ mod predicates;
 mod tx_pointer;
 mod txn_status_subscription;
 mod txpool;
+mod txpool_admin;
+mod txpool_inspection;
 mod upgrade;
 mod utxo_validation;
 
@@ -401,6 +403,83 @@ async fn submit_and_await_status() {
     assert!(matches!(final_status, TransactionStatus::Success { .. }));
 }
 
+#[tokio::test]
+async fn submit_and_await_status_with_txpool_v2() {
+    let mut config = Config::local_node();
+    config.txpool_v2 = Some(fuel_core::txpool_v2::Config {
+        utxo_validation: config.utxo_validation,
+        ..Default::default()
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(1_000_000)
+    .add_fee_input()
+    .finalize_as_transaction();
+
+    let mut status_stream = client.submit_and_await_status(&tx).await.unwrap();
+    let intermediate_status = status_stream.next().await.unwrap().unwrap();
+    assert!(matches!(
+        intermediate_status,
+        TransactionStatus::Submitted { .. }
+    ));
+    let final_status = status_stream.next().await.unwrap().unwrap();
+    assert!(matches!(final_status, TransactionStatus::Success { .. }));
+}
+
+#[tokio::test]
+async fn replaced_transaction_is_squeezed_out_with_txpool_v2() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.txpool_v2 = Some(fuel_core::txpool_v2::Config {
+        utxo_validation: config.utxo_validation,
+        min_tip_bump_percent: 10,
+        ..Default::default()
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let utxo_id: UtxoId = rng.gen();
+    let tx_with_tip = |tip| {
+        TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(10_000)
+            .tip(tip)
+            .max_fee_limit(1_000)
+            .add_unsigned_coin_input(
+                secret,
+                utxo_id,
+                1_000_000,
+                AssetId::BASE,
+                Default::default(),
+            )
+            .finalize_as_transaction()
+    };
+    let old_tx = tx_with_tip(100);
+    let new_tx = tx_with_tip(200);
+    let old_tx_id = old_tx.id(&ChainId::default());
+    let new_tx_id = new_tx.id(&ChainId::default());
+
+    let mut old_tx_statuses = client.submit_and_await_status(&old_tx).await.unwrap();
+    let submitted = old_tx_statuses.next().await.unwrap().unwrap();
+    assert!(matches!(submitted, TransactionStatus::Submitted { .. }));
+
+    // When
+    client.submit(&new_tx).await.unwrap();
+
+    // Then
+    let squeezed_out = old_tx_statuses.next().await.unwrap().unwrap();
+    let TransactionStatus::SqueezedOut { reason } = squeezed_out else {
+        panic!("Expected {old_tx_id} to be squeezed out, got {squeezed_out:?}")
+    };
+    assert!(reason.contains(&format!("replaced by the transaction {new_tx_id}")));
+}
+
 #[tokio::test]
 async fn dry_run_transaction_should_use_latest_block_time() {
     // Given
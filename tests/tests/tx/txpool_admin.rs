@@ -0,0 +1,171 @@
+#![allow(non_snake_case)]
+
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    types::{
+        TransactionStatus,
+        TxPoolBlackList,
+    },
+    FuelClient,
+};
+use fuel_core_poa::Trigger;
+use fuel_core_types::{
+    fuel_crypto::SecretKey,
+    fuel_tx::{
+        AssetId,
+        Input,
+        Transaction,
+        TransactionBuilder,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::ChainId,
+};
+use futures::StreamExt;
+use rand::{
+    prelude::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+const ADMIN_TOKEN: &str = "admin-token";
+
+async fn node_with_admin_api() -> (FuelService, FuelClient) {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.graphql_config.admin_token = Some(ADMIN_TOKEN.to_string());
+    config.txpool_v2 = Some(fuel_core::txpool_v2::Config {
+        utxo_validation: config.utxo_validation,
+        ..Default::default()
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address).with_admin_token(ADMIN_TOKEN);
+    (srv, client)
+}
+
+fn transaction(rng: &mut StdRng, secret: SecretKey) -> Transaction {
+    let utxo_id: UtxoId = rng.gen();
+    TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10_000)
+        .max_fee_limit(1_000)
+        .add_unsigned_coin_input(
+            secret,
+            utxo_id,
+            1_000_000,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .finalize_as_transaction()
+}
+
+#[tokio::test]
+async fn add_to_txpool_black_list__removes_pooled_transaction_of_blacklisted_owner() {
+    let (_srv, client) = node_with_admin_api().await;
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let tx = transaction(&mut rng, secret);
+    let tx_id = tx.id(&ChainId::default());
+    let mut statuses = client.submit_and_await_status(&tx).await.unwrap();
+    let submitted = statuses.next().await.unwrap().unwrap();
+    assert!(matches!(submitted, TransactionStatus::Submitted { .. }));
+
+    // When
+    let removed = client
+        .add_to_txpool_black_list(TxPoolBlackList {
+            owners: vec![owner],
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(removed, vec![tx_id]);
+    let squeezed_out = statuses.next().await.unwrap().unwrap();
+    assert!(matches!(
+        squeezed_out,
+        TransactionStatus::SqueezedOut { .. }
+    ));
+    let black_list = client.txpool_black_list().await.unwrap();
+    assert_eq!(black_list.owners, vec![owner]);
+    let result = client.submit(&transaction(&mut rng, secret)).await;
+    assert!(result.unwrap_err().to_string().contains("is blacklisted"));
+}
+
+#[tokio::test]
+async fn remove_from_txpool_black_list__allows_transactions_of_owner_again() {
+    let (_srv, client) = node_with_admin_api().await;
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let black_list = TxPoolBlackList {
+        owners: vec![Input::owner(&secret.public_key())],
+        ..Default::default()
+    };
+    client
+        .add_to_txpool_black_list(black_list.clone())
+        .await
+        .unwrap();
+
+    // When
+    client
+        .remove_from_txpool_black_list(black_list)
+        .await
+        .unwrap();
+
+    // Then
+    let result = client.submit(&transaction(&mut rng, secret)).await;
+    assert!(result.is_ok());
+    assert_eq!(
+        client.txpool_black_list().await.unwrap(),
+        TxPoolBlackList::default()
+    );
+}
+
+#[tokio::test]
+async fn remove_txpool_transactions__removes_transaction_by_id() {
+    let (_srv, client) = node_with_admin_api().await;
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let tx = transaction(&mut rng, secret);
+    let tx_id = tx.id(&ChainId::default());
+    let mut statuses = client.submit_and_await_status(&tx).await.unwrap();
+    let submitted = statuses.next().await.unwrap().unwrap();
+    assert!(matches!(submitted, TransactionStatus::Submitted { .. }));
+    assert_eq!(
+        client.txpool_transaction_ids(10).await.unwrap(),
+        vec![tx_id]
+    );
+
+    // When
+    let removed = client
+        .remove_txpool_transactions(&[tx_id], Some("Spam".to_string()))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(removed, vec![tx_id]);
+    assert!(client.txpool_transaction_ids(10).await.unwrap().is_empty());
+    let squeezed_out = statuses.next().await.unwrap().unwrap();
+    assert!(matches!(
+        squeezed_out,
+        TransactionStatus::SqueezedOut { reason } if reason.contains("Spam")
+    ));
+}
+
+#[tokio::test]
+async fn txpool_admin_api__rejects_requests_with_invalid_token() {
+    let (srv, _) = node_with_admin_api().await;
+    let client = FuelClient::from(srv.bound_address).with_admin_token("invalid");
+
+    // When
+    let result = client.txpool_black_list().await;
+
+    // Then
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("The admin token is invalid"));
+}
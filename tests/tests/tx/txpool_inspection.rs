@@ -0,0 +1,167 @@
+#![allow(non_snake_case)]
+
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::{
+        TxPoolDependencies,
+        TxPoolOrder,
+    },
+    FuelClient,
+};
+use fuel_core_poa::Trigger;
+use fuel_core_types::{
+    fuel_crypto::SecretKey,
+    fuel_tx::{
+        AssetId,
+        Input,
+        Output,
+        Transaction,
+        TransactionBuilder,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::ChainId,
+};
+use rand::{
+    prelude::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+async fn node_with_txpool_v2() -> (FuelService, FuelClient) {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.txpool_v2 = Some(fuel_core::txpool_v2::Config {
+        utxo_validation: config.utxo_validation,
+        ..Default::default()
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    (srv, client)
+}
+
+fn transaction(secret: SecretKey, utxo_id: UtxoId, amount: u64, tip: u64) -> Transaction {
+    let owner = Input::owner(&secret.public_key());
+    TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10_000)
+        .max_fee_limit(1_000)
+        .tip(tip)
+        .add_unsigned_coin_input(
+            secret,
+            utxo_id,
+            amount,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .add_output(Output::coin(owner, amount / 2, AssetId::BASE))
+        .finalize_as_transaction()
+}
+
+fn all_transactions() -> PaginationRequest<String> {
+    PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    }
+}
+
+#[tokio::test]
+async fn txpool_transactions__returns_pooled_transactions_sorted_by_tip() {
+    let (_srv, client) = node_with_txpool_v2().await;
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let low_tip_tx = transaction(secret, rng.gen(), 1_000_000, 10);
+    let high_tip_tx = transaction(secret, rng.gen(), 1_000_000, 20);
+    client.submit(&low_tip_tx).await.unwrap();
+    client.submit(&high_tip_tx).await.unwrap();
+
+    // When
+    let info = client.txpool_info().await.unwrap();
+    let transactions = client
+        .txpool_transactions(None, TxPoolOrder::Tip, all_transactions())
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(info.count, 2);
+    let ids = transactions
+        .results
+        .iter()
+        .map(|tx| tx.id)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ids,
+        vec![
+            high_tip_tx.id(&ChainId::default()),
+            low_tip_tx.id(&ChainId::default())
+        ]
+    );
+    assert_eq!(transactions.results[0].position, 0);
+    assert_eq!(transactions.results[0].tip, 20);
+}
+
+#[tokio::test]
+async fn txpool_transactions__filters_transactions_by_owner() {
+    let (_srv, client) = node_with_txpool_v2().await;
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let other_secret = SecretKey::random(&mut rng);
+    let tx = transaction(secret, rng.gen(), 1_000_000, 10);
+    let other_tx = transaction(other_secret, rng.gen(), 1_000_000, 20);
+    client.submit(&tx).await.unwrap();
+    client.submit(&other_tx).await.unwrap();
+
+    // When
+    let owner = Input::owner(&secret.public_key());
+    let transactions = client
+        .txpool_transactions(Some(&owner), TxPoolOrder::GasPrice, all_transactions())
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(transactions.results.len(), 1);
+    assert_eq!(transactions.results[0].id, tx.id(&ChainId::default()));
+    assert_eq!(transactions.results[0].position, 1);
+}
+
+#[tokio::test]
+async fn txpool_dependencies__returns_parent_and_child_of_dependent_transactions() {
+    let (_srv, client) = node_with_txpool_v2().await;
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let parent = transaction(secret, rng.gen(), 1_000_000, 0);
+    let parent_id = parent.id(&ChainId::default());
+    let child = transaction(secret, UtxoId::new(parent_id, 0), 500_000, 0);
+    let child_id = child.id(&ChainId::default());
+    client.submit(&parent).await.unwrap();
+    client.submit(&child).await.unwrap();
+
+    // When
+    let parent_dependencies = client.txpool_dependencies(&parent_id).await.unwrap();
+    let child_dependencies = client.txpool_dependencies(&child_id).await.unwrap();
+
+    // Then
+    assert_eq!(
+        parent_dependencies,
+        Some(TxPoolDependencies {
+            dependencies: vec![],
+            dependents: vec![child_id],
+        })
+    );
+    assert_eq!(
+        child_dependencies,
+        Some(TxPoolDependencies {
+            dependencies: vec![parent_id],
+            dependents: vec![],
+        })
+    );
+    let unknown_tx_dependencies = client.txpool_dependencies(&rng.gen()).await.unwrap();
+    assert_eq!(unknown_tx_dependencies, None);
+}